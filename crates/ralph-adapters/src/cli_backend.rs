@@ -1,6 +1,7 @@
 //! CLI backend definitions for different AI tools.
 
 use ralph_core::{CliConfig, HatBackend};
+use std::collections::BTreeMap;
 use std::fmt;
 use std::io::Write;
 use tempfile::NamedTempFile;
@@ -92,6 +93,7 @@ impl CliBackend {
         if backend.command == "codex" {
             Self::reconcile_codex_args(&mut backend.args);
         }
+        backend.apply_env(&config.env);
 
         // Honor command override for named backends (e.g., custom binary path)
         if let Some(ref cmd) = config.command {
@@ -670,14 +672,27 @@ impl CliBackend {
             PromptMode::Arg
         };
 
-        Ok(Self {
+        let mut backend = Self {
             command,
             args: config.args.clone(),
             prompt_mode,
             prompt_flag: config.prompt_flag.clone(),
             output_format: OutputFormat::Text,
             env_vars: vec![],
-        })
+        };
+        backend.apply_env(&config.env);
+        Ok(backend)
+    }
+
+    /// Adds configured `cli.env` variables to the backend's process environment.
+    ///
+    /// Backend-specific variables win; configured entries only fill gaps.
+    pub fn apply_env(&mut self, env: &BTreeMap<String, String>) {
+        for (key, value) in env {
+            if !self.env_vars.iter().any(|(existing, _)| existing == key) {
+                self.env_vars.push((key.clone(), value.clone()));
+            }
+        }
     }
 
     /// Builds roo prompt-file args: writes prompt to a temp file and
@@ -1101,6 +1116,33 @@ mod tests {
         assert!(backend.args.contains(&"--print".to_string()));
     }
 
+    #[test]
+    fn test_from_config_applies_env() {
+        let mut config = CliConfig {
+            backend: "custom".to_string(),
+            command: Some("my-agent".to_string()),
+            ..Default::default()
+        };
+        config
+            .env
+            .insert("API_TOKEN".to_string(), "secret".to_string());
+
+        let backend = CliBackend::from_config(&config).unwrap();
+        assert_eq!(
+            backend.env_vars,
+            vec![("API_TOKEN".to_string(), "secret".to_string())]
+        );
+
+        config.backend = "claude".to_string();
+        config.command = None;
+        let backend = CliBackend::from_config(&config).unwrap();
+        assert!(
+            backend
+                .env_vars
+                .contains(&("API_TOKEN".to_string(), "secret".to_string()))
+        );
+    }
+
     #[test]
     fn test_from_config_command_override() {
         let config = CliConfig {
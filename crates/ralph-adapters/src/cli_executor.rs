@@ -25,8 +25,10 @@ const TERMINATION_GRACE_TIMEOUT: Duration = Duration::from_secs(2);
 /// Result of a CLI execution.
 #[derive(Debug)]
 pub struct ExecutionResult {
-    /// The full output from the CLI.
+    /// The full output from the CLI (stderr lines included with a `[stderr]` prefix).
     pub output: String,
+    /// Stderr lines only, kept separately so failure reasons can be surfaced.
+    pub stderr: String,
     /// Whether the execution succeeded (exit code 0).
     pub success: bool,
     /// The exit code.
@@ -145,6 +147,7 @@ impl CliExecutor {
         let mut stdout_done = stdout_task.is_none();
         let mut stderr_done = stderr_task.is_none();
         let mut accumulated_output = String::new();
+        let mut accumulated_stderr = String::new();
 
         if let Some(duration) = timeout {
             debug!(
@@ -215,6 +218,8 @@ impl CliExecutor {
                     accumulated_output.push_str("[stderr] ");
                     accumulated_output.push_str(&line);
                     accumulated_output.push('\n');
+                    accumulated_stderr.push_str(&line);
+                    accumulated_stderr.push('\n');
                 }
                 Some(StreamEvent::StdoutEof) => stdout_done = true,
                 Some(StreamEvent::StderrEof) => stderr_done = true,
@@ -240,6 +245,7 @@ impl CliExecutor {
 
         Ok(ExecutionResult {
             output: accumulated_output,
+            stderr: accumulated_stderr,
             success: status.success() && !timed_out,
             exit_code: status.code(),
             timed_out,
@@ -432,6 +438,29 @@ mod tests {
         assert_eq!(result.exit_code, Some(1));
     }
 
+    #[tokio::test]
+    async fn test_execute_captures_stderr_separately() {
+        let backend = CliBackend {
+            command: "sh".to_string(),
+            args: vec!["-c".to_string()],
+            prompt_mode: PromptMode::Arg,
+            prompt_flag: None,
+            output_format: OutputFormat::Text,
+            env_vars: vec![],
+        };
+
+        let executor = CliExecutor::new(backend);
+        let result = executor
+            .execute_capture("echo working; echo 'error: invalid API key' >&2; exit 2")
+            .await
+            .unwrap();
+
+        assert!(!result.success);
+        assert_eq!(result.stderr, "error: invalid API key\n");
+        assert!(result.output.contains("working"));
+        assert!(result.output.contains("[stderr] error: invalid API key"));
+    }
+
     #[tokio::test]
     async fn test_execute_timeout() {
         // Use sleep to test timeout behavior
@@ -61,5 +61,5 @@ pub use pty_executor::{
 pub use pty_handle::{ControlCommand, PtyHandle};
 pub use stream_handler::{
     ConsoleStreamHandler, PrettyStreamHandler, QuietStreamHandler, SessionResult, StreamHandler,
-    TuiStreamHandler,
+    SummaryStreamHandler, TuiStreamHandler,
 };
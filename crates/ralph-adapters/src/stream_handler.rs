@@ -16,6 +16,7 @@ use std::{
     borrow::Cow,
     io::{self, Write},
     sync::{Arc, Mutex},
+    time::Instant,
 };
 use termimad::MadSkin;
 
@@ -75,6 +76,23 @@ fn sanitize_tui_inline_text(text: &str) -> String {
     s
 }
 
+/// Prepends `prefix` to the start of every line in `text`.
+///
+/// `at_line_start` indicates whether the previous chunk ended with a newline,
+/// so streamed chunks that continue a line are not prefixed twice.
+fn prefix_lines(text: &str, prefix: &str, at_line_start: bool) -> String {
+    let mut out = String::with_capacity(text.len() + prefix.len());
+    let mut at_line_start = at_line_start;
+    for segment in text.split_inclusive('\n') {
+        if at_line_start {
+            out.push_str(prefix);
+        }
+        out.push_str(segment);
+        at_line_start = segment.ends_with('\n');
+    }
+    out
+}
+
 /// Session completion result data.
 #[derive(Debug, Clone, Default)]
 pub struct SessionResult {
@@ -130,6 +148,8 @@ pub struct PrettyStreamHandler {
     text_buffer: String,
     /// Skin for markdown rendering
     skin: MadSkin,
+    /// Prefix (e.g. `[builder #3] `) written at the start of each line; empty disables it
+    line_prefix: String,
 }
 
 impl PrettyStreamHandler {
@@ -140,17 +160,27 @@ impl PrettyStreamHandler {
             verbose,
             text_buffer: String::new(),
             skin: MadSkin::default(),
+            line_prefix: String::new(),
         }
     }
 
+    /// Prefixes every emitted line with `prefix` (e.g. the active hat and iteration).
+    pub fn with_line_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.line_prefix = prefix.into();
+        self
+    }
+
     /// Flush buffered text as rendered markdown.
     fn flush_text_buffer(&mut self) {
         if self.text_buffer.is_empty() {
             return;
         }
         // Render markdown to string, then write
-        let rendered = self.skin.term_text(&self.text_buffer);
-        let _ = self.stdout.write(rendered.to_string().as_bytes());
+        let mut rendered = self.skin.term_text(&self.text_buffer).to_string();
+        if !self.line_prefix.is_empty() {
+            rendered = prefix_lines(&rendered, &self.line_prefix, true);
+        }
+        let _ = self.stdout.write(rendered.as_bytes());
         let _ = self.stdout.flush();
         self.text_buffer.clear();
     }
@@ -170,9 +200,8 @@ impl StreamHandler for PrettyStreamHandler {
             let _ = self
                 .stdout
                 .queue(style::SetForegroundColor(Color::DarkGrey));
-            let _ = self
-                .stdout
-                .write(format!(" \u{2713} {}\n", truncate(output, 200)).as_bytes());
+            let line = format!("{} \u{2713} {}\n", self.line_prefix, truncate(output, 200));
+            let _ = self.stdout.write(line.as_bytes());
             let _ = self.stdout.queue(style::ResetColor);
             let _ = self.stdout.flush();
         }
@@ -180,9 +209,8 @@ impl StreamHandler for PrettyStreamHandler {
 
     fn on_error(&mut self, error: &str) {
         let _ = self.stdout.queue(style::SetForegroundColor(Color::Red));
-        let _ = self
-            .stdout
-            .write(format!("\n\u{2717} Error: {}\n", error).as_bytes());
+        let line = format!("\n{}\u{2717} Error: {}\n", self.line_prefix, error);
+        let _ = self.stdout.write(line.as_bytes());
         let _ = self.stdout.queue(style::ResetColor);
         let _ = self.stdout.flush();
     }
@@ -199,7 +227,8 @@ impl StreamHandler for PrettyStreamHandler {
         };
         let _ = self.stdout.queue(style::SetForegroundColor(color));
         let summary = format_session_summary(result);
-        let _ = self.stdout.write(format!("{}\n", summary).as_bytes());
+        let line = format!("{}{}\n", self.line_prefix, summary);
+        let _ = self.stdout.write(line.as_bytes());
         let _ = self.stdout.queue(style::ResetColor);
         let _ = self.stdout.flush();
     }
@@ -210,7 +239,8 @@ impl StreamHandler for PrettyStreamHandler {
 
         // ⚙️ [ToolName]
         let _ = self.stdout.queue(style::SetForegroundColor(Color::Blue));
-        let _ = self.stdout.write(format!("\u{2699} [{}]", name).as_bytes());
+        let line = format!("{}\u{2699} [{}]", self.line_prefix, name);
+        let _ = self.stdout.write(line.as_bytes());
 
         if let Some(summary) = format_tool_summary(name, input) {
             let _ = self
@@ -261,6 +291,8 @@ pub struct ConsoleStreamHandler {
     stderr: io::Stderr,
     /// Tracks whether last output ended with a newline
     last_was_newline: bool,
+    /// Prefix (e.g. `[builder #3] `) written at the start of each line; empty disables it
+    line_prefix: String,
 }
 
 impl ConsoleStreamHandler {
@@ -274,9 +306,18 @@ impl ConsoleStreamHandler {
             stdout: io::stdout(),
             stderr: io::stderr(),
             last_was_newline: true, // Start true so first output doesn't get extra newline
+            line_prefix: String::new(),
         }
     }
 
+    /// Prefixes every emitted line with `prefix` (e.g. the active hat and iteration).
+    ///
+    /// Useful for multi-hat loops so interleaved logs show which hat produced them.
+    pub fn with_line_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.line_prefix = prefix.into();
+        self
+    }
+
     /// Ensures output starts on a new line if the previous output didn't end with one.
     fn ensure_newline(&mut self) {
         if !self.last_was_newline {
@@ -288,7 +329,12 @@ impl ConsoleStreamHandler {
 
 impl StreamHandler for ConsoleStreamHandler {
     fn on_text(&mut self, text: &str) {
-        let _ = write!(self.stdout, "{}", text);
+        if self.line_prefix.is_empty() {
+            let _ = write!(self.stdout, "{}", text);
+        } else {
+            let prefixed = prefix_lines(text, &self.line_prefix, self.last_was_newline);
+            let _ = write!(self.stdout, "{}", prefixed);
+        }
         let _ = self.stdout.flush();
         self.last_was_newline = text.ends_with('\n');
     }
@@ -297,10 +343,14 @@ impl StreamHandler for ConsoleStreamHandler {
         self.ensure_newline();
         match format_tool_summary(name, input) {
             Some(summary) => {
-                let _ = writeln!(self.stdout, "[Tool] {}: {}", name, summary);
+                let _ = writeln!(
+                    self.stdout,
+                    "{}[Tool] {}: {}",
+                    self.line_prefix, name, summary
+                );
             }
             None => {
-                let _ = writeln!(self.stdout, "[Tool] {}", name);
+                let _ = writeln!(self.stdout, "{}[Tool] {}", self.line_prefix, name);
             }
         }
         // writeln always ends with newline
@@ -309,21 +359,28 @@ impl StreamHandler for ConsoleStreamHandler {
 
     fn on_tool_result(&mut self, _id: &str, output: &str) {
         if self.verbose {
-            let _ = writeln!(self.stdout, "[Result] {}", truncate(output, 200));
+            let _ = writeln!(
+                self.stdout,
+                "{}[Result] {}",
+                self.line_prefix,
+                truncate(output, 200)
+            );
         }
     }
 
     fn on_error(&mut self, error: &str) {
         // Write to both stdout (inline) and stderr (for separation)
-        let _ = writeln!(self.stdout, "[Error] {}", error);
-        let _ = writeln!(self.stderr, "[Error] {}", error);
+        let _ = writeln!(self.stdout, "{}[Error] {}", self.line_prefix, error);
+        let _ = writeln!(self.stderr, "{}[Error] {}", self.line_prefix, error);
     }
 
     fn on_complete(&mut self, result: &SessionResult) {
         if self.verbose {
             let _ = writeln!(
                 self.stdout,
-                "\n--- Session Complete ---\n{}",
+                "\n{}--- Session Complete ---\n{}{}",
+                self.line_prefix,
+                self.line_prefix,
                 format_session_summary(result)
             );
         }
@@ -341,6 +398,112 @@ impl StreamHandler for QuietStreamHandler {
     fn on_complete(&mut self, _: &SessionResult) {}
 }
 
+/// Suppresses streaming output but prints a one-line iteration summary to stderr.
+///
+/// Gives CI logs signal (hat, events published, duration) without the full
+/// stream. The summary is printed on `on_complete`, or on drop for backends
+/// that never report a session result.
+pub struct SummaryStreamHandler {
+    hat: String,
+    iteration: u32,
+    started: Instant,
+    events: Vec<String>,
+    tool_calls: u32,
+    errors: u32,
+    printed: bool,
+}
+
+impl SummaryStreamHandler {
+    /// Creates a summary handler for one iteration of `hat`.
+    pub fn new(hat: impl Into<String>, iteration: u32) -> Self {
+        Self {
+            hat: hat.into(),
+            iteration,
+            started: Instant::now(),
+            events: Vec::new(),
+            tool_calls: 0,
+            errors: 0,
+            printed: false,
+        }
+    }
+
+    /// Builds the compact summary line for this iteration.
+    fn summary_line(&self, result: Option<&SessionResult>) -> String {
+        let duration_ms = match result {
+            Some(r) if r.duration_ms > 0 => r.duration_ms,
+            _ => self.started.elapsed().as_millis() as u64,
+        };
+        let events = if self.events.is_empty() {
+            "none".to_string()
+        } else {
+            self.events.join(", ")
+        };
+
+        let mut line = format!(
+            "[iter {}] {} | {:.1}s | events: {} | tools: {}",
+            self.iteration,
+            self.hat,
+            duration_ms as f64 / 1000.0,
+            events,
+            self.tool_calls
+        );
+        if let Some(r) = result {
+            line.push_str(&format!(
+                " | turns: {} | est. cost: ${:.4}",
+                r.num_turns, r.total_cost_usd
+            ));
+        }
+        if self.errors > 0 || result.is_some_and(|r| r.is_error) {
+            line.push_str(&format!(" | errors: {}", self.errors.max(1)));
+        }
+        line
+    }
+
+    fn print_summary(&mut self, result: Option<&SessionResult>) {
+        if self.printed {
+            return;
+        }
+        self.printed = true;
+        let _ = writeln!(io::stderr(), "{}", self.summary_line(result));
+    }
+}
+
+/// Extracts the topic from a `ralph emit <topic> ...` shell command, if any.
+fn emitted_topic(input: &serde_json::Value) -> Option<String> {
+    let command = input.get("command")?.as_str()?;
+    let mut words = command.split_whitespace();
+    words.find(|w| *w == "emit")?;
+    let topic = words.next()?.trim_matches(|c| c == '"' || c == '\'');
+    (!topic.is_empty()).then(|| topic.to_string())
+}
+
+impl StreamHandler for SummaryStreamHandler {
+    fn on_text(&mut self, _: &str) {}
+
+    fn on_tool_call(&mut self, _name: &str, _id: &str, input: &serde_json::Value) {
+        self.tool_calls += 1;
+        if let Some(topic) = emitted_topic(input) {
+            self.events.push(topic);
+        }
+    }
+
+    fn on_tool_result(&mut self, _: &str, _: &str) {}
+
+    fn on_error(&mut self, _: &str) {
+        self.errors += 1;
+    }
+
+    fn on_complete(&mut self, result: &SessionResult) {
+        self.print_summary(Some(result));
+    }
+}
+
+impl Drop for SummaryStreamHandler {
+    fn drop(&mut self) {
+        self.print_summary(None);
+    }
+}
+
 /// Converts text to styled ratatui Lines, handling both ANSI and markdown.
 ///
 /// When text contains ANSI escape sequences (e.g., from CLI tools like Kiro),
@@ -659,6 +822,25 @@ mod tests {
         }); // Should be silent
     }
 
+    #[test]
+    fn test_console_handler_with_line_prefix() {
+        let mut handler = ConsoleStreamHandler::new(true).with_line_prefix("[builder #2] ");
+        assert_eq!(handler.line_prefix, "[builder #2] ");
+
+        // These calls should not panic
+        handler.on_text("Hello\nWorld");
+        handler.on_tool_call("Bash", "tool_1", &json!({"command": "ls"}));
+        handler.on_tool_result("tool_1", "output");
+    }
+
+    #[test]
+    fn test_prefix_lines() {
+        assert_eq!(prefix_lines("a\nb\n", "> ", true), "> a\n> b\n");
+        // Continuation of a partial line is not prefixed again
+        assert_eq!(prefix_lines("tail\nnext", "> ", false), "tail\n> next");
+        assert_eq!(prefix_lines("", "> ", true), "");
+    }
+
     #[test]
     fn test_quiet_handler_is_silent() {
         let mut handler = QuietStreamHandler;
@@ -678,6 +860,58 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_summary_handler_collects_emitted_events() {
+        let mut handler = SummaryStreamHandler::new("builder", 3);
+
+        handler.on_text("Working...");
+        handler.on_tool_call("Read", "tool_1", &json!({"file_path": "src/main.rs"}));
+        handler.on_tool_call(
+            "Bash",
+            "tool_2",
+            &json!({"command": "ralph emit \"build.done\" \"tests pass\""}),
+        );
+
+        let line = handler.summary_line(Some(&SessionResult {
+            duration_ms: 12_300,
+            total_cost_usd: 0.0123,
+            num_turns: 4,
+            ..Default::default()
+        }));
+        assert_eq!(
+            line,
+            "[iter 3] builder | 12.3s | events: build.done | tools: 2 | turns: 4 | est. cost: $0.0123"
+        );
+
+        // Printing is idempotent: on_complete prints once, drop does not repeat it.
+        handler.on_complete(&SessionResult::default());
+        assert!(handler.printed);
+    }
+
+    #[test]
+    fn test_summary_handler_reports_errors_without_session_result() {
+        let mut handler = SummaryStreamHandler::new("planner", 1);
+        handler.on_error("boom");
+        let line = handler.summary_line(None);
+        assert!(line.starts_with("[iter 1] planner | "));
+        assert!(line.contains("events: none | tools: 0"));
+        assert!(line.ends_with(" | errors: 1"));
+    }
+
+    #[test]
+    fn test_emitted_topic() {
+        assert_eq!(
+            emitted_topic(&json!({"command": "ralph emit build.done \"ok\""})),
+            Some("build.done".to_string())
+        );
+        assert_eq!(
+            emitted_topic(&json!({"command": "ralph emit 'review.done' --json '{}'"})),
+            Some("review.done".to_string())
+        );
+        assert_eq!(emitted_topic(&json!({"command": "cargo test"})), None);
+        assert_eq!(emitted_topic(&json!({"file_path": "emit"})), None);
+    }
+
     #[test]
     fn test_format_session_summary_table() {
         // Six rows from spec §9 — five rendered rows plus the
@@ -6,14 +6,36 @@ use std::path::{Path, PathBuf};
 
 use chrono::Utc;
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
+use serde_json::{Value, json};
 use tracing::warn;
 
 use crate::errors::ApiError;
 use crate::loop_support::now_ts;
+use crate::stream_domain::StreamDomain;
 
 use self::yaml::{export_collection_yaml, graph_from_yaml};
 
+/// Lines returned by `collection.run_log` when the caller gives no tail size.
+const DEFAULT_RUN_LOG_TAIL_LINES: usize = 200;
+
+/// `ralph run` switches a `collection.run` caller may pass through.
+const ALLOWED_RUN_FLAGS: &[&str] = &[
+    "--exclusive",
+    "--no-auto-merge",
+    "--skip-preflight",
+    "--verbose",
+    "--quiet",
+];
+
+/// `ralph run` options a caller may pass as `--flag=value`. Options that
+/// name files or run commands (`--env-file`, `--on-event`, ...) are left out.
+const ALLOWED_RUN_VALUE_FLAGS: &[&str] = &[
+    "--completion-promise",
+    "--until-event",
+    "--max-same-topic",
+    "--idle-timeout",
+];
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CollectionCreateParams {
@@ -39,11 +61,35 @@ pub struct CollectionImportParams {
     pub description: Option<String>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CollectionRunParams {
     pub id: String,
     pub prompt: String,
+    pub max_iterations: Option<u32>,
+    pub backend: Option<String>,
+    /// Additional `ralph run` flags, limited to [`ALLOWED_RUN_FLAGS`] and
+    /// `--flag=value` forms of [`ALLOWED_RUN_VALUE_FLAGS`].
+    #[serde(default)]
+    pub extra_args: Vec<String>,
+}
+
+/// What a `collection.run` started with, saved so `collection.restart`
+/// resumes under the same hat graph even if the collection was edited since.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct StoredRun {
+    params: CollectionRunParams,
+    /// Hats overlay YAML exported when the run started.
+    config: String,
+    starting_hat: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CollectionRunLogParams {
+    pub id: String,
+    pub tail_lines: Option<usize>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -51,6 +97,8 @@ pub struct CollectionRunParams {
 pub struct CollectionRunResult {
     pub success: bool,
     pub config_path: String,
+    /// Where the spawned loop's stdout and stderr are captured.
+    pub log_path: String,
     pub pid: u32,
     /// The hat that will activate first, derived from the graph topology.
     /// The frontend uses this to highlight the entry node immediately
@@ -59,6 +107,13 @@ pub struct CollectionRunResult {
     pub starting_hat: Option<String>,
 }
 
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CollectionRunLogResult {
+    pub log_path: String,
+    pub lines: Vec<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CollectionSummary {
@@ -293,14 +348,75 @@ impl CollectionDomain {
     /// core config (backend, max_iterations, backpressure). The collection
     /// only provides hats and events.
     ///
-    /// Returns the PID so the frontend can track the process.
+    /// Returns the PID so the frontend can track the process. A non-zero
+    /// exit after startup is published to `streams` as `error.raised`.
     pub fn run(
         &self,
         params: CollectionRunParams,
         ralph_command: &str,
         workspace_root: &Path,
+        streams: &StreamDomain,
     ) -> Result<CollectionRunResult, ApiError> {
-        let yaml = self.export(&params.id)?;
+        let collection = self.get(&params.id)?;
+        let run = StoredRun {
+            config: export_collection_yaml(&collection)?,
+            // Computed from the collection's topology so the frontend can
+            // highlight the starting hat immediately (timing-race fix).
+            starting_hat: yaml::starting_hat_for_collection(&collection),
+            params,
+        };
+        self.spawn_run(run, false, ralph_command, workspace_root, streams)
+    }
+
+    /// Re-run the collection's last `collection.run` in `--continue` mode,
+    /// reusing the stored prompt, overrides and exported config so the loop
+    /// picks up from the existing scratchpad under the same hat graph, even
+    /// if the collection was edited since. Output is appended to the same
+    /// run log.
+    pub fn restart(
+        &self,
+        id: &str,
+        ralph_command: &str,
+        workspace_root: &Path,
+        streams: &StreamDomain,
+    ) -> Result<CollectionRunResult, ApiError> {
+        // Only known ids reach the filesystem.
+        self.get(id)?;
+
+        let params_path = run_params_path(workspace_root, id);
+        let content = fs::read_to_string(&params_path).map_err(|error| {
+            if error.kind() == std::io::ErrorKind::NotFound {
+                ApiError::not_found(format!("collection '{id}' has no previous run to restart"))
+            } else {
+                ApiError::internal(format!(
+                    "failed reading collection run params '{}': {error}",
+                    params_path.display()
+                ))
+            }
+        })?;
+        let run: StoredRun = serde_json::from_str(&content).map_err(|error| {
+            ApiError::internal(format!(
+                "failed parsing collection run params '{}': {error}",
+                params_path.display()
+            ))
+        })?;
+
+        self.spawn_run(run, true, ralph_command, workspace_root, streams)
+    }
+
+    fn spawn_run(
+        &self,
+        run: StoredRun,
+        continue_mode: bool,
+        ralph_command: &str,
+        workspace_root: &Path,
+        streams: &StreamDomain,
+    ) -> Result<CollectionRunResult, ApiError> {
+        let params = &run.params;
+        let mut overrides = run_override_args(params)?;
+        if continue_mode {
+            overrides.push("--continue".to_string());
+        }
 
         // Write the exported YAML to a predictable path.
         let collections_dir = workspace_root.join(".ralph/collections");
@@ -311,13 +427,49 @@ impl CollectionDomain {
         })?;
 
         let config_path = collections_dir.join(format!("{}-run.yml", params.id));
-        fs::write(&config_path, &yaml).map_err(|error| {
+        fs::write(&config_path, &run.config).map_err(|error| {
             ApiError::internal(format!(
                 "failed writing collection run config '{}': {error}",
                 config_path.display()
             ))
         })?;
 
+        // Capture the loop's stdout and stderr in a per-collection log so the
+        // raw backend output stays readable through `collection.run_log`.
+        // Remember the request and config so `collection.restart` can replay it.
+        let params_path = run_params_path(workspace_root, &params.id);
+        let stored = serde_json::to_string_pretty(&run).map_err(|error| {
+            ApiError::internal(format!("failed serializing collection run params: {error}"))
+        })?;
+        fs::write(&params_path, stored).map_err(|error| {
+            ApiError::internal(format!(
+                "failed writing collection run params '{}': {error}",
+                params_path.display()
+            ))
+        })?;
+
+        let log_path = run_log_path(workspace_root, &params.id);
+        let log_file = fs::OpenOptions::new()
+            .create(true)
+            .write(true)
+            .append(continue_mode)
+            .truncate(!continue_mode)
+            .open(&log_path)
+            .map_err(|error| {
+                ApiError::internal(format!(
+                    "failed creating collection run log '{}': {error}",
+                    log_path.display()
+                ))
+            })?;
+        // A restart appends; early-failure output starts after what is there.
+        let log_start = log_file.metadata().map_or(0, |metadata| metadata.len());
+        let stderr_log = log_file.try_clone().map_err(|error| {
+            ApiError::internal(format!(
+                "failed opening collection run log '{}': {error}",
+                log_path.display()
+            ))
+        })?;
+
         // Spawn ralph run with -H (hats overlay) so the user's ralph.yml
         // provides backend/max_iterations/backpressure and the collection
         // provides hats/events. -a (autonomous) forces headless mode, which
@@ -334,8 +486,9 @@ impl CollectionDomain {
                 "-p",
                 &params.prompt,
             ])
-            .stdout(std::process::Stdio::null())
-            .stderr(std::process::Stdio::piped())
+            .args(&overrides)
+            .stdout(log_file)
+            .stderr(stderr_log)
             .spawn()
             .map_err(|error| {
                 ApiError::internal(format!(
@@ -350,24 +503,21 @@ impl CollectionDomain {
         std::thread::sleep(std::time::Duration::from_millis(500));
         match child.try_wait() {
             Ok(Some(status)) if !status.success() => {
-                let mut stderr_output = String::new();
-                if let Some(mut stderr) = child.stderr.take() {
-                    use std::io::Read;
-                    let _ = stderr.read_to_string(&mut stderr_output);
-                }
-                let trimmed = stderr_output.trim();
+                let output = fs::read(&log_path).unwrap_or_default();
+                let output = output
+                    .get(usize::try_from(log_start).unwrap_or(usize::MAX)..)
+                    .map(String::from_utf8_lossy)
+                    .unwrap_or_default();
+                let trimmed = output.trim();
                 // `status.code()` is `Some(code)` on normal exit; `None` means
                 // signal-terminated on Unix. We format both cleanly to avoid
                 // the double "exit status:" prefix that `ExitStatus: Display`
                 // would produce.
-                let status_label = match status.code() {
-                    Some(code) => format!("exit code {code}"),
-                    None => format!("{status}"),
-                };
+                let status_label = exit_status_label(status);
                 let message = if trimmed.is_empty() {
-                    format!("ralph run exited with {status_label} (no stderr)")
+                    format!("ralph run exited with {status_label} (no output)")
                 } else {
-                    // Pass ralph's stderr through verbatim. Spawn-failure
+                    // Pass ralph's output through verbatim. Spawn-failure
                     // output is small; truncation risks hiding the actual
                     // error line.
                     format!("ralph run exited with {status_label}:\n{trimmed}")
@@ -377,23 +527,62 @@ impl CollectionDomain {
             _ => {
                 // Still running or exited successfully. Detach a reaper so
                 // the eventual exit doesn't leave a zombie process — the
-                // API may outlive many loop runs.
+                // API may outlive many loop runs. A crash or early failure
+                // may never write a loop.terminate record, so the exit status
+                // itself is reported.
+                let streams = streams.clone();
+                let collection_id = params.id.clone();
                 std::thread::spawn(move || {
-                    let _ = child.wait();
+                    if let Ok(status) = child.wait()
+                        && !status.success()
+                    {
+                        publish_run_exit(&streams, &collection_id, pid, status);
+                    }
                 });
             }
         }
 
-        // Compute the starting hat from the collection's topology so the
-        // frontend can highlight it immediately (timing-race fix).
-        let collection = self.get(&params.id)?;
-        let starting_hat = yaml::starting_hat_for_collection(&collection);
-
         Ok(CollectionRunResult {
             success: true,
             config_path: config_path.to_string_lossy().to_string(),
+            log_path: log_path.to_string_lossy().to_string(),
             pid,
-            starting_hat,
+            starting_hat: run.starting_hat,
+        })
+    }
+
+    /// Returns the tail of the log captured by the collection's latest run.
+    pub fn run_log(
+        &self,
+        params: CollectionRunLogParams,
+        workspace_root: &Path,
+    ) -> Result<CollectionRunLogResult, ApiError> {
+        // Only known ids reach the filesystem, so the id cannot escape the
+        // collections directory.
+        self.get(&params.id)?;
+
+        let log_path = run_log_path(workspace_root, &params.id);
+        let content = fs::read_to_string(&log_path).map_err(|error| {
+            if error.kind() == std::io::ErrorKind::NotFound {
+                ApiError::not_found(format!(
+                    "collection '{}' has no run log; run it first",
+                    params.id
+                ))
+            } else {
+                ApiError::internal(format!(
+                    "failed reading collection run log '{}': {error}",
+                    log_path.display()
+                ))
+            }
+        })?;
+
+        let tail_lines = params.tail_lines.unwrap_or(DEFAULT_RUN_LOG_TAIL_LINES);
+        let lines: Vec<&str> = content.lines().collect();
+        let start = lines.len().saturating_sub(tail_lines);
+
+        Ok(CollectionRunLogResult {
+            log_path: log_path.to_string_lossy().to_string(),
+            lines: lines[start..].iter().map(ToString::to_string).collect(),
         })
     }
 
@@ -482,6 +671,95 @@ fn parse_graph(raw: Value) -> Result<GraphData, ApiError> {
         .map_err(|error| ApiError::invalid_params(format!("invalid collection graph: {error}")))
 }
 
+/// Translates the optional `collection.run` overrides into `ralph run` flags,
+/// rejecting anything outside the allowlists.
+fn run_override_args(params: &CollectionRunParams) -> Result<Vec<String>, ApiError> {
+    let mut args = Vec::new();
+
+    if let Some(max_iterations) = params.max_iterations {
+        args.push(format!("--max-iterations={max_iterations}"));
+    }
+
+    if let Some(backend) = &params.backend {
+        let valid = !backend.is_empty()
+            && backend
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+            && !backend.starts_with('-');
+        if !valid {
+            return Err(ApiError::invalid_params(format!(
+                "invalid backend '{backend}'"
+            )));
+        }
+        args.push(format!("--backend={backend}"));
+    }
+
+    for arg in &params.extra_args {
+        let flag = arg.split_once('=').map_or(arg.as_str(), |(flag, _)| flag);
+        let allowed = if arg.contains('=') {
+            ALLOWED_RUN_VALUE_FLAGS.contains(&flag)
+        } else {
+            ALLOWED_RUN_FLAGS.contains(&flag)
+        };
+        if !allowed {
+            return Err(ApiError::invalid_params(format!(
+                "extra arg '{arg}' is not allowed for collection.run"
+            ))
+            .with_details(serde_json::json!({
+                "allowedFlags": ALLOWED_RUN_FLAGS,
+                "allowedValueFlags": ALLOWED_RUN_VALUE_FLAGS,
+            })));
+        }
+        args.push(arg.clone());
+    }
+
+    Ok(args)
+}
+
+fn run_log_path(workspace_root: &Path, collection_id: &str) -> PathBuf {
+    workspace_root
+        .join(".ralph/collections")
+        .join(format!("{collection_id}-run.log"))
+}
+
+fn run_params_path(workspace_root: &Path, collection_id: &str) -> PathBuf {
+    workspace_root
+        .join(".ralph/collections")
+        .join(format!("{collection_id}-run.json"))
+}
+
+/// Formats an exit status as `exit code N`, or the signal description when
+/// the process was killed. Avoids the double "exit status:" prefix that
+/// `ExitStatus: Display` would produce.
+fn exit_status_label(status: std::process::ExitStatus) -> String {
+    match status.code() {
+        Some(code) => format!("exit code {code}"),
+        None => format!("{status}"),
+    }
+}
+
+/// Publishes `error.raised` for a `ralph run` that exited unsuccessfully.
+fn publish_run_exit(
+    streams: &StreamDomain,
+    collection_id: &str,
+    pid: u32,
+    status: std::process::ExitStatus,
+) {
+    streams.publish(
+        "error.raised",
+        "collection",
+        collection_id,
+        json!({
+            "code": "LOOP_EXITED",
+            "message": format!("ralph run (pid {pid}) exited with {}", exit_status_label(status)),
+            "retryable": false,
+            "collectionId": collection_id,
+            "pid": pid,
+            "exitCode": status.code(),
+        }),
+    );
+}
+
 fn collection_not_found_error(collection_id: &str) -> ApiError {
     ApiError::collection_not_found(format!("Collection with id '{collection_id}' not found"))
         .with_details(serde_json::json!({ "collectionId": collection_id }))
@@ -507,6 +785,75 @@ mod tests {
         (temp, domain, record.id)
     }
 
+    /// Writes an executable `/bin/sh` stub running `script` to stand in for
+    /// `ralph`, returning its path.
+    fn write_fake_ralph(dir: &Path, script: &str) -> PathBuf {
+        use std::os::unix::fs::PermissionsExt;
+
+        let path = dir.join("fake-ralph.sh");
+        std::fs::write(&path, format!("#!/bin/sh\n{script}\n")).expect("write wrapper");
+        let mut perms = std::fs::metadata(&path).unwrap().permissions();
+        perms.set_mode(0o755);
+        std::fs::set_permissions(&path, perms).expect("chmod");
+        path
+    }
+
+    fn run_params(id: String) -> CollectionRunParams {
+        CollectionRunParams {
+            id,
+            prompt: "hello".to_string(),
+            max_iterations: None,
+            backend: None,
+            extra_args: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn run_override_args_translates_overrides_to_flags() {
+        let params = CollectionRunParams {
+            max_iterations: Some(7),
+            backend: Some("claude".to_string()),
+            extra_args: vec![
+                "--skip-preflight".to_string(),
+                "--until-event=spec.approved".to_string(),
+            ],
+            ..run_params("collection-1".to_string())
+        };
+
+        assert_eq!(
+            run_override_args(&params).unwrap(),
+            vec![
+                "--max-iterations=7",
+                "--backend=claude",
+                "--skip-preflight",
+                "--until-event=spec.approved",
+            ]
+        );
+    }
+
+    #[test]
+    fn run_override_args_rejects_unlisted_flags() {
+        for extra in [
+            "--on-event=build.done=rm -rf /",
+            "--env-file=.env",
+            "--",
+            "-v",
+        ] {
+            let params = CollectionRunParams {
+                extra_args: vec![extra.to_string()],
+                ..run_params("collection-1".to_string())
+            };
+            let err = run_override_args(&params).expect_err(extra);
+            assert!(err.message.contains("is not allowed"), "{}", err.message);
+        }
+
+        let params = CollectionRunParams {
+            backend: Some("--on-event=x".to_string()),
+            ..run_params("collection-1".to_string())
+        };
+        assert!(run_override_args(&params).is_err());
+    }
+
     #[test]
     fn run_surfaces_stderr_from_failed_spawn() {
         // A stub "ralph" that prints a distinctive error and exits non-zero.
@@ -519,22 +866,15 @@ mod tests {
         // positional args; sh echoes to stderr and exits 1 regardless.
         let script = r#"echo "pi: command not found (simulated)" >&2; exit 1"#;
 
-        // Build a wrapper script that always writes the marker to stderr
-        // and exits 1. We'll point ralph_command at it.
-        let wrapper_path = temp.path().join("fake-ralph.sh");
-        std::fs::write(&wrapper_path, format!("#!/bin/sh\n{script}\n")).expect("write wrapper");
-        use std::os::unix::fs::PermissionsExt;
-        let mut perms = std::fs::metadata(&wrapper_path).unwrap().permissions();
-        perms.set_mode(0o755);
-        std::fs::set_permissions(&wrapper_path, perms).expect("chmod");
+        // The wrapper always writes the marker to stderr and exits 1.
+        // We'll point ralph_command at it.
+        let wrapper_path = write_fake_ralph(temp.path(), script);
 
         let result = domain.run(
-            CollectionRunParams {
-                id,
-                prompt: "hello".to_string(),
-            },
+            run_params(id),
             wrapper_path.to_str().expect("wrapper path"),
             temp.path(),
+            &StreamDomain::new(),
         );
 
         let err = result.expect_err("fake ralph should fail");
@@ -553,6 +893,188 @@ mod tests {
         );
     }
 
+    #[test]
+    fn run_log_tails_captured_output() {
+        let (temp, domain, id) = fixture_with_collection();
+
+        let wrapper_path = write_fake_ralph(
+            temp.path(),
+            "echo first\necho second\necho third >&2\nexit 1",
+        );
+
+        let _ = domain.run(
+            run_params(id.clone()),
+            wrapper_path.to_str().expect("wrapper path"),
+            temp.path(),
+            &StreamDomain::new(),
+        );
+
+        let log = domain
+            .run_log(
+                CollectionRunLogParams {
+                    id,
+                    tail_lines: Some(2),
+                },
+                temp.path(),
+            )
+            .expect("run log should exist");
+        assert_eq!(log.lines, vec!["second", "third"]);
+        assert!(log.log_path.ends_with("-run.log"));
+    }
+
+    #[test]
+    fn run_reports_non_zero_exit_after_startup() {
+        let (temp, domain, id) = fixture_with_collection();
+
+        // Outlives the startup check, then fails without writing anything
+        let wrapper_path = write_fake_ralph(temp.path(), "sleep 1\nexit 7");
+
+        let streams = StreamDomain::new();
+        let mut receiver = streams.live_receiver();
+        let result = domain
+            .run(
+                run_params(id.clone()),
+                wrapper_path.to_str().expect("wrapper path"),
+                temp.path(),
+                &streams,
+            )
+            .expect("fake ralph should start");
+
+        let deadline = std::time::Instant::now() + std::time::Duration::from_secs(10);
+        let event = loop {
+            if let Ok(event) = receiver.try_recv() {
+                break event;
+            }
+            assert!(
+                std::time::Instant::now() < deadline,
+                "reaper never reported the exit"
+            );
+            std::thread::sleep(std::time::Duration::from_millis(50));
+        };
+        assert_eq!(event.topic, "error.raised");
+        assert_eq!(event.resource.id, id);
+        assert_eq!(event.payload["code"], "LOOP_EXITED");
+        assert_eq!(event.payload["exitCode"], 7);
+        assert_eq!(event.payload["pid"], result.pid);
+        assert!(
+            event.payload["message"]
+                .as_str()
+                .unwrap()
+                .contains("exit code 7")
+        );
+    }
+
+    #[test]
+    fn restart_replays_stored_params_with_continue() {
+        let (temp, domain, id) = fixture_with_collection();
+
+        // A stub that echoes its arguments and exits non-zero so the spawn
+        // error carries exactly what ralph would have been invoked with.
+        let wrapper_path = write_fake_ralph(temp.path(), "echo \"args: $*\"\nexit 1");
+        let ralph_command = wrapper_path.to_str().expect("wrapper path");
+
+        let first = domain.run(
+            CollectionRunParams {
+                max_iterations: Some(3),
+                ..run_params(id.clone())
+            },
+            ralph_command,
+            temp.path(),
+            &StreamDomain::new(),
+        );
+        let first = format!("{:?}", first.expect_err("fake ralph should fail"));
+        assert!(!first.contains("--continue"), "{first}");
+
+        let restarted = domain.restart(&id, ralph_command, temp.path(), &StreamDomain::new());
+        let restarted = format!("{:?}", restarted.expect_err("fake ralph should fail"));
+        assert!(restarted.contains("-p hello"), "{restarted}");
+        assert!(restarted.contains("--max-iterations=3"), "{restarted}");
+        assert!(restarted.contains("--continue"), "{restarted}");
+
+        let log = domain
+            .run_log(
+                CollectionRunLogParams {
+                    id,
+                    tail_lines: None,
+                },
+                temp.path(),
+            )
+            .expect("run log should exist");
+        assert_eq!(log.lines.len(), 2, "restart should append to the run log");
+    }
+
+    #[test]
+    fn restart_reuses_the_config_of_the_last_run() {
+        let (temp, mut domain, id) = fixture_with_collection();
+
+        let wrapper_path = write_fake_ralph(temp.path(), "exit 1");
+        let ralph_command = wrapper_path.to_str().expect("wrapper path");
+
+        let _ = domain.run(
+            run_params(id.clone()),
+            ralph_command,
+            temp.path(),
+            &StreamDomain::new(),
+        );
+        let config_path = temp.path().join(format!(".ralph/collections/{id}-run.yml"));
+        let run_config = std::fs::read_to_string(&config_path).expect("run config");
+
+        domain
+            .update(CollectionUpdateParams {
+                id: id.clone(),
+                name: None,
+                description: None,
+                graph: Some(json!({
+                    "nodes": [{
+                        "id": "builder-node",
+                        "type": "hatNode",
+                        "position": { "x": 0, "y": 0 },
+                        "data": {
+                            "key": "builder",
+                            "name": "Builder",
+                            "description": "Builds features",
+                            "triggersOn": ["task.start"],
+                            "publishes": ["build.done"]
+                        }
+                    }],
+                    "edges": [],
+                    "viewport": { "x": 0, "y": 0, "zoom": 1 }
+                })),
+            })
+            .expect("update collection");
+        assert_ne!(domain.export(&id).expect("export"), run_config);
+
+        let _ = domain.restart(&id, ralph_command, temp.path(), &StreamDomain::new());
+        let restart_config = std::fs::read_to_string(&config_path).expect("restart config");
+        assert_eq!(restart_config, run_config);
+    }
+
+    #[test]
+    fn restart_requires_a_previous_run() {
+        let (temp, domain, id) = fixture_with_collection();
+
+        let err = domain
+            .restart(&id, "ralph", temp.path(), &StreamDomain::new())
+            .expect_err("nothing to restart");
+        assert!(format!("{err:?}").contains("no previous run to restart"));
+    }
+
+    #[test]
+    fn run_log_requires_a_previous_run() {
+        let (temp, domain, id) = fixture_with_collection();
+
+        let err = domain
+            .run_log(
+                CollectionRunLogParams {
+                    id,
+                    tail_lines: None,
+                },
+                temp.path(),
+            )
+            .expect_err("no run yet");
+        assert!(format!("{err:?}").contains("has no run log"));
+    }
+
     #[test]
     fn create_rejects_empty_name() {
         let temp = TempDir::new().expect("tempdir");
@@ -587,12 +1109,10 @@ mod tests {
         let missing = temp.path().join("definitely-not-here");
 
         let result = domain.run(
-            CollectionRunParams {
-                id,
-                prompt: "hello".to_string(),
-            },
+            run_params(id),
             missing.to_str().expect("missing path"),
             temp.path(),
+            &StreamDomain::new(),
         );
 
         let err = result.expect_err("missing binary should fail");
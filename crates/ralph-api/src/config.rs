@@ -43,6 +43,12 @@ pub struct ApiConfig {
     pub idempotency_ttl_secs: u64,
     pub workspace_root: PathBuf,
     pub loop_process_interval_ms: u64,
+    /// How often the event watcher polls the active events file. This caps
+    /// file reads during bursts; each record is still published individually.
+    pub event_poll_interval_ms: u64,
+    /// Upper bound on live loops when starting another via `collection.run`.
+    /// `None` leaves loop starts unbounded.
+    pub max_concurrent_loops: Option<usize>,
     pub ralph_command: String,
 }
 
@@ -59,6 +65,8 @@ impl Default for ApiConfig {
             idempotency_ttl_secs: 60 * 60,
             workspace_root,
             loop_process_interval_ms: 30_000,
+            event_poll_interval_ms: 500,
+            max_concurrent_loops: None,
             ralph_command: "ralph".to_string(),
         }
     }
@@ -108,6 +116,18 @@ impl ApiConfig {
             })?;
         }
 
+        if let Ok(interval_ms) = env::var("RALPH_API_EVENT_POLL_INTERVAL_MS") {
+            config.event_poll_interval_ms = interval_ms.parse::<u64>().with_context(|| {
+                format!("failed parsing RALPH_API_EVENT_POLL_INTERVAL_MS='{interval_ms}' as u64")
+            })?;
+        }
+
+        if let Ok(max_loops) = env::var("RALPH_API_MAX_CONCURRENT_LOOPS") {
+            config.max_concurrent_loops = Some(max_loops.parse::<usize>().with_context(|| {
+                format!("failed parsing RALPH_API_MAX_CONCURRENT_LOOPS='{max_loops}' as usize")
+            })?);
+        }
+
         if let Ok(ralph_command) = env::var("RALPH_API_RALPH_COMMAND")
             && !ralph_command.trim().is_empty()
         {
@@ -134,6 +154,23 @@ impl ApiConfig {
             );
         }
 
+        // Every domain reads its state from under the workspace root; a
+        // missing root would silently serve empty task/loop/collection lists.
+        if !self.workspace_root.is_dir() {
+            anyhow::bail!(
+                "workspace root '{}' does not exist or is not a directory; set RALPH_API_WORKSPACE_ROOT to an existing directory",
+                self.workspace_root.display()
+            );
+        }
+
+        if self.event_poll_interval_ms == 0 {
+            anyhow::bail!("RALPH_API_EVENT_POLL_INTERVAL_MS must be greater than 0");
+        }
+
+        if self.max_concurrent_loops == Some(0) {
+            anyhow::bail!("RALPH_API_MAX_CONCURRENT_LOOPS must be greater than 0");
+        }
+
         Ok(())
     }
 }
@@ -196,4 +233,34 @@ mod tests {
 
         assert!(config.validate().is_ok());
     }
+
+    #[test]
+    fn missing_workspace_root_is_rejected() {
+        let temp = tempfile::tempdir().unwrap();
+        let mut config = ApiConfig::default();
+        config.workspace_root = temp.path().join("does-not-exist");
+
+        let err = config.validate().unwrap_err().to_string();
+        assert!(err.contains("does-not-exist"), "{err}");
+
+        config.workspace_root = temp.path().to_path_buf();
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn zero_event_poll_interval_is_rejected() {
+        let mut config = ApiConfig::default();
+        config.event_poll_interval_ms = 0;
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn zero_max_concurrent_loops_is_rejected() {
+        let mut config = ApiConfig::default();
+        config.max_concurrent_loops = Some(0);
+        assert!(config.validate().is_err());
+
+        config.max_concurrent_loops = Some(1);
+        assert!(config.validate().is_ok());
+    }
 }
@@ -188,6 +188,10 @@ impl ApiError {
         Self::new(RpcErrorCode::IdempotencyConflict, message)
     }
 
+    pub fn rate_limited(message: impl Into<String>) -> Self {
+        Self::new(RpcErrorCode::RateLimited, message)
+    }
+
     pub fn service_unavailable(message: impl Into<String>) -> Self {
         Self::new(RpcErrorCode::ServiceUnavailable, message)
     }
@@ -1,40 +1,54 @@
 //! Polls the active `.ralph/events-*.jsonl` file for new event records and
 //! publishes them to the stream domain as `loop.orchestration` events.
+//! A `loop.terminate` record whose reason is a failure is additionally
+//! surfaced as an `error.raised` event so clients can leave their running
+//! state instead of waiting on a loop that has already stopped.
 //!
 //! The watcher runs as an independent tokio task spawned at server startup.
 //! It has no interaction with the RPC mutex or any other shared state beyond
 //! the [`StreamDomain`] publish interface.
-
+//!
+//! # Bursts
+//!
+//! There is no separate debounce. Only the active events file is followed,
+//! and it is read at most once per `poll_interval`, however many appends land
+//! in between. A burst therefore costs one read per tick rather than one per
+//! write. Every complete line is still published as its own
+//! `loop.orchestration` event, in order, because clients animate each record.
+//! The last write of a burst goes out on the next tick. Raise
+//! `RALPH_API_EVENT_POLL_INTERVAL_MS` to trade latency for fewer wakeups.
+
+use std::io::{Read, Seek, SeekFrom};
 use std::path::{Path, PathBuf};
 use std::time::Duration;
 use std::{fs, io};
 
-use ralph_core::EventRecord;
+use ralph_core::{EventRecord, TerminationReason};
 use serde_json::json;
 use tracing::{debug, trace, warn};
 
 use crate::stream_domain::StreamDomain;
 
-/// How often the watcher checks for new lines.
-const POLL_INTERVAL: Duration = Duration::from_millis(500);
-
 /// Spawns the event-file watcher as a background tokio task.
 ///
-/// The task runs until the provided `shutdown` future resolves (typically
-/// wired to the server's graceful-shutdown signal).
+/// The task polls every `poll_interval` and runs until the provided
+/// `shutdown` future resolves (typically wired to the server's
+/// graceful-shutdown signal).
 pub fn spawn_watcher(
     workspace_root: PathBuf,
     streams: StreamDomain,
+    poll_interval: Duration,
     shutdown: tokio::sync::watch::Receiver<()>,
 ) -> tokio::task::JoinHandle<()> {
     tokio::spawn(async move {
-        run_watcher(workspace_root, streams, shutdown).await;
+        run_watcher(workspace_root, streams, poll_interval, shutdown).await;
     })
 }
 
 async fn run_watcher(
     workspace_root: PathBuf,
     streams: StreamDomain,
+    poll_interval: Duration,
     mut shutdown: tokio::sync::watch::Receiver<()>,
 ) {
     let marker_path = workspace_root.join(".ralph/current-events");
@@ -43,7 +57,7 @@ async fn run_watcher(
 
     debug!(
         "event watcher started, polling every {}ms",
-        POLL_INTERVAL.as_millis()
+        poll_interval.as_millis()
     );
 
     loop {
@@ -52,7 +66,7 @@ async fn run_watcher(
                 debug!("event watcher shutting down");
                 return;
             }
-            _ = tokio::time::sleep(POLL_INTERVAL) => {
+            _ = tokio::time::sleep(poll_interval) => {
                 poll_once(&marker_path, &workspace_root, &streams, &mut current_file, &mut offset);
             }
         }
@@ -116,6 +130,10 @@ fn resolve_active_events_path(marker_path: &Path, workspace_root: &Path) -> Opti
 }
 
 /// Reads all complete lines from `offset` to EOF, advancing `offset`.
+///
+/// A trailing line without a newline is still being written; it is left
+/// unread and `offset` stops at its first byte, so the next poll picks it up
+/// once the writer finishes it.
 fn read_new_lines(path: &Path, offset: &mut u64) -> io::Result<Vec<String>> {
     let metadata = fs::metadata(path)?;
     let file_len = metadata.len();
@@ -131,19 +149,22 @@ fn read_new_lines(path: &Path, offset: &mut u64) -> io::Result<Vec<String>> {
     }
 
     // Read the new bytes.
-    let content = fs::read_to_string(path)?;
-    let new_content = if (*offset as usize) < content.len() {
-        &content[(*offset as usize)..]
-    } else {
-        ""
+    let mut file = fs::File::open(path)?;
+    file.seek(SeekFrom::Start(*offset))?;
+    let mut new_bytes = Vec::new();
+    file.read_to_end(&mut new_bytes)?;
+
+    let Some(last_newline) = new_bytes.iter().rposition(|&byte| byte == b'\n') else {
+        return Ok(Vec::new());
     };
+    let complete = &new_bytes[..=last_newline];
 
-    let mut lines = Vec::new();
-    for line in new_content.lines() {
-        lines.push(line.to_string());
-    }
+    let lines = String::from_utf8_lossy(complete)
+        .lines()
+        .map(str::to_string)
+        .collect();
 
-    *offset = file_len;
+    *offset += complete.len() as u64;
     Ok(lines)
 }
 
@@ -162,9 +183,40 @@ fn publish_record(streams: &StreamDomain, record: &EventRecord) {
             "hat": record.hat,
             "topic": record.topic,
             "triggered": record.triggered,
+            "triggered_hats": record.triggered_hats,
             "ts": record.ts,
         }),
     );
+
+    if record.topic == "loop.terminate"
+        && let Some(reason) = failed_termination_reason(&record.payload)
+    {
+        streams.publish(
+            "error.raised",
+            "loop",
+            &record.hat,
+            json!({
+                "code": "LOOP_TERMINATED",
+                "message": format!(
+                    "loop terminated at iteration {}: {reason}",
+                    record.iteration
+                ),
+                "retryable": false,
+            }),
+        );
+    }
+}
+
+/// Extracts the reason from a `loop.terminate` payload when it is a failure.
+///
+/// The payload starts with a `## Reason` section holding the reason string.
+/// Limits and intentional stops are not failures; only reasons that exit
+/// with code 1 are.
+fn failed_termination_reason(payload: &str) -> Option<TerminationReason> {
+    let mut lines = payload.lines().map(str::trim);
+    lines.find(|line| *line == "## Reason")?;
+    let reason = lines.next()?.parse::<TerminationReason>().ok()?;
+    (reason.exit_code() == 1).then_some(reason)
 }
 
 #[cfg(test)]
@@ -199,11 +251,13 @@ mod tests {
             hat: hat.to_string(),
             topic: topic.to_string(),
             triggered: Some("reviewer".to_string()),
+            triggered_hats: Vec::new(),
             payload: String::new(),
             blocked_count: None,
             wave_id: None,
             wave_index: None,
             wave_total: None,
+            schema: 1,
         }
     }
 
@@ -300,4 +354,153 @@ mod tests {
         );
         assert_eq!(current_file, Some(tmp.path().join(".ralph/events-b.jsonl")));
     }
+
+    #[test]
+    fn poll_once_publishes_every_line_of_a_burst() {
+        let tmp = TempDir::new().unwrap();
+        let marker_path = tmp.path().join(".ralph/current-events");
+        write_marker(tmp.path(), ".ralph/events-a.jsonl");
+        for (iteration, topic) in [(1, "build.task"), (2, "build.blocked"), (3, "build.done")] {
+            write_event_line(
+                tmp.path(),
+                ".ralph/events-a.jsonl",
+                &sample_record(iteration, "builder", topic),
+            );
+        }
+
+        let streams = StreamDomain::new();
+        let mut receiver = streams.live_receiver();
+        let mut current_file: Option<PathBuf> = None;
+        let mut offset = 0u64;
+
+        poll_once(
+            &marker_path,
+            tmp.path(),
+            &streams,
+            &mut current_file,
+            &mut offset,
+        );
+
+        let topics: Vec<String> = std::iter::from_fn(|| receiver.try_recv().ok())
+            .map(|event| event.payload["topic"].as_str().unwrap().to_string())
+            .collect();
+        assert_eq!(topics, vec!["build.task", "build.blocked", "build.done"]);
+    }
+
+    #[test]
+    fn read_new_lines_waits_for_trailing_newline() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("events.jsonl");
+        fs::write(&path, "line1\nlin").unwrap();
+
+        let mut offset = 0u64;
+        let lines = read_new_lines(&path, &mut offset).unwrap();
+        assert_eq!(lines, vec!["line1"]);
+        assert_eq!(offset, 6);
+
+        let mut f = fs::OpenOptions::new().append(true).open(&path).unwrap();
+        f.write_all(b"e2\n").unwrap();
+
+        let lines = read_new_lines(&path, &mut offset).unwrap();
+        assert_eq!(lines, vec!["line2"]);
+        assert_eq!(offset, 12);
+    }
+
+    #[test]
+    fn poll_once_publishes_line_written_in_two_chunks_once() {
+        let tmp = TempDir::new().unwrap();
+        let marker_path = tmp.path().join(".ralph/current-events");
+        write_marker(tmp.path(), ".ralph/events-a.jsonl");
+        let events_path = tmp.path().join(".ralph/events-a.jsonl");
+
+        let line = serde_json::to_string(&sample_record(1, "builder", "build.done")).unwrap();
+        let (head, tail) = line.split_at(line.len() / 2);
+        let mut f = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&events_path)
+            .unwrap();
+
+        let streams = StreamDomain::new();
+        let mut receiver = streams.live_receiver();
+        let mut current_file: Option<PathBuf> = None;
+        let mut offset = 0u64;
+
+        f.write_all(head.as_bytes()).unwrap();
+        poll_once(
+            &marker_path,
+            tmp.path(),
+            &streams,
+            &mut current_file,
+            &mut offset,
+        );
+        assert!(
+            receiver.try_recv().is_err(),
+            "partial line must not publish"
+        );
+
+        f.write_all(tail.as_bytes()).unwrap();
+        f.write_all(b"\n").unwrap();
+        poll_once(
+            &marker_path,
+            tmp.path(),
+            &streams,
+            &mut current_file,
+            &mut offset,
+        );
+
+        let published: Vec<_> = std::iter::from_fn(|| receiver.try_recv().ok()).collect();
+        assert_eq!(published.len(), 1);
+        assert_eq!(published[0].payload["topic"], "build.done");
+    }
+
+    #[test]
+    fn failed_termination_reason_only_matches_failures() {
+        let payload = |reason: &str| format!("## Reason\n{reason}\n\n## Status\nstopped");
+
+        assert_eq!(
+            failed_termination_reason(&payload("consecutive_failures")),
+            Some(TerminationReason::ConsecutiveFailures)
+        );
+        assert_eq!(failed_termination_reason(&payload("completed")), None);
+        assert_eq!(failed_termination_reason(&payload("max_iterations")), None);
+        assert_eq!(failed_termination_reason(&payload("user_stop")), None);
+        assert_eq!(failed_termination_reason("no reason section"), None);
+    }
+
+    #[test]
+    fn poll_once_raises_error_for_failed_termination() {
+        let tmp = TempDir::new().unwrap();
+        let marker_path = tmp.path().join(".ralph/current-events");
+        write_marker(tmp.path(), ".ralph/events-a.jsonl");
+        let mut terminate = sample_record(4, "loop", "loop.terminate");
+        terminate.payload = "## Reason\nloop_thrashing\n\n## Status\nthrashing".to_string();
+        write_event_line(tmp.path(), ".ralph/events-a.jsonl", &terminate);
+
+        let streams = StreamDomain::new();
+        let mut receiver = streams.live_receiver();
+        let mut current_file: Option<PathBuf> = None;
+        let mut offset = 0u64;
+
+        poll_once(
+            &marker_path,
+            tmp.path(),
+            &streams,
+            &mut current_file,
+            &mut offset,
+        );
+
+        let published: Vec<_> = std::iter::from_fn(|| receiver.try_recv().ok()).collect();
+        assert_eq!(published.len(), 2);
+        assert_eq!(published[0].topic, "loop.orchestration");
+        assert_eq!(published[1].topic, "error.raised");
+        assert_eq!(published[1].payload["code"], "LOOP_TERMINATED");
+        assert_eq!(published[1].payload["retryable"], false);
+        assert!(
+            published[1].payload["message"]
+                .as_str()
+                .unwrap()
+                .contains("loop_thrashing")
+        );
+    }
 }
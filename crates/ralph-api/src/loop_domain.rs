@@ -219,6 +219,35 @@ impl LoopDomain {
         self.last_processed_at = Some(now_ts());
         Ok(())
     }
+
+    /// Pids of loops whose process is still running: the primary loop holding
+    /// the workspace lock plus every live worktree loop in the registry.
+    pub fn active_pids(&self) -> Result<Vec<u32>, ApiError> {
+        let mut pids: Vec<u32> = LoopLock::read_existing(&self.workspace_root)
+            .ok()
+            .flatten()
+            .map(|metadata| metadata.pid)
+            .filter(|pid| is_pid_alive(*pid))
+            .into_iter()
+            .collect();
+
+        pids.extend(
+            LoopRegistry::new(&self.workspace_root)
+                .list()
+                .map_err(|error| ApiError::internal(format!("failed listing loops: {error}")))?
+                .iter()
+                .filter(|entry| entry.is_alive())
+                .map(|entry| entry.pid),
+        );
+
+        Ok(pids)
+    }
+
+    /// Counts loops whose process is still running. See [`Self::active_pids`].
+    pub fn active_count(&self) -> Result<usize, ApiError> {
+        Ok(self.active_pids()?.len())
+    }
+
     pub fn prune(&self) -> Result<(), ApiError> {
         let registry = LoopRegistry::new(&self.workspace_root);
         registry
@@ -583,6 +583,10 @@ fn build_tool(
         "collection.import" => "Import a preset collection from YAML.".into(),
         "collection.export" => "Export a preset collection to YAML.".into(),
         "collection.run" => "Run a loop using a collection's hats with a given prompt.".into(),
+        "collection.restart" => {
+            "Re-run a collection's last run in --continue mode, reusing its scratchpad.".into()
+        }
+        "collection.run_log" => "Tail the output captured from a collection's last run.".into(),
         "stream.subscribe" => "Create a Ralph event stream subscription.".into(),
         "stream.unsubscribe" => "Close a Ralph event stream subscription.".into(),
         "stream.ack" => "Advance a Ralph event stream subscription cursor.".into(),
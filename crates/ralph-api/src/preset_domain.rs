@@ -1,4 +1,7 @@
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
 
 use serde::Deserialize;
 use serde::Serialize;
@@ -17,25 +20,51 @@ pub struct PresetRecord {
     pub description: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub path: Option<String>,
+    /// Folder relative to `.ralph/hats/` for presets found by a recursive listing.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PresetListParams {
+    /// Also walk subdirectories of `.ralph/hats/` (up to `MAX_RECURSIVE_DEPTH`).
+    pub recursive: Option<bool>,
 }
 
+/// Maximum subdirectory depth walked by a recursive preset listing.
+const MAX_RECURSIVE_DEPTH: usize = 3;
+
+/// Maximum length of a description taken from a preset's header comment.
+const MAX_HEADER_DESCRIPTION_CHARS: usize = 200;
+
 #[derive(Debug, Clone)]
 pub struct PresetDomain {
     workspace_root: PathBuf,
+    descriptions: DescriptionCache,
 }
 
 impl PresetDomain {
     pub fn new(workspace_root: impl AsRef<Path>) -> Self {
         Self {
             workspace_root: workspace_root.as_ref().to_path_buf(),
+            descriptions: DescriptionCache::default(),
         }
     }
 
-    pub fn list(&self, collections: &[CollectionSummary]) -> Vec<PresetRecord> {
+    pub fn list(
+        &self,
+        params: &PresetListParams,
+        collections: &[CollectionSummary],
+    ) -> Vec<PresetRecord> {
         let hats_dir = self.workspace_root.join(".ralph/hats");
 
-        let mut builtin = read_builtin_presets(&self.workspace_root);
-        let mut directory = read_presets_from_dir(&hats_dir, "directory", true);
+        let mut builtin = read_builtin_presets(&self.workspace_root, &self.descriptions);
+        let mut directory = if params.recursive.unwrap_or(false) {
+            read_presets_recursive(&hats_dir, &self.descriptions)
+        } else {
+            read_presets_from_dir(&hats_dir, "directory", true, &self.descriptions)
+        };
         let mut collection_presets: Vec<_> = collections
             .iter()
             .map(|collection| PresetRecord {
@@ -44,6 +73,7 @@ impl PresetDomain {
                 source: "collection".to_string(),
                 description: collection.description.clone(),
                 path: None,
+                group: None,
             })
             .collect();
 
@@ -60,19 +90,71 @@ impl PresetDomain {
     }
 }
 
+/// Preset descriptions keyed by file path, invalidated when the file's
+/// modification time or size changes.
+///
+/// Polling `preset.list` would otherwise re-read and re-parse every preset
+/// YAML file on each call.
+#[derive(Debug, Clone, Default)]
+struct DescriptionCache {
+    entries: Arc<Mutex<HashMap<PathBuf, CachedDescription>>>,
+}
+
+#[derive(Debug, Clone)]
+struct CachedDescription {
+    modified: SystemTime,
+    len: u64,
+    description: Option<String>,
+}
+
+impl DescriptionCache {
+    fn get(&self, path: &Path) -> Option<String> {
+        let Some((modified, len)) = std::fs::metadata(path)
+            .ok()
+            .and_then(|metadata| Some((metadata.modified().ok()?, metadata.len())))
+        else {
+            // No usable metadata: read directly without caching
+            return read_preset_description(path);
+        };
+
+        let mut entries = self
+            .entries
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+
+        if let Some(cached) = entries.get(path)
+            && cached.modified == modified
+            && cached.len == len
+        {
+            return cached.description.clone();
+        }
+
+        let description = read_preset_description(path);
+        entries.insert(
+            path.to_path_buf(),
+            CachedDescription {
+                modified,
+                len,
+                description: description.clone(),
+            },
+        );
+        description
+    }
+}
+
 #[derive(Debug, Deserialize)]
 struct BuiltinPresetIndexEntry {
     name: String,
     description: String,
 }
 
-fn read_builtin_presets(workspace_root: &Path) -> Vec<PresetRecord> {
+fn read_builtin_presets(workspace_root: &Path, cache: &DescriptionCache) -> Vec<PresetRecord> {
     let index_path = workspace_root.join("presets").join("index.json");
     let content = match std::fs::read_to_string(&index_path) {
         Ok(content) => content,
         Err(error) => {
             warn!(path = %index_path.display(), %error, "failed reading builtin preset index");
-            return read_presets_from_dir(&workspace_root.join("presets"), "builtin", false);
+            return read_presets_from_dir(&workspace_root.join("presets"), "builtin", false, cache);
         }
     };
 
@@ -80,7 +162,7 @@ fn read_builtin_presets(workspace_root: &Path) -> Vec<PresetRecord> {
         Ok(entries) => entries,
         Err(error) => {
             warn!(path = %index_path.display(), %error, "failed parsing builtin preset index");
-            return read_presets_from_dir(&workspace_root.join("presets"), "builtin", false);
+            return read_presets_from_dir(&workspace_root.join("presets"), "builtin", false, cache);
         }
     };
 
@@ -94,33 +176,26 @@ fn read_builtin_presets(workspace_root: &Path) -> Vec<PresetRecord> {
             source: "builtin".to_string(),
             description: Some(entry.description),
             path: None,
+            group: None,
         })
         .collect()
 }
 
-fn read_presets_from_dir(dir: &Path, source: &str, include_path: bool) -> Vec<PresetRecord> {
+fn read_presets_from_dir(
+    dir: &Path,
+    source: &str,
+    include_path: bool,
+    cache: &DescriptionCache,
+) -> Vec<PresetRecord> {
     if !dir.exists() {
         return Vec::new();
     }
 
-    let Ok(entries) = std::fs::read_dir(dir) else {
-        return Vec::new();
-    };
-
-    let mut files: Vec<PathBuf> = entries
-        .filter_map(Result::ok)
-        .map(|entry| entry.path())
-        .filter(|path| path.is_file())
-        .filter(|path| path.extension().is_some_and(|extension| extension == "yml"))
-        .collect();
-
-    files.sort();
-
-    files
+    yaml_files_in(dir)
         .into_iter()
         .filter_map(|path| {
             let file_stem = path.file_stem()?.to_str()?.to_string();
-            let description = read_preset_description(&path);
+            let description = cache.get(&path);
 
             Some(PresetRecord {
                 id: format!("{source}:{file_stem}"),
@@ -128,11 +203,85 @@ fn read_presets_from_dir(dir: &Path, source: &str, include_path: bool) -> Vec<Pr
                 source: source.to_string(),
                 description,
                 path: include_path.then(|| path.display().to_string()),
+                group: None,
             })
         })
         .collect()
 }
 
+/// Lists directory presets from `dir` and its subdirectories (bounded depth).
+///
+/// Presets in subfolders are grouped by their folder relative to `dir`, and
+/// their ID includes that folder so same-named files don't collide.
+fn read_presets_recursive(dir: &Path, cache: &DescriptionCache) -> Vec<PresetRecord> {
+    let mut files = Vec::new();
+    collect_yaml_files(dir, MAX_RECURSIVE_DEPTH, &mut files);
+
+    files
+        .into_iter()
+        .filter_map(|path| {
+            let file_stem = path.file_stem()?.to_str()?.to_string();
+            let group = path
+                .parent()
+                .and_then(|parent| parent.strip_prefix(dir).ok())
+                .filter(|relative| !relative.as_os_str().is_empty())
+                .map(|relative| relative.to_string_lossy().replace('\\', "/"));
+            let id = match &group {
+                Some(group) => format!("directory:{group}/{file_stem}"),
+                None => format!("directory:{file_stem}"),
+            };
+
+            Some(PresetRecord {
+                id,
+                name: file_stem,
+                source: "directory".to_string(),
+                description: cache.get(&path),
+                path: Some(path.display().to_string()),
+                group,
+            })
+        })
+        .collect()
+}
+
+fn collect_yaml_files(dir: &Path, depth_remaining: usize, files: &mut Vec<PathBuf>) {
+    files.extend(yaml_files_in(dir));
+
+    if depth_remaining == 0 {
+        return;
+    }
+
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    let mut subdirs: Vec<PathBuf> = entries
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_ok_and(|file_type| file_type.is_dir()))
+        .map(|entry| entry.path())
+        .collect();
+    subdirs.sort();
+
+    for subdir in subdirs {
+        collect_yaml_files(&subdir, depth_remaining - 1, files);
+    }
+}
+
+/// Returns the sorted `*.yml` files directly inside `dir`.
+fn yaml_files_in(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+
+    let mut files: Vec<PathBuf> = entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "yml"))
+        .collect();
+
+    files.sort();
+    files
+}
+
 fn read_preset_description(path: &Path) -> Option<String> {
     let content = std::fs::read_to_string(path).ok()?;
     let parsed: Value = match serde_yaml::from_str(&content) {
@@ -148,4 +297,201 @@ fn read_preset_description(path: &Path) -> Option<String> {
         .and_then(|mapping| mapping.get(Value::String("description".to_string())))
         .and_then(Value::as_str)
         .map(std::string::ToString::to_string)
+        .or_else(|| extract_yaml_description(&content))
+}
+
+/// Builds a description from a preset's leading comment block.
+///
+/// Collects consecutive `#` lines up to the first blank comment line or YAML
+/// content, skipping `Usage:` and `ralph ...` command lines, and joins them
+/// into a single line capped at `MAX_HEADER_DESCRIPTION_CHARS`.
+fn extract_yaml_description(content: &str) -> Option<String> {
+    let mut parts = Vec::new();
+
+    for line in content.lines().map(str::trim) {
+        let Some(comment) = line.strip_prefix('#') else {
+            // Leading blank lines are allowed; anything else ends the header
+            if line.is_empty() && parts.is_empty() {
+                continue;
+            }
+            break;
+        };
+
+        let comment = comment.trim();
+        if comment.is_empty() {
+            if parts.is_empty() {
+                continue;
+            }
+            break;
+        }
+        if comment.to_lowercase().starts_with("usage:") || comment.starts_with("ralph ") {
+            continue;
+        }
+        parts.push(comment);
+    }
+
+    if parts.is_empty() {
+        return None;
+    }
+
+    Some(ralph_core::truncate_with_ellipsis(
+        &parts.join(" "),
+        MAX_HEADER_DESCRIPTION_CHARS,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn directory_description(domain: &PresetDomain, name: &str) -> Option<String> {
+        domain
+            .list(&PresetListParams::default(), &[])
+            .into_iter()
+            .find(|preset| preset.id == format!("directory:{name}"))
+            .and_then(|preset| preset.description)
+    }
+
+    #[test]
+    fn list_reuses_cached_description_until_file_changes() {
+        let temp_dir = tempfile::tempdir().expect("temp dir");
+        let hats_dir = temp_dir.path().join(".ralph/hats");
+        std::fs::create_dir_all(&hats_dir).expect("create hats dir");
+        let preset_path = hats_dir.join("review.yml");
+        std::fs::write(&preset_path, "description: First pass\n").expect("write preset");
+
+        let domain = PresetDomain::new(temp_dir.path());
+        assert_eq!(
+            directory_description(&domain, "review").as_deref(),
+            Some("First pass")
+        );
+        assert_eq!(domain.descriptions.entries.lock().unwrap().len(), 1);
+
+        // Cached value is served while the file is unchanged
+        domain
+            .descriptions
+            .entries
+            .lock()
+            .unwrap()
+            .get_mut(&preset_path)
+            .expect("cached entry")
+            .description = Some("from cache".to_string());
+        assert_eq!(
+            directory_description(&domain, "review").as_deref(),
+            Some("from cache")
+        );
+
+        // Editing the local config invalidates the cached description
+        std::fs::write(&preset_path, "description: Updated review preset\n")
+            .expect("rewrite preset");
+        assert_eq!(
+            directory_description(&domain, "review").as_deref(),
+            Some("Updated review preset")
+        );
+    }
+
+    #[test]
+    fn recursive_list_groups_presets_by_folder() {
+        let temp_dir = tempfile::tempdir().expect("temp dir");
+        let hats_dir = temp_dir.path().join(".ralph/hats");
+        std::fs::create_dir_all(hats_dir.join("configs/team")).expect("create dirs");
+        std::fs::create_dir_all(hats_dir.join("a/b/c/d")).expect("create deep dirs");
+        std::fs::write(hats_dir.join("top.yml"), "description: Top\n").expect("write");
+        std::fs::write(hats_dir.join("configs/review.yml"), "description: Nested\n")
+            .expect("write");
+        std::fs::write(
+            hats_dir.join("configs/team/review.yml"),
+            "description: Team\n",
+        )
+        .expect("write");
+        std::fs::write(hats_dir.join("a/b/c/d/too-deep.yml"), "description: Deep\n")
+            .expect("write");
+
+        let domain = PresetDomain::new(temp_dir.path());
+        let directory_ids = |params: &PresetListParams| -> Vec<String> {
+            domain
+                .list(params, &[])
+                .into_iter()
+                .filter(|preset| preset.source == "directory")
+                .map(|preset| preset.id)
+                .collect()
+        };
+
+        // Non-recursive is the default
+        assert_eq!(
+            directory_ids(&PresetListParams::default()),
+            vec!["directory:top"]
+        );
+
+        let recursive = PresetListParams {
+            recursive: Some(true),
+        };
+        let mut ids = directory_ids(&recursive);
+        ids.sort();
+        assert_eq!(
+            ids,
+            vec![
+                "directory:configs/review",
+                "directory:configs/team/review",
+                "directory:top",
+            ]
+        );
+
+        let presets = domain.list(&recursive, &[]);
+        let nested = presets
+            .iter()
+            .find(|preset| preset.id == "directory:configs/review")
+            .expect("nested preset");
+        assert_eq!(nested.name, "review");
+        assert_eq!(nested.group.as_deref(), Some("configs"));
+        assert_eq!(nested.description.as_deref(), Some("Nested"));
+    }
+
+    #[test]
+    fn header_comment_description_joins_consecutive_lines() {
+        let content = "# Review Preset\n# Runs a strict reviewer over each change.\n#\n# Details that should not be included.\nevent_loop:\n  max_iterations: 5\n";
+        assert_eq!(
+            extract_yaml_description(content).as_deref(),
+            Some("Review Preset Runs a strict reviewer over each change.")
+        );
+    }
+
+    #[test]
+    fn header_comment_description_skips_usage_lines_and_caps_length() {
+        let content = "# Usage:\n# ralph run -c review.yml\n# Reviewer\nhats: {}\n";
+        assert_eq!(
+            extract_yaml_description(content).as_deref(),
+            Some("Reviewer")
+        );
+
+        let long = format!("# {}\n# {}\n", "a".repeat(150), "b".repeat(150));
+        let description = extract_yaml_description(&long).expect("description");
+        assert_eq!(description.chars().count(), MAX_HEADER_DESCRIPTION_CHARS);
+        assert!(description.ends_with("..."));
+
+        assert_eq!(extract_yaml_description("event_loop: {}\n"), None);
+    }
+
+    #[test]
+    fn description_key_takes_precedence_over_header_comment() {
+        let temp_dir = tempfile::tempdir().expect("temp dir");
+        let hats_dir = temp_dir.path().join(".ralph/hats");
+        std::fs::create_dir_all(&hats_dir).expect("create hats dir");
+        std::fs::write(
+            hats_dir.join("keyed.yml"),
+            "# Header\ndescription: From key\n",
+        )
+        .expect("write");
+        std::fs::write(hats_dir.join("commented.yml"), "# Header only\nhats: {}\n").expect("write");
+
+        let domain = PresetDomain::new(temp_dir.path());
+        assert_eq!(
+            directory_description(&domain, "keyed").as_deref(),
+            Some("From key")
+        );
+        assert_eq!(
+            directory_description(&domain, "commented").as_deref(),
+            Some("Header only")
+        );
+    }
 }
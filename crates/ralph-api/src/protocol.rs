@@ -57,6 +57,8 @@ pub const KNOWN_METHODS: &[&str] = &[
     "collection.import",
     "collection.export",
     "collection.run",
+    "collection.restart",
+    "collection.run_log",
     "robot.question",
     "robot.respond",
     "robot.guidance",
@@ -95,6 +97,7 @@ pub const MUTATING_METHODS: &[&str] = &[
     "collection.delete",
     "collection.import",
     "collection.run",
+    "collection.restart",
     "robot.respond",
     "robot.guidance",
 ];
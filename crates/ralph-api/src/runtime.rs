@@ -42,6 +42,10 @@ pub struct RpcRuntime {
     idempotency: Arc<dyn IdempotencyStore>,
     tasks: Arc<Mutex<TaskDomain>>,
     loops: Arc<Mutex<LoopDomain>>,
+    /// Pids of `ralph run` processes spawned by this API that have not yet
+    /// shown up holding a loop lock. Counted against `max_concurrent_loops`
+    /// so concurrent starts cannot all pass the cap before any child locks.
+    loop_starts: Arc<Mutex<Vec<u32>>>,
     planning: Arc<Mutex<PlanningDomain>>,
     collections: Arc<Mutex<CollectionDomain>>,
     streams: StreamDomain,
@@ -78,6 +82,7 @@ impl RpcRuntime {
             config.loop_process_interval_ms,
             config.ralph_command.clone(),
         )));
+        let loop_starts = Arc::new(Mutex::new(Vec::new()));
         let planning = Arc::new(Mutex::new(PlanningDomain::new(&config.workspace_root)));
         let collections = Arc::new(Mutex::new(CollectionDomain::new(&config.workspace_root)));
         let streams = StreamDomain::new();
@@ -91,6 +96,7 @@ impl RpcRuntime {
             idempotency,
             tasks,
             loops,
+            loop_starts,
             planning,
             collections,
             streams,
@@ -218,6 +224,12 @@ impl RpcRuntime {
             .map_err(|_| ApiError::internal("loop domain lock poisoned"))
     }
 
+    pub(crate) fn loop_starts_mut(&self) -> Result<MutexGuard<'_, Vec<u32>>, ApiError> {
+        self.loop_starts
+            .lock()
+            .map_err(|_| ApiError::internal("loop starts lock poisoned"))
+    }
+
     pub(crate) fn planning_domain_mut(&self) -> Result<MutexGuard<'_, PlanningDomain>, ApiError> {
         self.planning
             .lock()
@@ -10,9 +10,11 @@ use crate::errors::ApiError;
 use crate::loop_domain::{
     LoopListParams, LoopRetryParams, LoopStopMergeParams, LoopTriggerMergeTaskParams,
 };
+use crate::loop_support::is_pid_alive;
 use crate::planning_domain::{
     PlanningGetArtifactParams, PlanningRespondParams, PlanningStartParams,
 };
+use crate::preset_domain::PresetListParams;
 use crate::protocol::{API_VERSION, RpcRequestEnvelope};
 use crate::stream_domain::{StreamAckParams, StreamSubscribeParams, StreamUnsubscribeParams};
 use crate::task_domain::{TaskCreateParams, TaskListParams, TaskUpdateInput};
@@ -264,8 +266,9 @@ impl RpcRuntime {
     fn dispatch_preset(&self, request: &RpcRequestEnvelope) -> Result<Value, ApiError> {
         match request.method.as_str() {
             "preset.list" => {
+                let params: PresetListParams = self.parse_params(request)?;
                 let collections = self.collection_domain_mut()?.list();
-                let presets = self.preset_domain().list(&collections);
+                let presets = self.preset_domain().list(&params, &collections);
                 Ok(json!({ "presets": presets }))
             }
             _ => Err(ApiError::service_unavailable(format!(
@@ -314,11 +317,37 @@ impl RpcRuntime {
             "collection.run" => {
                 let params: crate::collection_domain::CollectionRunParams =
                     self.parse_params(request)?;
+                // Hold the reservation list across the check and the spawn
+                let mut loop_starts = self.loop_starts_mut()?;
+                self.ensure_loop_capacity(&mut loop_starts)?;
                 let result = self.collection_domain_mut()?.run(
                     params,
                     &self.config.ralph_command,
                     &self.config.workspace_root,
+                    &self.stream_domain(),
                 )?;
+                loop_starts.push(result.pid);
+                Ok(json!(result))
+            }
+            "collection.restart" => {
+                let params: IdOnlyParams = self.parse_params(request)?;
+                let mut loop_starts = self.loop_starts_mut()?;
+                self.ensure_loop_capacity(&mut loop_starts)?;
+                let result = self.collection_domain_mut()?.restart(
+                    &params.id,
+                    &self.config.ralph_command,
+                    &self.config.workspace_root,
+                    &self.stream_domain(),
+                )?;
+                loop_starts.push(result.pid);
+                Ok(json!(result))
+            }
+            "collection.run_log" => {
+                let params: crate::collection_domain::CollectionRunLogParams =
+                    self.parse_params(request)?;
+                let result = self
+                    .collection_domain_mut()?
+                    .run_log(params, &self.config.workspace_root)?;
                 Ok(json!(result))
             }
             _ => Err(ApiError::service_unavailable(format!(
@@ -389,6 +418,28 @@ impl RpcRuntime {
     }
 }
 
+impl RpcRuntime {
+    /// Rejects a loop start once `max_concurrent_loops` loops are running.
+    /// Rejects a loop start once `max_concurrent_loops` is reached. Loops
+    /// spawned here but not yet holding a lock count via `loop_starts`;
+    /// entries are dropped once their process exits or appears as running.
+    fn ensure_loop_capacity(&self, loop_starts: &mut Vec<u32>) -> Result<(), ApiError> {
+        let Some(max) = self.config.max_concurrent_loops else {
+            return Ok(());
+        };
+        let running = self.loop_domain_mut()?.active_pids()?;
+        loop_starts.retain(|pid| is_pid_alive(*pid) && !running.contains(pid));
+        let active = running.len() + loop_starts.len();
+        if active >= max {
+            return Err(ApiError::rate_limited(format!(
+                "{active} loop(s) already running; max_concurrent_loops is {max}"
+            ))
+            .with_details(json!({ "active": active, "max": max })));
+        }
+        Ok(())
+    }
+}
+
 use serde::Deserialize as InternalDeserialize;
 
 #[derive(Debug, Clone, InternalDeserialize)]
@@ -90,6 +90,7 @@ where
     let _watcher_handle = crate::event_watcher::spawn_watcher(
         runtime.config.workspace_root.clone(),
         runtime.stream_domain(),
+        std::time::Duration::from_millis(runtime.config.event_poll_interval_ms),
         watcher_shutdown_rx,
     );
 
@@ -191,3 +191,19 @@ async fn deduplicates_mutating_requests_by_idempotency_key() -> Result<()> {
     server.stop().await;
     Ok(())
 }
+
+#[test]
+fn runtime_rejects_missing_workspace_root() {
+    let parent = tempfile::tempdir().expect("tempdir should be created");
+    let config = ApiConfig {
+        workspace_root: parent.path().join("missing-workspace"),
+        ..ApiConfig::default()
+    };
+
+    let error = match RpcRuntime::new(config) {
+        Ok(_) => panic!("runtime should not start without a workspace root"),
+        Err(error) => error.to_string(),
+    };
+    assert!(error.contains("missing-workspace"), "{error}");
+    assert!(!parent.path().join("missing-workspace").exists());
+}
@@ -292,6 +292,105 @@ async fn loop_discard_removes_worktree_and_marks_discarded() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn collection_run_rejects_when_max_concurrent_loops_reached() -> Result<()> {
+    let config = ApiConfig {
+        max_concurrent_loops: Some(1),
+        ..ApiConfig::default()
+    };
+    let server = TestServer::start(config).await;
+    let client = Client::new();
+
+    let registry = LoopRegistry::new(server.workspace_path());
+    registry.register(LoopEntry::with_id(
+        "loop-busy-1",
+        "Already running",
+        None::<String>,
+        server.workspace_path().display().to_string(),
+    ))?;
+
+    let run_request = rpc_request(
+        "req-collection-run-limit-1",
+        "collection.run",
+        json!({ "id": "collection-any", "prompt": "Start another loop" }),
+        Some("idem-collection-run-limit-1"),
+    );
+    let (status, payload) = post_rpc(&client, &server, &run_request).await?;
+
+    assert_eq!(status, 429);
+    assert_eq!(payload["error"]["code"], "RATE_LIMITED");
+    assert_eq!(payload["error"]["details"]["active"], 1);
+    assert_eq!(payload["error"]["details"]["max"], 1);
+    assert!(
+        payload["error"]["message"]
+            .as_str()
+            .is_some_and(|message| message.contains("1 loop(s) already running"))
+    );
+
+    server.stop().await;
+    Ok(())
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn collection_run_counts_spawned_loops_not_yet_holding_a_lock() -> Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+
+    // A loop that stays alive without ever taking the loop lock, like a real
+    // `ralph run` that has not reached lock acquisition yet.
+    let fake_bin = tempfile::tempdir()?;
+    let fake_ralph = fake_bin.path().join("slow-ralph");
+    fs::write(&fake_ralph, "#!/bin/sh\nexec sleep 5\n")?;
+    let mut permissions = fs::metadata(&fake_ralph)?.permissions();
+    permissions.set_mode(0o755);
+    fs::set_permissions(&fake_ralph, permissions)?;
+
+    let config = ApiConfig {
+        max_concurrent_loops: Some(1),
+        ralph_command: fake_ralph.to_string_lossy().to_string(),
+        ..ApiConfig::default()
+    };
+    let server = TestServer::start(config).await;
+    let client = Client::new();
+
+    let create = rpc_request(
+        "req-collection-create-limit-1",
+        "collection.create",
+        json!({ "name": "Limited" }),
+        Some("idem-collection-create-limit-1"),
+    );
+    let (status, create_payload) = post_rpc(&client, &server, &create).await?;
+    assert_eq!(status, 200);
+    let collection_id = create_payload["result"]["collection"]["id"]
+        .as_str()
+        .expect("collection id should be present")
+        .to_string();
+
+    let first_run = rpc_request(
+        "req-collection-run-reserve-1",
+        "collection.run",
+        json!({ "id": collection_id, "prompt": "First loop" }),
+        Some("idem-collection-run-reserve-1"),
+    );
+    let (status, _) = post_rpc(&client, &server, &first_run).await?;
+    assert_eq!(status, 200);
+
+    let second_run = rpc_request(
+        "req-collection-run-reserve-2",
+        "collection.run",
+        json!({ "id": collection_id, "prompt": "Second loop" }),
+        Some("idem-collection-run-reserve-2"),
+    );
+    let (status, payload) = post_rpc(&client, &server, &second_run).await?;
+
+    assert_eq!(status, 429);
+    assert_eq!(payload["error"]["code"], "RATE_LIMITED");
+    assert_eq!(payload["error"]["details"]["active"], 1);
+
+    server.stop().await;
+    Ok(())
+}
+
 // ── Real-flow integration helpers ────────────────────────────────────────────
 
 /// Locate the `ralph` binary built by this workspace.
@@ -529,10 +529,12 @@ fn format_termination_reason(reason: &TerminationReason) -> String {
         TerminationReason::LoopStale => "LoopStale".to_string(),
         TerminationReason::ValidationFailure => "ValidationFailure".to_string(),
         TerminationReason::Stopped => "Stopped".to_string(),
+        TerminationReason::UserStop => "UserStop".to_string(),
         TerminationReason::Interrupted => "Interrupted".to_string(),
         TerminationReason::RestartRequested => "RestartRequested".to_string(),
         TerminationReason::WorkspaceGone => "WorkspaceGone".to_string(),
         TerminationReason::Cancelled => "Cancelled".to_string(),
+        TerminationReason::TargetEventReached => "TargetEventReached".to_string(),
     }
 }
 
@@ -4,9 +4,14 @@
 //! iteration separators, termination messages, event tables,
 //! and other terminal UI elements.
 
-use ralph_core::{EventRecord, TerminationReason, floor_char_boundary, truncate_with_ellipsis};
-use ralph_proto::HatId;
-use std::collections::HashMap;
+use ralph_cli::{glyph, no_emoji};
+use ralph_core::utils::{format_duration, format_duration_precise};
+use ralph_core::{
+    EventRecord, HatConfig, TerminationReason, floor_char_boundary, truncate_with_ellipsis,
+};
+use ralph_proto::{HatId, Topic};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::io::IsTerminal;
 use std::path::Path;
 use std::time::Duration;
 
@@ -21,9 +26,13 @@ pub mod colors {
     pub const CYAN: &str = "\x1b[36m";
     pub const BLUE: &str = "\x1b[34m";
     pub const MAGENTA: &str = "\x1b[35m";
+    pub const BRIGHT_YELLOW: &str = "\x1b[93m";
+    pub const BRIGHT_BLUE: &str = "\x1b[94m";
+    pub const BRIGHT_MAGENTA: &str = "\x1b[95m";
+    pub const BRIGHT_CYAN: &str = "\x1b[96m";
 }
 
-/// Returns the emoji for a hat ID.
+/// Returns the built-in emoji for a hat ID.
 pub fn hat_emoji(hat_id: &str) -> &'static str {
     match hat_id {
         "planner" => "?",
@@ -33,6 +42,22 @@ pub fn hat_emoji(hat_id: &str) -> &'static str {
     }
 }
 
+/// Resolves the emoji for a hat, preferring its configured `emoji` or the one
+/// leading its `name` over the built-in [`hat_emoji`] defaults.
+pub fn resolve_hat_emoji<'a>(hat_id: &str, hat: Option<&'a HatConfig>) -> &'a str {
+    hat.and_then(HatConfig::display_emoji)
+        .unwrap_or_else(|| hat_emoji(hat_id))
+}
+
+/// Formats a hat label with its emoji, or just the name under `--no-emoji`.
+fn hat_label(emoji: &str, name: &str) -> String {
+    if no_emoji() {
+        name.to_string()
+    } else {
+        format!("{emoji} {name}")
+    }
+}
+
 /// Prints a startup banner with loop discovery info for `--no-tui` runs.
 ///
 /// Advertises the loop ID, key state files, and the tail / resume commands
@@ -115,8 +140,8 @@ pub fn print_iteration_footer(
         0.0
     };
 
-    let iter_dur = format_elapsed(iteration_duration);
-    let total_dur = format_elapsed(cumulative_elapsed);
+    let iter_dur = format_duration_precise(iteration_duration);
+    let total_dur = format_duration(cumulative_elapsed);
 
     // Cost fragment: only emit when we actually have cost signal, otherwise
     // noise accumulates for free / self-hosted backends.
@@ -158,6 +183,34 @@ fn resume_hint_for(reason: &TerminationReason, loop_id: &str) -> Option<String>
     }
 }
 
+/// Separator width used when the terminal size is unknown (pipes, CI logs).
+const DEFAULT_SEPARATOR_WIDTH: usize = 79;
+/// Bounds for terminal-sized separators, so very narrow or very wide terminals
+/// still get a readable box.
+const MIN_SEPARATOR_WIDTH: usize = 40;
+const MAX_SEPARATOR_WIDTH: usize = 120;
+
+/// Returns the separator width for the current stdout.
+///
+/// Sized to the terminal when stdout is a TTY, otherwise falls back to
+/// [`DEFAULT_SEPARATOR_WIDTH`].
+fn separator_width() -> usize {
+    if !std::io::stdout().is_terminal() {
+        return DEFAULT_SEPARATOR_WIDTH;
+    }
+    crossterm::terminal::size().map_or(DEFAULT_SEPARATOR_WIDTH, |(columns, _)| {
+        separator_width_for(columns)
+    })
+}
+
+/// Clamps a terminal column count to a separator width, leaving the last
+/// column free so terminals that wrap on it don't break the box.
+fn separator_width_for(columns: u16) -> usize {
+    usize::from(columns)
+        .saturating_sub(1)
+        .clamp(MIN_SEPARATOR_WIDTH, MAX_SEPARATOR_WIDTH)
+}
+
 /// Prints the iteration demarcation separator.
 ///
 /// Per spec: "Each iteration must be clearly demarcated in the output so users can
@@ -172,25 +225,32 @@ fn resume_hint_for(reason: &TerminationReason, loop_id: &str) -> Option<String>
 pub fn print_iteration_separator(
     iteration: u32,
     hat_id: &str,
+    hat: Option<&HatConfig>,
     elapsed: Duration,
     max_iterations: u32,
     use_colors: bool,
 ) {
     use colors::*;
 
-    let emoji = hat_emoji(hat_id);
-    let elapsed_str = format_elapsed(elapsed);
-
-    // Build the content line (without box chars for measuring)
-    let content = format!(
-        " ITERATION {} | {} {} | {} elapsed | {}/{}",
-        iteration, emoji, hat_id, elapsed_str, iteration, max_iterations
-    );
+    let emoji = resolve_hat_emoji(hat_id, hat);
+    let elapsed_str = format_duration(elapsed);
 
-    // Use fixed width of 79 characters for the box (standard terminal width)
-    let box_width = 79;
+    let box_width = separator_width();
     let separator = "=".repeat(box_width);
 
+    // Build the content line, truncated so it never wraps on narrow terminals
+    let content = truncate_with_ellipsis(
+        &format!(
+            " ITERATION {} | {} | {} elapsed | {}/{}",
+            iteration,
+            hat_label(emoji, hat_id),
+            elapsed_str,
+            iteration,
+            max_iterations
+        ),
+        box_width,
+    );
+
     if use_colors {
         println!("\n{BOLD}{CYAN}{separator}{RESET}");
         println!("{BOLD}{CYAN}{content}{RESET}");
@@ -202,22 +262,6 @@ pub fn print_iteration_separator(
     }
 }
 
-/// Formats elapsed duration as human-readable string.
-pub fn format_elapsed(d: Duration) -> String {
-    let total_secs = d.as_secs();
-    let hours = total_secs / 3600;
-    let minutes = (total_secs % 3600) / 60;
-    let seconds = total_secs % 60;
-
-    if hours > 0 {
-        format!("{}h {}m {}s", hours, minutes, seconds)
-    } else if minutes > 0 {
-        format!("{}m {}s", minutes, seconds)
-    } else {
-        format!("{}s", seconds)
-    }
-}
-
 /// Truncates a string to max_len characters, adding ellipsis if truncated.
 pub fn truncate(s: &str, max_len: usize) -> String {
     truncate_with_ellipsis(s, max_len)
@@ -247,11 +291,13 @@ pub fn print_termination(
         TerminationReason::LoopThrashing => (RED, "?", "Loop thrashing detected"),
         TerminationReason::LoopStale => (RED, "?", "Stale loop detected"),
         TerminationReason::ValidationFailure => (RED, "?", "Too many malformed JSONL events"),
-        TerminationReason::Stopped => (CYAN, "?", "Manually stopped"),
+        TerminationReason::Stopped => (CYAN, "?", "Stopped without completing"),
+        TerminationReason::UserStop => (CYAN, "?", "Stopped by user request"),
         TerminationReason::Interrupted => (YELLOW, "?", "Interrupted by signal"),
         TerminationReason::RestartRequested => (CYAN, "↻", "Restarting by human request"),
         TerminationReason::WorkspaceGone => (RED, "?", "Workspace directory removed"),
         TerminationReason::Cancelled => (CYAN, "⏹", "Cancelled gracefully"),
+        TerminationReason::TargetEventReached => (GREEN, "⏹", "Target event reached"),
     };
 
     let separator = "-".repeat(58);
@@ -301,7 +347,23 @@ pub fn print_termination(
     }
 }
 
+/// Colors for topic families without a built-in mapping. Red and green are
+/// left out so custom topics never read as a failure or a success.
+const TOPIC_PALETTE: [&str; 8] = [
+    colors::BLUE,
+    colors::MAGENTA,
+    colors::CYAN,
+    colors::YELLOW,
+    colors::BRIGHT_BLUE,
+    colors::BRIGHT_MAGENTA,
+    colors::BRIGHT_CYAN,
+    colors::BRIGHT_YELLOW,
+];
+
 /// Gets the color for a topic based on its prefix.
+///
+/// The core workflow topics keep fixed colors; any other prefix (`spec.*`,
+/// `deploy.*`, ...) hashes to a stable entry in [`TOPIC_PALETTE`].
 pub fn get_topic_color(topic: &str) -> &'static str {
     use colors::*;
     if topic.starts_with("task.") {
@@ -315,7 +377,104 @@ pub fn get_topic_color(topic: &str) -> &'static str {
     } else if topic.starts_with("review.") {
         MAGENTA
     } else {
-        BLUE
+        TOPIC_PALETTE[Topic::new(topic).prefix_bucket(TOPIC_PALETTE.len())]
+    }
+}
+
+/// Graph syntax for `ralph events --graph`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+pub enum EventGraphFormat {
+    /// Mermaid flowchart - for Markdown and external rendering tools
+    #[default]
+    Mermaid,
+    /// Graphviz DOT syntax - pipe into `dot -Tsvg`
+    Dot,
+}
+
+/// Observed publisher → triggered-hat transitions, keyed by topic.
+///
+/// Events published by the loop itself (or written by agents without a hat)
+/// and events that triggered no hat are attributed to Ralph, matching the
+/// catch-all node in `ralph hats graph`.
+fn event_flow_edges(records: &[EventRecord]) -> BTreeMap<(String, String, String), usize> {
+    let node = |name: &str| match name {
+        "" | "loop" | "ralph" => "Ralph".to_string(),
+        other => other.to_string(),
+    };
+
+    let mut edges = BTreeMap::new();
+    for record in records {
+        let from = node(&record.hat);
+        let mut triggered = record.all_triggered();
+        if triggered.is_empty() {
+            triggered.push("");
+        }
+        for hat in triggered {
+            *edges
+                .entry((from.clone(), node(hat), record.topic.clone()))
+                .or_insert(0) += 1;
+        }
+    }
+    edges
+}
+
+/// Renders the event flow of a run as a Mermaid or DOT graph.
+///
+/// Each edge is an observed transition labeled with its topic and how many
+/// times it fired, so the graph shows what actually happened rather than the
+/// configured topology.
+pub fn render_event_graph(records: &[EventRecord], format: EventGraphFormat) -> String {
+    let edges = event_flow_edges(records);
+    let hats: BTreeSet<&str> = edges
+        .keys()
+        .flat_map(|(from, to, _)| [from.as_str(), to.as_str()])
+        .filter(|name| *name != "Ralph")
+        .collect();
+    let label = |topic: &str, count: usize| format!("{topic} ({count})");
+
+    match format {
+        EventGraphFormat::Mermaid => {
+            let ids = ralph_core::unique_mermaid_ids(hats.iter().copied());
+            let id = |name: &str| ids.get(name).map_or("Ralph", String::as_str);
+            let mut out = String::from("```mermaid\nflowchart LR\n");
+            out.push_str("    Ralph((Ralph))\n");
+            for hat in &hats {
+                out.push_str(&format!(
+                    "    {}[\"{}\"]\n",
+                    id(hat),
+                    hat.replace('"', "#quot;")
+                ));
+            }
+            for ((from, to, topic), count) in &edges {
+                out.push_str(&format!(
+                    "    {} -->|\"{}\"| {}\n",
+                    id(from),
+                    label(topic, *count).replace('"', "#quot;"),
+                    id(to)
+                ));
+            }
+            out.push_str("```\n");
+            out
+        }
+        EventGraphFormat::Dot => {
+            let escape = |value: &str| value.replace('\\', "\\\\").replace('"', "\\\"");
+            let mut out = String::from("digraph events {\n");
+            out.push_str("    rankdir=LR;\n");
+            out.push_str("    \"Ralph\" [shape=doublecircle];\n");
+            for hat in &hats {
+                out.push_str(&format!("    \"{}\" [shape=box];\n", escape(hat)));
+            }
+            for ((from, to, topic), count) in &edges {
+                out.push_str(&format!(
+                    "    \"{}\" -> \"{}\" [label=\"{}\"];\n",
+                    escape(from),
+                    escape(to),
+                    escape(&label(topic, *count))
+                ));
+            }
+            out.push_str("}\n");
+            out
+        }
     }
 }
 
@@ -342,7 +501,10 @@ pub fn print_events_table(records: &[EventRecord], use_colors: bool) {
 
     for (i, record) in records.iter().enumerate() {
         let topic_color = get_topic_color(&record.topic);
-        let triggered = record.triggered.as_deref().unwrap_or("-");
+        let triggered = match record.all_triggered().as_slice() {
+            [] => "-".to_string(),
+            hats => hats.join(","),
+        };
         let payload_one_line = record.payload.replace('\n', " ");
         let payload_preview = truncate_with_ellipsis(&payload_one_line, 40);
 
@@ -373,7 +535,7 @@ pub fn print_events_table(records: &[EventRecord], use_colors: bool) {
                 record.iteration,
                 truncate(&record.hat, 13),
                 truncate(&record.topic, 18),
-                truncate(triggered, 14),
+                truncate(&triggered, 14),
                 payload_preview
             );
         } else {
@@ -384,7 +546,7 @@ pub fn print_events_table(records: &[EventRecord], use_colors: bool) {
                 record.iteration,
                 truncate(&record.hat, 13),
                 truncate(&record.topic, 18),
-                truncate(triggered, 14),
+                truncate(&triggered, 14),
                 payload_preview
             );
         }
@@ -404,16 +566,28 @@ pub fn print_events_table(records: &[EventRecord], use_colors: bool) {
 /// ```text
 /// ── WAVE: 🔍 Reviewer | 3 workers | timeout 600s ─────────────────────────────
 /// ```
-pub fn print_wave_header(hat_name: &str, worker_count: usize, timeout_secs: u64, use_colors: bool) {
+pub fn print_wave_header(
+    hat: &HatConfig,
+    worker_count: usize,
+    timeout_secs: u64,
+    use_colors: bool,
+) {
     use colors::*;
 
-    let emoji = hat_emoji(hat_name);
+    let emoji = resolve_hat_emoji(&hat.name, Some(hat));
+    // Preset names already lead with their emoji; don't print it twice.
+    let hat_name = hat
+        .name
+        .strip_prefix(emoji)
+        .map_or(hat.name.as_str(), str::trim_start);
     let content = format!(
-        " WAVE: {} {} | {} workers | timeout {}s ",
-        emoji, hat_name, worker_count, timeout_secs
+        " WAVE: {} | {} workers | timeout {}s ",
+        hat_label(emoji, hat_name),
+        worker_count,
+        timeout_secs
     );
 
-    let box_width = 79;
+    let box_width = separator_width();
     let content_len = content.len();
     let pad = if content_len + 2 < box_width {
         box_width - content_len - 2
@@ -445,15 +619,15 @@ pub fn print_wave_worker_done(
 ) {
     use colors::*;
 
-    let elapsed = format_elapsed(duration);
+    let elapsed = format_duration(duration);
     let status_word = if success { "done" } else { "failed" };
     let preview = truncate(payload_preview, 60);
 
     if use_colors {
         let (icon, color) = if success {
-            ("✓", GREEN)
+            (glyph("✓", "[ok]"), GREEN)
         } else {
-            ("✗", RED)
+            (glyph("✗", "[x]"), RED)
         };
         eprintln!(
             "  {color}{BOLD}{icon}{RESET} Worker {}/{} {} ({}) — {}",
@@ -464,7 +638,11 @@ pub fn print_wave_worker_done(
             preview
         );
     } else {
-        let icon = if success { "✓" } else { "✗" };
+        let icon = if success {
+            glyph("✓", "[ok]")
+        } else {
+            glyph("✗", "[x]")
+        };
         eprintln!(
             "  {} Worker {}/{} {} ({}) — {}",
             icon,
@@ -491,13 +669,13 @@ pub fn print_wave_summary(
 ) {
     use colors::*;
 
-    let elapsed = format_elapsed(total_duration);
+    let elapsed = format_duration(total_duration);
     let content = format!(
         " Wave complete: {} succeeded, {} failed ({}) ",
         succeeded, failed, elapsed
     );
 
-    let box_width = 79;
+    let box_width = separator_width();
     let content_len = content.len();
     let pad = if content_len + 2 < box_width {
         box_width - content_len - 2
@@ -542,9 +720,9 @@ mod tests {
     use ralph_core::RalphConfig;
 
     #[test]
-    fn test_format_elapsed_seconds_only() {
+    fn test_format_duration_seconds_only() {
         let d = Duration::from_secs(45);
-        assert_eq!(format_elapsed(d), "45s");
+        assert_eq!(format_duration(d), "45s");
     }
 
     #[test]
@@ -563,6 +741,15 @@ mod tests {
         assert!(resume_hint_for(&TerminationReason::Cancelled, "abc").is_none());
     }
 
+    #[test]
+    fn test_separator_width_for_clamps_terminal_width() {
+        assert_eq!(separator_width_for(80), 79);
+        assert_eq!(separator_width_for(100), 99);
+        assert_eq!(separator_width_for(20), MIN_SEPARATOR_WIDTH);
+        assert_eq!(separator_width_for(0), MIN_SEPARATOR_WIDTH);
+        assert_eq!(separator_width_for(300), MAX_SEPARATOR_WIDTH);
+    }
+
     #[test]
     fn test_resume_hint_skipped_for_restart_requested() {
         // Loop auto-restarts via main; hint would be redundant noise.
@@ -596,7 +783,7 @@ mod tests {
         );
 
         println!();
-        print_iteration_separator(1, "planner", Duration::from_secs(0), 150, false);
+        print_iteration_separator(1, "planner", None, Duration::from_secs(0), 150, false);
         println!("  (… backend streams tool calls and text here …)");
         print_iteration_footer(
             1,
@@ -608,7 +795,7 @@ mod tests {
             false,
         );
 
-        print_iteration_separator(2, "builder", Duration::from_secs(42), 150, false);
+        print_iteration_separator(2, "builder", None, Duration::from_secs(42), 150, false);
         println!("  (… builder output …)");
         print_iteration_footer(
             2,
@@ -620,7 +807,7 @@ mod tests {
             false,
         );
 
-        print_iteration_separator(3, "reviewer", Duration::from_secs(110), 150, false);
+        print_iteration_separator(3, "reviewer", None, Duration::from_secs(110), 150, false);
         println!("  (… reviewer output …)");
         print_iteration_footer(
             3,
@@ -737,15 +924,15 @@ mod tests {
     }
 
     #[test]
-    fn test_format_elapsed_minutes_and_seconds() {
+    fn test_format_duration_minutes_and_seconds() {
         let d = Duration::from_secs(125); // 2m 5s
-        assert_eq!(format_elapsed(d), "2m 5s");
+        assert_eq!(format_duration(d), "2m 5s");
     }
 
     #[test]
-    fn test_format_elapsed_hours_minutes_seconds() {
+    fn test_format_duration_hours_minutes_seconds() {
         let d = Duration::from_secs(3725); // 1h 2m 5s
-        assert_eq!(format_elapsed(d), "1h 2m 5s");
+        assert_eq!(format_duration(d), "1h 2m 5s");
     }
 
     #[test]
@@ -782,11 +969,13 @@ mod tests {
             hat: "hat".to_string(),
             topic: "task.start".to_string(),
             triggered: None,
+            triggered_hats: Vec::new(),
             payload,
             blocked_count: None,
             wave_id: None,
             wave_index: None,
             wave_total: None,
+            schema: 1,
         };
 
         print_events_table(&[record], false);
@@ -802,16 +991,43 @@ mod tests {
             hat: "hat".to_string(),
             topic: "task.start".to_string(),
             triggered: None,
+            triggered_hats: Vec::new(),
             payload: "ok".to_string(),
             blocked_count: None,
             wave_id: None,
             wave_index: None,
             wave_total: None,
+            schema: 1,
         };
 
         print_events_table(&[record], false);
     }
 
+    #[test]
+    fn test_get_topic_color_custom_prefixes_are_stable() {
+        assert_eq!(get_topic_color("task.start"), colors::CYAN);
+        assert_eq!(get_topic_color("build.blocked"), colors::RED);
+        assert_eq!(get_topic_color("review.done"), colors::MAGENTA);
+
+        // Same prefix, same color - regardless of the rest of the topic
+        assert_eq!(
+            get_topic_color("spec.ready"),
+            get_topic_color("spec.approved")
+        );
+        assert_eq!(
+            get_topic_color("deploy.start"),
+            get_topic_color("deploy.done")
+        );
+        // Custom families spread across the palette instead of all rendering blue
+        let seen: std::collections::HashSet<_> =
+            ["spec.x", "deploy.x", "docs.x", "qa.x", "infra.x"]
+                .iter()
+                .map(|topic| get_topic_color(topic))
+                .collect();
+        assert!(seen.len() > 1);
+        assert!(!seen.contains(colors::RED) && !seen.contains(colors::GREEN));
+    }
+
     #[test]
     fn test_hat_emoji_known_hats() {
         assert_eq!(hat_emoji("planner"), "?");
@@ -824,6 +1040,31 @@ mod tests {
         assert_eq!(hat_emoji("custom_hat"), "?");
     }
 
+    #[test]
+    fn test_resolve_hat_emoji_prefers_hat_config() {
+        let named: HatConfig = serde_yaml::from_str(r#"name: "🛡️ Security Reviewer""#).unwrap();
+        assert_eq!(resolve_hat_emoji("security_reviewer", Some(&named)), "🛡️");
+
+        let explicit: HatConfig = serde_yaml::from_str(
+            r#"
+name: "Architecture Reviewer"
+emoji: "🏛️"
+"#,
+        )
+        .unwrap();
+        assert_eq!(
+            resolve_hat_emoji("architecture_reviewer", Some(&explicit)),
+            "🏛️"
+        );
+
+        let plain: HatConfig = serde_yaml::from_str(r#"name: "Custom""#).unwrap();
+        assert_eq!(
+            resolve_hat_emoji("custom", Some(&plain)),
+            hat_emoji("custom")
+        );
+        assert_eq!(resolve_hat_emoji("planner", None), hat_emoji("planner"));
+    }
+
     #[test]
     fn test_build_tui_hat_map_extracts_custom_hats() {
         // Given: A config with custom hats from pr-review preset
@@ -925,4 +1166,71 @@ hats:
             "Wildcard-only patterns should produce empty hat map"
         );
     }
+
+    fn record(hat: &str, topic: &str, triggered: Option<&str>) -> EventRecord {
+        let event = ralph_proto::Event::new(topic, "");
+        EventRecord::new(1, hat, &event, triggered.map(HatId::new).as_ref())
+    }
+
+    fn flow_records() -> Vec<EventRecord> {
+        vec![
+            record("loop", "task.start", Some("builder")),
+            record("builder", "build.done", Some("reviewer")),
+            record("reviewer", "review.rejected", Some("builder")),
+            record("builder", "build.done", Some("reviewer")),
+            record("reviewer", "LOOP_COMPLETE", None),
+        ]
+    }
+
+    #[test]
+    fn test_render_event_graph_mermaid_counts_edges() {
+        let graph = render_event_graph(&flow_records(), EventGraphFormat::Mermaid);
+
+        assert!(graph.starts_with("```mermaid\nflowchart LR\n"));
+        assert!(graph.contains("    builder[\"builder\"]\n"));
+        assert!(graph.contains("    Ralph -->|\"task.start (1)\"| builder\n"));
+        assert!(graph.contains("    builder -->|\"build.done (2)\"| reviewer\n"));
+        assert!(graph.contains("    reviewer -->|\"review.rejected (1)\"| builder\n"));
+        assert!(graph.contains("    reviewer -->|\"LOOP_COMPLETE (1)\"| Ralph\n"));
+        assert!(graph.ends_with("```\n"));
+    }
+
+    #[test]
+    fn test_render_event_graph_mermaid_dedupes_colliding_ids() {
+        let records = vec![
+            record("code-review", "review.done", Some("code_review")),
+            record("code_review", "review.escalated", Some("!!!")),
+        ];
+        let graph = render_event_graph(&records, EventGraphFormat::Mermaid);
+
+        assert!(graph.contains("    codereview[\"code-review\"]\n"));
+        assert!(graph.contains("    codereview2[\"code_review\"]\n"));
+        assert!(graph.contains("    hat[\"!!!\"]\n"));
+        assert!(graph.contains("    codereview -->|\"review.done (1)\"| codereview2\n"));
+        assert!(graph.contains("    codereview2 -->|\"review.escalated (1)\"| hat\n"));
+    }
+
+    #[test]
+    fn test_render_event_graph_dot() {
+        let graph = render_event_graph(&flow_records(), EventGraphFormat::Dot);
+
+        assert!(graph.starts_with("digraph events {\n"));
+        assert!(graph.contains("    \"reviewer\" [shape=box];\n"));
+        assert!(graph.contains("    \"builder\" -> \"reviewer\" [label=\"build.done (2)\"];\n"));
+        assert_eq!(
+            graph.matches("build.done").count(),
+            1,
+            "edges are aggregated"
+        );
+        assert!(graph.ends_with("}\n"));
+    }
+
+    #[test]
+    fn test_render_event_graph_attributes_agent_events_to_ralph() {
+        // Agent-written events carry no hat and may not record a triggered hat
+        let records = vec![record("", "build.task", Some("builder"))];
+        let graph = render_event_graph(&records, EventGraphFormat::Dot);
+
+        assert!(graph.contains("    \"Ralph\" -> \"builder\" [label=\"build.task (1)\"];\n"));
+    }
 }
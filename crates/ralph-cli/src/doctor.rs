@@ -619,6 +619,7 @@ mod tests {
         HatConfig {
             name: name.to_string(),
             description: Some("Test hat".to_string()),
+            emoji: None,
             triggers: vec!["work.start".to_string()],
             publishes: vec![],
             instructions: String::new(),
@@ -627,6 +628,7 @@ mod tests {
             backend,
             default_publishes: None,
             max_activations: None,
+            cooldown_secs: None,
             scratchpad: None,
             disallowed_tools: vec![],
             timeout: None,
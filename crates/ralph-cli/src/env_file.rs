@@ -0,0 +1,142 @@
+//! Dotenv-style environment files (`ralph run --env-file`).
+//!
+//! Each non-blank, non-comment line must be `KEY=VALUE`, optionally prefixed
+//! with `export `. Values may be wrapped in matching single or double quotes.
+//! Parsed pairs are handed to backend processes rather than exported into
+//! ralph's own environment, and never override variables that are already set.
+
+use anyhow::{Context, Result};
+use std::path::Path;
+
+/// Reads and parses an env file, failing on the first malformed line.
+pub fn load_env_file(path: &Path) -> Result<Vec<(String, String)>> {
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read env file {}", path.display()))?;
+    parse_env_file(&content).map_err(|(line, reason)| {
+        anyhow::anyhow!(
+            "Invalid env file {}:{line}: {reason}. Expected KEY=VALUE lines.",
+            path.display()
+        )
+    })
+}
+
+/// Drops pairs whose key is already set in the current process environment.
+pub fn unset_only(pairs: Vec<(String, String)>) -> Vec<(String, String)> {
+    pairs
+        .into_iter()
+        .filter(|(key, _)| std::env::var_os(key).is_none())
+        .collect()
+}
+
+/// Parses env file content into ordered pairs.
+///
+/// Returns the 1-based line number and a reason for the first bad line.
+fn parse_env_file(content: &str) -> Result<Vec<(String, String)>, (usize, String)> {
+    let mut pairs = Vec::new();
+    for (index, raw) in content.lines().enumerate() {
+        let line = raw.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let line = line.strip_prefix("export ").unwrap_or(line);
+        let Some((key, value)) = line.split_once('=') else {
+            return Err((index + 1, format!("missing '=' in '{line}'")));
+        };
+        let key = key.trim();
+        if !is_valid_key(key) {
+            return Err((index + 1, format!("invalid variable name '{key}'")));
+        }
+        pairs.push((key.to_string(), unquote(value.trim()).to_string()));
+    }
+    Ok(pairs)
+}
+
+fn is_valid_key(key: &str) -> bool {
+    let mut chars = key.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+fn unquote(value: &str) -> &str {
+    for quote in ['"', '\''] {
+        if let Some(inner) = value
+            .strip_prefix(quote)
+            .and_then(|v| v.strip_suffix(quote))
+        {
+            return inner;
+        }
+    }
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_pairs_comments_and_quotes() {
+        let content = "\
+# credentials
+ANTHROPIC_API_KEY=sk-test
+
+export REGION = us-east-1
+QUOTED=\"hello world\"
+SINGLE='a=b'
+EMPTY=
+";
+        let pairs = parse_env_file(content).unwrap();
+        assert_eq!(
+            pairs,
+            vec![
+                ("ANTHROPIC_API_KEY".to_string(), "sk-test".to_string()),
+                ("REGION".to_string(), "us-east-1".to_string()),
+                ("QUOTED".to_string(), "hello world".to_string()),
+                ("SINGLE".to_string(), "a=b".to_string()),
+                ("EMPTY".to_string(), String::new()),
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_first_bad_line() {
+        let content = "GOOD=1\n\nnot a pair\n1BAD=2\n";
+        let (line, reason) = parse_env_file(content).unwrap_err();
+        assert_eq!(line, 3);
+        assert!(reason.contains("missing '='"), "{reason}");
+
+        let (line, reason) = parse_env_file("GOOD=1\n1BAD=2\n").unwrap_err();
+        assert_eq!(line, 2);
+        assert!(reason.contains("'1BAD'"), "{reason}");
+    }
+
+    #[test]
+    fn load_env_file_includes_path_and_line_in_error() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(".env");
+        std::fs::write(&path, "A=1\nB C=2\n").unwrap();
+
+        let err = load_env_file(&path).unwrap_err().to_string();
+        assert!(err.contains(&format!("{}:2", path.display())), "{err}");
+    }
+
+    #[test]
+    fn unset_only_keeps_variables_ralph_does_not_already_have() {
+        let pairs = vec![
+            ("PATH".to_string(), "/override".to_string()),
+            (
+                "RALPH_ENV_FILE_TEST_UNSET_VAR".to_string(),
+                "value".to_string(),
+            ),
+        ];
+        let kept = unset_only(pairs);
+        assert_eq!(
+            kept,
+            vec![(
+                "RALPH_ENV_FILE_TEST_UNSET_VAR".to_string(),
+                "value".to_string()
+            )]
+        );
+    }
+}
@@ -0,0 +1,125 @@
+//! CLI commands for the `ralph tools event` namespace.
+//!
+//! Lets agents (and humans) check an events file before the loop reads it:
+//! - `validate`: report malformed JSONL lines, exiting nonzero if any exist
+
+use anyhow::{Result, bail};
+use clap::{Parser, Subcommand};
+use ralph_core::{EventReader, ParseResult};
+use std::path::{Path, PathBuf};
+
+/// Event file tools.
+#[derive(Parser, Debug)]
+pub struct EventArgs {
+    #[command(subcommand)]
+    pub command: EventCommands,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum EventCommands {
+    /// Check a JSONL events file for malformed lines
+    Validate(ValidateArgs),
+}
+
+/// Arguments for the `event validate` command.
+#[derive(Parser, Debug)]
+pub struct ValidateArgs {
+    /// Path to the JSONL events file
+    pub file: PathBuf,
+}
+
+/// Execute an event command.
+pub fn execute(args: EventArgs) -> Result<()> {
+    match args.command {
+        EventCommands::Validate(args) => validate_command(&args.file),
+    }
+}
+
+fn validate_command(file: &Path) -> Result<()> {
+    let result = validate_file(file)?;
+    print!("{}", format_report(file, &result));
+
+    if !result.malformed.is_empty() {
+        bail!(
+            "{} malformed line(s) in {}",
+            result.malformed.len(),
+            file.display()
+        );
+    }
+    Ok(())
+}
+
+/// Parses every line of `file` the same way the event loop does.
+fn validate_file(file: &Path) -> Result<ParseResult> {
+    if !file.is_file() {
+        bail!("Events file not found: {}", file.display());
+    }
+    Ok(EventReader::new(file).peek_new_events()?)
+}
+
+fn format_report(file: &Path, result: &ParseResult) -> String {
+    let mut report = String::new();
+    for line in &result.malformed {
+        report.push_str(&format!(
+            "{}:{}: {}\n    {}\n",
+            file.display(),
+            line.line_number,
+            line.error,
+            line.content
+        ));
+    }
+    report.push_str(&format!(
+        "{} valid event(s), {} malformed line(s)\n",
+        result.events.len(),
+        result.malformed.len()
+    ));
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_file_reports_malformed_lines() {
+        let temp_dir = tempfile::tempdir().expect("temp dir");
+        let file = temp_dir.path().join("events.jsonl");
+        std::fs::write(
+            &file,
+            "{\"topic\":\"build.done\",\"payload\":\"ok\",\"ts\":\"2026-01-01T00:00:00Z\"}\n\
+             \n\
+             {\"topic\": \"build.done\", payload: oops}\n",
+        )
+        .unwrap();
+
+        let result = validate_file(&file).expect("validate");
+        assert_eq!(result.events.len(), 1);
+        assert_eq!(result.malformed.len(), 1);
+        assert_eq!(result.malformed[0].line_number, 3);
+
+        let report = format_report(&file, &result);
+        assert!(report.contains("events.jsonl:3: "), "{report}");
+        assert!(report.contains("1 valid event(s), 1 malformed line(s)"));
+
+        assert!(validate_command(&file).is_err());
+    }
+
+    #[test]
+    fn test_validate_file_accepts_clean_file() {
+        let temp_dir = tempfile::tempdir().expect("temp dir");
+        let file = temp_dir.path().join("events.jsonl");
+        std::fs::write(
+            &file,
+            "{\"topic\":\"build.task\",\"payload\":\"go\",\"ts\":\"2026-01-01T00:00:00Z\"}\n",
+        )
+        .unwrap();
+
+        assert!(validate_command(&file).is_ok());
+    }
+
+    #[test]
+    fn test_validate_file_missing() {
+        let err = validate_file(Path::new("/nonexistent/events.jsonl")).unwrap_err();
+        assert!(err.to_string().contains("not found"));
+    }
+}
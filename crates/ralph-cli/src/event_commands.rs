@@ -0,0 +1,155 @@
+//! Shell commands triggered by published events (`ralph run --on-event`).
+//!
+//! Each `--on-event <topic>=<command>` registers an `EventLoop` observer that
+//! runs the command through `sh -c` whenever a matching event is published.
+//! The event payload is piped on stdin and the topic is exported as
+//! `RALPH_EVENT_TOPIC`. Commands run on their own thread so the loop never
+//! waits on them; failures are logged and otherwise ignored.
+
+use ralph_proto::{Event, Topic};
+use std::io::Write;
+use std::process::{Command, Stdio};
+use tracing::{debug, warn};
+
+/// A topic pattern paired with the shell command it triggers.
+#[derive(Debug, Clone)]
+pub struct EventCommand {
+    /// Topic pattern; supports the same wildcards as hat triggers.
+    pub pattern: Topic,
+    /// Shell command run via `sh -c`.
+    pub command: String,
+}
+
+/// Parses a `<topic>=<command>` argument.
+pub fn parse_event_command(value: &str) -> Result<EventCommand, String> {
+    let (topic, command) = value
+        .split_once('=')
+        .ok_or_else(|| format!("expected <topic>=<command>, got '{value}'"))?;
+    let (topic, command) = (topic.trim(), command.trim());
+    if topic.is_empty() {
+        return Err("topic must not be empty".to_string());
+    }
+    if command.is_empty() {
+        return Err(format!("command for '{topic}' must not be empty"));
+    }
+    Ok(EventCommand {
+        pattern: Topic::new(topic),
+        command: command.to_string(),
+    })
+}
+
+/// Returns an `EventLoop` observer that runs every command matching an event.
+pub fn observer(commands: Vec<EventCommand>) -> impl Fn(&Event) + Send + 'static {
+    move |event: &Event| {
+        for entry in commands.iter().filter(|c| c.pattern.matches(&event.topic)) {
+            let command = entry.command.clone();
+            let topic = event.topic.as_str().to_string();
+            let payload = event.payload.clone();
+            std::thread::spawn(move || run(&command, &topic, &payload));
+        }
+    }
+}
+
+/// Runs one command to completion, logging any failure.
+fn run(command: &str, topic: &str, payload: &str) {
+    let child = Command::new("sh")
+        .args(["-c", command])
+        .env("RALPH_EVENT_TOPIC", topic)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn();
+
+    let mut child = match child {
+        Ok(child) => child,
+        Err(e) => {
+            warn!(topic, command, error = %e, "Failed to start --on-event command");
+            return;
+        }
+    };
+
+    if let Some(mut stdin) = child.stdin.take() {
+        // The command may exit without reading stdin; that's not an error
+        let _ = stdin.write_all(payload.as_bytes());
+    }
+
+    match child.wait_with_output() {
+        Ok(output) if output.status.success() => {
+            debug!(topic, command, "--on-event command finished");
+        }
+        Ok(output) => warn!(
+            topic,
+            command,
+            status = %output.status,
+            stderr = %String::from_utf8_lossy(&output.stderr).trim(),
+            "--on-event command failed"
+        ),
+        Err(e) => warn!(topic, command, error = %e, "Failed to wait for --on-event command"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn test_parse_event_command() {
+        let parsed = parse_event_command("build.done=notify-send 'built'").unwrap();
+        assert_eq!(parsed.pattern.as_str(), "build.done");
+        assert_eq!(parsed.command, "notify-send 'built'");
+
+        // Only the first '=' separates topic from command
+        let parsed = parse_event_command("review.* = echo a=b").unwrap();
+        assert_eq!(parsed.pattern.as_str(), "review.*");
+        assert_eq!(parsed.command, "echo a=b");
+
+        assert!(parse_event_command("build.done").is_err());
+        assert!(parse_event_command("=echo hi").is_err());
+        assert!(parse_event_command("build.done=").is_err());
+    }
+
+    fn wait_for(path: &std::path::Path) -> String {
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while Instant::now() < deadline {
+            if let Ok(content) = std::fs::read_to_string(path)
+                && !content.is_empty()
+            {
+                return content;
+            }
+            std::thread::sleep(Duration::from_millis(20));
+        }
+        panic!("command did not write {}", path.display());
+    }
+
+    #[test]
+    fn test_observer_pipes_payload_to_matching_commands() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let out = temp_dir.path().join("out.txt");
+        let skipped = temp_dir.path().join("skipped.txt");
+        let commands = vec![
+            parse_event_command(&format!(
+                "build.*={{ printf '%s:' \"$RALPH_EVENT_TOPIC\"; cat; }} > {0}.tmp && mv {0}.tmp {0}",
+                out.display()
+            ))
+            .unwrap(),
+            parse_event_command(&format!("review.done=touch {}", skipped.display())).unwrap(),
+        ];
+
+        let observe = observer(commands);
+        observe(&Event::new("build.done", "tests: pass"));
+
+        assert_eq!(wait_for(&out), "build.done:tests: pass");
+        assert!(!skipped.exists());
+    }
+
+    #[test]
+    fn test_failing_command_is_not_fatal() {
+        run("exit 3", "build.done", "");
+        run(
+            "cat > /dev/null; exit 1",
+            "build.done",
+            &"x".repeat(1 << 20),
+        );
+    }
+}
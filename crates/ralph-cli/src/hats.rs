@@ -14,8 +14,8 @@ use anyhow::{Context, Result};
 use clap::{Parser, Subcommand, ValueEnum};
 use indicatif::{ProgressBar, ProgressStyle};
 use ralph_adapters::{CliBackend, detect_backend_default};
-use ralph_core::{HatRegistry, RalphConfig, truncate_with_ellipsis};
-use std::collections::{BTreeMap, HashSet};
+use ralph_core::{HatRegistry, HatTopology, RalphConfig, truncate_with_ellipsis};
+use std::collections::BTreeMap;
 use std::io::Write;
 use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
@@ -34,7 +34,7 @@ pub enum HatsCommands {
     Validate,
     /// Display hat topology graph
     Graph {
-        /// Output format (unicode, ascii, compact, mermaid)
+        /// Output format (unicode, ascii, compact, mermaid, dot)
         #[arg(long, default_value = "unicode")]
         format: GraphFormat,
         /// Backend for AI-generated diagrams (claude, kiro, gemini, codex, forge, amp, copilot, opencode, pi, custom)
@@ -77,8 +77,10 @@ pub enum GraphFormat {
     Ascii,
     /// Compact single-glyph nodes - minimal output
     Compact,
-    /// Raw Mermaid syntax - for external rendering tools
+    /// Mermaid flowchart of hat-to-hat edges labeled by topic - for external rendering tools
     Mermaid,
+    /// Graphviz DOT syntax - pipe into `dot -Tsvg`
+    Dot,
 }
 
 #[derive(ValueEnum, Clone, Debug, Default)]
@@ -572,12 +574,19 @@ fn graph_hats<W: Write>(
     match format {
         GraphFormat::Mermaid => {
             writeln!(writer, "```mermaid")?;
-            write!(writer, "{}", generate_mermaid_string(registry))?;
+            write!(
+                writer,
+                "{}",
+                HatTopology::from_registry(registry).to_mermaid()
+            )?;
             writeln!(writer, "```")?;
         }
         GraphFormat::Compact => {
             write!(writer, "{}", generate_compact_graph(registry))?;
         }
+        GraphFormat::Dot => {
+            write!(writer, "{}", HatTopology::from_registry(registry).to_dot())?;
+        }
         GraphFormat::Unicode | GraphFormat::Ascii => {
             // Generate diagram via AI backend
             let rendered = render_hat_dag_via_ai(config, registry, backend_override)?;
@@ -832,74 +841,6 @@ fn generate_compact_graph(registry: &HatRegistry) -> String {
     output
 }
 
-/// Generate Mermaid flowchart syntax for the hat topology.
-fn generate_mermaid_string(registry: &HatRegistry) -> String {
-    let mut output = String::new();
-    output.push_str("flowchart LR\n");
-    output.push_str("    Start[task.start] --> Ralph\n");
-
-    // Reconstruct Ralph's publishes (what hats subscribe to)
-    let mut ralph_publishes: HashSet<String> = HashSet::new();
-    for hat in registry.all() {
-        for sub in &hat.subscriptions {
-            ralph_publishes.insert(sub.as_str().to_string());
-        }
-    }
-
-    // Ralph -> Hats
-    for hat in registry.all() {
-        let node_id = sanitize_id(&hat.name);
-        for sub in &hat.subscriptions {
-            output.push_str(&format!("    Ralph -->|{}| {}\n", sub.as_str(), node_id));
-        }
-    }
-
-    // Hats -> Ralph
-    for hat in registry.all() {
-        let node_id = sanitize_id(&hat.name);
-        for pub_event in &hat.publishes {
-            output.push_str(&format!(
-                "    {} -->|{}| Ralph\n",
-                node_id,
-                pub_event.as_str()
-            ));
-        }
-    }
-
-    // Hat -> Hat (direct flow visualization)
-    // Even though everything goes through Ralph, it's useful to see A -> B
-    for source in registry.all() {
-        let source_id = sanitize_id(&source.name);
-        for pub_event in &source.publishes {
-            // Find hats that subscribe to this
-            for target in registry.all() {
-                if target.id == source.id {
-                    continue;
-                }
-                if target
-                    .subscriptions
-                    .iter()
-                    .any(|s| s.as_str() == pub_event.as_str())
-                {
-                    let target_id = sanitize_id(&target.name);
-                    output.push_str(&format!(
-                        "    {} -.->|{}| {}\n",
-                        source_id,
-                        pub_event.as_str(),
-                        target_id
-                    ));
-                }
-            }
-        }
-    }
-
-    output
-}
-
-fn sanitize_id(name: &str) -> String {
-    name.chars().filter(|c| c.is_alphanumeric()).collect()
-}
-
 fn show_hat<W: Write>(
     writer: &mut W,
     registry: &HatRegistry,
@@ -960,21 +901,13 @@ mod tests {
     use ralph_proto::Hat;
 
     fn mock_hat(name: &str, subs: &[&str], pubs: &[&str]) -> Hat {
-        let mut hat = Hat::new(sanitize_id(name), name);
+        let mut hat = Hat::new(name, name);
         hat.description = format!("Description for {}", name);
         hat.subscriptions = subs.iter().map(|s| (*s).into()).collect();
         hat.publishes = pubs.iter().map(|s| (*s).into()).collect();
         hat
     }
 
-    #[test]
-    fn test_sanitize_id() {
-        assert_eq!(sanitize_id("My Hat"), "MyHat");
-        assert_eq!(sanitize_id("cool-hat"), "coolhat");
-        assert_eq!(sanitize_id("Hat!@#"), "Hat");
-        assert_eq!(sanitize_id("123"), "123");
-    }
-
     #[test]
     fn test_list_hats_empty() {
         let registry = HatRegistry::new();
@@ -1039,6 +972,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_graph_hats_dot() {
+        let mut registry = HatRegistry::new();
+        registry.register(mock_hat("A", &["start"], &["mid"]));
+        registry.register(mock_hat("B", &["mid"], &["end"]));
+
+        let config = RalphConfig::default();
+        let mut buf = Vec::new();
+
+        graph_hats(&mut buf, &config, &registry, GraphFormat::Dot, None).unwrap();
+        let output = String::from_utf8(buf).unwrap();
+
+        assert!(output.starts_with("digraph hats {"));
+        assert!(output.contains("\"Ralph\" -> \"A\" [label=\"start\"];"));
+        assert!(output.contains("\"A\" -> \"B\" [label=\"mid\"];"));
+        assert!(output.contains("\"B\" -> \"Ralph\" [label=\"end\"];"));
+    }
+
     #[test]
     #[ignore = "requires live AI backend"]
     fn test_graph_hats_ascii() {
@@ -1072,19 +1023,23 @@ mod tests {
     }
 
     #[test]
-    fn test_generate_mermaid_string() {
+    fn test_graph_hats_mermaid() {
         let mut registry = HatRegistry::new();
         registry.register(mock_hat("A", &["start"], &["mid"]));
         registry.register(mock_hat("B", &["mid"], &["end"]));
 
-        let output = generate_mermaid_string(&registry);
+        let config = RalphConfig::default();
+        let mut buf = Vec::new();
+
+        graph_hats(&mut buf, &config, &registry, GraphFormat::Mermaid, None).unwrap();
+        let output = String::from_utf8(buf).unwrap();
 
-        assert!(output.contains("flowchart LR"));
+        assert!(output.starts_with("```mermaid\nflowchart LR"));
         assert!(output.contains("Ralph -->|start| A"));
-        assert!(output.contains("A -->|mid| Ralph"));
-        assert!(output.contains("Ralph -->|mid| B"));
-        // Hat-to-hat connection (A publishes mid, B subscribes to mid)
-        assert!(output.contains("A -.->|mid| B"));
+        // Hat-to-hat connection labeled by topic (A publishes mid, B subscribes to mid)
+        assert!(output.contains("A -->|mid| B"));
+        assert!(output.contains("B -->|end| Ralph"));
+        assert!(output.trim_end().ends_with("```"));
     }
 
     #[test]
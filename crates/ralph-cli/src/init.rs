@@ -1,13 +1,15 @@
 //! Init command implementation for ralph.
 //!
 //! Handles initialization of ralph.yml configuration files, either from
-//! a minimal backend template or from an embedded preset.
+//! a minimal backend template, an embedded preset, a remote HTTPS URL, or
+//! the interactive wizard.
 
 use crate::backend_support;
-use crate::presets::list_presets;
 #[cfg(test)]
-use crate::presets::{get_preset, preset_names};
+use crate::presets::preset_names;
+use crate::presets::{get_preset, list_presets};
 use std::fs;
+use std::io::{self, BufRead, Write};
 use std::path::Path;
 
 /// Errors that can occur during initialization.
@@ -30,7 +32,18 @@ pub enum InitError {
     #[error("Failed to write ralph.yml: {0}")]
     WriteError(#[from] std::io::Error),
 
-    #[cfg(test)]
+    #[error("Invalid preset URL '{0}': only https:// URLs are supported")]
+    InsecureUrl(String),
+
+    #[error("Failed to fetch preset from {0}: {1}")]
+    FetchError(String, String),
+
+    #[error("Remote preset exceeds the {MAX_REMOTE_PRESET_BYTES} byte limit")]
+    TooLarge,
+
+    #[error("Remote preset is not a valid ralph config: {0}")]
+    InvalidConfig(String),
+
     #[error("Failed to parse/generate YAML: {0}")]
     YamlError(String),
 }
@@ -38,6 +51,12 @@ pub enum InitError {
 /// Valid backend names.
 const VALID_BACKENDS: &[&str] = backend_support::VALID_BACKENDS;
 
+/// Maximum size of a preset downloaded with `--from-url` (1 MiB).
+const MAX_REMOTE_PRESET_BYTES: usize = 1024 * 1024;
+
+/// Maximum number of redirects followed when downloading a preset.
+const MAX_PRESET_REDIRECTS: usize = 10;
+
 /// Generates the minimal config template for a given backend.
 fn generate_template(backend: &str) -> String {
     format!(
@@ -79,8 +98,179 @@ event_loop:
     )
 }
 
+/// Answers collected by the `ralph init --interactive` wizard.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WizardAnswers {
+    pub backend: String,
+    /// Builtin hat collection whose config and hats are written, if any.
+    pub collection: Option<String>,
+    pub memories: bool,
+}
+
+/// Returns the backends from `VALID_BACKENDS` whose CLI is installed.
+pub fn installed_backends() -> Vec<&'static str> {
+    VALID_BACKENDS
+        .iter()
+        .copied()
+        .filter(|backend| *backend != "custom")
+        .filter(|backend| ralph_adapters::is_backend_available(backend))
+        .collect()
+}
+
+/// Runs the init wizard, prompting on `output` and reading answers from `input`.
+///
+/// Only `backends` are offered. Empty input (or EOF) accepts the default shown
+/// in brackets.
+pub fn run_wizard<R: BufRead, W: Write>(
+    input: &mut R,
+    output: &mut W,
+    backends: &[&str],
+) -> io::Result<WizardAnswers> {
+    let backend_options: Vec<(String, String)> = backends
+        .iter()
+        .map(|backend| ((*backend).to_string(), String::new()))
+        .collect();
+    let backend = prompt_choice(input, output, "Backend", &backend_options)?;
+
+    let mut collection_options = vec![("none".to_string(), "Core config only".to_string())];
+    collection_options.extend(
+        list_presets()
+            .into_iter()
+            .map(|preset| (preset.name.to_string(), preset.description.to_string())),
+    );
+    let collection = prompt_choice(input, output, "Hat collection", &collection_options)?;
+
+    let memories = prompt_yes_no(input, output, "Enable memories?", false)?;
+
+    Ok(WizardAnswers {
+        backend,
+        collection: (collection != "none").then_some(collection),
+        memories,
+    })
+}
+
+/// Prompts for one of `options` by number, defaulting to the first.
+fn prompt_choice<R: BufRead, W: Write>(
+    input: &mut R,
+    output: &mut W,
+    label: &str,
+    options: &[(String, String)],
+) -> io::Result<String> {
+    writeln!(output, "{label}:")?;
+    for (index, (name, description)) in options.iter().enumerate() {
+        if description.is_empty() {
+            writeln!(output, "  {}) {}", index + 1, name)?;
+        } else {
+            writeln!(output, "  {}) {:<22} {}", index + 1, name, description)?;
+        }
+    }
+
+    loop {
+        write!(output, "Select [1]: ")?;
+        output.flush()?;
+
+        let mut line = String::new();
+        if input.read_line(&mut line)? == 0 {
+            writeln!(output)?;
+            return Ok(options[0].0.clone());
+        }
+
+        let answer = line.trim();
+        if answer.is_empty() {
+            return Ok(options[0].0.clone());
+        }
+        if let Some((name, _)) = answer
+            .parse::<usize>()
+            .ok()
+            .and_then(|n| n.checked_sub(1))
+            .and_then(|index| options.get(index))
+        {
+            return Ok(name.clone());
+        }
+        if let Some((name, _)) = options.iter().find(|(name, _)| name == answer) {
+            return Ok(name.clone());
+        }
+        writeln!(output, "Please enter a number from 1 to {}.", options.len())?;
+    }
+}
+
+/// Prompts for a yes/no answer.
+fn prompt_yes_no<R: BufRead, W: Write>(
+    input: &mut R,
+    output: &mut W,
+    label: &str,
+    default: bool,
+) -> io::Result<bool> {
+    let hint = if default { "[Y/n]" } else { "[y/N]" };
+    loop {
+        write!(output, "{label} {hint}: ")?;
+        output.flush()?;
+
+        let mut line = String::new();
+        if input.read_line(&mut line)? == 0 {
+            writeln!(output)?;
+            return Ok(default);
+        }
+
+        match line.trim().to_lowercase().as_str() {
+            "" => return Ok(default),
+            "y" | "yes" => return Ok(true),
+            "n" | "no" => return Ok(false),
+            _ => writeln!(output, "Please answer y or n.")?,
+        }
+    }
+}
+
+/// Generates the config for the wizard's answers.
+///
+/// A chosen hat collection becomes the base config (with the chosen backend);
+/// otherwise the minimal backend template is used.
+fn generate_wizard_config(answers: &WizardAnswers) -> Result<String, InitError> {
+    let mut content = match &answers.collection {
+        Some(collection) => {
+            let preset = get_preset(collection).ok_or_else(|| {
+                InitError::YamlError(format!("unknown hat collection '{collection}'"))
+            })?;
+            format!(
+                "# Generated by: ralph init --interactive (hat collection: {collection})\n{}",
+                override_backend_in_yaml(preset.content, &answers.backend)?
+            )
+        }
+        None => generate_template(&answers.backend).replace(
+            &format!("# Generated by: ralph init --backend {}", answers.backend),
+            "# Generated by: ralph init --interactive",
+        ),
+    };
+
+    if answers.memories {
+        content.push_str("\nmemories:\n  enabled: true\n");
+    }
+
+    Ok(content)
+}
+
+/// Writes ralph.yml from the wizard's answers.
+///
+/// # Errors
+/// Returns error if file exists (without force) or the backend is invalid.
+pub fn init_from_answers(answers: &WizardAnswers, force: bool) -> Result<(), InitError> {
+    if !VALID_BACKENDS.contains(&answers.backend.as_str()) {
+        return Err(InitError::UnknownBackend(
+            backend_support::unknown_backend_message(&answers.backend),
+        ));
+    }
+
+    check_file_exists(force)?;
+    fs::write("ralph.yml", generate_wizard_config(answers)?)?;
+
+    Ok(())
+}
+
 /// Checks if ralph.yml exists and handles the force flag.
-fn check_file_exists(force: bool) -> Result<(), InitError> {
+///
+/// The wizard calls this before its first prompt so a refused overwrite
+/// doesn't throw away the user's answers.
+pub fn check_file_exists(force: bool) -> Result<(), InitError> {
     let path = Path::new("ralph.yml");
     if path.exists() && !force {
         return Err(InitError::FileExists);
@@ -112,6 +302,102 @@ pub fn init_from_backend(backend: &str, force: bool) -> Result<(), InitError> {
     Ok(())
 }
 
+/// Initializes ralph.yml from a YAML preset downloaded over HTTPS.
+///
+/// The response must be at most `MAX_REMOTE_PRESET_BYTES` and parse as a
+/// `RalphConfig`; it is written verbatim so comments are preserved.
+///
+/// # Errors
+/// Returns error if the URL is not HTTPS, the file exists (without force),
+/// the download fails or is too large, or the content is not a valid config.
+pub async fn init_from_url(url: &str, force: bool) -> Result<(), InitError> {
+    validate_preset_url(url)?;
+    check_file_exists(force)?;
+
+    let content = fetch_preset(url).await?;
+    validate_remote_preset(&content)?;
+
+    fs::write("ralph.yml", content)?;
+
+    Ok(())
+}
+
+/// Rejects anything other than a well-formed `https://` URL.
+fn validate_preset_url(url: &str) -> Result<(), InitError> {
+    match reqwest::Url::parse(url) {
+        Ok(parsed) if parsed.scheme() == "https" && parsed.host().is_some() => Ok(()),
+        _ => Err(InitError::InsecureUrl(url.to_string())),
+    }
+}
+
+/// Downloads the preset body, aborting once it exceeds the size limit.
+async fn fetch_preset(url: &str) -> Result<String, InitError> {
+    let fetch_error =
+        |e: &dyn std::fmt::Display| InitError::FetchError(url.to_string(), e.to_string());
+
+    let client = reqwest::Client::builder()
+        .redirect(https_only_redirects())
+        .build()
+        .map_err(|e| fetch_error(&e))?;
+    let mut response = client.get(url).send().await.map_err(|e| {
+        // reqwest's message omits why the redirect policy refused the hop
+        match std::error::Error::source(&e) {
+            Some(reason) if e.is_redirect() => fetch_error(&reason),
+            _ => fetch_error(&e),
+        }
+    })?;
+    if !response.status().is_success() {
+        return Err(fetch_error(&format!("HTTP {}", response.status())));
+    }
+    if response
+        .content_length()
+        .is_some_and(|len| len > MAX_REMOTE_PRESET_BYTES as u64)
+    {
+        return Err(InitError::TooLarge);
+    }
+
+    // Content-Length can be absent or wrong, so enforce the limit while reading
+    let mut body = Vec::new();
+    while let Some(chunk) = response.chunk().await.map_err(|e| fetch_error(&e))? {
+        append_limited(&mut body, &chunk)?;
+    }
+
+    String::from_utf8(body).map_err(|e| fetch_error(&e))
+}
+
+/// Follows redirects only while they stay on `https://`.
+///
+/// `validate_preset_url` checks just the first URL, so a redirect must not be
+/// able to downgrade the download to plain HTTP.
+fn https_only_redirects() -> reqwest::redirect::Policy {
+    reqwest::redirect::Policy::custom(|attempt| {
+        if attempt.url().scheme() != "https" {
+            let url = attempt.url().to_string();
+            attempt.error(format!("redirect to non-https URL {url}"))
+        } else if attempt.previous().len() >= MAX_PRESET_REDIRECTS {
+            attempt.error("too many redirects")
+        } else {
+            attempt.follow()
+        }
+    })
+}
+
+/// Appends a chunk to the body, failing if the size limit would be exceeded.
+fn append_limited(body: &mut Vec<u8>, chunk: &[u8]) -> Result<(), InitError> {
+    if body.len() + chunk.len() > MAX_REMOTE_PRESET_BYTES {
+        return Err(InitError::TooLarge);
+    }
+    body.extend_from_slice(chunk);
+    Ok(())
+}
+
+/// Ensures downloaded content parses as a `RalphConfig`.
+fn validate_remote_preset(content: &str) -> Result<(), InitError> {
+    ralph_core::RalphConfig::parse_yaml(content)
+        .map(|_| ())
+        .map_err(|e| InitError::InvalidConfig(e.to_string()))
+}
+
 /// Initializes ralph.yml from an embedded preset.
 ///
 /// # Arguments
@@ -156,7 +442,6 @@ pub fn init_from_preset(
 
 /// Overrides the backend field in YAML content using regex for surgical replacement.
 /// Preserves all comments and formatting.
-#[cfg(test)]
 fn override_backend_in_yaml(content: &str, backend: &str) -> Result<String, InitError> {
     use regex::Regex;
 
@@ -279,6 +564,206 @@ mod tests {
         );
     }
 
+    fn wizard(input: &str, backends: &[&str]) -> (WizardAnswers, String) {
+        let mut output = Vec::new();
+        let answers = run_wizard(&mut input.as_bytes(), &mut output, backends).expect("wizard");
+        (answers, String::from_utf8(output).expect("utf8"))
+    }
+
+    #[test]
+    fn test_wizard_defaults_on_empty_input() {
+        let (answers, output) = wizard("\n\n\n", &["claude", "gemini"]);
+        assert_eq!(
+            answers,
+            WizardAnswers {
+                backend: "claude".to_string(),
+                collection: None,
+                memories: false,
+            }
+        );
+        assert!(output.contains("1) claude"));
+        assert!(output.contains("2) gemini"));
+        assert!(output.contains("code-assist"));
+    }
+
+    #[test]
+    fn test_wizard_only_offers_given_backends() {
+        let (_, output) = wizard("", &["gemini"]);
+        assert!(output.contains("1) gemini"));
+        assert!(!output.contains("claude"));
+    }
+
+    #[test]
+    fn test_wizard_selections_and_retry() {
+        let code_assist_index = list_presets()
+            .iter()
+            .position(|preset| preset.name == "code-assist")
+            .expect("code-assist preset")
+            + 2; // 1-based, after "none"
+        let input = format!("9\n2\n{code_assist_index}\nmaybe\ny\n");
+
+        let (answers, output) = wizard(&input, &["claude", "gemini"]);
+        assert_eq!(answers.backend, "gemini");
+        assert_eq!(answers.collection.as_deref(), Some("code-assist"));
+        assert!(answers.memories);
+        assert!(output.contains("Please enter a number from 1 to 2."));
+        assert!(output.contains("Please answer y or n."));
+    }
+
+    #[test]
+    fn test_init_from_answers_writes_valid_config() {
+        let temp_dir = TempDir::new().expect("create temp dir");
+        let _cwd = CwdGuard::set(temp_dir.path());
+
+        let answers = WizardAnswers {
+            backend: "gemini".to_string(),
+            collection: Some("code-assist".to_string()),
+            memories: true,
+        };
+        init_from_answers(&answers, false).expect("init_from_answers");
+
+        let content = fs::read_to_string("ralph.yml").expect("read ralph.yml");
+        assert!(content.contains("ralph init --interactive"));
+
+        let config = ralph_core::RalphConfig::parse_yaml(&content).expect("valid config");
+        assert_eq!(config.cli.backend, "gemini");
+        assert!(config.memories.enabled);
+        let preset = ralph_core::RalphConfig::parse_yaml(
+            get_preset("code-assist")
+                .expect("code-assist preset")
+                .content,
+        )
+        .expect("valid preset");
+        assert!(!config.hats.is_empty());
+        assert_eq!(
+            config
+                .hats
+                .keys()
+                .collect::<std::collections::BTreeSet<_>>(),
+            preset
+                .hats
+                .keys()
+                .collect::<std::collections::BTreeSet<_>>(),
+            "the chosen hat collection must be part of the config"
+        );
+
+        assert!(matches!(
+            init_from_answers(&answers, false),
+            Err(InitError::FileExists)
+        ));
+    }
+
+    #[test]
+    fn test_wizard_config_merges_every_collection() {
+        for preset in list_presets() {
+            let answers = WizardAnswers {
+                backend: "codex".to_string(),
+                collection: Some(preset.name.to_string()),
+                memories: true,
+            };
+            let content = generate_wizard_config(&answers).expect("wizard config");
+
+            let config = ralph_core::RalphConfig::parse_yaml(&content).unwrap_or_else(|e| {
+                panic!("{} should merge into a valid config: {e}", preset.name)
+            });
+            assert_eq!(config.cli.backend, "codex", "{}", preset.name);
+            assert!(config.memories.enabled, "{}", preset.name);
+        }
+    }
+
+    #[test]
+    fn test_validate_preset_url_requires_https() {
+        assert!(
+            validate_preset_url("https://gist.githubusercontent.com/u/abc/raw/ralph.yml").is_ok()
+        );
+
+        for url in [
+            "http://example.com/ralph.yml",
+            "file:///etc/passwd",
+            "ftp://example.com/ralph.yml",
+            "not a url",
+        ] {
+            let err = validate_preset_url(url).unwrap_err();
+            assert!(
+                matches!(err, InitError::InsecureUrl(_)),
+                "{url} should be rejected"
+            );
+            assert!(err.to_string().contains("https://"));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_init_from_url_rejects_http_before_fetching() {
+        let temp_dir = TempDir::new().expect("create temp dir");
+        let _cwd = CwdGuard::set(temp_dir.path());
+
+        let result = init_from_url("http://127.0.0.1:1/ralph.yml", true).await;
+        assert!(matches!(result, Err(InitError::InsecureUrl(_))));
+        assert!(!Path::new("ralph.yml").exists());
+    }
+
+    #[tokio::test]
+    async fn test_init_from_url_respects_existing_file() {
+        let temp_dir = TempDir::new().expect("create temp dir");
+        let _cwd = CwdGuard::set(temp_dir.path());
+        fs::write("ralph.yml", "cli:\n  backend: claude\n").expect("write existing");
+
+        let result = init_from_url("https://example.invalid/ralph.yml", false).await;
+        assert!(matches!(result, Err(InitError::FileExists)));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_preset_refuses_redirect_to_http() {
+        use std::io::{Read, Write};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").expect("bind listener");
+        let addr = listener.local_addr().expect("listener addr");
+        // Answers one request with a redirect to plain HTTP, then closes
+        let server = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().expect("accept request");
+            let mut request = [0u8; 1024];
+            let _ = stream.read(&mut request);
+            let response = format!(
+                "HTTP/1.1 302 Found\r\nLocation: http://{addr}/ralph.yml\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+            );
+            stream
+                .write_all(response.as_bytes())
+                .expect("write response");
+        });
+
+        let result = fetch_preset(&format!("http://{addr}/start")).await;
+
+        let err = result.expect_err("redirect to http must be refused");
+        assert!(matches!(err, InitError::FetchError(..)));
+        assert!(err.to_string().contains("non-https"), "{err}");
+        server.join().expect("server thread");
+    }
+
+    #[test]
+    fn test_append_limited_enforces_size_cap() {
+        let mut body = Vec::new();
+        append_limited(&mut body, &vec![b'a'; MAX_REMOTE_PRESET_BYTES]).expect("exactly at limit");
+        assert!(matches!(
+            append_limited(&mut body, b"x"),
+            Err(InitError::TooLarge)
+        ));
+        assert_eq!(body.len(), MAX_REMOTE_PRESET_BYTES);
+    }
+
+    #[test]
+    fn test_validate_remote_preset() {
+        assert!(
+            validate_remote_preset("cli:\n  backend: claude\nevent_loop:\n  max_iterations: 10\n")
+                .is_ok()
+        );
+
+        let err = validate_remote_preset("cli: [not, a, mapping]\n").unwrap_err();
+        assert!(matches!(err, InitError::InvalidConfig(_)));
+
+        let err = validate_remote_preset("<html>Not Found</html>").unwrap_err();
+        assert!(matches!(err, InitError::InvalidConfig(_)));
+    }
+
     #[test]
     fn test_unknown_backend_error() {
         // We can't actually test file operations without filesystem mocking,
@@ -0,0 +1,147 @@
+//! NDJSON output for `ralph run --output json-stream`.
+//!
+//! Writes one JSON object per line to stdout for every published event, plus
+//! synthetic `iteration_start` and `termination` records. Unlike `--rpc`, this
+//! stream is output-only: no commands are read from stdin.
+
+use chrono::Utc;
+use ralph_core::TerminationReason;
+use ralph_proto::Event;
+use serde::Serialize;
+use std::io::Write;
+
+/// A single line of the JSON stream, tagged by `type`.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum JsonStreamRecord {
+    /// An iteration is about to execute.
+    IterationStart {
+        iteration: u32,
+        hat: String,
+        backend: String,
+        timestamp: String,
+    },
+    /// An event was published on the bus.
+    Event {
+        topic: String,
+        payload: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        source: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        target: Option<String>,
+        timestamp: String,
+    },
+    /// The loop has terminated.
+    Termination {
+        reason: String,
+        exit_code: i32,
+        iterations: u32,
+        duration_ms: u64,
+        timestamp: String,
+    },
+}
+
+impl JsonStreamRecord {
+    /// Builds an `iteration_start` record.
+    pub fn iteration_start(iteration: u32, hat: &str, backend: &str) -> Self {
+        Self::IterationStart {
+            iteration,
+            hat: hat.to_string(),
+            backend: backend.to_string(),
+            timestamp: Utc::now().to_rfc3339(),
+        }
+    }
+
+    /// Builds an `event` record from a published bus event.
+    pub fn event(event: &Event) -> Self {
+        Self::Event {
+            topic: event.topic.as_str().to_string(),
+            payload: event.payload.clone(),
+            source: event.source.as_ref().map(|h| h.as_str().to_string()),
+            target: event.target.as_ref().map(|h| h.as_str().to_string()),
+            timestamp: Utc::now().to_rfc3339(),
+        }
+    }
+
+    /// Builds a `termination` record.
+    pub fn termination(reason: &TerminationReason, iterations: u32, duration_ms: u64) -> Self {
+        Self::Termination {
+            reason: reason.as_str().to_string(),
+            exit_code: reason.exit_code(),
+            iterations,
+            duration_ms,
+            timestamp: Utc::now().to_rfc3339(),
+        }
+    }
+
+    /// Serializes the record as a single newline-terminated JSON line.
+    pub fn to_line(&self) -> String {
+        let mut line = serde_json::to_string(self).unwrap_or_else(|_| "{}".to_string());
+        line.push('\n');
+        line
+    }
+}
+
+/// Writes a record to stdout and flushes so consumers see it immediately.
+pub fn emit(record: &JsonStreamRecord) {
+    let mut stdout = std::io::stdout().lock();
+    let _ = stdout.write_all(record.to_line().as_bytes());
+    let _ = stdout.flush();
+}
+
+/// Returns an `EventLoop` observer that streams every published event.
+pub fn observer() -> impl Fn(&Event) + Send + 'static {
+    |event: &Event| emit(&JsonStreamRecord::event(event))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ralph_proto::HatId;
+
+    fn parse(record: &JsonStreamRecord) -> serde_json::Value {
+        let line = record.to_line();
+        assert!(line.ends_with('\n'));
+        assert_eq!(line.matches('\n').count(), 1, "must be a single line");
+        serde_json::from_str(line.trim_end()).unwrap()
+    }
+
+    #[test]
+    fn test_event_record() {
+        let event =
+            Event::new("build.done", "tests: pass\nlint: pass").with_source(HatId::new("builder"));
+        let value = parse(&JsonStreamRecord::event(&event));
+
+        assert_eq!(value["type"], "event");
+        assert_eq!(value["topic"], "build.done");
+        assert_eq!(value["payload"], "tests: pass\nlint: pass");
+        assert_eq!(value["source"], "builder");
+        assert!(value.get("target").is_none());
+        assert!(value["timestamp"].is_string());
+    }
+
+    #[test]
+    fn test_iteration_start_record() {
+        let value = parse(&JsonStreamRecord::iteration_start(3, "builder", "claude"));
+
+        assert_eq!(value["type"], "iteration_start");
+        assert_eq!(value["iteration"], 3);
+        assert_eq!(value["hat"], "builder");
+        assert_eq!(value["backend"], "claude");
+    }
+
+    #[test]
+    fn test_termination_record() {
+        let value = parse(&JsonStreamRecord::termination(
+            &TerminationReason::MaxIterations,
+            10,
+            1500,
+        ));
+
+        assert_eq!(value["type"], "termination");
+        assert_eq!(value["reason"], TerminationReason::MaxIterations.as_str());
+        assert_eq!(value["exit_code"], 2);
+        assert_eq!(value["iterations"], 10);
+        assert_eq!(value["duration_ms"], 1500);
+    }
+}
@@ -1,6 +1,11 @@
 use anyhow::{Context, Result};
+use chrono::{DateTime, Local, NaiveDateTime};
+use ralph_core::{Worktree, list_ralph_worktrees, remove_worktree};
 use std::fs;
-use std::path::Path;
+use std::io::{BufRead, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
 
 mod colors {
     pub const DIM: &str = "\x1b[2m";
@@ -9,8 +14,133 @@ mod colors {
     pub const GREEN: &str = "\x1b[32m";
 }
 
+static NO_EMOJI: AtomicBool = AtomicBool::new(false);
+
+/// Disables emoji in CLI output (`--no-emoji` / `RALPH_NO_EMOJI`).
+pub fn set_no_emoji(no_emoji: bool) {
+    NO_EMOJI.store(no_emoji, Ordering::Relaxed);
+}
+
+/// Returns true when emoji output has been disabled.
+pub fn no_emoji() -> bool {
+    NO_EMOJI.load(Ordering::Relaxed)
+}
+
+/// Returns `emoji`, or its ASCII stand-in when emoji output is disabled.
+pub fn glyph<'a>(emoji: &'a str, ascii: &'a str) -> &'a str {
+    if no_emoji() { ascii } else { emoji }
+}
+
+/// Which diagnostics sessions `clean_diagnostics` should keep.
+///
+/// With no filters set, the whole diagnostics directory is removed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DiagnosticsRetention {
+    /// Only delete sessions older than this.
+    pub older_than: Option<Duration>,
+    /// Always keep this many of the most recent sessions.
+    pub keep_last: Option<usize>,
+}
+
+impl DiagnosticsRetention {
+    fn is_filtered(&self) -> bool {
+        self.older_than.is_some() || self.keep_last.is_some()
+    }
+}
+
+/// A session subdirectory of `.ralph/diagnostics`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct DiagnosticsSession {
+    path: PathBuf,
+    started: DateTime<Local>,
+}
+
+/// Parses a duration such as `30m`, `12h`, `7d`, or `2w` (bare numbers are seconds).
+pub fn parse_age(value: &str) -> Result<Duration, String> {
+    let value = value.trim();
+    let split = value
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(value.len());
+    let (amount, unit) = value.split_at(split);
+    let amount: u64 = amount
+        .parse()
+        .map_err(|_| format!("invalid duration '{value}' (expected e.g. 30m, 12h, 7d)"))?;
+    let seconds_per_unit = match unit {
+        "" | "s" => 1,
+        "m" => 60,
+        "h" => 60 * 60,
+        "d" => 24 * 60 * 60,
+        "w" => 7 * 24 * 60 * 60,
+        _ => {
+            return Err(format!(
+                "invalid duration unit '{unit}' (expected s, m, h, d, or w)"
+            ));
+        }
+    };
+    amount
+        .checked_mul(seconds_per_unit)
+        .map(Duration::from_secs)
+        .ok_or_else(|| format!("duration '{value}' is too large"))
+}
+
+/// Lists session directories, newest first.
+///
+/// Session age comes from the directory name (the timestamp diagnostics uses
+/// when creating it), falling back to the directory's modification time.
+fn list_sessions(diagnostics_dir: &Path) -> Result<Vec<DiagnosticsSession>> {
+    let mut sessions = Vec::new();
+    for entry in fs::read_dir(diagnostics_dir)? {
+        let entry = entry?;
+        if !entry.file_type()?.is_dir() {
+            continue;
+        }
+
+        let name = entry.file_name().to_string_lossy().to_string();
+        let started = match NaiveDateTime::parse_from_str(&name, "%Y-%m-%dT%H-%M-%S")
+            .ok()
+            .and_then(|naive| naive.and_local_timezone(Local).earliest())
+        {
+            Some(started) => started,
+            None => DateTime::from(entry.metadata()?.modified()?),
+        };
+        sessions.push(DiagnosticsSession {
+            path: entry.path(),
+            started,
+        });
+    }
+
+    sessions.sort_by(|a, b| b.started.cmp(&a.started).then(b.path.cmp(&a.path)));
+    Ok(sessions)
+}
+
+/// Selects the sessions the retention filters allow deleting.
+fn sessions_to_delete(
+    sessions: Vec<DiagnosticsSession>,
+    retention: DiagnosticsRetention,
+    now: DateTime<Local>,
+) -> Vec<DiagnosticsSession> {
+    let cutoff = retention
+        .older_than
+        .and_then(|age| chrono::Duration::from_std(age).ok())
+        .and_then(|age| now.checked_sub_signed(age));
+
+    sessions
+        .into_iter()
+        .skip(retention.keep_last.unwrap_or(0))
+        .filter(|session| cutoff.is_none_or(|cutoff| session.started < cutoff))
+        .collect()
+}
+
 /// Clean diagnostic logs from .ralph/diagnostics directory
-pub fn clean_diagnostics(workspace_root: &Path, use_colors: bool, dry_run: bool) -> Result<()> {
+///
+/// With `retention` filters set, only the matching session subdirectories are
+/// removed; otherwise the whole directory is deleted.
+pub fn clean_diagnostics(
+    workspace_root: &Path,
+    use_colors: bool,
+    dry_run: bool,
+    retention: DiagnosticsRetention,
+) -> Result<()> {
     let diagnostics_dir = workspace_root.join(".ralph/diagnostics");
 
     // Check if directory exists
@@ -31,6 +161,10 @@ pub fn clean_diagnostics(workspace_root: &Path, use_colors: bool, dry_run: bool)
         return Ok(());
     }
 
+    if retention.is_filtered() {
+        return clean_sessions(&diagnostics_dir, use_colors, dry_run, retention);
+    }
+
     // Dry run mode - list what would be deleted
     if dry_run {
         if use_colors {
@@ -44,10 +178,8 @@ pub fn clean_diagnostics(workspace_root: &Path, use_colors: bool, dry_run: bool)
         }
         println!("  {}", diagnostics_dir.display());
 
-        // List directory contents (simplified for lib - just show count)
-        if let Ok(entries) = fs::read_dir(&diagnostics_dir) {
-            let count = entries.count();
-            println!("  ({} session directories)", count);
+        for session in list_sessions(&diagnostics_dir)? {
+            println!("    {}", session.path.display());
         }
 
         return Ok(());
@@ -64,8 +196,9 @@ pub fn clean_diagnostics(workspace_root: &Path, use_colors: bool, dry_run: bool)
     // Success message
     if use_colors {
         println!(
-            "{}✓{} Cleaned: Deleted '{}' and all contents",
+            "{}{}{} Cleaned: Deleted '{}' and all contents",
             colors::GREEN,
+            glyph("✓", "[ok]"),
             colors::RESET,
             diagnostics_dir.display()
         );
@@ -79,14 +212,282 @@ pub fn clean_diagnostics(workspace_root: &Path, use_colors: bool, dry_run: bool)
     Ok(())
 }
 
+/// Deletes only the sessions selected by `retention`.
+fn clean_sessions(
+    diagnostics_dir: &Path,
+    use_colors: bool,
+    dry_run: bool,
+    retention: DiagnosticsRetention,
+) -> Result<()> {
+    let sessions = list_sessions(diagnostics_dir)
+        .with_context(|| format!("Failed to read directory '{}'", diagnostics_dir.display()))?;
+    let total = sessions.len();
+    let doomed = sessions_to_delete(sessions, retention, Local::now());
+
+    if doomed.is_empty() {
+        if use_colors {
+            println!(
+                "{}Nothing to clean:{} No sessions match the filters ({} kept)",
+                colors::DIM,
+                colors::RESET,
+                total
+            );
+        } else {
+            println!("Nothing to clean: No sessions match the filters ({total} kept)");
+        }
+        return Ok(());
+    }
+
+    if dry_run {
+        if use_colors {
+            println!(
+                "{}Dry run mode:{} Would delete {} of {} sessions:",
+                colors::CYAN,
+                colors::RESET,
+                doomed.len(),
+                total
+            );
+        } else {
+            println!(
+                "Dry run mode: Would delete {} of {} sessions:",
+                doomed.len(),
+                total
+            );
+        }
+        for session in &doomed {
+            println!("  {}", session.path.display());
+        }
+        return Ok(());
+    }
+
+    for session in &doomed {
+        fs::remove_dir_all(&session.path).with_context(|| {
+            format!(
+                "Failed to delete directory '{}'. Check permissions and try again.",
+                session.path.display()
+            )
+        })?;
+    }
+
+    if use_colors {
+        println!(
+            "{}{}{} Cleaned: Deleted {} of {} sessions from '{}'",
+            colors::GREEN,
+            glyph("✓", "[ok]"),
+            colors::RESET,
+            doomed.len(),
+            total,
+            diagnostics_dir.display()
+        );
+    } else {
+        println!(
+            "Cleaned: Deleted {} of {} sessions from '{}'",
+            doomed.len(),
+            total,
+            diagnostics_dir.display()
+        );
+    }
+
+    Ok(())
+}
+
+/// Everything `ralph clean --all` would remove.
+#[derive(Debug, Default)]
+struct CleanAllTargets {
+    /// Ralph-managed worktrees (those on `ralph/` branches).
+    worktrees: Vec<Worktree>,
+    /// Directories removed recursively.
+    dirs: Vec<PathBuf>,
+    /// Event logs and markers under `.ralph/`.
+    files: Vec<PathBuf>,
+}
+
+impl CleanAllTargets {
+    fn collect(workspace_root: &Path, agent_dir: &Path) -> Result<Self> {
+        let mut targets = Self::default();
+
+        // Not being in a git repo just means there are no worktrees to remove
+        if let Ok(worktrees) = list_ralph_worktrees(workspace_root) {
+            let root = workspace_root.canonicalize().ok();
+            targets.worktrees = worktrees
+                .into_iter()
+                .filter(|wt| !wt.is_main && wt.path.canonicalize().ok() != root)
+                .collect();
+        }
+
+        let diagnostics_dir = workspace_root.join(".ralph/diagnostics");
+        for dir in [agent_dir.to_path_buf(), diagnostics_dir] {
+            if dir.is_dir() {
+                targets.dirs.push(dir);
+            }
+        }
+
+        let ralph_dir = workspace_root.join(".ralph");
+        if ralph_dir.is_dir() {
+            let mut files = Vec::new();
+            for entry in fs::read_dir(&ralph_dir)? {
+                let entry = entry?;
+                let path = entry.path();
+                let name = entry.file_name().to_string_lossy().to_string();
+                let is_events_file = name.starts_with("events")
+                    && path.extension().is_some_and(|ext| ext == "jsonl");
+                if entry.file_type()?.is_file() && (is_events_file || name == "current-events") {
+                    files.push(path);
+                }
+            }
+            files.sort();
+            targets.files = files;
+        }
+
+        Ok(targets)
+    }
+
+    fn is_empty(&self) -> bool {
+        self.worktrees.is_empty() && self.dirs.is_empty() && self.files.is_empty()
+    }
+
+    fn print(&self) {
+        for wt in &self.worktrees {
+            println!("  worktree {} ({})", wt.path.display(), wt.branch);
+        }
+        for dir in &self.dirs {
+            println!("  {}/", dir.display());
+        }
+        for file in &self.files {
+            println!("  {}", file.display());
+        }
+    }
+}
+
+/// Remove `.agent`, `.ralph/diagnostics`, event logs, the `current-events`
+/// marker, and Ralph-managed worktrees in one pass.
+///
+/// Unless `force` is set, asks for confirmation on stderr and reads the answer
+/// from `input`.
+pub fn clean_all<R: BufRead>(
+    workspace_root: &Path,
+    agent_dir: &Path,
+    use_colors: bool,
+    dry_run: bool,
+    force: bool,
+    input: &mut R,
+) -> Result<()> {
+    let targets = CleanAllTargets::collect(workspace_root, agent_dir)?;
+
+    if targets.is_empty() {
+        if use_colors {
+            println!(
+                "{}Nothing to clean:{} No Ralph state found in '{}'",
+                colors::DIM,
+                colors::RESET,
+                workspace_root.display()
+            );
+        } else {
+            println!(
+                "Nothing to clean: No Ralph state found in '{}'",
+                workspace_root.display()
+            );
+        }
+        return Ok(());
+    }
+
+    if dry_run {
+        if use_colors {
+            println!(
+                "{}Dry run mode:{} Would delete:",
+                colors::CYAN,
+                colors::RESET
+            );
+        } else {
+            println!("Dry run mode: Would delete:");
+        }
+        targets.print();
+        return Ok(());
+    }
+
+    if !force {
+        println!("This will permanently delete:");
+        targets.print();
+        eprint!("Continue? [y/N] ");
+        std::io::stderr().flush()?;
+
+        let mut answer = String::new();
+        input.read_line(&mut answer)?;
+        if !answer.trim().eq_ignore_ascii_case("y") {
+            println!("Aborted.");
+            return Ok(());
+        }
+    }
+
+    for wt in &targets.worktrees {
+        remove_worktree(workspace_root, &wt.path)
+            .with_context(|| format!("Failed to remove worktree '{}'", wt.path.display()))?;
+    }
+    for dir in &targets.dirs {
+        fs::remove_dir_all(dir).with_context(|| {
+            format!(
+                "Failed to delete directory '{}'. Check permissions and try again.",
+                dir.display()
+            )
+        })?;
+    }
+    for file in &targets.files {
+        fs::remove_file(file)
+            .with_context(|| format!("Failed to delete file '{}'", file.display()))?;
+    }
+
+    let summary = format!(
+        "{} worktree(s), {} director(ies), {} file(s)",
+        targets.worktrees.len(),
+        targets.dirs.len(),
+        targets.files.len()
+    );
+    if use_colors {
+        println!(
+            "{}{}{} Cleaned: Deleted {}",
+            colors::GREEN,
+            glyph("✓", "[ok]"),
+            colors::RESET,
+            summary
+        );
+    } else {
+        println!("Cleaned: Deleted {summary}");
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    /// Restores the process-global emoji setting when a test finishes.
+    struct NoEmojiGuard(bool);
+
+    impl Drop for NoEmojiGuard {
+        fn drop(&mut self) {
+            set_no_emoji(self.0);
+        }
+    }
+
+    #[test]
+    fn glyph_falls_back_to_ascii_when_emoji_disabled() {
+        let _guard = NoEmojiGuard(no_emoji());
+        set_no_emoji(true);
+        assert_eq!(glyph("✓", "[ok]"), "[ok]");
+        set_no_emoji(false);
+        assert_eq!(glyph("✓", "[ok]"), "✓");
+    }
+
     #[test]
     fn clean_diagnostics_no_dir_is_ok() {
         let temp_dir = tempfile::tempdir().expect("temp dir");
-        let result = clean_diagnostics(temp_dir.path(), false, false);
+        let result = clean_diagnostics(
+            temp_dir.path(),
+            false,
+            false,
+            DiagnosticsRetention::default(),
+        );
         assert!(result.is_ok());
         assert!(!temp_dir.path().join(".ralph/diagnostics").exists());
     }
@@ -98,7 +499,13 @@ mod tests {
         std::fs::create_dir_all(&diagnostics_dir).expect("create diagnostics");
         std::fs::write(diagnostics_dir.join("session.log"), "data").expect("write log");
 
-        clean_diagnostics(temp_dir.path(), false, true).expect("dry run");
+        clean_diagnostics(
+            temp_dir.path(),
+            false,
+            true,
+            DiagnosticsRetention::default(),
+        )
+        .expect("dry run");
         assert!(diagnostics_dir.exists());
     }
 
@@ -109,7 +516,233 @@ mod tests {
         std::fs::create_dir_all(&diagnostics_dir).expect("create diagnostics");
         std::fs::write(diagnostics_dir.join("session.log"), "data").expect("write log");
 
-        clean_diagnostics(temp_dir.path(), false, false).expect("clean diagnostics");
+        clean_diagnostics(
+            temp_dir.path(),
+            false,
+            false,
+            DiagnosticsRetention::default(),
+        )
+        .expect("clean diagnostics");
         assert!(!diagnostics_dir.exists());
     }
+
+    fn make_sessions(diagnostics_dir: &Path, names: &[&str]) {
+        for name in names {
+            let session_dir = diagnostics_dir.join(name);
+            std::fs::create_dir_all(&session_dir).expect("create session");
+            std::fs::write(session_dir.join("errors.jsonl"), "data").expect("write log");
+        }
+    }
+
+    fn session_names(sessions: &[DiagnosticsSession]) -> Vec<String> {
+        sessions
+            .iter()
+            .map(|s| s.path.file_name().unwrap().to_string_lossy().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn parse_age_units() {
+        assert_eq!(parse_age("90").unwrap(), Duration::from_secs(90));
+        assert_eq!(parse_age("30m").unwrap(), Duration::from_mins(30));
+        assert_eq!(parse_age("12h").unwrap(), Duration::from_hours(12));
+        assert_eq!(parse_age("7d").unwrap(), Duration::from_hours(7 * 24));
+        assert_eq!(parse_age("2w").unwrap(), Duration::from_hours(14 * 24));
+        assert!(parse_age("7y").is_err());
+        assert!(parse_age("d").is_err());
+        assert!(parse_age("").is_err());
+    }
+
+    #[test]
+    fn sessions_to_delete_applies_filters() {
+        let temp_dir = tempfile::tempdir().expect("temp dir");
+        let diagnostics_dir = temp_dir.path().join(".ralph/diagnostics");
+        make_sessions(
+            &diagnostics_dir,
+            &[
+                "2024-01-01T10-00-00",
+                "2024-01-05T10-00-00",
+                "2024-01-09T10-00-00",
+                "2024-01-10T10-00-00",
+            ],
+        );
+        let now = NaiveDateTime::parse_from_str("2024-01-10T12-00-00", "%Y-%m-%dT%H-%M-%S")
+            .unwrap()
+            .and_local_timezone(Local)
+            .earliest()
+            .unwrap();
+        let sessions = list_sessions(&diagnostics_dir).unwrap();
+        assert_eq!(
+            session_names(&sessions),
+            vec![
+                "2024-01-10T10-00-00",
+                "2024-01-09T10-00-00",
+                "2024-01-05T10-00-00",
+                "2024-01-01T10-00-00",
+            ]
+        );
+
+        let older_than = DiagnosticsRetention {
+            older_than: Some(parse_age("3d").unwrap()),
+            keep_last: None,
+        };
+        assert_eq!(
+            session_names(&sessions_to_delete(sessions.clone(), older_than, now)),
+            vec!["2024-01-05T10-00-00", "2024-01-01T10-00-00"]
+        );
+
+        let keep_last = DiagnosticsRetention {
+            older_than: None,
+            keep_last: Some(3),
+        };
+        assert_eq!(
+            session_names(&sessions_to_delete(sessions.clone(), keep_last, now)),
+            vec!["2024-01-01T10-00-00"]
+        );
+
+        // Both filters: a session must be old enough and outside the newest N
+        let both = DiagnosticsRetention {
+            older_than: Some(parse_age("1h").unwrap()),
+            keep_last: Some(3),
+        };
+        assert_eq!(
+            session_names(&sessions_to_delete(sessions, both, now)),
+            vec!["2024-01-01T10-00-00"]
+        );
+    }
+
+    #[test]
+    fn clean_diagnostics_keep_last_removes_only_old_sessions() {
+        let temp_dir = tempfile::tempdir().expect("temp dir");
+        let diagnostics_dir = temp_dir.path().join(".ralph/diagnostics");
+        make_sessions(
+            &diagnostics_dir,
+            &[
+                "2024-01-01T10-00-00",
+                "2024-01-02T10-00-00",
+                "2024-01-03T10-00-00",
+            ],
+        );
+        // The `latest` pointer is not a session of its own
+        #[cfg(unix)]
+        std::os::unix::fs::symlink("2024-01-03T10-00-00", diagnostics_dir.join("latest")).unwrap();
+        let retention = DiagnosticsRetention {
+            older_than: None,
+            keep_last: Some(1),
+        };
+
+        clean_diagnostics(temp_dir.path(), false, true, retention).expect("dry run");
+        assert_eq!(list_sessions(&diagnostics_dir).unwrap().len(), 3);
+
+        clean_diagnostics(temp_dir.path(), false, false, retention).expect("clean");
+        assert_eq!(
+            session_names(&list_sessions(&diagnostics_dir).unwrap()),
+            vec!["2024-01-03T10-00-00"]
+        );
+    }
+
+    fn make_ralph_state(root: &Path) {
+        std::fs::create_dir_all(root.join(".agent")).unwrap();
+        std::fs::write(root.join(".agent/scratchpad.md"), "notes").unwrap();
+        make_sessions(&root.join(".ralph/diagnostics"), &["2024-01-01T10-00-00"]);
+        std::fs::write(root.join(".ralph/events.jsonl"), "{}").unwrap();
+        std::fs::write(root.join(".ralph/events-20240101-100000.jsonl"), "{}").unwrap();
+        std::fs::write(
+            root.join(".ralph/current-events"),
+            ".ralph/events-20240101-100000.jsonl",
+        )
+        .unwrap();
+        std::fs::write(root.join(".ralph/merge-queue.jsonl"), "{}").unwrap();
+    }
+
+    #[test]
+    fn clean_all_collects_ralph_state() {
+        let temp_dir = tempfile::tempdir().expect("temp dir");
+        let root = temp_dir.path();
+        make_ralph_state(root);
+
+        let targets = CleanAllTargets::collect(root, &root.join(".agent")).unwrap();
+        assert!(targets.worktrees.is_empty());
+        assert_eq!(
+            targets.dirs,
+            vec![root.join(".agent"), root.join(".ralph/diagnostics")]
+        );
+        assert_eq!(
+            targets.files,
+            vec![
+                root.join(".ralph/current-events"),
+                root.join(".ralph/events-20240101-100000.jsonl"),
+                root.join(".ralph/events.jsonl"),
+            ]
+        );
+    }
+
+    #[test]
+    fn clean_all_dry_run_and_declined_prompt_keep_everything() {
+        let temp_dir = tempfile::tempdir().expect("temp dir");
+        let root = temp_dir.path();
+        make_ralph_state(root);
+        let agent_dir = root.join(".agent");
+
+        clean_all(root, &agent_dir, false, true, false, &mut "".as_bytes()).expect("dry run");
+        clean_all(root, &agent_dir, false, false, false, &mut "n\n".as_bytes()).expect("declined");
+
+        assert!(agent_dir.exists());
+        assert!(root.join(".ralph/current-events").exists());
+    }
+
+    #[test]
+    fn clean_all_removes_state_but_keeps_other_ralph_files() {
+        let temp_dir = tempfile::tempdir().expect("temp dir");
+        let root = temp_dir.path();
+        make_ralph_state(root);
+        let agent_dir = root.join(".agent");
+
+        clean_all(root, &agent_dir, false, false, false, &mut "y\n".as_bytes()).expect("clean");
+
+        assert!(!agent_dir.exists());
+        assert!(!root.join(".ralph/diagnostics").exists());
+        assert!(!root.join(".ralph/events.jsonl").exists());
+        assert!(!root.join(".ralph/current-events").exists());
+        assert!(root.join(".ralph/merge-queue.jsonl").exists());
+
+        // Nothing left to clean
+        clean_all(root, &agent_dir, false, false, true, &mut "".as_bytes()).expect("noop");
+    }
+
+    #[test]
+    fn clean_all_removes_ralph_worktrees() {
+        let temp_dir = tempfile::tempdir().expect("temp dir");
+        let root = temp_dir.path();
+        let git = |args: &[&str]| {
+            let output = std::process::Command::new("git")
+                .args(args)
+                .current_dir(root)
+                .output()
+                .expect("run git");
+            assert!(output.status.success(), "git {args:?} failed");
+        };
+        git(&["init", "--initial-branch=main"]);
+        git(&["config", "user.email", "test@example.com"]);
+        git(&["config", "user.name", "Test"]);
+        git(&["commit", "--allow-empty", "-m", "init"]);
+
+        let worktree =
+            ralph_core::create_worktree(root, "loop-1234", &ralph_core::WorktreeConfig::default())
+                .expect("create worktree");
+        assert!(worktree.path.exists());
+
+        clean_all(
+            root,
+            &root.join(".agent"),
+            false,
+            false,
+            true,
+            &mut "".as_bytes(),
+        )
+        .expect("clean");
+
+        assert!(!worktree.path.exists());
+        assert!(list_ralph_worktrees(root).unwrap().is_empty());
+    }
 }
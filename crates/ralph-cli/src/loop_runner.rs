@@ -10,8 +10,9 @@ use ralph_adapters::{
     ConsoleStreamHandler, ContentBlock, CopilotStreamParser, JsonRpcStreamHandler,
     OutputFormat as BackendOutputFormat, PiAssistantEvent, PiContentBlock, PiStreamEvent,
     PiStreamParser, PrettyStreamHandler, PtyConfig, PtyExecutor, QuietStreamHandler, StreamHandler,
-    TuiStreamHandler,
+    SummaryStreamHandler, TuiStreamHandler,
 };
+use ralph_cli::glyph;
 use ralph_core::diagnostics::{HookDisposition, HookRunTelemetryEntry};
 use ralph_core::{
     CompletionAction, EventLogger, EventLoop, EventParser, EventRecord, HookEngine, HookExecutor,
@@ -39,6 +40,8 @@ use crate::display::{
     build_tui_hat_map, print_iteration_footer, print_iteration_separator, print_loop_banner,
     print_termination, print_wave_header, print_wave_summary, print_wave_worker_done,
 };
+use crate::event_commands::{self, EventCommand};
+use crate::json_stream::{self, JsonStreamRecord};
 use crate::process_management;
 use crate::rpc_stdin::{GuidanceMessage, RpcDispatcher, run_stdin_reader, run_stdout_emitter};
 use crate::web_robot_service::WebRobotService;
@@ -48,6 +51,9 @@ use crate::{ColorMode, Verbosity};
 pub(crate) struct ExecutionOutcome {
     pub output: String,
     pub success: bool,
+    /// Backend stderr. PTY and ACP executors merge stderr into the output
+    /// stream, so for them this is the output tail when the execution failed.
+    pub stderr: String,
     pub termination: Option<TerminationReason>,
     pub total_cost_usd: f64,
     pub input_tokens: u64,
@@ -59,6 +65,28 @@ pub(crate) struct ExecutionOutcome {
     pub num_turns: u32,
 }
 
+/// Trailing lines kept as failure context in logs and the loop summary.
+const FAILURE_TAIL_LINES: usize = 20;
+
+/// Returns the last `max_lines` non-blank lines of `text`.
+fn tail_lines(text: &str, max_lines: usize) -> String {
+    let lines: Vec<&str> = text
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .collect();
+    lines[lines.len().saturating_sub(max_lines)..].join("\n")
+}
+
+/// Failure context for a PTY/ACP execution, whose stderr is merged into the
+/// output stream. Empty when the execution succeeded.
+fn pty_failure_output(pty_result: &ralph_adapters::PtyExecutionResult) -> String {
+    if pty_result.success {
+        String::new()
+    } else {
+        tail_lines(&pty_result.stripped_output, FAILURE_TAIL_LINES)
+    }
+}
+
 fn context_tokens_from_pty_result(pty_result: &ralph_adapters::PtyExecutionResult) -> u64 {
     pty_result.input_tokens
 }
@@ -105,29 +133,57 @@ fn resolve_loop_id(
     })
 }
 
+/// Finds the most recently modified `events-*.jsonl` file in `.ralph/`.
+///
+/// Returns the workspace-relative path written to `.ralph/current-events`, so
+/// `--continue` resumes against the latest run even when the marker is stale.
+/// Ties on mtime fall back to the (timestamped) file name.
+fn latest_events_file(ctx: &ralph_core::LoopContext) -> Option<String> {
+    let entries = fs::read_dir(ctx.ralph_dir()).ok()?;
+    entries
+        .filter_map(Result::ok)
+        .filter_map(|entry| {
+            let name = entry.file_name().into_string().ok()?;
+            let is_jsonl = entry.path().extension().is_some_and(|ext| ext == "jsonl");
+            if !(name.starts_with("events-") && is_jsonl) {
+                return None;
+            }
+            let modified = entry.metadata().ok()?.modified().ok()?;
+            Some((modified, name))
+        })
+        .max()
+        .map(|(_, name)| format!(".ralph/{}", name))
+}
+
 /// Core loop implementation supporting both fresh start and continue modes.
 ///
 /// # Arguments
 ///
 /// * `resume` - If true, publishes `task.resume` instead of `task.start`,
 ///   signaling the planner to read existing scratchpad rather than doing fresh gap analysis.
+/// * `enable_json_stream` - If true, writes NDJSON iteration/event/termination records
+///   to stdout (`--output json-stream`) instead of human-readable console output.
 /// * `record_session` - If provided, records all events to the specified JSONL file for replay testing.
 /// * `auto_merge_override` - Explicit auto-merge setting. If `Some(false)`, disables auto-merge
 ///   (equivalent to `--no-auto-merge`). If `None`, uses `config.features.auto_merge`.
 /// * `resume_loop_id` - Explicit loop ID to use when resuming (`--loop-id`).
 ///   If `None` and `resume` is true, reuses the existing `current-loop-id` marker.
+/// * `event_commands` - Shell commands to run when matching events are published (`--on-event`).
+#[allow(clippy::fn_params_excessive_bools)]
 pub async fn run_loop_impl(
     config: RalphConfig,
     color_mode: ColorMode,
     resume: bool,
     enable_tui: bool,
     enable_rpc: bool,
+    enable_json_stream: bool,
     verbosity: Verbosity,
     record_session: Option<PathBuf>,
     loop_context: Option<LoopContext>,
     custom_args: Vec<String>,
     auto_merge_override: Option<bool>,
     resume_loop_id: Option<String>,
+    event_commands: Vec<EventCommand>,
 ) -> Result<TerminationReason> {
     // Set up process group leadership per spec
     // "The orchestrator must run as a process group leader"
@@ -170,7 +226,8 @@ pub async fn run_loop_impl(
     // 3. Config prompt (inline text)
     // 4. Config prompt_file (file path)
     // 5. Default PROMPT.md
-    let prompt_content = resolve_prompt_content(&config.event_loop)?;
+    let prompt_content =
+        resolve_prompt_content(&config.event_loop, config.core.prompt_warning_chars)?;
 
     // Create or use provided loop context for path resolution
     // This ensures events are written to the correct location for worktree loops
@@ -239,6 +296,12 @@ pub async fn run_loop_impl(
                 );
             }
         }
+    } else if let Some(relative_events_path) = latest_events_file(&ctx) {
+        // The marker may point at an older run (e.g. after a crash), so resume
+        // against whichever timestamped events file was written most recently.
+        fs::write(ctx.current_events_marker(), &relative_events_path)
+            .context("Failed to write current-events marker file")?;
+        info!("Continuing with events file: {}", relative_events_path);
     }
 
     // Initialize event loop with context for proper path resolution
@@ -331,7 +394,7 @@ pub async fn run_loop_impl(
 
     // Set up session recording if requested
     // This records all events to a JSONL file for replay testing
-    let _session_recorder: Option<Arc<SessionRecorder<BufWriter<File>>>> =
+    let session_recorder: Option<Arc<SessionRecorder<BufWriter<File>>>> =
         if let Some(record_path) = record_session {
             let file = File::create(&record_path).with_context(|| {
                 format!("Failed to create session recording file: {:?}", record_path)
@@ -428,7 +491,11 @@ pub async fn run_loop_impl(
     // TUI is observation-only - works in both interactive and autonomous modes
     // Requirements: both stdin and stdout must be terminals for TUI
     // (Crossterm requires stdin for keyboard input, stdout for rendering)
-    let enable_tui = enable_tui && !enable_rpc && stdin().is_terminal() && stdout().is_terminal();
+    let enable_tui = enable_tui
+        && !enable_rpc
+        && !enable_json_stream
+        && stdin().is_terminal()
+        && stdout().is_terminal();
 
     // RPC mode state: channels for stdin commands and stdout events
     let (rpc_event_tx, rpc_event_rx) = if enable_rpc {
@@ -591,6 +658,16 @@ pub async fn run_loop_impl(
         });
     }
 
+    // JSON stream mode: serialize every published event to stdout as NDJSON
+    if enable_json_stream {
+        event_loop.add_observer(json_stream::observer());
+    }
+
+    // --on-event: run user commands for matching published events
+    if !event_commands.is_empty() {
+        event_loop.add_observer(event_commands::observer(event_commands));
+    }
+
     // Give TUI task time to initialize (enter alternate screen, enable raw mode)
     // before the main loop starts doing work
     if tui_handle.is_some() {
@@ -666,6 +743,8 @@ pub async fn run_loop_impl(
 
     // Track consecutive fallback attempts to prevent infinite loops
     let mut consecutive_fallbacks: u32 = 0;
+    // Whether the last iteration was a re-prompt for a missing event.
+    let mut publish_retry_pending = false;
     const MAX_FALLBACK_ATTEMPTS: u32 = 3;
 
     // Initialize loop history if we have a loop context
@@ -711,14 +790,27 @@ pub async fn run_loop_impl(
         }
     }
 
+    // Completion hooks from `features.completion_commands`, run by the
+    // termination handler after a successful completion
+    let completion_handler = LoopCompletionHandler::new(auto_merge).with_completion_commands(
+        &config.features.completion_commands,
+        &config.core.workspace_root,
+    );
+
     // Helper closure to handle termination (writes summary, prints status, records history)
     let handle_termination = |reason: &TerminationReason,
                               state: &ralph_core::LoopState,
                               scratchpad: &str,
                               history: &Option<LoopHistory>,
                               context: &Option<LoopContext>,
-                              auto_merge: bool,
                               prompt: &str| {
+        // Close out the session recording before anything else can fail
+        if let Some(recorder) = &session_recorder
+            && let Err(e) = recorder.finalize(reason.as_str(), state.iteration)
+        {
+            warn!("Failed to finalize session recording: {}", e);
+        }
+
         // Per spec: Write summary file on termination
         let summary_writer = SummaryWriter::default();
         let scratchpad_path = std::path::Path::new(scratchpad);
@@ -748,10 +840,12 @@ pub async fn run_loop_impl(
                 TerminationReason::LoopStale => "loop_stale",
                 TerminationReason::ValidationFailure => "validation_failure",
                 TerminationReason::Stopped => "stopped",
+                TerminationReason::UserStop => "user_stop",
                 TerminationReason::Interrupted => "interrupted",
                 TerminationReason::RestartRequested => "restart_requested",
                 TerminationReason::WorkspaceGone => "workspace_gone",
                 TerminationReason::Cancelled => "cancelled",
+                TerminationReason::TargetEventReached => "target_event_reached",
             };
 
             if matches!(reason, TerminationReason::Interrupted) {
@@ -817,12 +911,14 @@ pub async fn run_loop_impl(
                     TerminationReason::LoopThrashing => "loop thrashing detected",
                     TerminationReason::LoopStale => "stale loop detected",
                     TerminationReason::ValidationFailure => "validation failure",
-                    TerminationReason::Stopped => "manually stopped",
+                    TerminationReason::Stopped => "stopped without completing",
+                    TerminationReason::UserStop => "stopped by user",
                     TerminationReason::Interrupted => "interrupted by signal",
                     TerminationReason::CompletionPromise => unreachable!(),
                     TerminationReason::RestartRequested => "restart requested",
                     TerminationReason::WorkspaceGone => "workspace directory removed",
                     TerminationReason::Cancelled => "cancelled by human",
+                    TerminationReason::TargetEventReached => "stopped at target event",
                 };
                 if let Err(e) = queue.mark_needs_review(loop_id, reason_str) {
                     warn!(loop_id = %loop_id, error = %e, "Failed to mark merge as needs-review");
@@ -836,8 +932,7 @@ pub async fn run_loop_impl(
         // Per spec: merge loops do NOT enqueue themselves, even if run in worktree context
         if let Some(ctx) = context {
             if merge_loop_id.is_none() && matches!(reason, TerminationReason::CompletionPromise) {
-                let handler = LoopCompletionHandler::new(auto_merge);
-                match handler.handle_completion(ctx, prompt) {
+                match completion_handler.handle_completion(ctx, prompt) {
                     Ok(CompletionAction::None) => {
                         debug!("Loop completed, no action needed");
                     }
@@ -886,6 +981,16 @@ pub async fn run_loop_impl(
                         warn!("Completion handler failed: {}", e);
                     }
                 }
+                match completion_handler.run_hooks(reason, state) {
+                    Ok(failures) => {
+                        for failure in failures {
+                            warn!(error = %failure, "Completion hook failed, continuing");
+                        }
+                    }
+                    Err(e) => {
+                        warn!("Completion hook aborted: {}", e);
+                    }
+                }
             }
 
             // Handle merge queue processing for primary loop completion
@@ -903,10 +1008,18 @@ pub async fn run_loop_impl(
 
         // Print termination info to console (skip in TUI mode - TUI handles display)
         // Skip in RPC mode - JSON events replace console output
-        if !enable_tui && !enable_rpc {
+        if !enable_tui && !enable_rpc && !enable_json_stream {
             print_termination(reason, state, use_colors, Some(&loop_id));
         }
 
+        if enable_json_stream {
+            json_stream::emit(&JsonStreamRecord::termination(
+                reason,
+                state.iteration,
+                state.elapsed().as_millis() as u64,
+            ));
+        }
+
         // Mark RPC state as completed so get_state reflects termination
         if let Some(ref shared) = rpc_dispatcher_started {
             shared
@@ -928,7 +1041,9 @@ pub async fn run_loop_impl(
                 TerminationReason::MaxIterations => {
                     ralph_proto::json_rpc::TerminationReason::MaxIterations
                 }
-                TerminationReason::Interrupted | TerminationReason::Stopped => {
+                TerminationReason::Interrupted
+                | TerminationReason::Stopped
+                | TerminationReason::UserStop => {
                     ralph_proto::json_rpc::TerminationReason::Interrupted
                 }
                 _ => ralph_proto::json_rpc::TerminationReason::Error,
@@ -992,7 +1107,6 @@ pub async fn run_loop_impl(
             &config.core.scratchpad.path,
             &loop_history,
             &loop_context,
-            auto_merge,
             &prompt_content,
         );
 
@@ -1007,7 +1121,7 @@ pub async fn run_loop_impl(
     // Print startup banner for --no-tui runs. Gives agents/humans tailing
     // the stream the loop-id, key state files, and tail/resume commands up
     // front so they don't have to reverse-engineer them from scrollback.
-    if !enable_tui && !enable_rpc {
+    if !enable_tui && !enable_rpc && !enable_json_stream {
         let events_path = resolve_current_events_path(&ctx);
         let scratchpad_path = ctx.workspace().join(&config.core.scratchpad.path);
         print_loop_banner(
@@ -1081,7 +1195,6 @@ pub async fn run_loop_impl(
                 &config.core.scratchpad.path,
                 &loop_history,
                 &loop_context,
-                auto_merge,
                 &prompt_content,
             );
             // Signal TUI to exit immediately on interrupt
@@ -1089,6 +1202,24 @@ pub async fn run_loop_impl(
             return Ok(reason);
         }
 
+        // Run backpressure_command checks for build.done events held back by
+        // event processing. They run here, not inside process_events_from_jsonl,
+        // so an interrupt stops the command instead of waiting out its timeout.
+        let backpressure_checks = event_loop.take_backpressure_checks();
+        if !backpressure_checks.is_empty() {
+            for check in backpressure_checks {
+                info!(command = %check.command, "build.done missing evidence, running backpressure_command");
+                let outcome = check.run(interrupt_rx.clone()).await;
+                if let Some(event) = event_loop.resolve_backpressure_check(check, outcome) {
+                    log_backpressure_result(&mut event_logger, &mut event_loop, &event);
+                }
+            }
+            if *interrupt_rx.borrow() {
+                // Terminate through the interrupt handling at the top of the loop
+                continue;
+            }
+        }
+
         // Drain next-loop guidance queue and write as human.guidance events.
         // These will be picked up by process_events_from_jsonl() during build_prompt().
         // Handle both TUI guidance queue and RPC guidance channel.
@@ -1204,7 +1335,6 @@ pub async fn run_loop_impl(
                 &config.core.scratchpad.path,
                 &loop_history,
                 &loop_context,
-                auto_merge,
                 &prompt_content,
             );
             // Wait for user to exit TUI (press 'q') on natural completion
@@ -1214,6 +1344,21 @@ pub async fn run_loop_impl(
             return Ok(reason);
         }
 
+        // Every pending event waits on a hat cooldown: sleep instead of running
+        // an empty iteration, re-checking interrupts and termination regularly.
+        if let Some(wait) = event_loop.cooldown_wait() {
+            debug!(
+                wait_secs = wait.as_secs(),
+                "All pending events are held by hat cooldowns, waiting"
+            );
+            let mut interrupt_rx_cooldown = interrupt_rx.clone();
+            tokio::select! {
+                () = tokio::time::sleep(wait.min(Duration::from_millis(COOLDOWN_WAIT_POLL_INTERVAL_MS))) => {}
+                _ = interrupt_rx_cooldown.changed() => {}
+            }
+            continue;
+        }
+
         let iteration = event_loop.state().iteration + 1;
 
         if event_loop.has_pending_events() {
@@ -1289,7 +1434,6 @@ pub async fn run_loop_impl(
                     &config.core.scratchpad.path,
                     &loop_history,
                     &loop_context,
-                    auto_merge,
                     &prompt_content,
                 );
                 // Wait for user to exit TUI (press 'q') on natural completion
@@ -1363,7 +1507,6 @@ pub async fn run_loop_impl(
                             &config.core.scratchpad.path,
                             &loop_history,
                             &loop_context,
-                            auto_merge,
                             &prompt_content,
                         );
                         if let Some(handle) = tui_handle.take() {
@@ -1425,7 +1568,6 @@ pub async fn run_loop_impl(
                         &config.core.scratchpad.path,
                         &loop_history,
                         &loop_context,
-                        auto_merge,
                         &prompt_content,
                     );
                     // Wait for user to exit TUI (press 'q') on natural completion
@@ -1488,7 +1630,6 @@ pub async fn run_loop_impl(
                     &config.core.scratchpad.path,
                     &loop_history,
                     &loop_context,
-                    auto_merge,
                     &prompt_content,
                 );
                 // Wait for user to exit TUI (press 'q') on natural completion
@@ -1506,6 +1647,8 @@ pub async fn run_loop_impl(
                 .store(iteration, std::sync::atomic::Ordering::Relaxed);
         }
 
+        event_loop.notify_iteration_start(iteration, &hat_id);
+
         // Determine which hat to display in iteration separator
         // When Ralph is coordinating (hat_id == "ralph"), show the active hat being worked on
         let preview_display_hat = if hat_id.as_str() == "ralph" {
@@ -1586,7 +1729,6 @@ pub async fn run_loop_impl(
                 &config.core.scratchpad.path,
                 &loop_history,
                 &loop_context,
-                auto_merge,
                 &prompt_content,
             );
             // Wait for user to exit TUI (press 'q') on natural completion
@@ -1600,7 +1742,7 @@ pub async fn run_loop_impl(
         // Skip in TUI mode - TUI shows hat info in header, and stdout would corrupt display
         // Skip in RPC mode - JSON events replace console output
         if last_hat.as_ref() != Some(&hat_id) {
-            if tui_state.is_none() && !enable_rpc {
+            if tui_state.is_none() && !enable_rpc && !enable_json_stream {
                 if hat_id.as_str() == "ralph" {
                     info!("I'm Ralph. Let's do this.");
                 } else {
@@ -1628,6 +1770,7 @@ pub async fn run_loop_impl(
 
         // Log full prompt to diagnostics (RALPH_DIAGNOSTICS=1)
         event_loop.log_prompt(iteration, display_hat.as_str(), &prompt);
+        ctx.set_current_iteration(iteration, &display_hat, &prompt);
 
         let hat_display = event_loop
             .registry()
@@ -1663,16 +1806,24 @@ pub async fn run_loop_impl(
             };
             let _ = tx.try_send(start_event);
         }
+        if enable_json_stream {
+            json_stream::emit(&JsonStreamRecord::iteration_start(
+                iteration,
+                display_hat.as_str(),
+                &config.cli.backend,
+            ));
+        }
 
         // Per spec: Print iteration demarcation separator
         // "Each iteration must be clearly demarcated in the output so users can
         // visually distinguish where one iteration ends and another begins."
         // Skip when TUI is enabled - TUI has its own header showing iteration info
         // Skip in RPC mode - JSON events replace console output
-        if tui_state.is_none() && !enable_rpc {
+        if tui_state.is_none() && !enable_rpc && !enable_json_stream {
             print_iteration_separator(
                 iteration,
                 display_hat.as_str(),
+                config.hats.get(display_hat.as_str()),
                 event_loop.state().elapsed(),
                 config.event_loop.max_iterations,
                 use_colors,
@@ -1765,6 +1916,9 @@ pub async fn run_loop_impl(
         if let Some(args) = hat_backend_args {
             effective_backend.args.extend(args);
         }
+        // Hat-level backends are built without the global CLI config, so
+        // carry `cli.env` (including --env-file entries) over explicitly.
+        effective_backend.apply_env(&config.cli.env);
 
         // Step 3: Get timeout from config based on actual backend being used
         let timeout_secs = config.adapter_settings(&backend_name_for_timeout).timeout;
@@ -1834,6 +1988,7 @@ pub async fn run_loop_impl(
                         &result.output,
                     ),
                     success: result.success,
+                    stderr: result.stderr,
                     termination: None,
                     total_cost_usd: 0.0,
                     input_tokens: 0,
@@ -1895,7 +2050,7 @@ pub async fn run_loop_impl(
                 )
                 .await?;
 
-                handle_termination(&reason, event_loop.state(), &config.core.scratchpad.path, &loop_history, &loop_context, auto_merge, &prompt_content);
+                handle_termination(&reason, event_loop.state(), &config.core.scratchpad.path, &loop_history, &loop_context, &prompt_content);
                 // Signal TUI to exit immediately on interrupt
                 let _ = terminated_tx.send(true);
                 return Ok(reason);
@@ -1944,7 +2099,6 @@ pub async fn run_loop_impl(
                 &config.core.scratchpad.path,
                 &loop_history,
                 &loop_context,
-                auto_merge,
                 &prompt_content,
             );
             // Wait for user to exit TUI (press 'q') on natural completion
@@ -1967,6 +2121,12 @@ pub async fn run_loop_impl(
             context_tokens: outcome.context_tokens,
         };
 
+        if !outcome.success && !outcome.stderr.trim().is_empty() {
+            let stderr = tail_lines(&outcome.stderr, FAILURE_TAIL_LINES);
+            warn!(hat = %hat_id, iteration, "Backend execution failed:\n{stderr}");
+            event_loop.record_backend_error(stderr);
+        }
+
         let output = outcome.output;
         let success = outcome.success;
 
@@ -2003,7 +2163,7 @@ pub async fn run_loop_impl(
 
         // Per-iteration footer for --no-tui: one line with budget/cost/elapsed
         // so tailing agents can catch runaway loops without parsing events.
-        if tui_state.is_none() && !enable_rpc {
+        if tui_state.is_none() && !enable_rpc && !enable_json_stream {
             let iter_duration = iteration_started_at.elapsed();
             print_iteration_footer(
                 iteration,
@@ -2089,7 +2249,6 @@ pub async fn run_loop_impl(
                 &config.core.scratchpad.path,
                 &loop_history,
                 &loop_context,
-                auto_merge,
                 &prompt_content,
             );
             // Wait for user to exit TUI (press 'q') on natural completion
@@ -2187,7 +2346,6 @@ pub async fn run_loop_impl(
                     &config.core.scratchpad.path,
                     &loop_history,
                     &loop_context,
-                    auto_merge,
                     &prompt_content,
                 );
                 if let Some(handle) = tui_handle.take() {
@@ -2282,7 +2440,6 @@ pub async fn run_loop_impl(
                     &config.core.scratchpad.path,
                     &loop_history,
                     &loop_context,
-                    auto_merge,
                     &prompt_content,
                 );
                 if let Some(handle) = tui_handle.take() {
@@ -2379,7 +2536,6 @@ pub async fn run_loop_impl(
                     &config.core.scratchpad.path,
                     &loop_history,
                     &loop_context,
-                    auto_merge,
                     &prompt_content,
                 );
                 if let Some(handle) = tui_handle.take() {
@@ -2466,7 +2622,6 @@ pub async fn run_loop_impl(
                     &config.core.scratchpad.path,
                     &loop_history,
                     &loop_context,
-                    auto_merge,
                     &prompt_content,
                 );
                 if let Some(handle) = tui_handle.take() {
@@ -2511,7 +2666,8 @@ pub async fn run_loop_impl(
                 &backend,
                 &ctx,
                 use_colors,
-                enable_rpc,
+                // JSON stream output must stay NDJSON-only, like RPC
+                enable_rpc || enable_json_stream,
                 rpc_event_tx.as_ref(),
                 tui_state.as_ref(),
             )
@@ -2556,7 +2712,6 @@ pub async fn run_loop_impl(
                 &config.core.scratchpad.path,
                 &loop_history,
                 &loop_context,
-                auto_merge,
                 &prompt_content,
             );
             if let Some(handle) = tui_handle.take() {
@@ -2565,13 +2720,21 @@ pub async fn run_loop_impl(
             return Ok(reason);
         }
 
-        if let Some(reason) =
-            late_termination_reason.or_else(|| event_loop.check_completion_event())
+        if let Some(reason) = late_termination_reason
+            .or_else(|| event_loop.check_target_event())
+            .or_else(|| event_loop.check_completion_event())
         {
-            info!(
-                "Completion event {} detected.",
-                config.event_loop.completion_promise
-            );
+            if reason == TerminationReason::TargetEventReached {
+                info!(
+                    "Target event {} detected.",
+                    config.event_loop.until_event.as_deref().unwrap_or_default()
+                );
+            } else {
+                info!(
+                    "Completion event {} detected.",
+                    config.event_loop.completion_promise
+                );
+            }
 
             let reason = dispatch_pre_loop_termination_hooks(
                 &event_loop,
@@ -2614,7 +2777,6 @@ pub async fn run_loop_impl(
                 &config.core.scratchpad.path,
                 &loop_history,
                 &loop_context,
-                auto_merge,
                 &prompt_content,
             );
             if let Some(handle) = tui_handle.take() {
@@ -2681,7 +2843,6 @@ pub async fn run_loop_impl(
                     &config.core.scratchpad.path,
                     &loop_history,
                     &loop_context,
-                    auto_merge,
                     &prompt_content,
                 );
                 if let Some(handle) = tui_handle.take() {
@@ -2696,15 +2857,24 @@ pub async fn run_loop_impl(
         // Precheck validation: Warn if no pending events after processing output
         // Per EventLoop doc: "Use has_pending_events after process_output to detect
         // if the LLM failed to publish an event."
-        if !event_loop.has_pending_events() {
+        if event_loop.has_pending_events() {
+            publish_retry_pending = false;
+        } else {
             let expected = event_loop.get_hat_publishes(&hat_id);
             debug!(
                 hat = %hat_id.as_str(),
                 expected_topics = ?expected,
                 "No pending events after iteration. Agent may have failed to publish a valid event. \
-                 Expected one of: {:?}. Loop will terminate on next iteration.",
+                 Expected one of: {:?}.",
                 expected
             );
+            // Re-prompt once; if the retry also publishes nothing, fallback
+            // recovery takes over on the next iteration.
+            if config.event_loop.retry_missing_event && !publish_retry_pending {
+                info!(hat = %hat_id.as_str(), "Re-prompting agent to publish an event");
+                event_loop.inject_publish_retry(&hat_id);
+                publish_retry_pending = true;
+            }
         }
 
         // Cooldown delay between iterations (skip for human events)
@@ -3099,6 +3269,7 @@ async fn resolve_loop_termination_hook_outcomes(
 const RETRY_BACKOFF_DELAYS_MS: [u64; 3] = [100, 200, 400];
 const RETRY_BACKOFF_SIGNAL_POLL_INTERVAL_MS: u64 = 100;
 const SUSPEND_WAIT_SIGNAL_POLL_INTERVAL_MS: u64 = 250;
+const COOLDOWN_WAIT_POLL_INTERVAL_MS: u64 = 1_000;
 const HOOK_MUTATION_PAYLOAD_METADATA_KEY: &str = "metadata";
 const HOOK_MUTATION_METADATA_NAMESPACE_KEY: &str = "hook_metadata";
 
@@ -4007,7 +4178,7 @@ async fn wait_for_resume_if_suspended(
                 hook_name = %suspending_outcome.hook_name,
                 "Stop requested while suspended; terminating loop"
             );
-            return Ok(Some(TerminationReason::Stopped));
+            return Ok(Some(TerminationReason::UserStop));
         }
 
         if is_restart_requested(suspend_state_store.workspace_root()) {
@@ -4270,6 +4441,18 @@ fn prepare_tui_iteration(
     state.latest_iteration_lines_handle()
 }
 
+/// Builds the per-line prefix for non-interactive stream output.
+///
+/// Multi-hat loops prefix each line with the active hat and iteration so logs
+/// show which hat produced them. Solo mode returns an empty prefix (disabled).
+fn stream_line_prefix(config: &RalphConfig, hat: &str, iteration: u32) -> String {
+    if config.hats.is_empty() {
+        String::new()
+    } else {
+        format!("[{} #{}] ", hat, iteration)
+    }
+}
+
 /// Execute a prompt via ACP (Agent Client Protocol) for kiro-acp backend.
 async fn execute_acp(
     backend: &CliBackend,
@@ -4303,18 +4486,25 @@ async fn execute_acp(
                 let mut handler = QuietStreamHandler;
                 executor.execute(prompt, &mut handler).await?
             }
+            Verbosity::Summary => {
+                let mut handler = SummaryStreamHandler::new(hat, iteration);
+                executor.execute(prompt, &mut handler).await?
+            }
             Verbosity::Normal => {
-                let mut handler = ConsoleStreamHandler::new(false);
+                let mut handler = ConsoleStreamHandler::new(false)
+                    .with_line_prefix(stream_line_prefix(config, hat, iteration));
                 executor.execute(prompt, &mut handler).await?
             }
             Verbosity::Verbose => {
-                let mut handler = ConsoleStreamHandler::new(true);
+                let mut handler = ConsoleStreamHandler::new(true)
+                    .with_line_prefix(stream_line_prefix(config, hat, iteration));
                 executor.execute(prompt, &mut handler).await?
             }
         }
     };
 
     let context_tokens = context_tokens_from_pty_result(&pty_result);
+    let stderr = pty_failure_output(&pty_result);
     let output = if pty_result.extracted_text.is_empty() {
         pty_result.stripped_output
     } else {
@@ -4324,6 +4514,7 @@ async fn execute_acp(
     Ok(ExecutionOutcome {
         output,
         success: pty_result.success,
+        stderr,
         termination: None,
         total_cost_usd: pty_result.total_cost_usd,
         input_tokens: pty_result.input_tokens,
@@ -4431,6 +4622,7 @@ async fn execute_pty(
         // Use ConsoleStreamHandler for Text format backends (Kiro, Gemini, etc.) for immediate output
         let use_pretty =
             backend.output_format == BackendOutputFormat::StreamJson && stdout().is_terminal();
+        let line_prefix = stream_line_prefix(config, hat, iteration);
 
         match verbosity {
             Verbosity::Quiet => {
@@ -4438,24 +4630,30 @@ async fn execute_pty(
                 exec.run_observe_streaming(prompt, interrupt_rx, &mut handler)
                     .await
             }
+            Verbosity::Summary => {
+                let mut handler = SummaryStreamHandler::new(hat, iteration);
+                exec.run_observe_streaming(prompt, interrupt_rx, &mut handler)
+                    .await
+            }
             Verbosity::Normal => {
                 if use_pretty {
-                    let mut handler = PrettyStreamHandler::new(false);
+                    let mut handler = PrettyStreamHandler::new(false).with_line_prefix(line_prefix);
                     exec.run_observe_streaming(prompt, interrupt_rx, &mut handler)
                         .await
                 } else {
-                    let mut handler = ConsoleStreamHandler::new(false);
+                    let mut handler =
+                        ConsoleStreamHandler::new(false).with_line_prefix(line_prefix);
                     exec.run_observe_streaming(prompt, interrupt_rx, &mut handler)
                         .await
                 }
             }
             Verbosity::Verbose => {
                 if use_pretty {
-                    let mut handler = PrettyStreamHandler::new(true);
+                    let mut handler = PrettyStreamHandler::new(true).with_line_prefix(line_prefix);
                     exec.run_observe_streaming(prompt, interrupt_rx, &mut handler)
                         .await
                 } else {
-                    let mut handler = ConsoleStreamHandler::new(true);
+                    let mut handler = ConsoleStreamHandler::new(true).with_line_prefix(line_prefix);
                     exec.run_observe_streaming(prompt, interrupt_rx, &mut handler)
                         .await
                 }
@@ -4466,6 +4664,7 @@ async fn execute_pty(
     match result {
         Ok(pty_result) => {
             let context_tokens = context_tokens_from_pty_result(&pty_result);
+            let stderr = pty_failure_output(&pty_result);
             let termination = convert_termination_type(pty_result.termination, interactive);
 
             // Use extracted_text for event parsing when available (NDJSON backends like Claude),
@@ -4480,6 +4679,7 @@ async fn execute_pty(
             Ok(ExecutionOutcome {
                 output: output_for_parsing,
                 success: pty_result.success,
+                stderr,
                 termination,
                 total_cost_usd: pty_result.total_cost_usd,
                 input_tokens: pty_result.input_tokens,
@@ -4559,7 +4759,8 @@ fn log_events_from_output(
             }
         }
 
-        let record = EventRecord::new(iteration, hat_id.to_string(), &event, triggered);
+        let record = EventRecord::new(iteration, hat_id.to_string(), &event, triggered)
+            .with_triggered_hats(registry.find_all_by_trigger(event.topic.as_str()));
 
         if let Err(e) = logger.log(&record) {
             warn!("Failed to log event {}: {}", event.topic, e);
@@ -4580,6 +4781,27 @@ fn log_terminate_event(logger: &mut EventLogger, iteration: u32, event: &Event)
     }
 }
 
+/// Logs the event a `backpressure_command` check resolved to.
+///
+/// The event is already on the bus, so the event reader skips past the
+/// logged record instead of reading it back as agent output.
+fn log_backpressure_result(logger: &mut EventLogger, event_loop: &mut EventLoop, event: &Event) {
+    let registry = event_loop.registry();
+    let topic = event.topic.as_str();
+    let record = EventRecord::new(
+        event_loop.state().iteration,
+        "loop",
+        event,
+        registry.find_by_trigger(topic),
+    )
+    .with_triggered_hats(registry.find_all_by_trigger(topic));
+
+    if let Err(e) = logger.log(&record) {
+        warn!("Failed to log {} event: {}", event.topic, e);
+    }
+    event_loop.sync_event_reader_to_file_end();
+}
+
 /// Metrics captured once per iteration and emitted as the synthetic
 /// `iteration.summary` events.jsonl row.
 #[derive(Clone, Copy)]
@@ -4662,8 +4884,14 @@ fn get_last_commit_info() -> Option<String> {
 /// 4. Config event_loop.prompt_file (prompt file path)
 /// 5. Default PROMPT.md
 ///
+/// Logs a warning when the resolved prompt exceeds `warning_chars` (see
+/// `core.prompt_warning_chars`).
+///
 /// Note: CLI overrides are already applied to config before this function is called.
-fn resolve_prompt_content(event_loop_config: &ralph_core::EventLoopConfig) -> Result<String> {
+pub(crate) fn resolve_prompt_content(
+    event_loop_config: &ralph_core::EventLoopConfig,
+    warning_chars: usize,
+) -> Result<String> {
     debug!(
         inline_prompt = ?event_loop_config.prompt.as_ref().map(|s| format!("{}...", &s[..s.len().min(50)])),
         prompt_file = %event_loop_config.prompt_file,
@@ -4673,6 +4901,9 @@ fn resolve_prompt_content(event_loop_config: &ralph_core::EventLoopConfig) -> Re
     // Check for inline prompt first (CLI -p or config prompt)
     if let Some(ref inline_text) = event_loop_config.prompt {
         debug!(len = inline_text.len(), "Using inline prompt text");
+        if let Some(warning) = prompt_size_warning("Inline prompt", inline_text, warning_chars) {
+            warn!("{}", warning);
+        }
         return Ok(inline_text.clone());
     }
 
@@ -4685,6 +4916,10 @@ fn resolve_prompt_content(event_loop_config: &ralph_core::EventLoopConfig) -> Re
             let content = std::fs::read_to_string(path)
                 .with_context(|| format!("Failed to read prompt file: {}", prompt_file))?;
             debug!(path = %prompt_file, len = content.len(), "Read prompt from file");
+            let source = format!("Prompt file '{}'", prompt_file);
+            if let Some(warning) = prompt_size_warning(&source, &content, warning_chars) {
+                warn!("{}", warning);
+            }
             return Ok(content);
         } else {
             // File specified but doesn't exist - error with helpful message
@@ -4702,6 +4937,26 @@ fn resolve_prompt_content(event_loop_config: &ralph_core::EventLoopConfig) -> Re
     )
 }
 
+/// Builds the oversized-prompt warning for `content`, if it exceeds `warning_chars`.
+///
+/// Token counts are estimated at four characters per token.
+fn prompt_size_warning(source: &str, content: &str, warning_chars: usize) -> Option<String> {
+    let chars = content.chars().count();
+    if warning_chars == 0 || chars <= warning_chars {
+        return None;
+    }
+
+    Some(format!(
+        "{} is {} chars (~{} tokens), above the {}-char warning threshold. \
+         The backend may truncate it; consider trimming it or moving detail into specs. \
+         Adjust with core.prompt_warning_chars (0 disables).",
+        source,
+        chars,
+        chars.div_ceil(4),
+        warning_chars
+    ))
+}
+
 /// Checks for planning session user responses and publishes them as events.
 ///
 /// When running in planning mode (RALPH_PLANNING_SESSION_ID is set),
@@ -5063,12 +5318,14 @@ pub async fn start_loop(
         false, // not resume
         false, // no TUI
         false, // no RPC
+        false, // no JSON stream
         Verbosity::Normal,
         None,               // no session recording
         Some(loop_context), // loop context
         Vec::new(),         // no custom args
         None,               // default auto-merge
         None,               // no explicit loop ID
+        Vec::new(),         // no event commands
     )
     .await
 }
@@ -5210,7 +5467,7 @@ async fn handle_wave_events(
     // Announce wave start to CLI / RPC / TUI
     if out.show_cli {
         print_wave_header(
-            &detected.hat_config.name,
+            &detected.hat_config,
             detected.total as usize,
             wave_timeout_secs,
             out.use_colors,
@@ -5264,9 +5521,11 @@ async fn handle_wave_events(
     }
 
     let main_events_file = resolve_current_events_path(ctx);
+    let cli_env = event_loop.config().cli.env.clone();
     let wave_result = execute_wave(
         &detected,
         backend,
+        &cli_env,
         &main_events_file,
         out.show_cli,
         out.use_colors,
@@ -5368,6 +5627,7 @@ async fn handle_wave_events(
 async fn execute_wave(
     wave: &ralph_core::DetectedWave,
     global_backend: &CliBackend,
+    cli_env: &std::collections::BTreeMap<String, String>,
     main_events_file: &Path,
     show_progress: bool,
     use_colors: bool,
@@ -5425,7 +5685,13 @@ async fn execute_wave(
 
         // Resolve backend for this worker
         let mut worker_backend = if let Some(ref hat_backend) = hat_config.backend {
-            CliBackend::from_hat_backend(hat_backend).unwrap_or_else(|_| global_backend.clone())
+            CliBackend::from_hat_backend(hat_backend).map_or_else(
+                |_| global_backend.clone(),
+                |mut backend| {
+                    backend.apply_env(cli_env);
+                    backend
+                },
+            )
         } else {
             global_backend.clone()
         };
@@ -5671,7 +5937,7 @@ impl StreamHandler for WaveWorkerStreamHandler {
     }
 
     fn on_tool_call(&mut self, name: &str, _id: &str, input: &serde_json::Value) {
-        self.emit_delta(format!("⚙ {name}({input})\n"));
+        self.emit_delta(format!("{} {name}({input})\n", glyph("⚙", "[tool]")));
     }
 
     fn on_tool_result(&mut self, _id: &str, output: &str) {
@@ -5685,7 +5951,11 @@ impl StreamHandler for WaveWorkerStreamHandler {
         if error.is_empty() {
             return;
         }
-        self.emit_delta(format!("✗ {}\n", truncate_wave_worker_preview(error)));
+        self.emit_delta(format!(
+            "{} {}\n",
+            glyph("✗", "[x]"),
+            truncate_wave_worker_preview(error)
+        ));
     }
 
     fn on_complete(&mut self, _result: &ralph_adapters::SessionResult) {}
@@ -6184,7 +6454,10 @@ fn extract_readable_delta(line: &str, output_format: BackendOutputFormat) -> Opt
                                 text.push('\n');
                             }
                             ContentBlock::ToolUse { name, input, .. } => {
-                                text.push_str(&format!("⚙ {name}({input})\n"));
+                                text.push_str(&format!(
+                                    "{} {name}({input})\n",
+                                    glyph("⚙", "[tool]")
+                                ));
                             }
                         }
                     }
@@ -6221,10 +6494,14 @@ fn extract_readable_delta(line: &str, output_format: BackendOutputFormat) -> Opt
             }) => Some(delta),
             Some(PiStreamEvent::MessageUpdate {
                 assistant_message_event: PiAssistantEvent::Error { reason },
-            }) => Some(format!("✗ {}\n", truncate_wave_worker_preview(&reason))),
+            }) => Some(format!(
+                "{} {}\n",
+                glyph("✗", "[x]"),
+                truncate_wave_worker_preview(&reason)
+            )),
             Some(PiStreamEvent::ToolExecutionStart {
                 tool_name, args, ..
-            }) => Some(format!("⚙ {tool_name}({args})\n")),
+            }) => Some(format!("{} {tool_name}({args})\n", glyph("⚙", "[tool]"))),
             Some(PiStreamEvent::ToolExecutionEnd {
                 result, is_error, ..
             }) => {
@@ -6240,7 +6517,11 @@ fn extract_readable_delta(line: &str, output_format: BackendOutputFormat) -> Opt
                 if output.is_empty() {
                     None
                 } else if is_error {
-                    Some(format!("✗ {}\n", truncate_wave_worker_preview(&output)))
+                    Some(format!(
+                        "{} {}\n",
+                        glyph("✗", "[x]"),
+                        truncate_wave_worker_preview(&output)
+                    ))
                 } else {
                     Some(format!("→ {}\n", truncate_wave_worker_preview(&output)))
                 }
@@ -6365,6 +6646,37 @@ mod tests {
         assert_eq!(context_tokens_from_pty_result(&pty_result), 90_000);
     }
 
+    #[test]
+    fn test_pty_failure_output_keeps_tail_of_merged_stream() {
+        let mut stripped_output = (1..=30)
+            .map(|i| format!("line {i}"))
+            .collect::<Vec<_>>()
+            .join("\n\n");
+        stripped_output.push_str("\nError: authentication failed\n");
+        let mut pty_result = ralph_adapters::PtyExecutionResult {
+            output: String::new(),
+            stripped_output,
+            extracted_text: String::new(),
+            success: false,
+            exit_code: Some(1),
+            termination: ralph_adapters::TerminationType::Natural,
+            total_cost_usd: 0.0,
+            input_tokens: 0,
+            output_tokens: 0,
+            cache_read_tokens: 0,
+            cache_write_tokens: 0,
+            num_turns: 0,
+        };
+
+        let tail = pty_failure_output(&pty_result);
+        assert_eq!(tail.lines().count(), FAILURE_TAIL_LINES);
+        assert!(tail.starts_with("line 12\n"));
+        assert!(tail.ends_with("Error: authentication failed"));
+
+        pty_result.success = true;
+        assert!(pty_failure_output(&pty_result).is_empty());
+    }
+
     #[test]
     fn test_context_window_for_backend_uses_effective_hat_backend() {
         let mut config = RalphConfig::default();
@@ -6376,6 +6688,55 @@ mod tests {
         assert_eq!(context_window_for_backend(&config, "claude"), 200_000);
     }
 
+    #[test]
+    fn test_stream_line_prefix_only_in_multi_hat_mode() {
+        let solo = RalphConfig::default();
+        assert_eq!(stream_line_prefix(&solo, "ralph", 1), "");
+
+        let multi: RalphConfig = serde_yaml::from_str(
+            r#"
+hats:
+  builder:
+    name: "Builder"
+    triggers: ["build.task"]
+"#,
+        )
+        .unwrap();
+        assert_eq!(stream_line_prefix(&multi, "builder", 3), "[builder #3] ");
+    }
+
+    #[test]
+    fn test_latest_events_file_picks_most_recent_mtime() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let ctx = ralph_core::LoopContext::primary(temp.path().to_path_buf());
+        ctx.ensure_ralph_dir().unwrap();
+
+        let now = std::time::SystemTime::now();
+        let stale = ctx.ralph_dir().join("events-20260302-090000.jsonl");
+        let fresh = ctx.ralph_dir().join("events-20260301-090000.jsonl");
+        for (path, age_secs) in [(&stale, 600), (&fresh, 0)] {
+            let file = std::fs::File::create(path).unwrap();
+            file.set_modified(now - Duration::from_secs(age_secs))
+                .unwrap();
+        }
+        std::fs::write(ctx.ralph_dir().join("events.jsonl"), "").unwrap();
+
+        assert_eq!(
+            latest_events_file(&ctx).as_deref(),
+            Some(".ralph/events-20260301-090000.jsonl")
+        );
+    }
+
+    #[test]
+    fn test_latest_events_file_none_without_timestamped_files() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let ctx = ralph_core::LoopContext::primary(temp.path().to_path_buf());
+        ctx.ensure_ralph_dir().unwrap();
+        std::fs::write(ctx.ralph_dir().join("events.jsonl"), "").unwrap();
+
+        assert_eq!(latest_events_file(&ctx), None);
+    }
+
     #[test]
     fn test_resolve_loop_id_fresh_generates_new() {
         let temp = tempfile::TempDir::new().unwrap();
@@ -8649,7 +9010,7 @@ exit 61"#
         ))
         .expect("wait helper should succeed");
 
-        assert_eq!(wait_result, Some(TerminationReason::Stopped));
+        assert_eq!(wait_result, Some(TerminationReason::UserStop));
         assert!(!temp_dir.path().join(".ralph/stop-requested").exists());
     }
 
@@ -8953,7 +9314,7 @@ exit 73"#
         ))
         .expect("wait helper should succeed");
 
-        assert_eq!(wait_result, Some(TerminationReason::Stopped));
+        assert_eq!(wait_result, Some(TerminationReason::UserStop));
         assert!(!temp_dir.path().join(".ralph/stop-requested").exists());
         assert!(!suspend_state_store.resume_requested_path().exists());
     }
@@ -9725,7 +10086,7 @@ exit 73"#
         ))
         .expect("wait helper should succeed");
 
-        assert_eq!(wait_result, Some(TerminationReason::Stopped));
+        assert_eq!(wait_result, Some(TerminationReason::UserStop));
         assert!(!temp_dir.path().join(".ralph/stop-requested").exists());
         assert!(!suspend_state_store.suspend_state_path().exists());
         assert!(!suspend_state_store.resume_requested_path().exists());
@@ -10152,6 +10513,7 @@ hats:
             wave_id: Some("w-test".to_string()),
             wave_index: Some(0),
             wave_total: Some(1),
+            schema: 1,
         };
 
         ralph_core::DetectedWave {
@@ -10160,6 +10522,7 @@ hats:
             hat_config: ralph_core::HatConfig {
                 name: "Reviewer".to_string(),
                 description: Some("Wave worker test".to_string()),
+                emoji: None,
                 triggers: vec!["review.perspective".to_string()],
                 publishes,
                 instructions: "Emit review.done when finished.".to_string(),
@@ -10168,6 +10531,7 @@ hats:
                 backend_args: None,
                 default_publishes: None,
                 max_activations: None,
+                cooldown_secs: None,
                 disallowed_tools: vec![],
                 timeout: Some(timeout_secs),
                 concurrency: 1,
@@ -10222,9 +10586,18 @@ hats:
 
         let events_file = temp_dir.path().join("events.jsonl");
         let wave = make_test_wave_with_timeout(vec!["review.done".to_string()], timeout_secs);
-        execute_wave(&wave, &backend, &events_file, false, false, None, None)
-            .await
-            .expect("wave execution")
+        execute_wave(
+            &wave,
+            &backend,
+            &std::collections::BTreeMap::new(),
+            &events_file,
+            false,
+            false,
+            None,
+            None,
+        )
+        .await
+        .expect("wave execution")
     }
 
     #[cfg(unix)]
@@ -10251,9 +10624,18 @@ hats:
 
         let events_file = temp_dir.path().join("events.jsonl");
         let wave = make_test_wave(vec!["review.done".to_string()]);
-        execute_wave(&wave, &backend, &events_file, false, false, None, None)
-            .await
-            .expect("wave execution")
+        execute_wave(
+            &wave,
+            &backend,
+            &std::collections::BTreeMap::new(),
+            &events_file,
+            false,
+            false,
+            None,
+            None,
+        )
+        .await
+        .expect("wave execution")
     }
 
     #[cfg(unix)]
@@ -10314,9 +10696,18 @@ hats:
             30,
             task_payload.to_string(),
         );
-        let completed = execute_wave(&wave, &backend, &events_file, false, false, None, None)
-            .await
-            .expect("wave execution");
+        let completed = execute_wave(
+            &wave,
+            &backend,
+            &std::collections::BTreeMap::new(),
+            &events_file,
+            false,
+            false,
+            None,
+            None,
+        )
+        .await
+        .expect("wave execution");
         let captured: CapturedWaveInvocation = serde_json::from_str(
             &std::fs::read_to_string(&worker_capture_path).expect("read captured invocation"),
         )
@@ -10367,6 +10758,7 @@ hats:
         execute_wave(
             &wave,
             &global_backend,
+            &std::collections::BTreeMap::new(),
             &events_file,
             false,
             false,
@@ -10410,6 +10802,7 @@ hats:
         let completed = execute_wave(
             &wave,
             &missing_global_wave_backend(),
+            &std::collections::BTreeMap::new(),
             &events_file,
             false,
             false,
@@ -10450,9 +10843,18 @@ hats:
         wave.hat_config.backend_args = backend_args;
         let backend = CliBackend::from_name("kiro-acp").expect("named ACP backend");
 
-        let completed = execute_wave(&wave, &backend, &events_file, false, false, None, None)
-            .await
-            .expect("wave execution");
+        let completed = execute_wave(
+            &wave,
+            &backend,
+            &std::collections::BTreeMap::new(),
+            &events_file,
+            false,
+            false,
+            None,
+            None,
+        )
+        .await
+        .expect("wave execution");
         let captured: CapturedAcpWaveInvocation = serde_json::from_str(
             &std::fs::read_to_string(&worker_capture_path).expect("read captured ACP invocation"),
         )
@@ -10500,6 +10902,7 @@ hats:
         let completed = execute_wave(
             &wave,
             &missing_global_wave_backend(),
+            &std::collections::BTreeMap::new(),
             &events_file,
             false,
             false,
@@ -10524,6 +10927,7 @@ hats:
             wave_id: None,
             wave_index: None,
             wave_total: None,
+            schema: 1,
         }
     }
 
@@ -12292,6 +12696,7 @@ EOF"#,
         let completed = execute_wave(
             &wave,
             &missing_global_wave_backend(),
+            &std::collections::BTreeMap::new(),
             &events_file,
             false,
             false,
@@ -12829,7 +13234,7 @@ EOF"#,
         config.event_loop.prompt = Some("inline prompt".to_string());
         config.event_loop.prompt_file = "missing.md".to_string();
 
-        let resolved = resolve_prompt_content(&config.event_loop).expect("inline prompt");
+        let resolved = resolve_prompt_content(&config.event_loop, 0).expect("inline prompt");
         assert_eq!(resolved, "inline prompt");
     }
 
@@ -12843,7 +13248,7 @@ EOF"#,
         config.event_loop.prompt = None;
         config.event_loop.prompt_file = prompt_path.to_string_lossy().to_string();
 
-        let resolved = resolve_prompt_content(&config.event_loop).expect("file prompt");
+        let resolved = resolve_prompt_content(&config.event_loop, 0).expect("file prompt");
         assert_eq!(resolved, "file prompt");
     }
 
@@ -12856,7 +13261,7 @@ EOF"#,
         config.event_loop.prompt = None;
         config.event_loop.prompt_file = missing_path.to_string_lossy().to_string();
 
-        let err = resolve_prompt_content(&config.event_loop).expect_err("missing prompt");
+        let err = resolve_prompt_content(&config.event_loop, 0).expect_err("missing prompt");
         assert!(
             err.to_string().contains("Prompt file"),
             "unexpected error: {err}"
@@ -12869,13 +13274,35 @@ EOF"#,
         config.event_loop.prompt = None;
         config.event_loop.prompt_file = String::new();
 
-        let err = resolve_prompt_content(&config.event_loop).expect_err("missing prompt");
+        let err = resolve_prompt_content(&config.event_loop, 0).expect_err("missing prompt");
         assert!(
             err.to_string().contains("No prompt specified"),
             "unexpected error: {err}"
         );
     }
 
+    #[test]
+    fn test_prompt_size_warning_threshold() {
+        assert!(prompt_size_warning("Inline prompt", "short", 100).is_none());
+        assert!(prompt_size_warning("Inline prompt", &"x".repeat(100), 100).is_none());
+        // 0 disables the check
+        assert!(prompt_size_warning("Inline prompt", &"x".repeat(500), 0).is_none());
+
+        let warning = prompt_size_warning("Prompt file 'PROMPT.md'", &"x".repeat(401), 100)
+            .expect("oversized prompt warns");
+        assert!(warning.starts_with("Prompt file 'PROMPT.md' is 401 chars (~101 tokens)"));
+        assert!(warning.contains("100-char warning threshold"));
+    }
+
+    #[test]
+    fn test_resolve_prompt_content_oversized_still_resolves() {
+        let mut config = RalphConfig::default();
+        config.event_loop.prompt = Some("x".repeat(50));
+
+        let resolved = resolve_prompt_content(&config.event_loop, 10).expect("warning only");
+        assert_eq!(resolved.len(), 50);
+    }
+
     #[test]
     fn test_log_events_from_output_records_orphan_event() {
         let temp_dir = tempfile::tempdir().expect("temp dir");
@@ -298,7 +298,7 @@ fn is_process_alive(pid: u32) -> bool {
 }
 
 /// Format duration as relative age (e.g., "5m", "2h", "1d").
-fn format_age(duration: chrono::Duration) -> String {
+pub(crate) fn format_age(duration: chrono::Duration) -> String {
     let secs = duration.num_seconds();
     if secs < 60 {
         format!("{}s", secs)
@@ -558,7 +558,7 @@ struct LoopRow {
     merge: Option<String>,
 }
 
-fn colorize_status(status: &str) -> String {
+pub(crate) fn colorize_status(status: &str) -> String {
     match status {
         "running" => format!("\x1b[32m{}\x1b[0m", status), // green
         "merging" => format!("\x1b[33m{}\x1b[0m", status), // yellow
@@ -1529,7 +1529,7 @@ fn default_review_base_branch(cwd: &std::path::Path, remote: &str) -> String {
     "main".to_string()
 }
 
-fn git_ref_exists(cwd: &std::path::Path, reference: &str) -> bool {
+pub(crate) fn git_ref_exists(cwd: &std::path::Path, reference: &str) -> bool {
     Command::new("git")
         .args(["rev-parse", "--verify", "--quiet", reference])
         .current_dir(cwd)
@@ -1603,7 +1603,7 @@ fn run_git_checked(cwd: &std::path::Path, args: &[&str], context: &str) -> Resul
     bail!("{}: {}", context, detail);
 }
 
-fn loop_branch(loop_id: &str) -> String {
+pub(crate) fn loop_branch(loop_id: &str) -> String {
     format!("ralph/{loop_id}")
 }
 
@@ -1726,7 +1726,7 @@ fn merge_loop(args: MergeArgs) -> Result<()> {
 }
 
 /// Helper to spawn merge-ralph
-fn spawn_merge_ralph(cwd: &std::path::Path, loop_id: &str) -> Result<()> {
+pub(crate) fn spawn_merge_ralph(cwd: &std::path::Path, loop_id: &str) -> Result<()> {
     // Get the merge-loop preset and write a core-only config file.
     let preset = crate::presets::get_preset("merge-loop").context("merge-loop preset not found")?;
 
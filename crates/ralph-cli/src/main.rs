@@ -22,14 +22,19 @@ mod bot;
 mod config_resolution;
 mod display;
 mod doctor;
+mod env_file;
+mod event_cli;
+mod event_commands;
 mod hats;
 mod hooks;
 mod init;
 mod interact;
+mod json_stream;
 mod loop_runner;
 mod loops;
 mod mcp;
 mod memory;
+mod merge_cli;
 mod preflight;
 mod presets;
 mod rpc_stdin;
@@ -46,10 +51,11 @@ mod web_robot_service;
 use anyhow::{Context, Result};
 use clap::{ArgAction, CommandFactory, Parser, Subcommand, ValueEnum};
 use ralph_adapters::detect_backend;
+use ralph_cli::glyph;
 use ralph_core::{
     CheckStatus, EventHistory, LockError, LoopContext, LoopEntry, LoopLock, LoopRegistry,
-    PreflightReport, PreflightRunner, RalphConfig, TerminationReason, UrgentSteerStore,
-    truncate_with_ellipsis,
+    MarkdownMemoryStore, PreflightReport, PreflightRunner, RalphConfig, TerminationReason,
+    UrgentSteerStore, truncate_with_ellipsis,
     worktree::{WorktreeConfig, create_worktree, ensure_gitignore, remove_worktree},
 };
 use std::fs;
@@ -247,6 +253,8 @@ pub enum Verbosity {
     Normal,
     /// Show everything including tool results and session summary
     Verbose,
+    /// Suppress streaming output but print a one-line summary per iteration to stderr
+    Summary,
 }
 
 impl Verbosity {
@@ -290,6 +298,13 @@ impl Verbosity {
     }
 }
 
+/// Machine-readable output mode for `ralph run --output`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum RunOutput {
+    /// Newline-delimited JSON of iteration, event, and termination records on stdout
+    JsonStream,
+}
+
 /// Output format for events command.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
 pub enum OutputFormat {
@@ -633,6 +648,10 @@ struct Cli {
     /// Color output mode (auto, always, never)
     #[arg(long, value_enum, default_value_t = ColorMode::Auto, global = true)]
     color: ColorMode,
+
+    /// Replace emoji with ASCII in CLI output (also `RALPH_NO_EMOJI=1`)
+    #[arg(long, global = true)]
+    no_emoji: bool,
 }
 
 #[derive(Subcommand, Debug)]
@@ -688,6 +707,9 @@ enum Commands {
     /// Manage parallel loops
     Loops(loops::LoopsArgs),
 
+    /// Inspect and advance the merge queue
+    Merge(merge_cli::MergeArgs),
+
     /// Manage configured hats
     Hats(hats::HatsArgs),
 
@@ -727,6 +749,22 @@ struct InitArgs {
     #[arg(long, conflicts_with = "backend", conflicts_with = "preset")]
     list_presets: bool,
 
+    /// Download a YAML config from an https:// URL (e.g., a shared gist) into ralph.yml
+    #[arg(
+        long,
+        value_name = "URL",
+        conflicts_with_all = ["backend", "preset", "list_presets"]
+    )]
+    from_url: Option<String>,
+
+    /// Prompt for backend, hat collection, and memories (uses the detected backend without a TTY)
+    #[arg(
+        short,
+        long,
+        conflicts_with_all = ["backend", "preset", "list_presets", "from_url"]
+    )]
+    interactive: bool,
+
     /// Overwrite existing ralph.yml if present
     #[arg(long)]
     force: bool,
@@ -755,10 +793,41 @@ struct RunArgs {
     #[arg(long)]
     completion_promise: Option<String>,
 
+    /// Stop successfully once this topic is published (e.g., "spec.approved").
+    /// Iteration, runtime, and cost limits still apply.
+    #[arg(long, value_name = "TOPIC")]
+    until_event: Option<String>,
+
+    /// Stop (loop_stale) once the same topic and payload is published more
+    /// than N times in a row. Overrides event_loop.max_same_topic.
+    #[arg(long, value_name = "N")]
+    max_same_topic: Option<u32>,
+
+    /// Preload memories from a markdown file (memories.md format) before the
+    /// loop starts. Requires memories to be enabled.
+    #[arg(long, value_name = "FILE")]
+    seed_memory: Option<PathBuf>,
+
+    /// Run a shell command when a matching event is published (repeatable).
+    /// The payload is piped on stdin; failures are logged, not fatal.
+    #[arg(long = "on-event", value_name = "TOPIC=COMMAND", value_parser = event_commands::parse_event_command)]
+    on_event: Vec<event_commands::EventCommand>,
+
+    /// Load KEY=VALUE pairs from a file into the backend environment.
+    /// Variables already set in the environment take precedence.
+    #[arg(long, value_name = "FILE")]
+    env_file: Option<PathBuf>,
+
     /// Dry run - show what would be executed without running
     #[arg(long)]
     dry_run: bool,
 
+    /// Print the prompt the named hat would receive (`ralph` for the
+    /// coordinator), including memory injection and instructions, then exit
+    /// without calling the backend
+    #[arg(long, value_name = "HAT")]
+    dump_prompt: Option<String>,
+
     /// Continue from existing scratchpad (resume interrupted loop).
     /// Use this when a previous run was interrupted and you want to
     /// continue from where it left off.
@@ -794,6 +863,16 @@ struct RunArgs {
     #[arg(long, hide = true, conflicts_with = "rpc", conflicts_with = "no_tui")]
     legacy_tui: bool,
 
+    /// Stream machine-readable output to stdout instead of the TUI/console display.
+    /// `json-stream` writes one JSON record per line (iteration starts, events, termination).
+    #[arg(
+        long,
+        value_enum,
+        value_name = "FORMAT",
+        conflicts_with_all = ["rpc", "legacy_tui", "verbose", "summary"]
+    )]
+    output: Option<RunOutput>,
+
     /// Idle timeout in seconds for interactive mode (default: 30).
     /// Process is terminated after this many seconds of inactivity.
     /// Set to 0 to disable idle timeout.
@@ -832,6 +911,11 @@ struct RunArgs {
     #[arg(short = 'q', long, conflicts_with = "verbose")]
     quiet: bool,
 
+    /// Suppress streaming output but print a one-line summary per iteration
+    /// (hat, events published, duration) to stderr. Takes precedence over --quiet.
+    #[arg(long, conflicts_with = "verbose")]
+    summary: bool,
+
     /// Record session to JSONL file for replay testing
     #[arg(long, value_name = "FILE")]
     record_session: Option<PathBuf>,
@@ -906,6 +990,10 @@ struct EventsArgs {
     /// Clear the event history
     #[arg(long)]
     clear: bool,
+
+    /// Render observed topic → triggered-hat transitions as a graph instead of a table
+    #[arg(long, value_enum, num_args = 0..=1, default_missing_value = "mermaid", conflicts_with = "clear")]
+    graph: Option<display::EventGraphFormat>,
 }
 
 /// Arguments for the clean subcommand.
@@ -918,6 +1006,22 @@ struct CleanArgs {
     /// Clean diagnostic logs instead of `.ralph/` directory
     #[arg(long)]
     diagnostics: bool,
+
+    /// Remove `.agent`, diagnostics, event logs, and Ralph-managed worktrees together
+    #[arg(long, conflicts_with = "diagnostics")]
+    all: bool,
+
+    /// With --all, skip the confirmation prompt
+    #[arg(short, long, requires = "all")]
+    force: bool,
+
+    /// With --diagnostics, only delete sessions older than this (e.g., 12h, 7d, 2w)
+    #[arg(long, value_name = "DURATION", requires = "diagnostics", value_parser = ralph_cli::parse_age)]
+    older_than: Option<std::time::Duration>,
+
+    /// With --diagnostics, always keep the N most recent sessions
+    #[arg(long, value_name = "N", requires = "diagnostics")]
+    keep_last: Option<usize>,
 }
 
 /// Arguments for the emit subcommand.
@@ -1064,6 +1168,12 @@ fn is_diagnostics_eligible_command(command: Option<&Commands>) -> bool {
     matches!(command, Some(Commands::Run(_) | Commands::Resume(_)) | None)
 }
 
+/// Returns true when an on/off environment variable is set to anything but
+/// empty or `0`.
+fn env_flag_enabled(name: &str) -> bool {
+    std::env::var(name).is_ok_and(|value| !value.is_empty() && value != "0")
+}
+
 #[tokio::main]
 async fn main() -> Result<()> {
     // Install panic hook to restore terminal state on crash
@@ -1071,18 +1181,22 @@ async fn main() -> Result<()> {
     install_panic_hook();
 
     let cli = Cli::parse();
+    ralph_cli::set_no_emoji(cli.no_emoji || env_flag_enabled("RALPH_NO_EMOJI"));
 
     // Detect if TUI mode is requested - TUI owns the terminal, so logs must not go to stdout
     // TUI is enabled by default unless --no-tui, --autonomous, or --rpc is specified
     // RPC mode also suppresses stdout logging (JSON-only output)
     let tui_enabled = match &cli.command {
-        Some(Commands::Run(args)) => !args.no_tui && !args.autonomous && !args.rpc,
+        Some(Commands::Run(args)) => {
+            !args.no_tui && !args.autonomous && !args.rpc && args.output.is_none()
+        }
         Some(Commands::Resume(args)) => !args.no_tui && !args.autonomous && !args.rpc,
         None => true,
         _ => false,
     };
+    // JSON stream output is treated like RPC here: stdout is reserved for records
     let rpc_enabled = match &cli.command {
-        Some(Commands::Run(args)) => args.rpc,
+        Some(Commands::Run(args)) => args.rpc || args.output.is_some(),
         Some(Commands::Resume(args)) => args.rpc,
         _ => false,
     };
@@ -1110,7 +1224,11 @@ async fn main() -> Result<()> {
                     ralph_core::diagnostics::DiagnosticsCollector::new(std::path::Path::new("."))
                     && let Some(session_dir) = collector.session_dir()
                 {
-                    if let Ok(trace_layer) = DiagnosticTraceLayer::new(session_dir) {
+                    // The trace log is the chattiest stream; only the env level
+                    // applies here because config has not been loaded yet
+                    if collector.records_traces()
+                        && let Ok(trace_layer) = DiagnosticTraceLayer::new(session_dir)
+                    {
                         tracing_subscriber::registry()
                             .with(
                                 tracing_subscriber::fmt::layer()
@@ -1154,7 +1272,9 @@ async fn main() -> Result<()> {
                 ralph_core::diagnostics::DiagnosticsCollector::new(std::path::Path::new("."))
                 && let Some(session_dir) = collector.session_dir()
             {
-                if let Ok(trace_layer) = DiagnosticTraceLayer::new(session_dir) {
+                if collector.records_traces()
+                    && let Ok(trace_layer) = DiagnosticTraceLayer::new(session_dir)
+                {
                     tracing_subscriber::registry()
                         .with(tracing_subscriber::fmt::layer())
                         .with(tracing_subscriber::EnvFilter::new(filter))
@@ -1237,7 +1357,7 @@ async fn main() -> Result<()> {
             .await
         }
         Some(Commands::Events(args)) => events_command(cli.color, args),
-        Some(Commands::Init(args)) => init_command(cli.color, args),
+        Some(Commands::Init(args)) => init_command(cli.color, args).await,
         Some(Commands::Clean(args)) => clean_command(&config_sources, cli.color, args),
         Some(Commands::Emit(args)) => emit_command(cli.color, args),
         Some(Commands::Plan(args)) => {
@@ -1252,6 +1372,7 @@ async fn main() -> Result<()> {
         Some(Commands::Tools(args)) => tools::execute(args, cli.color.should_use_colors()).await,
         Some(Commands::Wave(args)) => wave::execute(args, cli.color.should_use_colors()),
         Some(Commands::Loops(args)) => loops::execute(args, cli.color.should_use_colors()),
+        Some(Commands::Merge(args)) => merge_cli::execute(args, cli.color.should_use_colors()),
         Some(Commands::Hats(args)) => {
             hats::execute(
                 &config_sources,
@@ -1282,7 +1403,13 @@ async fn main() -> Result<()> {
                 backend: None,
                 max_iterations: None,
                 completion_promise: None,
+                until_event: None,
+                max_same_topic: None,
+                seed_memory: None,
+                on_event: Vec::new(),
+                env_file: None,
                 dry_run: false,
+                dump_prompt: None,
                 continue_mode: false,
                 loop_id: None,
                 no_tui: false, // TUI enabled by default
@@ -1295,6 +1422,8 @@ async fn main() -> Result<()> {
                 skip_preflight: false,
                 verbose: false,
                 quiet: false,
+                summary: false,
+                output: None,
                 record_session: None,
                 custom_args: Vec::new(),
             };
@@ -1316,9 +1445,9 @@ fn format_preflight_summary(report: &PreflightReport) -> String {
         .iter()
         .map(|check| {
             let icon = match check.status {
-                CheckStatus::Pass => "✓",
-                CheckStatus::Warn => "⚠",
-                CheckStatus::Fail => "✗",
+                CheckStatus::Pass => glyph("✓", "[ok]"),
+                CheckStatus::Warn => glyph("⚠", "[!]"),
+                CheckStatus::Fail => glyph("✗", "[x]"),
             };
             format!("{icon} {}", check.name)
         })
@@ -1450,7 +1579,12 @@ fn print_preflight_summary(
         if check.status == CheckStatus::Fail
             && let Some(message) = &check.message
         {
-            emit(format!("  ✗ {}: {}", check.name, message));
+            emit(format!(
+                "  {} {}: {}",
+                glyph("✗", "[x]"),
+                check.name,
+                message
+            ));
         }
     }
 
@@ -1459,7 +1593,12 @@ fn print_preflight_summary(
             if check.status == CheckStatus::Warn
                 && let Some(message) = &check.message
             {
-                emit(format!("  ⚠ {}: {}", check.name, message));
+                emit(format!(
+                    "  {} {}: {}",
+                    glyph("⚠", "[!]"),
+                    check.name,
+                    message
+                ));
             }
         }
     }
@@ -1509,6 +1648,23 @@ async fn run_command(
     if let Some(promise) = args.completion_promise {
         config.event_loop.completion_promise = promise;
     }
+    if let Some(topic) = args.until_event {
+        config.event_loop.until_event = Some(topic);
+    }
+    if let Some(max_same_topic) = args.max_same_topic {
+        config.event_loop.max_same_topic = max_same_topic;
+    }
+    // Parse seed memories up front so a bad file fails before any work starts
+    let seed_memories = args
+        .seed_memory
+        .as_deref()
+        .map(memory::load_seed_memories)
+        .transpose()?;
+    if let Some(ref path) = args.env_file {
+        for (key, value) in env_file::unset_only(env_file::load_env_file(path)?) {
+            config.cli.env.entry(key).or_insert(value);
+        }
+    }
     if verbose {
         config.verbose = true;
     }
@@ -1539,6 +1695,10 @@ async fn run_command(
         eprintln!("{warning}");
     }
 
+    if let Some(hat) = args.dump_prompt {
+        return dump_prompt_command(config, &hat);
+    }
+
     // Handle auto-detection if backend is "auto"
     if config.cli.backend == "auto" {
         let priority = config.get_agent_priority();
@@ -1590,6 +1750,9 @@ async fn run_command(
             "  Completion promise: {}",
             config.event_loop.completion_promise
         );
+        if let Some(ref topic) = config.event_loop.until_event {
+            println!("  Until event: {}", topic);
+        }
         println!("  Max iterations: {}", config.event_loop.max_iterations);
         println!("  Max runtime: {}s", config.event_loop.max_runtime_seconds);
         println!(
@@ -1617,6 +1780,21 @@ async fn run_command(
     // This is done after dry-run check to avoid creating directories during dry-run
     ensure_scratchpad_directory(&config)?;
 
+    if let Some(memories) = seed_memories {
+        if config.memories.enabled {
+            let store = MarkdownMemoryStore::with_default_path(&config.core.workspace_root);
+            let added = memory::seed_memories(&store, &memories)?;
+            info!(
+                "Seeded {} of {} memories into {}",
+                added,
+                memories.len(),
+                store.path().display()
+            );
+        } else {
+            warn!("--seed-memory ignored: memories are disabled (set memories.enabled: true)");
+        }
+    }
+
     // Get the prompt for lock metadata (short version for display)
     // When prompt_file is used, read its content for the summary instead of showing the file path
     let prompt_summary = config
@@ -1644,8 +1822,12 @@ async fn run_command(
     // Determine TUI mode early (before lock acquisition) to avoid self-lock contention
     // in subprocess TUI mode. The child RPC process will acquire the lock itself.
     let is_tty = std::io::stdin().is_terminal() && std::io::stdout().is_terminal();
-    let use_subprocess_tui =
-        !args.no_tui && !args.autonomous && !args.rpc && !args.legacy_tui && is_tty;
+    let use_subprocess_tui = !args.no_tui
+        && !args.autonomous
+        && !args.rpc
+        && !args.legacy_tui
+        && args.output.is_none()
+        && is_tty;
 
     // Try to acquire the loop lock for multi-loop concurrency support
     // This implements the lock detection flow from the multi-loop spec
@@ -1816,7 +1998,15 @@ async fn run_command(
     let wants_tui = !args.no_tui && !args.autonomous && !args.rpc;
     let use_legacy_tui = args.legacy_tui;
     let enable_rpc = args.rpc;
-    let verbosity = Verbosity::resolve(verbose || args.verbose, args.quiet);
+    let enable_json_stream = args.output == Some(RunOutput::JsonStream);
+    let verbosity = if enable_json_stream {
+        // Agent output would interleave with the NDJSON records on stdout
+        Verbosity::Quiet
+    } else if args.summary {
+        Verbosity::Summary
+    } else {
+        Verbosity::resolve(verbose || args.verbose, args.quiet)
+    };
     let custom_args = args.custom_args.clone();
     // --no-auto-merge CLI flag overrides config.features.auto_merge
     let auto_merge_override = if args.no_auto_merge {
@@ -1838,19 +2028,23 @@ async fn run_command(
     } else {
         // In-process mode: run_loop_impl handles everything
         let enable_tui = wants_tui && use_legacy_tui;
-        loop_runner::run_loop_impl(
+        // Boxed: the loop future is large enough to trip `clippy::large_futures`
+        // in every caller of `run_command`.
+        Box::pin(loop_runner::run_loop_impl(
             config,
             color_mode,
             resume,
             enable_tui,
             enable_rpc,
+            enable_json_stream,
             verbosity,
             args.record_session,
             Some(loop_context),
             custom_args,
             auto_merge_override,
             args.loop_id,
-        )
+            args.on_event,
+        ))
         .await?
     };
 
@@ -1901,6 +2095,30 @@ fn clear_restart_request_signal(workspace_root: &std::path::Path) {
     let _ = std::fs::remove_file(&restart_path);
 }
 
+/// Prints the prompt `hat` would receive on its first activation and exits.
+///
+/// Builds the prompt through the real event loop (memories, scratchpad, ready
+/// tasks, hat instructions) without calling a backend.
+fn dump_prompt_command(config: RalphConfig, hat: &str) -> Result<()> {
+    if hat != "ralph" && !config.hats.contains_key(hat) {
+        let mut known: Vec<&str> = config.hats.keys().map(String::as_str).collect();
+        known.sort_unstable();
+        known.insert(0, "ralph");
+        anyhow::bail!("Unknown hat '{}'. Known hats: {}", hat, known.join(", "));
+    }
+
+    let prompt_content =
+        loop_runner::resolve_prompt_content(&config.event_loop, config.core.prompt_warning_chars)?;
+    let context = LoopContext::primary(config.core.workspace_root.clone());
+    let mut event_loop = ralph_core::EventLoop::with_context(config, context);
+    let prompt = event_loop
+        .preview_prompt(&ralph_proto::HatId::new(hat), &prompt_content)
+        .with_context(|| format!("Hat '{}' has no triggers to build a prompt from", hat))?;
+
+    println!("{}", prompt);
+    Ok(())
+}
+
 /// Arguments needed for subprocess TUI mode.
 /// We clone these early before RunArgs fields are consumed.
 #[derive(Clone)]
@@ -1910,6 +2128,10 @@ struct SubprocessTuiArgs {
     backend: Option<String>,
     max_iterations: Option<u32>,
     completion_promise: Option<String>,
+    until_event: Option<String>,
+    max_same_topic: Option<u32>,
+    on_event: Vec<event_commands::EventCommand>,
+    env_file: Option<PathBuf>,
     continue_mode: bool,
     loop_id: Option<String>,
     idle_timeout: Option<u32>,
@@ -1938,6 +2160,10 @@ impl SubprocessTuiArgs {
             backend: args.backend.clone(),
             max_iterations: args.max_iterations,
             completion_promise: args.completion_promise.clone(),
+            until_event: args.until_event.clone(),
+            max_same_topic: args.max_same_topic,
+            on_event: args.on_event.clone(),
+            env_file: args.env_file.clone(),
             continue_mode: args.continue_mode,
             loop_id: args.loop_id.clone(),
             idle_timeout: args.idle_timeout,
@@ -2014,6 +2240,30 @@ async fn run_subprocess_tui(
         child_args.push(promise.clone());
     }
 
+    // Forward target event
+    if let Some(ref topic) = args.until_event {
+        child_args.push("--until-event".to_string());
+        child_args.push(topic.clone());
+    }
+
+    // Forward same-topic limit
+    if let Some(max_same_topic) = args.max_same_topic {
+        child_args.push("--max-same-topic".to_string());
+        child_args.push(max_same_topic.to_string());
+    }
+
+    // Forward event commands
+    for entry in &args.on_event {
+        child_args.push("--on-event".to_string());
+        child_args.push(format!("{}={}", entry.pattern, entry.command));
+    }
+
+    // Forward env file
+    if let Some(ref path) = args.env_file {
+        child_args.push("--env-file".to_string());
+        child_args.push(path.to_string_lossy().to_string());
+    }
+
     // Forward continue mode and loop ID
     if resume || args.continue_mode {
         child_args.push("--continue".to_string());
@@ -2247,12 +2497,14 @@ async fn resume_command(
         true,
         enable_tui,
         enable_rpc,
+        false, // Resume command doesn't support --output
         verbosity,
         args.record_session,
         None,       // Deprecated resume command doesn't have loop_context
         Vec::new(), // Resume command doesn't support custom args
         None,       // Use config.features.auto_merge (deprecated command)
         None,       // Deprecated resume command doesn't support --loop-id
+        Vec::new(), // Deprecated resume command doesn't support --on-event
     )
     .await?;
     let exit_code = reason.exit_code();
@@ -2264,7 +2516,7 @@ async fn resume_command(
     Ok(())
 }
 
-fn init_command(color_mode: ColorMode, args: InitArgs) -> Result<()> {
+async fn init_command(color_mode: ColorMode, args: InitArgs) -> Result<()> {
     let use_colors = color_mode.should_use_colors();
 
     // Handle --list-presets (lists builtin hat collections)
@@ -2286,8 +2538,9 @@ fn init_command(color_mode: ColorMode, args: InitArgs) -> Result<()> {
             Ok(()) => {
                 if use_colors {
                     println!(
-                        "{}✓{} Created ralph.yml with {} backend",
+                        "{}{}{} Created ralph.yml with {} backend",
                         colors::GREEN,
+                        glyph("✓", "[ok]"),
                         colors::RESET,
                         backend
                     );
@@ -2310,10 +2563,35 @@ fn init_command(color_mode: ColorMode, args: InitArgs) -> Result<()> {
         }
     }
 
+    if args.interactive {
+        return init_interactive(use_colors, args.force);
+    }
+
+    // Handle --from-url (remote config)
+    if let Some(url) = args.from_url {
+        init::init_from_url(&url, args.force)
+            .await
+            .map_err(|e| anyhow::anyhow!("{}", e))?;
+        if use_colors {
+            println!(
+                "{}{}{} Created ralph.yml from {}",
+                colors::GREEN,
+                glyph("✓", "[ok]"),
+                colors::RESET,
+                url
+            );
+        } else {
+            println!("Created ralph.yml from {}", url);
+        }
+        return Ok(());
+    }
+
     // No flag specified - show help
     println!("Initialize a new ralph.yml configuration file.\n");
     println!("Usage:");
     println!("  ralph init --backend <backend>   Generate core config (ralph.yml)");
+    println!("  ralph init --from-url <url>      Download a shared config over HTTPS");
+    println!("  ralph init --interactive         Guided setup for backend, hats, and memories");
     println!("  ralph init --list-presets        Show builtin hat collections\n");
     println!("Backends: {}", backend_support::VALID_BACKENDS_LABEL);
     println!("\nThen run with hats, e.g.: ralph run -c ralph.yml -H builtin:code-assist");
@@ -2321,6 +2599,54 @@ fn init_command(color_mode: ColorMode, args: InitArgs) -> Result<()> {
     Ok(())
 }
 
+/// Runs the `ralph init --interactive` wizard, or writes a config for the
+/// detected backend when stdin is not a TTY.
+fn init_interactive(use_colors: bool, force: bool) -> Result<()> {
+    init::check_file_exists(force).map_err(|e| anyhow::anyhow!("{}", e))?;
+
+    let answers = if std::io::stdin().is_terminal() {
+        let backends = init::installed_backends();
+        if backends.is_empty() {
+            anyhow::bail!(
+                "No supported backend found in PATH.\n\nInstall one, or run: ralph init --backend <backend>\nBackends: {}",
+                backend_support::VALID_BACKENDS_LABEL
+            );
+        }
+
+        let stdin = std::io::stdin();
+        init::run_wizard(&mut stdin.lock(), &mut stdout(), &backends)?
+    } else {
+        let backend =
+            ralph_adapters::detect_backend_default().map_err(|e| anyhow::anyhow!("{}", e))?;
+        println!("stdin is not a TTY; using detected backend '{}'", backend);
+        init::WizardAnswers {
+            backend,
+            collection: None,
+            memories: false,
+        }
+    };
+
+    init::init_from_answers(&answers, force).map_err(|e| anyhow::anyhow!("{}", e))?;
+
+    if use_colors {
+        println!(
+            "\n{}{}{} Created ralph.yml with {} backend",
+            colors::GREEN,
+            glyph("✓", "[ok]"),
+            colors::RESET,
+            answers.backend
+        );
+    } else {
+        println!("\nCreated ralph.yml with {} backend", answers.backend);
+    }
+    if let Some(collection) = &answers.collection {
+        println!("Included the {collection} hat collection");
+    }
+    println!("Next: ralph run -c ralph.yml");
+
+    Ok(())
+}
+
 fn events_command(color_mode: ColorMode, args: EventsArgs) -> Result<()> {
     let use_colors = color_mode.should_use_colors();
     let workspace_root = resolve_workspace_root(None);
@@ -2339,7 +2665,12 @@ fn events_command(color_mode: ColorMode, args: EventsArgs) -> Result<()> {
     if args.clear {
         history.clear()?;
         if use_colors {
-            println!("{}✓{} Event history cleared", colors::GREEN, colors::RESET);
+            println!(
+                "{}{}{} Event history cleared",
+                colors::GREEN,
+                glyph("✓", "[ok]"),
+                colors::RESET
+            );
         } else {
             println!("Event history cleared");
         }
@@ -2387,6 +2718,11 @@ fn events_command(color_mode: ColorMode, args: EventsArgs) -> Result<()> {
         return Ok(());
     }
 
+    if let Some(graph_format) = args.graph {
+        print!("{}", display::render_event_graph(&records, graph_format));
+        return Ok(());
+    }
+
     match args.format {
         OutputFormat::Json => {
             let json = serde_json::to_string_pretty(&records)?;
@@ -2410,7 +2746,11 @@ fn clean_command(
     // If --diagnostics flag is set, clean diagnostics directory
     if args.diagnostics {
         let workspace_root = std::env::current_dir().context("Failed to get current directory")?;
-        return ralph_cli::clean_diagnostics(&workspace_root, use_colors, args.dry_run);
+        let retention = ralph_cli::DiagnosticsRetention {
+            older_than: args.older_than,
+            keep_last: args.keep_last,
+        };
+        return ralph_cli::clean_diagnostics(&workspace_root, use_colors, args.dry_run, retention);
     }
 
     // Load config with overrides applied
@@ -2425,6 +2765,18 @@ fn clean_command(
         )
     })?;
 
+    if args.all {
+        let workspace_root = std::env::current_dir().context("Failed to get current directory")?;
+        return ralph_cli::clean_all(
+            &workspace_root,
+            agent_dir,
+            use_colors,
+            args.dry_run,
+            args.force,
+            &mut std::io::stdin().lock(),
+        );
+    }
+
     // Check if directory exists
     if !agent_dir.exists() {
         // Not an error - just inform user
@@ -2474,8 +2826,9 @@ fn clean_command(
     // Success message
     if use_colors {
         println!(
-            "{}✓{} Cleaned: Deleted '{}' and all contents",
+            "{}{}{} Cleaned: Deleted '{}' and all contents",
             colors::GREEN,
+            glyph("✓", "[ok]"),
             colors::RESET,
             agent_dir.display()
         );
@@ -2606,8 +2959,9 @@ fn emit_command_with_root(
     // Success message
     if use_colors {
         println!(
-            "{}✓{} Event emitted: {}",
+            "{}{}{} Event emitted: {}",
             colors::GREEN,
+            glyph("✓", "[ok]"),
             colors::RESET,
             args.topic
         );
@@ -2821,8 +3175,9 @@ async fn code_task_command(
     // Show what we're starting
     if use_colors {
         println!(
-            "{}📋{} Starting {} session...",
+            "{}{}{} Starting {} session...",
             colors::CYAN,
+            glyph("📋", "[>]"),
             colors::RESET,
             Sop::CodeTaskGenerator.name()
         );
@@ -3051,6 +3406,67 @@ mod tests {
         assert_eq!(cli.hats.as_deref(), Some("builtin:code-assist"));
     }
 
+    #[test]
+    fn test_cli_parses_global_no_emoji_flag() {
+        let cli = Cli::try_parse_from(["ralph", "run", "--no-emoji"]).expect("CLI parse failed");
+        assert!(cli.no_emoji);
+        let cli = Cli::try_parse_from(["ralph", "--no-emoji", "events"]).expect("CLI parse failed");
+        assert!(cli.no_emoji);
+        let cli = Cli::try_parse_from(["ralph", "events"]).expect("CLI parse failed");
+        assert!(!cli.no_emoji);
+    }
+
+    #[test]
+    fn test_cli_parses_run_summary_flag() {
+        let cli = Cli::try_parse_from(["ralph", "run", "--summary"]).expect("CLI parse failed");
+        match cli.command {
+            Some(Commands::Run(args)) => assert!(args.summary),
+            other => panic!("Expected run command, got {:?}", other),
+        }
+
+        assert!(Cli::try_parse_from(["ralph", "run", "--summary", "--verbose"]).is_err());
+    }
+
+    #[test]
+    fn test_cli_parses_run_output_json_stream() {
+        let cli = Cli::try_parse_from(["ralph", "run", "--output", "json-stream"])
+            .expect("CLI parse failed");
+        match cli.command {
+            Some(Commands::Run(args)) => assert_eq!(args.output, Some(RunOutput::JsonStream)),
+            other => panic!("Expected run command, got {:?}", other),
+        }
+
+        assert!(Cli::try_parse_from(["ralph", "run", "--output", "json-stream", "--rpc"]).is_err());
+        assert!(
+            Cli::try_parse_from(["ralph", "run", "--output", "json-stream", "--legacy-tui"])
+                .is_err()
+        );
+        assert!(Cli::try_parse_from(["ralph", "run", "--output", "text"]).is_err());
+    }
+
+    #[test]
+    fn test_cli_parses_merge_subcommands() {
+        let cli = Cli::try_parse_from(["ralph", "merge", "add", "ralph/loop-1234", "-p", "auth"])
+            .expect("CLI parse failed");
+        match cli.command {
+            Some(Commands::Merge(args)) => match args.command {
+                merge_cli::MergeCommands::Add(add) => {
+                    assert_eq!(add.branch, "ralph/loop-1234");
+                    assert_eq!(add.prompt.as_deref(), Some("auth"));
+                }
+                other => panic!("Expected merge add, got {:?}", other),
+            },
+            other => panic!("Expected merge command, got {:?}", other),
+        }
+
+        assert!(Cli::try_parse_from(["ralph", "merge", "list", "--json", "--all"]).is_ok());
+        assert!(Cli::try_parse_from(["ralph", "merge", "advance"]).is_ok());
+        assert!(
+            Cli::try_parse_from(["ralph", "merge", "remove", "loop-1234", "-r", "stale"]).is_ok()
+        );
+        assert!(Cli::try_parse_from(["ralph", "merge"]).is_err());
+    }
+
     #[test]
     fn test_config_source_parse_remote_https() {
         let source = ConfigSource::parse("https://example.com/preset.yml");
@@ -3727,6 +4143,21 @@ core:
         );
     }
 
+    #[test]
+    fn test_init_interactive_refuses_existing_config_before_prompting() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let _cwd = CwdGuard::set(temp_dir.path());
+        std::fs::write("ralph.yml", "cli:\n  backend: claude\n").unwrap();
+
+        let err = init_interactive(false, false).unwrap_err();
+
+        assert!(err.to_string().contains("already exists"), "{err}");
+        assert_eq!(
+            std::fs::read_to_string("ralph.yml").unwrap(),
+            "cli:\n  backend: claude\n"
+        );
+    }
+
     #[test]
     fn test_load_config_with_overrides_applies_override_sources() {
         let temp_dir = tempfile::tempdir().unwrap();
@@ -3832,7 +4263,13 @@ core:
             prompt_file: None,
             max_iterations: None,
             completion_promise: None,
+            until_event: None,
+            max_same_topic: None,
+            seed_memory: None,
+            on_event: Vec::new(),
+            env_file: None,
             dry_run: false,
+            dump_prompt: None,
             continue_mode: false,
             loop_id: None,
             no_tui: true,
@@ -3845,6 +4282,8 @@ core:
             skip_preflight: true,
             verbose: false,
             quiet: false,
+            summary: false,
+            output: None,
             record_session: None,
             custom_args: Vec::new(),
         }
@@ -3945,6 +4384,7 @@ hats:
             format: OutputFormat::Table,
             file: None,
             clear: false,
+            graph: None,
         }));
         assert!(!is_diagnostics_eligible_command(command.as_ref()));
     }
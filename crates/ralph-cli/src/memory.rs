@@ -7,13 +7,19 @@
 //! - `delete`: Delete a memory by ID
 //! - `search`: Find memories by query
 //! - `prime`: Output memories for context injection
+//! - `preview`: Show the exact block injected into agent prompts
 //! - `init`: Initialize memories file
 
 use crate::resolve_workspace_root;
+use crate::skill_cli;
 use anyhow::{Context, Result};
 use clap::{Parser, Subcommand, ValueEnum};
-use ralph_core::{MarkdownMemoryStore, Memory, MemoryType, truncate_with_ellipsis};
-use std::path::PathBuf;
+use ralph_cli::glyph;
+use ralph_core::{
+    EventLoop, LoopContext, MarkdownMemoryStore, Memory, MemoryType, truncate_to_budget,
+    truncate_with_ellipsis,
+};
+use std::path::{Path, PathBuf};
 
 /// ANSI color codes for terminal output.
 mod colors {
@@ -101,6 +107,9 @@ pub enum MemoryCommands {
     /// Output memories for context injection
     Prime(PrimeArgs),
 
+    /// Show the exact memory block (with skills) injected into agent prompts
+    Preview,
+
     /// Initialize memories file
     Init(InitArgs),
 }
@@ -225,6 +234,7 @@ pub fn execute(args: MemoryArgs, use_colors: bool) -> Result<()> {
         MemoryCommands::Delete(delete_args) => delete_command(&store, delete_args, use_colors),
         MemoryCommands::Search(search_args) => search_command(&store, search_args, use_colors),
         MemoryCommands::Prime(prime_args) => prime_command(&store, prime_args),
+        MemoryCommands::Preview => preview_command(&root),
         MemoryCommands::Init(init_args) => init_command(&store, init_args, use_colors),
     }
 }
@@ -262,7 +272,13 @@ fn add_command(store: &MarkdownMemoryStore, args: AddArgs, use_colors: bool) ->
         }
         OutputFormat::Table => {
             if use_colors {
-                println!("{}📝 Memory stored:{} {}", colors::GREEN, colors::RESET, id);
+                println!(
+                    "{}{} Memory stored:{} {}",
+                    colors::GREEN,
+                    glyph("📝", "[+]"),
+                    colors::RESET,
+                    id
+                );
             } else {
                 println!("Memory stored: {}", id);
             }
@@ -328,8 +344,9 @@ fn delete_command(store: &MarkdownMemoryStore, args: DeleteArgs, use_colors: boo
     if deleted {
         if use_colors {
             println!(
-                "{}🗑️  Memory deleted:{} {}",
+                "{}{}  Memory deleted:{} {}",
                 colors::GREEN,
+                glyph("🗑️", "[-]"),
                 colors::RESET,
                 args.id
             );
@@ -498,6 +515,25 @@ fn prime_command(store: &MarkdownMemoryStore, args: PrimeArgs) -> Result<()> {
     Ok(())
 }
 
+fn preview_command(root: &Path) -> Result<()> {
+    let preview = memory_injection_preview(root);
+    if preview.is_empty() {
+        eprintln!("Nothing would be injected (memories and tasks are disabled or empty).");
+    } else {
+        println!("{}", preview);
+    }
+    Ok(())
+}
+
+/// Builds the block the event loop would prepend to the next prompt in `root`,
+/// using the same config and memory budget as `ralph run`.
+fn memory_injection_preview(root: &Path) -> String {
+    let mut config = skill_cli::load_config(root);
+    config.core.workspace_root = root.to_path_buf();
+    EventLoop::with_context(config, LoopContext::primary(root.to_path_buf()))
+        .memory_injection_preview()
+}
+
 fn init_command(store: &MarkdownMemoryStore, args: InitArgs, use_colors: bool) -> Result<()> {
     store.init(args.force).map_err(|e| {
         if e.kind() == std::io::ErrorKind::AlreadyExists {
@@ -512,8 +548,9 @@ fn init_command(store: &MarkdownMemoryStore, args: InitArgs, use_colors: bool) -
 
     if use_colors {
         println!(
-            "{}✓{} Initialized memories file at {}",
+            "{}{}{} Initialized memories file at {}",
             colors::GREEN,
+            glyph("✓", "[ok]"),
             colors::RESET,
             store.path().display()
         );
@@ -524,6 +561,41 @@ fn init_command(store: &MarkdownMemoryStore, args: InitArgs, use_colors: bool) -
     Ok(())
 }
 
+/// Reads and parses memories from a markdown file for `ralph run --seed-memory`.
+///
+/// The file must use the `memories.md` format and contain at least one memory.
+pub fn load_seed_memories(path: &Path) -> Result<Vec<Memory>> {
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read seed memory file {}", path.display()))?;
+    let memories = ralph_core::memory_parser::parse_memories(&content);
+    if memories.is_empty() {
+        anyhow::bail!(
+            "No memories found in seed memory file {}. Expected `## <Section>` headers \
+             followed by `### mem-<id>` entries with `> content` lines (see `ralph memory init`).",
+            path.display()
+        );
+    }
+    Ok(memories)
+}
+
+/// Appends seed memories to the store, skipping IDs that already exist.
+///
+/// Returns the number of memories added.
+pub fn seed_memories(store: &MarkdownMemoryStore, memories: &[Memory]) -> Result<usize> {
+    let existing = store.load().context("Failed to load existing memories")?;
+    let mut added = 0;
+    for memory in memories {
+        if existing.iter().any(|m| m.id == memory.id) {
+            continue;
+        }
+        store
+            .append(memory)
+            .with_context(|| format!("Failed to seed memory {}", memory.id))?;
+        added += 1;
+    }
+    Ok(added)
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Output Helpers
 // ─────────────────────────────────────────────────────────────────────────────
@@ -711,11 +783,10 @@ fn format_memories_as_markdown(memories: &[Memory]) -> String {
 
         for memory in type_memories {
             output.push_str(&format!(
-                "\n### {}\n> {}\n<!-- tags: {} | created: {} -->\n",
+                "\n### {}\n> {}\n{}\n",
                 memory.id,
                 memory.content.replace('\n', "\n> "),
-                memory.tags.join(", "),
-                memory.created
+                memory.metadata_comment()
             ));
         }
     }
@@ -742,38 +813,6 @@ fn format_memories_as_text(memories: &[Memory]) -> String {
     output
 }
 
-/// Truncate content to approximately fit within a token budget.
-///
-/// Uses a simple heuristic of ~4 characters per token.
-fn truncate_to_budget(content: &str, budget: usize) -> String {
-    // Rough estimate: 4 chars per token
-    let char_budget = budget * 4;
-
-    if content.len() <= char_budget {
-        return content.to_string();
-    }
-
-    // Find a good break point (end of a memory block)
-    let truncated = &content[..char_budget];
-
-    // Try to find the last complete memory block (ends with -->)
-    if let Some(last_complete) = truncated.rfind("-->") {
-        let end = last_complete + 3;
-        // Find the next newline after -->
-        let final_end = truncated[end..].find('\n').map_or(end, |n| end + n + 1);
-        format!(
-            "{}\n\n<!-- truncated: budget {} tokens exceeded -->",
-            &content[..final_end],
-            budget
-        )
-    } else {
-        format!(
-            "{}\n\n<!-- truncated: budget {} tokens exceeded -->",
-            truncated, budget
-        )
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -866,6 +905,60 @@ mod tests {
         assert!(truncated.contains("truncated: budget 1 tokens exceeded"));
     }
 
+    #[test]
+    fn load_seed_memories_rejects_file_without_memories() {
+        let temp_dir = tempfile::tempdir().expect("temp dir");
+        let path = temp_dir.path().join("seed.md");
+        std::fs::write(&path, "# Notes\n\nJust some text\n").expect("write seed");
+
+        let err = load_seed_memories(&path).unwrap_err();
+        assert!(err.to_string().contains("No memories found"), "{err}");
+    }
+
+    #[test]
+    fn seed_memories_appends_and_skips_existing_ids() {
+        let temp_dir = tempfile::tempdir().expect("temp dir");
+        let path = temp_dir.path().join("seed.md");
+        std::fs::write(
+            &path,
+            "# Memories\n\n## Patterns\n\n### mem-1737372000-a1b2\n> Uses barrel exports\n<!-- tags: imports | created: 2025-01-20 -->\n",
+        )
+        .expect("write seed");
+        let store = MarkdownMemoryStore::with_default_path(temp_dir.path());
+
+        let memories = load_seed_memories(&path).expect("parse seed");
+        assert_eq!(seed_memories(&store, &memories).expect("seed"), 1);
+        assert_eq!(seed_memories(&store, &memories).expect("reseed"), 0);
+
+        let stored = store.load().expect("load");
+        assert_eq!(stored.len(), 1);
+        assert_eq!(stored[0].content, "Uses barrel exports");
+    }
+
+    #[test]
+    fn memory_injection_preview_includes_memories_and_skill() {
+        let temp_dir = tempfile::tempdir().expect("temp dir");
+        std::fs::write(
+            temp_dir.path().join("ralph.yml"),
+            "memories:\n  enabled: true\n  budget: 0\n",
+        )
+        .expect("write config");
+        MarkdownMemoryStore::with_default_path(temp_dir.path())
+            .append(&Memory::new(
+                MemoryType::Fix,
+                "use --offline for cargo".to_string(),
+                vec![],
+            ))
+            .expect("append memory");
+
+        let preview = memory_injection_preview(temp_dir.path());
+        assert!(preview.contains("use --offline for cargo"), "{preview}");
+        assert!(
+            preview.contains("<ralph-tools-memories-skill>"),
+            "{preview}"
+        );
+    }
+
     #[test]
     fn format_memories_as_markdown_groups_by_type() {
         let memories = vec![
@@ -875,6 +968,8 @@ mod tests {
                 content: "alpha".to_string(),
                 tags: vec!["tag1".to_string()],
                 created: "2026-01-31".to_string(),
+                iteration: None,
+                metadata: std::collections::BTreeMap::new(),
             },
             Memory {
                 id: "mem-2".to_string(),
@@ -882,6 +977,8 @@ mod tests {
                 content: "beta".to_string(),
                 tags: vec![],
                 created: "2026-01-31".to_string(),
+                iteration: None,
+                metadata: std::collections::BTreeMap::new(),
             },
         ];
 
@@ -902,6 +999,8 @@ mod tests {
             content: "beta".to_string(),
             tags: vec!["tag1".to_string()],
             created: "2026-01-31".to_string(),
+            iteration: None,
+            metadata: std::collections::BTreeMap::new(),
         }];
 
         let output = format_memories_as_text(&memories);
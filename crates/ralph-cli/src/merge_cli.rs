@@ -0,0 +1,346 @@
+//! CLI commands for inspecting and driving the merge queue.
+//!
+//! `ralph merge` exposes the `.ralph/merge-queue.jsonl` log directly so a
+//! serialized merge workflow can be run from the terminal:
+//!
+//! - `list`: show queue entries and their state
+//! - `add`: enqueue a loop branch for merging
+//! - `advance`: merge the next queued entry
+//! - `remove`: drop an entry from the queue
+
+use anyhow::{Result, bail};
+use clap::{Parser, Subcommand};
+use ralph_core::{MergeEntry, MergeQueue, MergeQueueError, MergeState};
+use std::path::Path;
+
+use crate::loops::{colorize_status, format_age, git_ref_exists, loop_branch, spawn_merge_ralph};
+
+/// Inspect and advance the merge queue.
+#[derive(Parser, Debug)]
+pub struct MergeArgs {
+    #[command(subcommand)]
+    pub command: MergeCommands,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum MergeCommands {
+    /// List merge queue entries with their state
+    List(ListArgs),
+
+    /// Enqueue a loop branch for merging
+    Add(AddArgs),
+
+    /// Merge the next queued entry
+    Advance,
+
+    /// Remove an entry from the queue
+    Remove(RemoveArgs),
+}
+
+#[derive(Parser, Debug)]
+pub struct ListArgs {
+    /// Output JSON instead of table
+    #[arg(long)]
+    pub json: bool,
+
+    /// Include merged and discarded entries
+    #[arg(long)]
+    pub all: bool,
+}
+
+#[derive(Parser, Debug)]
+pub struct AddArgs {
+    /// Loop branch (e.g., ralph/ralph-20250124-a3f2) or loop ID
+    pub branch: String,
+
+    /// Prompt to record with the entry
+    #[arg(short, long)]
+    pub prompt: Option<String>,
+}
+
+#[derive(Parser, Debug)]
+pub struct RemoveArgs {
+    /// Loop ID or branch
+    pub loop_id: String,
+
+    /// Reason for removing the entry
+    #[arg(short, long)]
+    pub reason: Option<String>,
+}
+
+/// Execute a merge command.
+pub fn execute(args: MergeArgs, use_colors: bool) -> Result<()> {
+    let cwd = std::env::current_dir()?;
+    match args.command {
+        MergeCommands::List(args) => list_entries(&cwd, &args, use_colors),
+        MergeCommands::Add(args) => {
+            let loop_id = add_entry(&cwd, &args.branch, args.prompt.as_deref())?;
+            println!("Queued '{}' for merge.", loop_id);
+            Ok(())
+        }
+        MergeCommands::Advance => advance_queue(&cwd),
+        MergeCommands::Remove(args) => {
+            let loop_id = remove_entry(&cwd, &args.loop_id, args.reason.as_deref())?;
+            println!("Removed '{}' from the merge queue.", loop_id);
+            Ok(())
+        }
+    }
+}
+
+#[derive(serde::Serialize)]
+struct EntryRow {
+    loop_id: String,
+    state: &'static str,
+    queued_at: String,
+    prompt: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    commit: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reason: Option<String>,
+}
+
+impl From<&MergeEntry> for EntryRow {
+    fn from(entry: &MergeEntry) -> Self {
+        Self {
+            loop_id: entry.loop_id.clone(),
+            state: state_label(entry.state),
+            queued_at: entry.queued_at.to_rfc3339(),
+            prompt: entry.prompt.clone(),
+            commit: entry.merge_commit.clone(),
+            reason: entry
+                .failure_reason
+                .clone()
+                .or_else(|| entry.discard_reason.clone()),
+        }
+    }
+}
+
+fn state_label(state: MergeState) -> &'static str {
+    match state {
+        MergeState::Queued => "queued",
+        MergeState::Merging => "merging",
+        MergeState::Merged => "merged",
+        MergeState::NeedsReview => "needs-review",
+        MergeState::Discarded => "discarded",
+    }
+}
+
+/// Strips an optional `ralph/` branch prefix to get the loop ID.
+fn loop_id_from_branch(branch: &str) -> &str {
+    branch.strip_prefix("ralph/").unwrap_or(branch)
+}
+
+fn list_entries(cwd: &Path, args: &ListArgs, use_colors: bool) -> Result<()> {
+    let entries: Vec<MergeEntry> = MergeQueue::new(cwd)
+        .list()?
+        .into_iter()
+        .filter(|e| args.all || !e.state.is_terminal())
+        .collect();
+
+    if args.json {
+        let rows: Vec<EntryRow> = entries.iter().map(EntryRow::from).collect();
+        println!("{}", serde_json::to_string_pretty(&rows)?);
+        return Ok(());
+    }
+
+    if entries.is_empty() {
+        println!("Merge queue is empty.");
+        return Ok(());
+    }
+
+    let now = chrono::Utc::now();
+    println!(
+        "{:<4} {:<32} {:<14} {:<6} PROMPT",
+        "#", "LOOP", "STATE", "AGE"
+    );
+    println!("{}", "-".repeat(80));
+
+    for (position, entry) in entries.iter().enumerate() {
+        let state = state_label(entry.state);
+        // Pad before colorizing so escape codes don't break alignment
+        let padded = format!("{:<14}", state);
+        let state_display = if use_colors {
+            padded.replace(state, &colorize_status(state))
+        } else {
+            padded
+        };
+
+        println!(
+            "{:<4} {:<32} {} {:<6} {}",
+            position + 1,
+            entry.loop_id,
+            state_display,
+            format_age(now.signed_duration_since(entry.queued_at)),
+            ralph_core::truncate_with_ellipsis(&entry.prompt, 40)
+        );
+    }
+
+    Ok(())
+}
+
+/// Enqueues a loop branch, returning the loop ID.
+fn add_entry(cwd: &Path, branch: &str, prompt: Option<&str>) -> Result<String> {
+    let loop_id = loop_id_from_branch(branch).to_string();
+
+    if !git_ref_exists(cwd, &loop_branch(&loop_id)) {
+        bail!("Branch '{}' does not exist.", loop_branch(&loop_id));
+    }
+
+    let queue = MergeQueue::new(cwd);
+    if let Some(entry) = queue.get_entry(&loop_id)?
+        && !entry.state.is_terminal()
+    {
+        bail!(
+            "'{}' is already in the merge queue ({}).",
+            loop_id,
+            state_label(entry.state)
+        );
+    }
+
+    let prompt = prompt.map_or_else(|| format!("Merge {}", loop_branch(&loop_id)), String::from);
+    queue.enqueue(&loop_id, &prompt)?;
+    Ok(loop_id)
+}
+
+/// Drops an entry from the queue by discarding it, returning the loop ID.
+fn remove_entry(cwd: &Path, loop_id: &str, reason: Option<&str>) -> Result<String> {
+    let loop_id = loop_id_from_branch(loop_id).to_string();
+    let reason = reason.unwrap_or("Removed from merge queue");
+
+    match MergeQueue::new(cwd).discard(&loop_id, Some(reason)) {
+        Ok(()) => Ok(loop_id),
+        Err(MergeQueueError::NotFound(_)) => bail!("'{}' is not in the merge queue.", loop_id),
+        Err(MergeQueueError::InvalidTransition(_, from, _)) => {
+            bail!("Cannot remove '{}': it is {}.", loop_id, state_label(from))
+        }
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Merges the next queued entry and reports its resulting state.
+fn advance_queue(cwd: &Path) -> Result<()> {
+    let queue = MergeQueue::new(cwd);
+    let Some(entry) = queue.next_pending()? else {
+        println!("Merge queue is empty.");
+        return Ok(());
+    };
+
+    // merge-ralph records the merging/merged/needs-review transitions itself
+    spawn_merge_ralph(cwd, &entry.loop_id)?;
+
+    match queue.get_entry(&entry.loop_id)? {
+        Some(updated) => println!(
+            "'{}' is now {}.",
+            updated.loop_id,
+            state_label(updated.state)
+        ),
+        None => println!("'{}' is no longer in the merge queue.", entry.loop_id),
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::process::Command;
+
+    fn git(dir: &Path, args: &[&str]) {
+        let status = Command::new("git")
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .expect("run git");
+        assert!(status.status.success(), "git {:?} failed", args);
+    }
+
+    fn init_repo_with_branch(dir: &Path, branch: &str) {
+        git(dir, &["init", "--initial-branch=main"]);
+        git(dir, &["config", "user.email", "test@example.com"]);
+        git(dir, &["config", "user.name", "Test"]);
+        git(dir, &["commit", "--allow-empty", "-m", "init"]);
+        git(dir, &["branch", branch]);
+    }
+
+    #[test]
+    fn test_loop_id_from_branch() {
+        assert_eq!(loop_id_from_branch("ralph/loop-1234"), "loop-1234");
+        assert_eq!(loop_id_from_branch("loop-1234"), "loop-1234");
+    }
+
+    #[test]
+    fn test_add_entry_enqueues_branch() {
+        let temp_dir = tempfile::tempdir().expect("temp dir");
+        init_repo_with_branch(temp_dir.path(), "ralph/loop-1234");
+
+        let loop_id = add_entry(temp_dir.path(), "ralph/loop-1234", None).expect("add");
+        assert_eq!(loop_id, "loop-1234");
+
+        let entry = MergeQueue::new(temp_dir.path())
+            .get_entry("loop-1234")
+            .unwrap()
+            .expect("entry");
+        assert_eq!(entry.state, MergeState::Queued);
+        assert_eq!(entry.prompt, "Merge ralph/loop-1234");
+
+        // Adding again while queued is rejected
+        let err = add_entry(temp_dir.path(), "loop-1234", None).unwrap_err();
+        assert!(err.to_string().contains("already in the merge queue"));
+    }
+
+    #[test]
+    fn test_add_entry_rejects_missing_branch() {
+        let temp_dir = tempfile::tempdir().expect("temp dir");
+        init_repo_with_branch(temp_dir.path(), "ralph/loop-1234");
+
+        let err = add_entry(temp_dir.path(), "ralph/loop-9999", Some("prompt")).unwrap_err();
+        assert!(err.to_string().contains("does not exist"));
+    }
+
+    #[test]
+    fn test_remove_entry_discards() {
+        let temp_dir = tempfile::tempdir().expect("temp dir");
+        let queue = MergeQueue::new(temp_dir.path());
+        queue.enqueue("loop-1234", "prompt").unwrap();
+
+        remove_entry(temp_dir.path(), "ralph/loop-1234", None).expect("remove");
+
+        let entry = queue.get_entry("loop-1234").unwrap().expect("entry");
+        assert_eq!(entry.state, MergeState::Discarded);
+        assert_eq!(
+            entry.discard_reason.as_deref(),
+            Some("Removed from merge queue")
+        );
+        assert!(queue.next_pending().unwrap().is_none());
+
+        // Removing a terminal entry reports its state
+        let err = remove_entry(temp_dir.path(), "loop-1234", None).unwrap_err();
+        assert!(err.to_string().contains("discarded"));
+    }
+
+    #[test]
+    fn test_remove_entry_missing() {
+        let temp_dir = tempfile::tempdir().expect("temp dir");
+        let err = remove_entry(temp_dir.path(), "loop-1234", None).unwrap_err();
+        assert!(err.to_string().contains("not in the merge queue"));
+    }
+
+    #[test]
+    fn test_advance_empty_queue_is_noop() {
+        let temp_dir = tempfile::tempdir().expect("temp dir");
+        advance_queue(temp_dir.path()).expect("advance");
+    }
+
+    #[test]
+    fn test_entry_row_reason() {
+        let temp_dir = tempfile::tempdir().expect("temp dir");
+        let queue = MergeQueue::new(temp_dir.path());
+        queue.enqueue("loop-1234", "prompt").unwrap();
+        queue.discard("loop-1234", Some("stale")).unwrap();
+
+        let entry = queue.get_entry("loop-1234").unwrap().unwrap();
+        let row = EntryRow::from(&entry);
+        assert_eq!(row.state, "discarded");
+        assert_eq!(row.reason.as_deref(), Some("stale"));
+    }
+}
@@ -556,6 +556,9 @@ fn mapping_insert(mapping: &mut Mapping, key: &str, value: Value) {
     mapping.insert(Value::String(key.to_string()), value);
 }
 
+/// Prefix marking a hat's `instructions` as a path to a file to inline.
+const INSTRUCTIONS_FILE_PREFIX: &str = "@file:";
+
 fn source_base_dir(path: &Path) -> &Path {
     path.parent()
         .filter(|parent| !parent.as_os_str().is_empty())
@@ -578,7 +581,8 @@ fn resolve_hat_imports_in_config_value(
     let hats = hats_value
         .as_mapping_mut()
         .ok_or_else(|| anyhow::anyhow!("Config '{}' hats must be a YAML mapping", source_label))?;
-    resolve_hat_imports(hats, base_dir, source_label)
+    resolve_hat_imports(hats, base_dir, source_label)?;
+    resolve_instruction_files(hats, base_dir, source_label)
 }
 
 fn resolve_hat_imports_in_hats_source_value(
@@ -597,7 +601,8 @@ fn resolve_hat_imports_in_hats_source_value(
     let hats = hats_value.as_mapping_mut().ok_or_else(|| {
         anyhow::anyhow!("Hats config '{}' hats must be a YAML mapping", source_label)
     })?;
-    resolve_hat_imports(hats, base_dir, source_label)
+    resolve_hat_imports(hats, base_dir, source_label)?;
+    resolve_instruction_files(hats, base_dir, source_label)
 }
 
 fn resolve_hat_imports(hats: &mut Mapping, base_dir: &Path, source_label: &str) -> Result<()> {
@@ -635,11 +640,11 @@ fn resolve_hat_imports(hats: &mut Mapping, base_dir: &Path, source_label: &str)
             hat_import_error(source_label, &hat_id, Some(&resolved_path), cause)
         })?;
 
-        let imported_value: Value = serde_yaml::from_str(&content).map_err(|err| {
+        let mut imported_value: Value = serde_yaml::from_str(&content).map_err(|err| {
             hat_import_error(source_label, &hat_id, Some(&resolved_path), err.to_string())
         })?;
 
-        let imported_hat = imported_value.as_mapping().ok_or_else(|| {
+        let imported_hat = imported_value.as_mapping_mut().ok_or_else(|| {
             hat_import_error(
                 source_label,
                 &hat_id,
@@ -666,6 +671,14 @@ fn resolve_hat_imports(hats: &mut Mapping, base_dir: &Path, source_label: &str)
             ));
         }
 
+        // `@file:` instructions in the imported hat are relative to the imported file
+        resolve_hat_instructions_file(
+            imported_hat,
+            source_base_dir(&resolved_path),
+            &resolved_path.display().to_string(),
+            &hat_id,
+        )?;
+
         let local_overrides = local_hat.clone();
         let resolved_hat = merge_imported_hat(imported_hat.clone(), &local_overrides);
         serde_yaml::from_value::<HatConfig>(Value::Mapping(resolved_hat.clone())).map_err(
@@ -684,6 +697,58 @@ fn resolve_hat_imports(hats: &mut Mapping, base_dir: &Path, source_label: &str)
     Ok(())
 }
 
+/// Inlines `instructions: "@file:<path>"` for every hat, resolving paths
+/// relative to `base_dir` (the directory of the file that declared the hats).
+fn resolve_instruction_files(
+    hats: &mut Mapping,
+    base_dir: &Path,
+    source_label: &str,
+) -> Result<()> {
+    for (hat_key, hat_value) in hats.iter_mut() {
+        let hat_id = hat_key_label(hat_key);
+        if let Some(hat) = hat_value.as_mapping_mut() {
+            resolve_hat_instructions_file(hat, base_dir, source_label, &hat_id)?;
+        }
+    }
+    Ok(())
+}
+
+fn resolve_hat_instructions_file(
+    hat: &mut Mapping,
+    base_dir: &Path,
+    source_label: &str,
+    hat_id: &str,
+) -> Result<()> {
+    let Some(file_path) = mapping_get(hat, "instructions")
+        .and_then(Value::as_str)
+        .and_then(|instructions| instructions.trim().strip_prefix(INSTRUCTIONS_FILE_PREFIX))
+    else {
+        return Ok(());
+    };
+
+    let file_path = Path::new(file_path.trim());
+    let resolved_path = if file_path.is_absolute() {
+        file_path.to_path_buf()
+    } else {
+        base_dir.join(file_path)
+    };
+
+    let content = std::fs::read_to_string(&resolved_path).map_err(|err| {
+        let cause = if err.kind() == ErrorKind::NotFound {
+            "file not found".to_string()
+        } else {
+            err.to_string()
+        };
+        anyhow::anyhow!(
+            "failed to resolve hat instructions\n  --> {source_label}, hat '{hat_id}'\n  --> reads {}\n\n  cause: {cause}",
+            resolved_path.display()
+        )
+    })?;
+
+    mapping_insert(hat, "instructions", Value::String(content));
+    Ok(())
+}
+
 fn merge_imported_hat(mut imported: Mapping, local_overrides: &Mapping) -> Mapping {
     for (key, value) in local_overrides {
         if key.as_str() == Some("import") {
@@ -744,11 +809,28 @@ fn reject_hat_imports_in_mapping(
         let Some(hat) = hat_value.as_mapping() else {
             continue;
         };
+        let hat_id = hat_key_label(hat_key);
+
+        let has_instructions_file = mapping_get(hat, "instructions")
+            .and_then(Value::as_str)
+            .is_some_and(|instructions| instructions.trim().starts_with(INSTRUCTIONS_FILE_PREFIX));
+        if has_instructions_file {
+            let kind = match source {
+                UnsupportedImportSource::Embedded => "embedded presets",
+                UnsupportedImportSource::Remote => "remote presets",
+                UnsupportedImportSource::PresetDir => "preset directories",
+            };
+            anyhow::bail!(
+                "'{INSTRUCTIONS_FILE_PREFIX}' instructions are not supported in {kind} - '{}' in {} references a local file.\n\nhint: inline the instructions or use a file-based preset",
+                hat_id,
+                source_label
+            );
+        }
+
         if mapping_get(hat, "import").is_none() {
             continue;
         }
 
-        let hat_id = hat_key_label(hat_key);
         match source {
             UnsupportedImportSource::Embedded => anyhow::bail!(
                 "hat imports are not supported in embedded presets - '{}' contains an 'import:' directive.\n\nhint: use a file-based preset to use hat imports",
@@ -1373,6 +1455,104 @@ hats:
         assert!(remote_err.to_string().contains("remote presets"));
     }
 
+    #[tokio::test]
+    async fn load_config_for_preflight_inlines_instruction_files_relative_to_each_file() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let hats_dir = temp_dir.path().join("hats");
+        std::fs::create_dir_all(hats_dir.join("shared/docs")).unwrap();
+        std::fs::create_dir_all(hats_dir.join("rubrics")).unwrap();
+
+        std::fs::write(hats_dir.join("rubrics/builder.md"), "## Builder rubric\n").unwrap();
+        std::fs::write(
+            hats_dir.join("shared/docs/reviewer.md"),
+            "## Reviewer rubric\n",
+        )
+        .unwrap();
+        std::fs::write(
+            hats_dir.join("shared/reviewer.yml"),
+            r"
+name: Reviewer
+triggers: [review.start]
+publishes: [review.done]
+instructions: '@file:docs/reviewer.md'
+",
+        )
+        .unwrap();
+
+        let hats_path = hats_dir.join("workflow.yml");
+        std::fs::write(
+            &hats_path,
+            r"
+hats:
+  builder:
+    name: Builder
+    triggers: [build.start]
+    publishes: [build.done]
+    instructions: '@file:rubrics/builder.md'
+  reviewer:
+    import: shared/reviewer.yml
+",
+        )
+        .unwrap();
+
+        let config = load_config_for_preflight(
+            &[ConfigSource::File(temp_dir.path().join("missing.yml"))],
+            Some(&HatsSource::File(hats_path)),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(config.hats["builder"].instructions, "## Builder rubric\n");
+        assert_eq!(config.hats["reviewer"].instructions, "## Reviewer rubric\n");
+    }
+
+    #[test]
+    fn missing_instruction_file_errors_with_path() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let mut value: Value = serde_yaml::from_str(
+            r"
+hats:
+  builder:
+    name: Builder
+    instructions: '@file:rubrics/missing.md'
+",
+        )
+        .unwrap();
+
+        let err = resolve_hat_imports_in_config_value(&mut value, temp_dir.path(), "ralph.yml")
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("hat 'builder'"), "unexpected error: {err}");
+        assert!(
+            err.contains("rubrics/missing.md"),
+            "unexpected error: {err}"
+        );
+        assert!(err.contains("file not found"), "unexpected error: {err}");
+    }
+
+    #[test]
+    fn reject_instruction_files_in_unsupported_sources() {
+        let overlay: Value = serde_yaml::from_str(
+            r"
+hats:
+  builder:
+    instructions: '@file:builder.md'
+",
+        )
+        .unwrap();
+
+        let err = reject_hat_imports_in_hats_source_value(
+            &overlay,
+            "https://example.com/hats.yml",
+            UnsupportedImportSource::Remote,
+        )
+        .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("'@file:' instructions are not supported in remote presets")
+        );
+    }
+
     #[test]
     fn merge_hats_overlay_replaces_hats_and_merges_event_loop() {
         let core: Value = serde_yaml::from_str(
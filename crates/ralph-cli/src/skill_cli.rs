@@ -272,7 +272,7 @@ fn resolve_configured_skills_dir(root: &Path, dir: &Path) -> PathBuf {
 }
 
 /// Load config from workspace root, falling back to defaults.
-fn load_config(root: &Path) -> RalphConfig {
+pub(crate) fn load_config(root: &Path) -> RalphConfig {
     let mut merged = match config_resolution::default_core_value() {
         Ok(value) => value,
         Err(_) => return RalphConfig::default(),
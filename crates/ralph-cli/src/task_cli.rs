@@ -8,6 +8,8 @@
 //! - `start`: Mark a task as in progress
 //! - `close`: Mark a task as complete
 //! - `reopen`: Reopen a closed/failed task
+//! - `block`: Mark a task as waiting on a human or external system
+//! - `unblock`: Return a blocked task to open
 //! - `show`: Show a single task by ID
 
 use crate::{display::colors, resolve_path_from_workspace, resolve_workspace_root};
@@ -66,6 +68,12 @@ pub enum TaskCommands {
     /// Reopen a closed or failed task
     Reopen(ReopenArgs),
 
+    /// Mark a task as blocked on a human or external system
+    Block(BlockArgs),
+
+    /// Return a blocked task to open
+    Unblock(UnblockArgs),
+
     /// Show a single task by ID
     Show(ShowArgs),
 }
@@ -123,7 +131,7 @@ pub struct EnsureArgs {
 /// Arguments for the `task list` command.
 #[derive(Parser, Debug)]
 pub struct ListArgs {
-    /// Filter by status: open, in_progress, closed, failed
+    /// Filter by status: open, in_progress, blocked, closed, failed
     #[arg(short = 's', long)]
     pub status: Option<String>,
 
@@ -184,6 +192,24 @@ pub struct ReopenArgs {
     pub id: String,
 }
 
+/// Arguments for the `task block` command.
+#[derive(Parser, Debug)]
+pub struct BlockArgs {
+    /// Task ID to block
+    pub id: String,
+
+    /// What the task is waiting on
+    #[arg(short = 'r', long)]
+    pub reason: String,
+}
+
+/// Arguments for the `task unblock` command.
+#[derive(Parser, Debug)]
+pub struct UnblockArgs {
+    /// Task ID to unblock
+    pub id: String,
+}
+
 /// Arguments for the `task show` command.
 #[derive(Parser, Debug)]
 pub struct ShowArgs {
@@ -242,6 +268,7 @@ fn status_matches_filter(status: TaskStatus, filter: &str) -> bool {
         TaskStatus::InProgress => normalized == "inprogress",
         TaskStatus::Closed => normalized == "closed",
         TaskStatus::Failed => normalized == "failed",
+        TaskStatus::Blocked => normalized == "blocked",
     }
 }
 
@@ -289,8 +316,9 @@ fn filter_tasks_for_list(store: &TaskStore, args: &ListArgs) -> Vec<Task> {
         let status_rank = |s: TaskStatus| match s {
             TaskStatus::InProgress => 0,
             TaskStatus::Open => 1,
-            TaskStatus::Closed => 2,
-            TaskStatus::Failed => 3,
+            TaskStatus::Blocked => 2,
+            TaskStatus::Closed => 3,
+            TaskStatus::Failed => 4,
         };
 
         let rank_a = status_rank(a.status);
@@ -349,6 +377,10 @@ pub fn execute(args: TaskArgs, use_colors: bool) -> Result<()> {
         TaskCommands::Close(close_args) => execute_close(close_args, root.as_ref(), use_colors),
         TaskCommands::Fail(fail_args) => execute_fail(fail_args, root.as_ref(), use_colors),
         TaskCommands::Reopen(reopen_args) => execute_reopen(reopen_args, root.as_ref(), use_colors),
+        TaskCommands::Block(block_args) => execute_block(block_args, root.as_ref(), use_colors),
+        TaskCommands::Unblock(unblock_args) => {
+            execute_unblock(unblock_args, root.as_ref(), use_colors)
+        }
         TaskCommands::Show(show_args) => execute_show(show_args, root.as_ref(), use_colors),
     }
 }
@@ -481,6 +513,7 @@ fn execute_list(args: ListArgs, root: Option<&PathBuf>, use_colors: bool) -> Res
                         TaskStatus::InProgress => ("in_progress", colors::BLUE),
                         TaskStatus::Closed => ("closed", colors::DIM),
                         TaskStatus::Failed => ("failed", colors::RED),
+                        TaskStatus::Blocked => ("blocked", colors::YELLOW),
                     };
 
                     let priority_color = match task.priority {
@@ -712,6 +745,7 @@ fn execute_show(args: ShowArgs, root: Option<&PathBuf>, use_colors: bool) -> Res
                 TaskStatus::InProgress => "in_progress",
                 TaskStatus::Closed => "closed",
                 TaskStatus::Failed => "failed",
+                TaskStatus::Blocked => "blocked",
             };
 
             if use_colors {
@@ -720,6 +754,7 @@ fn execute_show(args: ShowArgs, root: Option<&PathBuf>, use_colors: bool) -> Res
                     TaskStatus::InProgress => colors::BLUE,
                     TaskStatus::Closed => colors::DIM,
                     TaskStatus::Failed => colors::RED,
+                    TaskStatus::Blocked => colors::YELLOW,
                 };
                 let priority_color = match task.priority {
                     1 => colors::RED,
@@ -750,6 +785,9 @@ fn execute_show(args: ShowArgs, root: Option<&PathBuf>, use_colors: bool) -> Res
                 if !task.blocked_by.is_empty() {
                     println!("Blocked by:  {}", task.blocked_by.join(", "));
                 }
+                if let Some(reason) = &task.blocked_reason {
+                    println!("Waiting on:  {}", reason);
+                }
                 println!("Created:     {}", task.created);
                 if let Some(started) = &task.started {
                     println!("Started:     {}", started);
@@ -771,6 +809,9 @@ fn execute_show(args: ShowArgs, root: Option<&PathBuf>, use_colors: bool) -> Res
                 if !task.blocked_by.is_empty() {
                     println!("Blocked by:  {}", task.blocked_by.join(", "));
                 }
+                if let Some(reason) = &task.blocked_reason {
+                    println!("Waiting on:  {}", reason);
+                }
                 println!("Created:     {}", task.created);
                 if let Some(started) = &task.started {
                     println!("Started:     {}", started);
@@ -816,6 +857,60 @@ fn execute_reopen(args: ReopenArgs, root: Option<&PathBuf>, use_colors: bool) ->
     Ok(())
 }
 
+fn execute_block(args: BlockArgs, root: Option<&PathBuf>, use_colors: bool) -> Result<()> {
+    let path = get_tasks_path(root);
+    let mut store = TaskStore::load(&path).context("Failed to load tasks")?;
+
+    let task_id = args.id;
+    let blocked = store
+        .with_exclusive_lock(|s| s.block(&task_id, args.reason.clone()).cloned())
+        .context("Failed to save tasks")?
+        .context(format!("Task {} not found", task_id))?;
+
+    if use_colors {
+        println!(
+            "{}Blocked task: {} - {} (waiting on: {}){}",
+            colors::YELLOW,
+            task_id,
+            blocked.title,
+            args.reason,
+            colors::RESET
+        );
+    } else {
+        println!(
+            "Blocked task: {} - {} (waiting on: {})",
+            task_id, blocked.title, args.reason
+        );
+    }
+
+    Ok(())
+}
+
+fn execute_unblock(args: UnblockArgs, root: Option<&PathBuf>, use_colors: bool) -> Result<()> {
+    let path = get_tasks_path(root);
+    let mut store = TaskStore::load(&path).context("Failed to load tasks")?;
+
+    let task_id = args.id;
+    let unblocked = store
+        .with_exclusive_lock(|s| s.unblock(&task_id).cloned())
+        .context("Failed to save tasks")?
+        .context(format!("Task {} not found", task_id))?;
+
+    if use_colors {
+        println!(
+            "{}Unblocked task: {} - {}{}",
+            colors::GREEN,
+            task_id,
+            unblocked.title,
+            colors::RESET
+        );
+    } else {
+        println!("Unblocked task: {} - {}", task_id, unblocked.title);
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -856,6 +951,28 @@ mod tests {
         assert_eq!(filtered[0].status, TaskStatus::InProgress);
     }
 
+    #[test]
+    fn test_list_status_filter_accepts_blocked() {
+        let temp_dir = TempDir::new().expect("temp dir");
+        let mut blocked = Task::new("Blocked".to_string(), 2);
+        blocked.block("Waiting on vendor".to_string());
+        let open_task = Task::new("Open".to_string(), 2);
+
+        let store = write_tasks(temp_dir.path(), vec![open_task, blocked]);
+
+        let args = ListArgs {
+            status: Some("blocked".to_string()),
+            days: None,
+            limit: None,
+            all: false,
+            format: OutputFormat::Quiet,
+        };
+
+        let filtered = filter_tasks_for_list(&store, &args);
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].status, TaskStatus::Blocked);
+    }
+
     #[test]
     fn test_ready_filters_by_loop_id_marker() {
         let temp_dir = TempDir::new().expect("temp dir");
@@ -9,10 +9,12 @@
 //! - `task`: Work item tracking (beads-lite)
 //! - `skill`: Load skill content on demand
 //! - `interact`: Human-in-the-loop communication (progress updates, notifications)
+//! - `event`: Validate JSONL events files
 
 use anyhow::Result;
 use clap::{Parser, Subcommand};
 
+use crate::event_cli;
 use crate::interact;
 use crate::memory;
 use crate::skill_cli;
@@ -38,6 +40,9 @@ pub enum ToolsCommands {
 
     /// Interact with human via Telegram (progress updates, notifications)
     Interact(interact::InteractArgs),
+
+    /// Validate events files written by agents
+    Event(event_cli::EventArgs),
 }
 
 /// Execute a tools command.
@@ -47,5 +52,6 @@ pub async fn execute(args: ToolsArgs, use_colors: bool) -> Result<()> {
         ToolsCommands::Task(task_args) => task_cli::execute(task_args, use_colors),
         ToolsCommands::Skill(skill_args) => skill_cli::execute(skill_args),
         ToolsCommands::Interact(interact_args) => interact::execute(interact_args).await,
+        ToolsCommands::Event(event_args) => event_cli::execute(event_args),
     }
 }
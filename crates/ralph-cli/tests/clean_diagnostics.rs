@@ -1,3 +1,4 @@
+use ralph_cli::DiagnosticsRetention;
 use std::fs;
 use tempfile::TempDir;
 
@@ -15,8 +16,9 @@ fn test_clean_diagnostics_removes_directory() {
 
     assert!(diagnostics_dir.exists());
 
-    // Clean diagnostics (use_colors=false, dry_run=false)
-    ralph_cli::clean_diagnostics(temp.path(), false, false).unwrap();
+    // Clean diagnostics (use_colors=false, dry_run=false, no filters)
+    ralph_cli::clean_diagnostics(temp.path(), false, false, DiagnosticsRetention::default())
+        .unwrap();
 
     // Directory should be gone
     assert!(!diagnostics_dir.exists());
@@ -31,7 +33,8 @@ fn test_clean_diagnostics_handles_missing_directory() {
     assert!(!diagnostics_dir.exists());
 
     // Should not error
-    let result = ralph_cli::clean_diagnostics(temp.path(), false, false);
+    let result =
+        ralph_cli::clean_diagnostics(temp.path(), false, false, DiagnosticsRetention::default());
     assert!(result.is_ok());
 }
 
@@ -51,9 +54,37 @@ fn test_clean_diagnostics_preserves_other_ralph_contents() {
     assert!(events_file.exists());
 
     // Clean diagnostics
-    ralph_cli::clean_diagnostics(temp.path(), false, false).unwrap();
+    ralph_cli::clean_diagnostics(temp.path(), false, false, DiagnosticsRetention::default())
+        .unwrap();
 
     // Diagnostics gone, events preserved
     assert!(!diagnostics_dir.exists());
     assert!(events_file.exists());
 }
+
+/// Test that --older-than removes only sessions older than the cutoff
+#[test]
+fn test_clean_diagnostics_older_than_keeps_recent_sessions() {
+    let temp = TempDir::new().unwrap();
+    let diagnostics_dir = temp.path().join(".ralph/diagnostics");
+    let old_session = diagnostics_dir.join("2024-01-15T10-23-45");
+    let recent_name = chrono::Local::now().format("%Y-%m-%dT%H-%M-%S").to_string();
+    let recent_session = diagnostics_dir.join(&recent_name);
+    fs::create_dir_all(&old_session).unwrap();
+    fs::create_dir_all(&recent_session).unwrap();
+
+    let retention = DiagnosticsRetention {
+        older_than: Some(ralph_cli::parse_age("7d").unwrap()),
+        keep_last: None,
+    };
+
+    // Dry run deletes nothing
+    ralph_cli::clean_diagnostics(temp.path(), false, true, retention).unwrap();
+    assert!(old_session.exists());
+
+    ralph_cli::clean_diagnostics(temp.path(), false, false, retention).unwrap();
+
+    assert!(!old_session.exists());
+    assert!(recent_session.exists());
+    assert!(diagnostics_dir.exists());
+}
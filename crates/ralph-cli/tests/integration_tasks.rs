@@ -247,3 +247,34 @@ fn test_task_start_and_reopen_update_lifecycle_fields() {
     assert!(task.started.is_some());
     assert!(task.closed.is_none());
 }
+
+#[test]
+fn test_task_block_and_unblock() {
+    let temp_dir = TempDir::new().expect("temp dir");
+    let temp_path = temp_dir.path();
+
+    let task_id = ralph_task_ok(temp_path, &["add", "Deploy", "--format", "quiet"])
+        .trim()
+        .to_string();
+
+    ralph_task_ok(
+        temp_path,
+        &["block", &task_id, "--reason", "Waiting on prod access"],
+    );
+    let blocked = list_tasks(temp_path, &["--status", "blocked"]);
+    assert_eq!(blocked.len(), 1);
+    assert_eq!(blocked[0].status, TaskStatus::Blocked);
+    assert_eq!(
+        blocked[0].blocked_reason.as_deref(),
+        Some("Waiting on prod access")
+    );
+
+    let ready = ralph_task_ok(temp_path, &["ready", "--format", "quiet"]);
+    assert!(!ready.contains(&task_id), "blocked task must not be ready");
+
+    ralph_task_ok(temp_path, &["unblock", &task_id]);
+    let stdout = ralph_task_ok(temp_path, &["show", &task_id, "--format", "json"]);
+    let task: Task = serde_json::from_str(&stdout).expect("parse unblocked task");
+    assert_eq!(task.status, TaskStatus::Open);
+    assert!(task.blocked_reason.is_none());
+}
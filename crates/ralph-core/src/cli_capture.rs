@@ -3,7 +3,11 @@
 //! `CliCapture` wraps a `Write` implementation to capture all bytes written
 //! to stdout/stderr while forwarding them to the underlying writer. This
 //! enables transparent recording without changing calling code.
+//!
+//! Captures can optionally keep a running transcript of the stream, exposed
+//! raw (with ANSI escapes) and/or stripped to plain text for archiving.
 
+use crate::session_player::strip_ansi;
 use ralph_proto::{FrameCapture, TerminalWrite, UxEvent};
 use std::io::{self, Write};
 use std::time::Instant;
@@ -46,6 +50,15 @@ pub struct CliCapture<W> {
 
     /// Whether this captures stdout (true) or stderr (false).
     is_stdout: bool,
+
+    /// Bytes written so far, kept only when a transcript was requested.
+    transcript: Vec<u8>,
+
+    /// Whether `raw()` exposes the transcript.
+    keep_raw: bool,
+
+    /// Whether `stripped()` exposes the transcript.
+    keep_stripped: bool,
 }
 
 impl<W> CliCapture<W> {
@@ -56,12 +69,7 @@ impl<W> CliCapture<W> {
     /// * `inner` - The writer to forward output to
     /// * `is_stdout` - `true` if capturing stdout, `false` for stderr
     pub fn new(inner: W, is_stdout: bool) -> Self {
-        Self {
-            inner,
-            captures: Vec::new(),
-            start_time: Instant::now(),
-            is_stdout,
-        }
+        Self::with_start_time(inner, is_stdout, Instant::now())
     }
 
     /// Creates a capture wrapper with a custom start time.
@@ -74,9 +82,43 @@ impl<W> CliCapture<W> {
             captures: Vec::new(),
             start_time,
             is_stdout,
+            transcript: Vec::new(),
+            keep_raw: false,
+            keep_stripped: false,
         }
     }
 
+    /// Keeps a raw transcript of the stream, ANSI escapes included.
+    #[must_use]
+    pub fn with_raw_transcript(mut self, enabled: bool) -> Self {
+        self.keep_raw = enabled;
+        self
+    }
+
+    /// Keeps a plain-text transcript of the stream with ANSI escapes removed.
+    #[must_use]
+    pub fn with_stripped_transcript(mut self, enabled: bool) -> Self {
+        self.keep_stripped = enabled;
+        self
+    }
+
+    /// Returns everything written so far, exactly as emitted.
+    ///
+    /// `None` unless enabled via [`CliCapture::with_raw_transcript`].
+    pub fn raw(&self) -> Option<&[u8]> {
+        self.keep_raw.then_some(self.transcript.as_slice())
+    }
+
+    /// Returns everything written so far as plain text.
+    ///
+    /// Escapes are stripped from the whole transcript rather than per write, so
+    /// sequences split across writes are still removed. `None` unless enabled
+    /// via [`CliCapture::with_stripped_transcript`].
+    pub fn stripped(&self) -> Option<String> {
+        self.keep_stripped
+            .then(|| String::from_utf8_lossy(&strip_ansi(&self.transcript)).into_owned())
+    }
+
     /// Returns the current offset in milliseconds since capture started.
     #[allow(clippy::cast_possible_truncation)]
     fn offset_ms(&self) -> u64 {
@@ -106,6 +148,9 @@ impl<W: Write> Write for CliCapture<W> {
         let n = self.inner.write(buf)?;
 
         // Only capture the bytes that were actually written
+        if n > 0 && (self.keep_raw || self.keep_stripped) {
+            self.transcript.extend_from_slice(&buf[..n]);
+        }
         if n > 0 {
             self.captures
                 .push(UxEvent::TerminalWrite(TerminalWrite::new(
@@ -154,6 +199,24 @@ impl<Stdout, Stderr> CliCapturePair<Stdout, Stderr> {
             stderr: CliCapture::with_start_time(stderr, false, start_time),
         }
     }
+
+    /// Keeps raw transcripts of both streams (see [`CliCapture::raw`]).
+    #[must_use]
+    pub fn with_raw_transcript(self, enabled: bool) -> Self {
+        Self {
+            stdout: self.stdout.with_raw_transcript(enabled),
+            stderr: self.stderr.with_raw_transcript(enabled),
+        }
+    }
+
+    /// Keeps plain-text transcripts of both streams (see [`CliCapture::stripped`]).
+    #[must_use]
+    pub fn with_stripped_transcript(self, enabled: bool) -> Self {
+        Self {
+            stdout: self.stdout.with_stripped_transcript(enabled),
+            stderr: self.stderr.with_stripped_transcript(enabled),
+        }
+    }
 }
 
 impl<Stdout: Send + Sync, Stderr: Send + Sync> CliCapturePair<Stdout, Stderr> {
@@ -280,4 +343,40 @@ mod tests {
             assert_eq!(decoded, ansi_text);
         }
     }
+
+    #[test]
+    fn test_transcripts_disabled_by_default() {
+        let mut capture = CliCapture::new(Vec::new(), true);
+        write!(capture, "\x1b[32mGreen\x1b[0m").unwrap();
+
+        assert!(capture.raw().is_none());
+        assert!(capture.stripped().is_none());
+    }
+
+    #[test]
+    fn test_raw_and_stripped_transcripts() {
+        let mut capture = CliCapture::new(Vec::new(), true)
+            .with_raw_transcript(true)
+            .with_stripped_transcript(true);
+
+        // Escape sequence split across two writes
+        capture.write_all(b"\x1b[3").unwrap();
+        capture.write_all(b"2mGreen\x1b[0m done").unwrap();
+
+        assert_eq!(capture.raw().unwrap(), b"\x1b[32mGreen\x1b[0m done");
+        assert_eq!(capture.stripped().unwrap(), "Green done");
+        // Forwarded output is untouched
+        assert_eq!(capture.inner(), b"\x1b[32mGreen\x1b[0m done");
+    }
+
+    #[test]
+    fn test_stripped_only_transcript() {
+        let mut pair =
+            CliCapturePair::new(Vec::<u8>::new(), Vec::<u8>::new()).with_stripped_transcript(true);
+        write!(pair.stderr, "\x1b[31merror\x1b[0m").unwrap();
+
+        assert!(pair.stderr.raw().is_none());
+        assert_eq!(pair.stderr.stripped().unwrap(), "error");
+        assert_eq!(pair.stdout.stripped().unwrap(), "");
+    }
 }
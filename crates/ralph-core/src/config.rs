@@ -5,7 +5,7 @@
 
 use ralph_proto::Topic;
 use serde::{Deserialize, Deserializer, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::path::{Path, PathBuf};
 use tracing::debug;
 
@@ -531,6 +531,28 @@ impl RalphConfig {
         if self.event_loop.completion_promise.trim().is_empty() {
             return Err(ConfigError::InvalidCompletionPromise);
         }
+        if self.event_loop.completion_confirmations_required == 0 {
+            return Err(ConfigError::InvalidCompletionConfirmations);
+        }
+        if let Some(window) = self.event_loop.failure_window
+            && window < self.event_loop.max_consecutive_failures
+        {
+            return Err(ConfigError::InvalidFailureWindow {
+                window,
+                max_failures: self.event_loop.max_consecutive_failures,
+            });
+        }
+        if self.event_loop.max_same_topic == 0 {
+            return Err(ConfigError::InvalidMaxSameTopic);
+        }
+        if let Some(repeats) = self.event_loop.max_cycle_repeats
+            && (repeats < 2 || self.event_loop.cycle_window < repeats.saturating_mul(2))
+        {
+            return Err(ConfigError::InvalidCycleDetection {
+                repeats,
+                window: self.event_loop.cycle_window,
+            });
+        }
 
         // Check custom backend has a command
         if self.cli.backend == "custom" && self.cli.command.as_ref().is_none_or(String::is_empty) {
@@ -636,6 +658,41 @@ impl RalphConfig {
             }
         }
 
+        // Check for self-triggering hats: publishing a topic the hat also triggers on
+        // re-activates it immediately, so require an activation or cooldown guard.
+        let mut hat_ids: Vec<&String> = self.hats.keys().collect();
+        hat_ids.sort();
+        for hat_id in hat_ids {
+            let hat_config = &self.hats[hat_id];
+            let self_triggers: Vec<String> = hat_config
+                .publishes
+                .iter()
+                .filter(|published| {
+                    hat_config
+                        .triggers
+                        .iter()
+                        .any(|trigger| Topic::new(trigger).matches_str(published))
+                })
+                .cloned()
+                .collect();
+            if self_triggers.is_empty() {
+                continue;
+            }
+            if hat_config.max_activations.is_none() && hat_config.cooldown_secs.is_none() {
+                return Err(ConfigError::UnguardedSelfTrigger {
+                    hat: hat_id.clone(),
+                    topics: self_triggers.join(", "),
+                });
+            }
+            warnings.push(ConfigWarning::InvalidValue {
+                field: format!("hats.{hat_id}.publishes"),
+                message: format!(
+                    "Hat '{hat_id}' publishes topics it also triggers on ({}); it will re-activate itself until max_activations or cooldown_secs stops it",
+                    self_triggers.join(", ")
+                ),
+            });
+        }
+
         // Check for ambiguous routing: each trigger topic must map to exactly one hat
         // Per spec: "Every trigger maps to exactly one hat | No ambiguous routing"
         if !self.hats.is_empty() {
@@ -895,6 +952,30 @@ pub struct EventLoopConfig {
     #[serde(default = "default_max_failures")]
     pub max_consecutive_failures: u32,
 
+    /// Count failures over the last N iterations instead of only consecutive
+    /// ones. When set, the loop stops once `max_consecutive_failures` failures
+    /// fall inside the window, so alternating success/failure can't run
+    /// forever. `None` (default) keeps the consecutive-only behavior.
+    #[serde(default)]
+    pub failure_window: Option<u32>,
+
+    /// Stop with `loop_stale` once the same topic and payload is published
+    /// more than this many times in a row. Defaults to `2`, so the third
+    /// identical event stops the loop. Must be at least 1.
+    #[serde(default = "default_max_same_topic")]
+    pub max_same_topic: u32,
+
+    /// Stop with `loop_thrashing` once the same cycle of hat activations
+    /// (e.g. A→B→A→B) repeats this many times in a row without any new event
+    /// topic appearing. `None` (default) disables cycle detection.
+    #[serde(default)]
+    pub max_cycle_repeats: Option<u32>,
+
+    /// Number of most recent (hat, topic) activations searched for a
+    /// repeating cycle when `max_cycle_repeats` is set.
+    #[serde(default = "default_cycle_window")]
+    pub cycle_window: u32,
+
     /// Delay in seconds before starting the next iteration.
     /// Skipped when the next iteration is triggered by a human event.
     #[serde(default)]
@@ -935,12 +1016,32 @@ pub struct EventLoopConfig {
     #[serde(default)]
     pub required_events: Vec<String>,
 
+    /// Consecutive verified completion events needed before the loop
+    /// terminates. `1` (default) terminates on the first verified completion;
+    /// higher values make the agent re-confirm on following iterations.
+    #[serde(default = "default_completion_confirmations_required")]
+    pub completion_confirmations_required: u32,
+
+    /// When true (default), a hat that finishes an iteration without
+    /// publishing an event is re-prompted once with its expected topics
+    /// before fallback recovery kicks in.
+    #[serde(default = "default_true")]
+    pub retry_missing_event: bool,
+
     /// Event topic that triggers graceful early termination WITHOUT chain validation.
     /// Use this for human rejection, timeout escalation, or other abort paths.
     /// Defaults to "" (disabled). Set to "loop.cancel" to enable.
     #[serde(default)]
     pub cancellation_promise: String,
 
+    /// Event topic that stops the loop successfully once observed, for running
+    /// a staged workflow up to a milestone (e.g. `spec.approved`).
+    ///
+    /// Unlike `completion_promise`, no chain validation is applied. Set by
+    /// `ralph run --until-event`; iteration and runtime limits still apply.
+    #[serde(default)]
+    pub until_event: Option<String>,
+
     /// When true, events emitted by a hat are validated against its declared
     /// `publishes` list. Out-of-scope events are dropped and replaced with
     /// `{hat_id}.scope_violation` diagnostic events. Defaults to false (permissive).
@@ -953,6 +1054,24 @@ pub struct EventLoopConfig {
     /// (e.g. Sonnet 4.x `[1m]` users: `1_000_000`).
     #[serde(default)]
     pub context_window_tokens: Option<u64>,
+
+    /// Approximate token budget (~4 chars per token) for the coordinator prompt.
+    /// When exceeded, injected memories and then the oldest pending events are
+    /// trimmed to fit. `None` (default) disables trimming.
+    #[serde(default)]
+    pub context_budget: Option<usize>,
+
+    /// Maximum event payload size in bytes embedded in prompts. Longer payloads
+    /// are truncated with a marker; `task.start`/`task.resume` prompts are kept
+    /// whole. `0` disables truncation.
+    #[serde(default = "default_max_event_payload_bytes")]
+    pub max_event_payload_bytes: usize,
+
+    /// Number of most recent logged events injected into each prompt as a
+    /// `<recent-events>` section, giving the active hat short-term continuity.
+    /// `0` disables the section.
+    #[serde(default = "default_recent_events_in_prompt")]
+    pub recent_events_in_prompt: usize,
 }
 
 /// Resolves the context-window ceiling in tokens for this run.
@@ -995,10 +1114,30 @@ fn default_max_runtime() -> u64 {
     14400 // 4 hours
 }
 
+fn default_max_event_payload_bytes() -> usize {
+    32 * 1024
+}
+
+fn default_recent_events_in_prompt() -> usize {
+    5
+}
+
 fn default_max_failures() -> u32 {
     5
 }
 
+fn default_max_same_topic() -> u32 {
+    2
+}
+
+fn default_cycle_window() -> u32 {
+    12
+}
+
+fn default_completion_confirmations_required() -> u32 {
+    1
+}
+
 impl Default for EventLoopConfig {
     fn default() -> Self {
         Self {
@@ -1009,15 +1148,25 @@ impl Default for EventLoopConfig {
             max_runtime_seconds: default_max_runtime(),
             max_cost_usd: None,
             max_consecutive_failures: default_max_failures(),
+            failure_window: None,
+            max_same_topic: default_max_same_topic(),
+            max_cycle_repeats: None,
+            cycle_window: default_cycle_window(),
             cooldown_delay_seconds: 0,
             starting_hat: None,
             starting_event: None,
             mutation_score_warn_threshold: None,
             persistent: false,
             required_events: Vec::new(),
+            completion_confirmations_required: default_completion_confirmations_required(),
+            retry_missing_event: true,
             cancellation_promise: String::new(),
+            until_event: None,
             enforce_hat_scope: false,
             context_window_tokens: None,
+            context_budget: None,
+            max_event_payload_bytes: default_max_event_payload_bytes(),
+            recent_events_in_prompt: default_recent_events_in_prompt(),
         }
     }
 }
@@ -1042,6 +1191,18 @@ pub struct CoreConfig {
     #[serde(default = "default_guardrails")]
     pub guardrails: Vec<String>,
 
+    /// Warn when the resolved prompt is longer than this many characters.
+    ///
+    /// Roughly four characters per token; `0` disables the warning.
+    #[serde(default = "default_prompt_warning_chars")]
+    pub prompt_warning_chars: usize,
+
+    /// How much `RALPH_DIAGNOSTICS=1` sessions record.
+    ///
+    /// `RALPH_DIAGNOSTICS_LEVEL` overrides this when set.
+    #[serde(default)]
+    pub diagnostics_level: crate::diagnostics::DiagnosticsLevel,
+
     /// Root directory for workspace-relative paths (.ralph/, specs, etc.).
     ///
     /// All relative paths (scratchpad, specs_dir, memories) are resolved relative
@@ -1056,6 +1217,10 @@ fn default_specs_dir() -> String {
     ".ralph/specs/".to_string()
 }
 
+fn default_prompt_warning_chars() -> usize {
+    100_000
+}
+
 fn default_guardrails() -> Vec<String> {
     vec![
         "Fresh context each iteration - scratchpad is memory".to_string(),
@@ -1073,6 +1238,8 @@ impl Default for CoreConfig {
             scratchpad: ScratchpadConfig::default(),
             specs_dir: default_specs_dir(),
             guardrails: default_guardrails(),
+            prompt_warning_chars: default_prompt_warning_chars(),
+            diagnostics_level: crate::diagnostics::DiagnosticsLevel::default(),
             workspace_root: std::env::var("RALPH_WORKSPACE_ROOT")
                 .map(std::path::PathBuf::from)
                 .unwrap_or_else(|_| {
@@ -1141,6 +1308,11 @@ pub struct CliConfig {
     /// If None, defaults to "-p" for arg mode.
     #[serde(default)]
     pub prompt_flag: Option<String>,
+
+    /// Extra environment variables set on every backend process.
+    /// `ralph run --env-file` adds entries for keys not already in the environment.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub env: BTreeMap<String, String>,
 }
 
 fn default_backend() -> String {
@@ -1169,6 +1341,7 @@ impl Default for CliConfig {
             idle_timeout_secs: default_idle_timeout(),
             args: Vec::new(),
             prompt_flag: None,
+            env: BTreeMap::new(),
         }
     }
 }
@@ -1669,6 +1842,8 @@ pub struct PreflightConfig {
 ///     enabled: false      # Opt-in: run preflight checks before `ralph run`
 ///     strict: false       # Treat warnings as failures
 ///     skip: ["telegram"]  # Skip specific checks by name
+///   backpressure_command: "cargo test && cargo clippy"  # Verify build.done lacking evidence
+///   completion_commands: ["git push origin HEAD"]       # Run after a successful completion
 ///   loop_naming:
 ///     format: human-readable  # or "timestamp" for legacy format
 ///     max_length: 50
@@ -1701,6 +1876,32 @@ pub struct FeaturesConfig {
     /// Preflight check configuration.
     #[serde(default)]
     pub preflight: PreflightConfig,
+
+    /// Shell command that verifies the build (e.g., `cargo test && cargo clippy`).
+    ///
+    /// When a `build.done` event arrives without backpressure evidence, Ralph
+    /// runs this command in the workspace root. If it exits successfully,
+    /// `backpressure_command: pass` is recorded in the payload and the event
+    /// is accepted instead of being converted to `build.blocked`.
+    #[serde(default)]
+    pub backpressure_command: Option<String>,
+
+    /// Seconds to wait for `backpressure_command` before killing it and
+    /// treating the build as unverified.
+    #[serde(default = "default_backpressure_command_timeout_secs")]
+    pub backpressure_command_timeout_secs: u64,
+
+    /// Shell commands run in the workspace root after the loop completes via
+    /// its completion promise (e.g., push the branch, post a summary).
+    ///
+    /// Commands run in order. A failing command is logged and does not stop
+    /// the remaining commands or change the loop's exit status.
+    #[serde(default)]
+    pub completion_commands: Vec<String>,
+}
+
+fn default_backpressure_command_timeout_secs() -> u64 {
+    600
 }
 
 impl Default for FeaturesConfig {
@@ -1710,6 +1911,9 @@ impl Default for FeaturesConfig {
             auto_merge: false, // Auto-merge disabled by default for safety
             loop_naming: crate::loop_name::LoopNamingConfig::default(),
             preflight: PreflightConfig::default(),
+            backpressure_command: None,
+            backpressure_command_timeout_secs: default_backpressure_command_timeout_secs(),
+            completion_commands: Vec::new(),
         }
     }
 }
@@ -1850,6 +2054,11 @@ pub struct HatConfig {
     /// Used in the HATS table to help Ralph understand when to delegate to this hat.
     pub description: Option<String>,
 
+    /// Emoji shown next to the hat in iteration separators and wave headers.
+    /// When unset, a leading emoji in `name` (e.g. `"🔍 Reviewer"`) is used.
+    #[serde(default)]
+    pub emoji: Option<String>,
+
     /// Events that trigger this hat to be worn.
     /// Per spec: "Hats define triggers — which events cause Ralph to wear this hat."
     #[serde(default)]
@@ -1902,6 +2111,13 @@ pub struct HatConfig {
     /// instead of activating the hat again.
     pub max_activations: Option<u32>,
 
+    /// Minimum seconds between activations of this hat.
+    ///
+    /// Events that would re-activate the hat sooner stay queued until the
+    /// cooldown has elapsed.
+    #[serde(default)]
+    pub cooldown_secs: Option<u64>,
+
     /// Per-hat scratchpad override. If None, inherits from core.scratchpad.
     /// Accepts both a plain string shorthand and a structured object.
     #[serde(default, deserialize_with = "deserialize_optional_scratchpad_config")]
@@ -1971,6 +2187,19 @@ impl HatConfig {
     pub fn publish_topics(&self) -> Vec<Topic> {
         self.publishes.iter().map(|s| Topic::new(s)).collect()
     }
+
+    /// Returns the emoji to display for this hat: the explicit `emoji` field,
+    /// or the symbol leading `name` when it has one (`"🔍 Reviewer"` → `🔍`).
+    pub fn display_emoji(&self) -> Option<&str> {
+        if let Some(emoji) = self.emoji.as_deref().map(str::trim)
+            && !emoji.is_empty()
+        {
+            return Some(emoji);
+        }
+        let (first, rest) = self.name.trim_start().split_once(char::is_whitespace)?;
+        let is_symbol = !first.is_ascii() && !first.chars().any(char::is_alphanumeric);
+        (is_symbol && !rest.trim().is_empty()).then_some(first)
+    }
 }
 
 /// RObot communication channel.
@@ -2150,6 +2379,27 @@ pub enum ConfigError {
     #[error("Invalid completion_promise: must be non-empty and non-whitespace")]
     InvalidCompletionPromise,
 
+    #[error(
+        "Invalid completion_confirmations_required: must be >= 1.\nFix: set 'event_loop.completion_confirmations_required' to 1 or higher."
+    )]
+    InvalidCompletionConfirmations,
+
+    #[error(
+        "Invalid failure_window: {window} iterations can never hold {max_failures} failures.\nFix: set 'event_loop.failure_window' to at least 'event_loop.max_consecutive_failures' ({max_failures}), or remove it."
+    )]
+    InvalidFailureWindow { window: u32, max_failures: u32 },
+
+    #[error(
+        "Invalid cycle detection: max_cycle_repeats {repeats} needs a cycle_window of at least {min} activations (got {window}).\nFix: set 'event_loop.max_cycle_repeats' to 2 or more and 'event_loop.cycle_window' to at least twice that, or remove 'max_cycle_repeats'.",
+        min = repeats.saturating_mul(2).max(4)
+    )]
+    InvalidCycleDetection { repeats: u32, window: u32 },
+
+    #[error(
+        "Invalid max_same_topic: 0 would stop the loop on the first event.\nFix: set 'event_loop.max_same_topic' (or `ralph run --max-same-topic`) to 1 or more."
+    )]
+    InvalidMaxSameTopic,
+
     #[error(
         "Custom backend requires a command.\nFix: set 'cli.command' in your config (or run `ralph init --backend custom`).\nSee: docs/reference/troubleshooting.md#custom-backend-command"
     )]
@@ -2195,6 +2445,11 @@ pub enum ConfigError {
     )]
     InvalidConcurrency { hat: String, value: u32 },
 
+    #[error(
+        "Hat '{hat}' publishes topics it also triggers on ({topics}) with no 'max_activations' or 'cooldown_secs' guard, creating an unbounded self-loop.\nFix: publish a different topic, or set 'max_activations' or 'cooldown_secs' on the hat."
+    )]
+    UnguardedSelfTrigger { hat: String, topics: String },
+
     #[error(
         "Hat '{hat}' has both 'aggregate' and 'concurrency > 1'. An aggregator hat cannot also be a concurrent worker.\nFix: remove 'aggregate' or set 'concurrency' to 1."
     )]
@@ -2595,6 +2850,49 @@ hats:
         );
     }
 
+    #[test]
+    fn test_unguarded_self_trigger_rejected() {
+        let yaml = r#"
+hats:
+  looper:
+    name: "Looper"
+    description: "Test hat"
+    triggers: ["work.*"]
+    publishes: ["work.again", "work.done.final"]
+"#;
+        let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+        let err = config.validate().unwrap_err();
+
+        assert!(
+            matches!(&err, ConfigError::UnguardedSelfTrigger { hat, topics }
+                if hat == "looper" && topics == "work.again"),
+            "Expected UnguardedSelfTrigger error, got: {:?}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_guarded_self_trigger_warns() {
+        let yaml = r#"
+hats:
+  looper:
+    name: "Looper"
+    description: "Test hat"
+    triggers: ["work.again"]
+    publishes: ["work.again"]
+    max_activations: 3
+"#;
+        let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+        let warnings = config.validate().unwrap();
+
+        assert!(
+            warnings.iter().any(|w| matches!(w,
+                ConfigWarning::InvalidValue { field, .. } if field == "hats.looper.publishes")),
+            "Expected self-trigger warning, got: {:?}",
+            warnings
+        );
+    }
+
     #[test]
     fn test_reserved_trigger_task_resume_rejected() {
         // Per design: task.resume is reserved for Ralph (the coordinator)
@@ -2668,6 +2966,7 @@ hats:
         assert_eq!(config.core.scratchpad.path, ".ralph/agent/scratchpad.md");
         assert!(config.core.scratchpad.enabled);
         assert_eq!(config.core.specs_dir, ".ralph/specs/");
+        assert_eq!(config.core.prompt_warning_chars, 100_000);
         // Default guardrails per spec
         assert_eq!(config.core.guardrails.len(), 6);
         assert!(config.core.guardrails[0].contains("Fresh context"));
@@ -2684,8 +2983,15 @@ hats:
 core:
   scratchpad: ".workspace/plan.md"
   specs_dir: "./specifications/"
+  prompt_warning_chars: 20000
+  diagnostics_level: orchestration
 "#;
         let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(config.core.prompt_warning_chars, 20_000);
+        assert_eq!(
+            config.core.diagnostics_level,
+            crate::diagnostics::DiagnosticsLevel::Orchestration
+        );
         assert_eq!(config.core.scratchpad.path, ".workspace/plan.md");
         assert!(config.core.scratchpad.enabled);
         assert_eq!(config.core.specs_dir, "./specifications/");
@@ -2785,6 +3091,81 @@ event_loop:
         );
     }
 
+    #[test]
+    fn test_zero_completion_confirmations_rejected() {
+        let mut config = RalphConfig::default();
+        assert_eq!(config.event_loop.completion_confirmations_required, 1);
+
+        config.event_loop.completion_confirmations_required = 0;
+        let err = config.validate().unwrap_err();
+        assert!(
+            matches!(&err, ConfigError::InvalidCompletionConfirmations),
+            "Expected InvalidCompletionConfirmations error, got: {:?}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_failure_window_smaller_than_max_failures_rejected() {
+        let mut config = RalphConfig::default();
+        assert!(config.event_loop.failure_window.is_none());
+
+        config.event_loop.max_consecutive_failures = 3;
+        config.event_loop.failure_window = Some(2);
+        let err = config.validate().unwrap_err();
+        assert!(
+            matches!(
+                &err,
+                ConfigError::InvalidFailureWindow {
+                    window: 2,
+                    max_failures: 3
+                }
+            ),
+            "Expected InvalidFailureWindow error, got: {:?}",
+            err
+        );
+
+        config.event_loop.failure_window = Some(10);
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_max_same_topic_zero_rejected() {
+        let mut config = RalphConfig::default();
+        config.event_loop.max_same_topic = 0;
+        assert!(matches!(
+            config.validate(),
+            Err(ConfigError::InvalidMaxSameTopic)
+        ));
+    }
+
+    #[test]
+    fn test_cycle_detection_needs_room_for_repeats() {
+        let mut config = RalphConfig::default();
+        config.event_loop.max_cycle_repeats = Some(3);
+        config.event_loop.cycle_window = 5;
+
+        let err = config.validate().unwrap_err();
+        assert!(
+            matches!(
+                &err,
+                ConfigError::InvalidCycleDetection {
+                    repeats: 3,
+                    window: 5
+                }
+            ),
+            "Expected InvalidCycleDetection error, got: {:?}",
+            err
+        );
+
+        config.event_loop.max_cycle_repeats = Some(1);
+        config.event_loop.cycle_window = 12;
+        assert!(config.validate().is_err());
+
+        config.event_loop.max_cycle_repeats = Some(3);
+        assert!(config.validate().is_ok());
+    }
+
     #[test]
     fn test_custom_backend_with_empty_command_errors() {
         // Custom backend with empty command should error
@@ -3059,6 +3440,27 @@ instructions: "Do work"
         assert!(hat.default_publishes.is_none());
     }
 
+    #[test]
+    fn test_hat_config_display_emoji() {
+        let hat: HatConfig = serde_yaml::from_str(r#"name: "🛡️ Security Reviewer""#).unwrap();
+        assert!(hat.emoji.is_none());
+        assert_eq!(hat.display_emoji(), Some("🛡️"));
+
+        let hat: HatConfig = serde_yaml::from_str(
+            r#"
+name: "🛡️ Security Reviewer"
+emoji: "🔒"
+"#,
+        )
+        .unwrap();
+        assert_eq!(hat.display_emoji(), Some("🔒"));
+
+        for name in ["Security Reviewer", "security_reviewer", "🛡️", "- Reviewer"] {
+            let hat: HatConfig = serde_yaml::from_str(&format!("name: '{name}'")).unwrap();
+            assert_eq!(hat.display_emoji(), None, "name {name:?}");
+        }
+    }
+
     #[test]
     fn test_mixed_backends_config() {
         let yaml = r#"
@@ -3164,6 +3566,44 @@ features:
         );
     }
 
+    #[test]
+    fn test_features_config_backpressure_command_from_yaml() {
+        let yaml = r#"
+features:
+  backpressure_command: "cargo test && cargo clippy"
+"#;
+        let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(
+            config.features.backpressure_command.as_deref(),
+            Some("cargo test && cargo clippy")
+        );
+        assert!(
+            RalphConfig::default()
+                .features
+                .backpressure_command
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_features_config_completion_commands_from_yaml() {
+        let yaml = r#"
+features:
+  completion_commands: ["git push origin HEAD", "./notify.sh"]
+"#;
+        let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(
+            config.features.completion_commands,
+            vec!["git push origin HEAD", "./notify.sh"]
+        );
+        assert!(
+            RalphConfig::default()
+                .features
+                .completion_commands
+                .is_empty()
+        );
+    }
+
     #[test]
     fn test_features_config_auto_merge_false_from_yaml() {
         // Explicit false should work too
@@ -3,7 +3,9 @@
 #[cfg(test)]
 mod tests {
     use crate::config::RalphConfig;
-    use crate::diagnostics::{DiagnosticsCollector, HookDisposition, HookRunTelemetryEntry};
+    use crate::diagnostics::{
+        DiagnosticsCollector, HookDisposition, HookRunTelemetryEntry, LATEST_SESSION_LINK,
+    };
     use crate::event_loop::EventLoop;
     use crate::hooks::{HookRunResult, HookStreamOutput, HookSuspendMode};
     use chrono::{TimeZone, Utc};
@@ -64,6 +66,7 @@ mod tests {
         let session_dirs: Vec<_> = std::fs::read_dir(&diagnostics_dir)
             .unwrap()
             .filter_map(|e| e.ok())
+            .filter(|e| e.file_name() != LATEST_SESSION_LINK)
             .collect();
 
         assert_eq!(
@@ -107,6 +110,7 @@ mod tests {
         let session_dirs: Vec<_> = std::fs::read_dir(&diagnostics_dir)
             .unwrap()
             .filter_map(|e| e.ok())
+            .filter(|e| e.file_name() != LATEST_SESSION_LINK)
             .collect();
         let session_dir = session_dirs[0].path();
         let orchestration_file = session_dir.join("orchestration.jsonl");
@@ -124,6 +128,50 @@ mod tests {
         assert!(has_hat_selected, "Should log hat_selected event");
     }
 
+    #[test]
+    fn test_event_loop_logs_hat_exhausted() {
+        let temp_dir = TempDir::new().unwrap();
+
+        let yaml = r#"
+hats:
+  code_reviewer:
+    name: "Code Reviewer"
+    triggers: ["implementation.done"]
+    publishes: ["review.done"]
+    max_activations: 1
+"#;
+        let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+        let diagnostics = DiagnosticsCollector::with_enabled(temp_dir.path(), true).unwrap();
+        let mut event_loop = EventLoop::with_diagnostics(config, diagnostics);
+        let ralph = ralph_proto::HatId::new("ralph");
+
+        for _ in 0..2 {
+            event_loop
+                .bus()
+                .publish(ralph_proto::Event::new("implementation.done", "done"));
+            let _ = event_loop.build_prompt(&ralph).unwrap();
+        }
+
+        let diagnostics_dir = temp_dir.path().join(".ralph").join("diagnostics");
+        let session_dirs: Vec<_> = std::fs::read_dir(&diagnostics_dir)
+            .unwrap()
+            .filter_map(|e| e.ok())
+            .filter(|e| e.file_name() != LATEST_SESSION_LINK)
+            .collect();
+        let orchestration_file = session_dirs[0].path().join("orchestration.jsonl");
+        let content = std::fs::read_to_string(orchestration_file).unwrap();
+
+        let exhausted: Vec<serde_json::Value> = content
+            .lines()
+            .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+            .filter(|entry| entry["event"]["type"] == "hat_exhausted")
+            .collect();
+        assert_eq!(exhausted.len(), 1, "exhaustion is logged once");
+        assert_eq!(exhausted[0]["event"]["hat"], "code_reviewer");
+        assert_eq!(exhausted[0]["event"]["max_activations"], 1);
+        assert_eq!(exhausted[0]["event"]["activations"], 1);
+    }
+
     /// Helper to write an event to a JSONL file for testing.
     fn write_event_to_jsonl(path: &std::path::Path, topic: &str, payload: &str) {
         use std::io::Write;
@@ -160,6 +208,7 @@ mod tests {
         let session_dirs: Vec<_> = std::fs::read_dir(&diagnostics_dir)
             .unwrap()
             .filter_map(|e| e.ok())
+            .filter(|e| e.file_name() != LATEST_SESSION_LINK)
             .collect();
         let session_dir = session_dirs[0].path();
         let orchestration_file = session_dir.join("orchestration.jsonl");
@@ -197,6 +246,7 @@ mod tests {
         let session_dirs: Vec<_> = std::fs::read_dir(&diagnostics_dir)
             .unwrap()
             .filter_map(|e| e.ok())
+            .filter(|e| e.file_name() != LATEST_SESSION_LINK)
             .collect();
         let session_dir = session_dirs[0].path();
         let orchestration_file = session_dir.join("orchestration.jsonl");
@@ -248,6 +298,7 @@ mod tests {
         let session_dirs: Vec<_> = std::fs::read_dir(&diagnostics_dir)
             .unwrap()
             .filter_map(|e| e.ok())
+            .filter(|e| e.file_name() != LATEST_SESSION_LINK)
             .collect();
         let session_dir = session_dirs[0].path();
         let orchestration_file = session_dir.join("orchestration.jsonl");
@@ -2,6 +2,9 @@
 //!
 //! Captures agent output, orchestration decisions, traces, performance metrics,
 //! and errors to structured JSONL files when `RALPH_DIAGNOSTICS=1` is set.
+//!
+//! `RALPH_DIAGNOSTICS_LEVEL` (or `core.diagnostics_level`) trims the chattier
+//! streams on long runs; orchestration events are recorded at every level.
 
 mod agent_output;
 mod errors;
@@ -25,9 +28,104 @@ pub use stream_handler::DiagnosticStreamHandler;
 pub use trace_layer::{DiagnosticTraceLayer, TraceEntry};
 
 use chrono::Local;
+use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
+use tracing::warn;
+
+/// Symlink under `.ralph/diagnostics/` pointing at the most recent session.
+pub const LATEST_SESSION_LINK: &str = "latest";
+
+/// Fallback pointer file holding the latest session's directory name, written
+/// where symlinks are unavailable.
+pub const LATEST_SESSION_POINTER: &str = "latest.txt";
+
+/// Resolves the most recent diagnostics session directory under `base_path`.
+///
+/// Follows the `latest` symlink, falling back to `latest.txt`. Returns `None`
+/// when neither points at an existing session.
+pub fn latest_session_dir(base_path: &Path) -> Option<PathBuf> {
+    let diagnostics_dir = base_path.join(".ralph").join("diagnostics");
+
+    let link = diagnostics_dir.join(LATEST_SESSION_LINK);
+    if let Ok(target) = fs::canonicalize(&link)
+        && target.is_dir()
+    {
+        return Some(target);
+    }
+
+    let name = fs::read_to_string(diagnostics_dir.join(LATEST_SESSION_POINTER)).ok()?;
+    let session_dir = diagnostics_dir.join(name.trim());
+    session_dir.is_dir().then_some(session_dir)
+}
+
+/// Points `latest` at `session_dir`, using `latest.txt` if symlinking fails.
+fn update_latest_pointer(diagnostics_dir: &Path, session_dir: &Path) -> std::io::Result<()> {
+    let Some(name) = session_dir.file_name() else {
+        return Ok(());
+    };
+
+    #[cfg(unix)]
+    {
+        let link = diagnostics_dir.join(LATEST_SESSION_LINK);
+        match fs::remove_file(&link) {
+            Ok(()) => {}
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => return Err(e),
+        }
+        // Relative target so the link survives moving the workspace
+        if std::os::unix::fs::symlink(name, &link).is_ok() {
+            let _ = fs::remove_file(diagnostics_dir.join(LATEST_SESSION_POINTER));
+            return Ok(());
+        }
+    }
+
+    fs::write(
+        diagnostics_dir.join(LATEST_SESSION_POINTER),
+        name.to_string_lossy().as_bytes(),
+    )
+}
+
+/// How much a diagnostics session records.
+///
+/// Levels are cumulative: each one records everything the previous one does.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DiagnosticsLevel {
+    /// Orchestration events, errors, and hook runs only.
+    Orchestration,
+    /// Adds performance metrics and the per-iteration prompt log.
+    Standard,
+    /// Adds agent output and the tracing log (the high-volume streams).
+    #[default]
+    Full,
+}
+
+impl DiagnosticsLevel {
+    /// Parses a level name, case-insensitively.
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "orchestration" => Some(Self::Orchestration),
+            "standard" => Some(Self::Standard),
+            "full" => Some(Self::Full),
+            _ => None,
+        }
+    }
+
+    /// Reads `RALPH_DIAGNOSTICS_LEVEL`, warning about unrecognized values.
+    pub fn from_env() -> Option<Self> {
+        let value = std::env::var("RALPH_DIAGNOSTICS_LEVEL").ok()?;
+        let level = Self::parse(&value);
+        if level.is_none() {
+            warn!(
+                "Ignoring RALPH_DIAGNOSTICS_LEVEL={:?}; expected orchestration, standard, or full",
+                value
+            );
+        }
+        level
+    }
+}
 
 /// Central coordinator for diagnostic logging.
 ///
@@ -35,6 +133,7 @@ use std::sync::{Arc, Mutex};
 /// session directory if enabled.
 pub struct DiagnosticsCollector {
     enabled: bool,
+    level: DiagnosticsLevel,
     session_dir: Option<PathBuf>,
     orchestration_logger: Option<Arc<Mutex<orchestration::OrchestrationLogger>>>,
     performance_logger: Option<Arc<Mutex<performance::PerformanceLogger>>>,
@@ -47,32 +146,56 @@ impl DiagnosticsCollector {
     ///
     /// If `RALPH_DIAGNOSTICS=1`, creates `.ralph/diagnostics/<timestamp>/` directory.
     pub fn new(base_path: &Path) -> std::io::Result<Self> {
+        Self::with_level(base_path, DiagnosticsLevel::default())
+    }
+
+    /// Creates a diagnostics collector recording at the configured level.
+    ///
+    /// `RALPH_DIAGNOSTICS_LEVEL` takes precedence over `configured`.
+    pub fn with_level(base_path: &Path, configured: DiagnosticsLevel) -> std::io::Result<Self> {
         let enabled = std::env::var("RALPH_DIAGNOSTICS")
             .map(|v| v == "1")
             .unwrap_or(false);
+        let level = DiagnosticsLevel::from_env().unwrap_or(configured);
 
-        Self::with_enabled(base_path, enabled)
+        Self::with_enabled_and_level(base_path, enabled, level)
     }
 
     /// Creates a diagnostics collector with explicit enabled flag (for testing).
     pub fn with_enabled(base_path: &Path, enabled: bool) -> std::io::Result<Self> {
+        Self::with_enabled_and_level(base_path, enabled, DiagnosticsLevel::Full)
+    }
+
+    /// Creates a diagnostics collector with explicit enabled flag and level.
+    pub fn with_enabled_and_level(
+        base_path: &Path,
+        enabled: bool,
+        level: DiagnosticsLevel,
+    ) -> std::io::Result<Self> {
         let (session_dir, orchestration_logger, performance_logger, error_logger, hook_run_logger) =
             if enabled {
                 let timestamp = Local::now().format("%Y-%m-%dT%H-%M-%S");
-                let dir = base_path
-                    .join(".ralph")
-                    .join("diagnostics")
-                    .join(timestamp.to_string());
+                let diagnostics_dir = base_path.join(".ralph").join("diagnostics");
+                let dir = diagnostics_dir.join(timestamp.to_string());
                 fs::create_dir_all(&dir)?;
+                if let Err(e) = update_latest_pointer(&diagnostics_dir, &dir) {
+                    warn!("Failed to update latest diagnostics pointer: {}", e);
+                }
 
                 let orch_logger = orchestration::OrchestrationLogger::new(&dir)?;
-                let perf_logger = performance::PerformanceLogger::new(&dir)?;
+                let perf_logger = if level >= DiagnosticsLevel::Standard {
+                    Some(Arc::new(Mutex::new(performance::PerformanceLogger::new(
+                        &dir,
+                    )?)))
+                } else {
+                    None
+                };
                 let err_logger = errors::ErrorLogger::new(&dir)?;
                 let hook_logger = hook_runs::HookRunLogger::new(&dir)?;
                 (
                     Some(dir),
                     Some(Arc::new(Mutex::new(orch_logger))),
-                    Some(Arc::new(Mutex::new(perf_logger))),
+                    perf_logger,
                     Some(Arc::new(Mutex::new(err_logger))),
                     Some(Arc::new(Mutex::new(hook_logger))),
                 )
@@ -82,6 +205,7 @@ impl DiagnosticsCollector {
 
         Ok(Self {
             enabled,
+            level,
             session_dir,
             orchestration_logger,
             performance_logger,
@@ -94,6 +218,7 @@ impl DiagnosticsCollector {
     pub fn disabled() -> Self {
         Self {
             enabled: false,
+            level: DiagnosticsLevel::default(),
             session_dir: None,
             orchestration_logger: None,
             performance_logger: None,
@@ -107,6 +232,16 @@ impl DiagnosticsCollector {
         self.enabled
     }
 
+    /// Returns the level this collector records at.
+    pub fn level(&self) -> DiagnosticsLevel {
+        self.level
+    }
+
+    /// Returns whether the tracing log (`trace.jsonl`) should be recorded.
+    pub fn records_traces(&self) -> bool {
+        self.enabled && self.level >= DiagnosticsLevel::Full
+    }
+
     /// Returns the session directory if diagnostics are enabled.
     pub fn session_dir(&self) -> Option<&Path> {
         self.session_dir.as_deref()
@@ -114,9 +249,12 @@ impl DiagnosticsCollector {
 
     /// Wraps a stream handler with diagnostic logging.
     ///
-    /// Returns the original handler if diagnostics are disabled.
+    /// Returns the original handler if diagnostics are disabled or the level
+    /// excludes agent output.
     pub fn wrap_stream_handler<H>(&self, handler: H) -> Result<DiagnosticStreamHandler<H>, H> {
-        if let Some(session_dir) = &self.session_dir {
+        if let Some(session_dir) = &self.session_dir
+            && self.level >= DiagnosticsLevel::Full
+        {
             match AgentOutputLogger::new(session_dir) {
                 Ok(logger) => {
                     let logger = Arc::new(Mutex::new(logger));
@@ -142,7 +280,7 @@ impl DiagnosticsCollector {
 
     /// Logs a performance metric.
     ///
-    /// Does nothing if diagnostics are disabled.
+    /// Does nothing if diagnostics are disabled or below the standard level.
     pub fn log_performance(&self, iteration: u32, hat: &str, metric: PerformanceMetric) {
         if let Some(logger) = &self.performance_logger
             && let Ok(mut logger) = logger.lock()
@@ -176,9 +314,11 @@ impl DiagnosticsCollector {
 
     /// Logs the full prompt for an iteration to `prompt-log.md`.
     ///
-    /// Does nothing if diagnostics are disabled.
+    /// Does nothing if diagnostics are disabled or below the standard level.
     pub fn log_prompt(&self, iteration: u32, hat: &str, prompt: &str) {
-        if let Some(session_dir) = &self.session_dir {
+        if let Some(session_dir) = &self.session_dir
+            && self.level >= DiagnosticsLevel::Standard
+        {
             use std::io::Write;
             let path = session_dir.join("prompt-log.md");
             if let Ok(mut file) = fs::OpenOptions::new().create(true).append(true).open(&path) {
@@ -318,4 +458,123 @@ mod tests {
             assert!(parsed.get("context").is_some());
         }
     }
+
+    #[test]
+    fn test_diagnostics_level_parse() {
+        assert_eq!(
+            DiagnosticsLevel::parse("Orchestration"),
+            Some(DiagnosticsLevel::Orchestration)
+        );
+        assert_eq!(
+            DiagnosticsLevel::parse(" standard "),
+            Some(DiagnosticsLevel::Standard)
+        );
+        assert_eq!(
+            DiagnosticsLevel::parse("full"),
+            Some(DiagnosticsLevel::Full)
+        );
+        assert_eq!(DiagnosticsLevel::parse("tokens"), None);
+    }
+
+    #[test]
+    fn test_orchestration_level_keeps_only_orchestration_streams() {
+        let temp = TempDir::new().unwrap();
+        let collector = DiagnosticsCollector::with_enabled_and_level(
+            temp.path(),
+            true,
+            DiagnosticsLevel::Orchestration,
+        )
+        .unwrap();
+        let session_dir = collector.session_dir().unwrap().to_path_buf();
+
+        collector.log_orchestration(
+            1,
+            "ralph",
+            OrchestrationEvent::HatSelected {
+                hat: "builder".to_string(),
+                reason: "pending events".to_string(),
+            },
+        );
+        collector.log_performance(
+            1,
+            "builder",
+            PerformanceMetric::AgentLatency { duration_ms: 800 },
+        );
+        collector.log_prompt(1, "builder", "prompt body");
+
+        let orchestration =
+            std::fs::read_to_string(session_dir.join("orchestration.jsonl")).unwrap();
+        assert_eq!(orchestration.lines().count(), 1);
+        assert!(!session_dir.join("performance.jsonl").exists());
+        assert!(!session_dir.join("prompt-log.md").exists());
+        assert!(!collector.records_traces());
+        assert!(collector.wrap_stream_handler(()).is_err());
+    }
+
+    #[test]
+    fn test_standard_level_skips_agent_output_and_traces() {
+        let temp = TempDir::new().unwrap();
+        let collector = DiagnosticsCollector::with_enabled_and_level(
+            temp.path(),
+            true,
+            DiagnosticsLevel::Standard,
+        )
+        .unwrap();
+
+        collector.log_prompt(1, "builder", "prompt body");
+
+        let session_dir = collector.session_dir().unwrap();
+        assert!(session_dir.join("prompt-log.md").exists());
+        assert!(!collector.records_traces());
+        assert!(collector.wrap_stream_handler(()).is_err());
+    }
+
+    #[test]
+    fn test_latest_points_at_session_dir() {
+        let temp = TempDir::new().unwrap();
+        assert!(latest_session_dir(temp.path()).is_none());
+
+        let collector = DiagnosticsCollector::with_enabled(temp.path(), true).unwrap();
+
+        let latest = latest_session_dir(temp.path()).expect("latest session");
+        assert_eq!(
+            latest,
+            fs::canonicalize(collector.session_dir().unwrap()).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_latest_pointer_file_fallback() {
+        let temp = TempDir::new().unwrap();
+        let diagnostics_dir = temp.path().join(".ralph/diagnostics");
+        fs::create_dir_all(diagnostics_dir.join("2024-01-21T08-49-56")).unwrap();
+        fs::write(
+            diagnostics_dir.join(LATEST_SESSION_POINTER),
+            "2024-01-21T08-49-56\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            latest_session_dir(temp.path()),
+            Some(diagnostics_dir.join("2024-01-21T08-49-56"))
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_latest_symlink_is_replaced() {
+        let temp = TempDir::new().unwrap();
+        let diagnostics_dir = temp.path().join(".ralph/diagnostics");
+        let older = diagnostics_dir.join("2024-01-21T08-49-56");
+        let newer = diagnostics_dir.join("2024-01-22T09-00-00");
+        fs::create_dir_all(&older).unwrap();
+        fs::create_dir_all(&newer).unwrap();
+
+        update_latest_pointer(&diagnostics_dir, &older).unwrap();
+        update_latest_pointer(&diagnostics_dir, &newer).unwrap();
+
+        let link = fs::read_link(diagnostics_dir.join(LATEST_SESSION_LINK)).unwrap();
+        assert_eq!(link, Path::new("2024-01-22T09-00-00"));
+        assert!(!diagnostics_dir.join(LATEST_SESSION_POINTER).exists());
+    }
 }
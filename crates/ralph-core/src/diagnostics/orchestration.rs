@@ -31,6 +31,11 @@ pub enum OrchestrationEvent {
     TaskAbandoned {
         reason: String,
     },
+    HatExhausted {
+        hat: String,
+        max_activations: u32,
+        activations: u32,
+    },
     WaveStarted {
         wave_id: String,
         expected_total: u32,
@@ -118,6 +123,11 @@ mod tests {
             OrchestrationEvent::TaskAbandoned {
                 reason: "max_iterations".to_string(),
             },
+            OrchestrationEvent::HatExhausted {
+                hat: "code_reviewer".to_string(),
+                max_activations: 3,
+                activations: 3,
+            },
             OrchestrationEvent::WaveStarted {
                 wave_id: "w-abc12345".to_string(),
                 expected_total: 3,
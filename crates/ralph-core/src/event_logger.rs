@@ -3,6 +3,7 @@
 //! Logs all events to `.ralph/events.jsonl` as specified in the event-loop spec.
 //! The observer pattern allows hooking into the event bus without modifying routing.
 
+use crate::event_reader::EVENT_SCHEMA_VERSION;
 use crate::loop_context::LoopContext;
 use crate::text::floor_char_boundary;
 use ralph_proto::{Event, HatId};
@@ -68,10 +69,15 @@ pub struct EventRecord {
     /// Event topic.
     pub topic: String,
 
-    /// Hat that will be triggered by this event.
+    /// Hat that will be triggered by this event (the primary, longest-match subscriber).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub triggered: Option<String>,
 
+    /// Every hat subscribed to this event's topic, when more than one hat may
+    /// react (e.g. several reviewers on `review.*`). Empty for older records.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub triggered_hats: Vec<String>,
+
     /// Event content (truncated if large). Defaults to empty string for agent events without payload.
     /// Accepts both string and object payloads - objects are serialized to JSON strings.
     #[serde(default, deserialize_with = "deserialize_flexible_payload")]
@@ -92,6 +98,10 @@ pub struct EventRecord {
     /// Total number of events in the wave.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub wave_total: Option<u32>,
+
+    /// Record schema version (1 when the line has no `schema` field).
+    #[serde(default = "crate::event_reader::default_event_schema")]
+    pub schema: u8,
 }
 
 impl EventRecord {
@@ -123,11 +133,29 @@ impl EventRecord {
             hat: hat.into(),
             topic: event.topic.to_string(),
             triggered: triggered.map(|h| h.to_string()),
+            triggered_hats: Vec::new(),
             payload,
             blocked_count: None,
             wave_id: event.wave_id.clone(),
             wave_index: event.wave_index,
             wave_total: event.wave_total,
+            schema: EVENT_SCHEMA_VERSION,
+        }
+    }
+
+    /// Records every hat subscribed to this event's topic.
+    pub fn with_triggered_hats<'a>(mut self, hats: impl IntoIterator<Item = &'a HatId>) -> Self {
+        self.triggered_hats = hats.into_iter().map(ToString::to_string).collect();
+        self
+    }
+
+    /// Returns all triggered hats, falling back to `triggered` for records
+    /// written before `triggered_hats` existed.
+    pub fn all_triggered(&self) -> Vec<&str> {
+        if self.triggered_hats.is_empty() {
+            self.triggered.as_deref().into_iter().collect()
+        } else {
+            self.triggered_hats.iter().map(String::as_str).collect()
         }
     }
 
@@ -595,6 +623,46 @@ mod tests {
         assert_eq!(record.topic, "build.done");
     }
 
+    #[test]
+    fn test_event_record_schema_written_and_defaulted() {
+        let event = make_event("build.done", "ok");
+        let record = EventRecord::new(1, "builder", &event, None);
+        let json = serde_json::to_string(&record).unwrap();
+        assert!(json.contains(&format!("\"schema\":{EVENT_SCHEMA_VERSION}")));
+
+        // Records written before schema versioning are treated as version 1
+        let legacy = r#"{"ts":"2024-01-15T10:00:00Z","topic":"build.done","payload":"ok"}"#;
+        let record: EventRecord = serde_json::from_str(legacy).unwrap();
+        assert_eq!(record.schema, 1);
+    }
+
+    #[test]
+    fn test_event_record_triggered_hats_roundtrip_and_fallback() {
+        let event = make_event("review.request", "pr");
+        let security = HatId::new("security");
+        let perf = HatId::new("perf");
+        let record = EventRecord::new(1, "builder", &event, Some(&perf))
+            .with_triggered_hats([&perf, &security]);
+        let json = serde_json::to_string(&record).unwrap();
+        assert!(json.contains(r#""triggered_hats":["perf","security"]"#));
+        let parsed: EventRecord = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.all_triggered(), vec!["perf", "security"]);
+
+        // Older records only carry the single `triggered` hat
+        let legacy = r#"{"ts":"2024-01-15T10:00:00Z","topic":"build.done","triggered":"reviewer","payload":"ok"}"#;
+        let record: EventRecord = serde_json::from_str(legacy).unwrap();
+        assert!(record.triggered_hats.is_empty());
+        assert_eq!(record.all_triggered(), vec!["reviewer"]);
+
+        let untriggered = EventRecord::new(1, "builder", &event, None);
+        assert!(
+            !serde_json::to_string(&untriggered)
+                .unwrap()
+                .contains("triggered_hats")
+        );
+        assert!(untriggered.all_triggered().is_empty());
+    }
+
     #[test]
     fn test_object_payload_from_ralph_emit_json() {
         // Test that `ralph emit --json` object payloads are parsed correctly
@@ -0,0 +1,182 @@
+//! Deferred `features.backpressure_command` checks.
+//!
+//! A `build.done` without backpressure evidence is held back while the
+//! configured command runs. The event loop only records the check; the
+//! orchestration loop runs it asynchronously so an interrupt can still stop
+//! the loop, then hands the outcome back via
+//! [`EventLoop::resolve_backpressure_check`](super::EventLoop::resolve_backpressure_check).
+
+use crate::text::truncate_with_ellipsis;
+use std::collections::VecDeque;
+use std::path::PathBuf;
+use std::process::{ExitStatus, Stdio};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncRead, BufReader};
+use tokio::sync::watch;
+use tokio::task::JoinHandle;
+
+/// Number of trailing output lines kept for a rejected `build.done`.
+const OUTPUT_TAIL_LINES: usize = 20;
+
+/// Longest output line kept, in characters.
+const OUTPUT_TAIL_LINE_CHARS: usize = 500;
+
+/// How long to wait for output still buffered in the pipes once the command ends.
+const OUTPUT_DRAIN_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// Last lines written to stdout or stderr, in arrival order.
+type OutputTail = Arc<Mutex<VecDeque<String>>>;
+
+/// A `build.done` waiting on `features.backpressure_command`.
+#[derive(Debug, Clone)]
+pub struct BackpressureCheck {
+    /// Original `build.done` payload.
+    pub payload: String,
+    /// Shell command to run.
+    pub command: String,
+    /// Time after which the command is killed.
+    pub timeout: Duration,
+    /// Directory the command runs in.
+    pub workspace_root: PathBuf,
+}
+
+/// Result of running a [`BackpressureCheck`].
+#[derive(Debug)]
+pub enum BackpressureCheckOutcome {
+    /// The command exited successfully.
+    Passed,
+    /// The command exited with a failure status.
+    Failed {
+        status: ExitStatus,
+        /// Last lines of the command's stdout and stderr.
+        output_tail: String,
+    },
+    /// The command was killed after exceeding its timeout.
+    TimedOut {
+        /// Last lines the command wrote before it was killed.
+        output_tail: String,
+    },
+    /// The loop was interrupted while the command was running.
+    Interrupted,
+    /// The command could not be started or waited on.
+    Error(String),
+}
+
+impl BackpressureCheck {
+    /// Runs the command, killing its process group on timeout or interrupt.
+    pub async fn run(&self, mut interrupt_rx: watch::Receiver<bool>) -> BackpressureCheckOutcome {
+        let mut cmd = tokio::process::Command::new("sh");
+        cmd.args(["-c", &self.command])
+            .current_dir(&self.workspace_root)
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true);
+
+        // Run in its own process group so a timeout kills the whole command tree
+        #[cfg(unix)]
+        cmd.process_group(0);
+
+        let mut child = match cmd.spawn() {
+            Ok(child) => child,
+            Err(e) => return BackpressureCheckOutcome::Error(e.to_string()),
+        };
+
+        let tail = OutputTail::default();
+        let readers: Vec<JoinHandle<()>> = [
+            child
+                .stdout
+                .take()
+                .map(|out| collect_tail(out, tail.clone())),
+            child
+                .stderr
+                .take()
+                .map(|err| collect_tail(err, tail.clone())),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+
+        let interrupted = async {
+            // A dropped sender means nobody can interrupt; wait for the command instead
+            if interrupt_rx
+                .wait_for(|interrupted| *interrupted)
+                .await
+                .is_err()
+            {
+                std::future::pending::<()>().await;
+            }
+        };
+
+        enum Ended {
+            Exited(std::io::Result<ExitStatus>),
+            TimedOut,
+            Interrupted,
+        }
+
+        let ended = tokio::select! {
+            status = child.wait() => Ended::Exited(status),
+            () = tokio::time::sleep(self.timeout) => Ended::TimedOut,
+            () = interrupted => Ended::Interrupted,
+        };
+
+        if !matches!(ended, Ended::Exited(_)) {
+            #[cfg(unix)]
+            if let Some(pid) = child.id() {
+                #[allow(clippy::cast_possible_wrap)]
+                let pgid = nix::unistd::Pid::from_raw(-(pid as i32));
+                let _ = nix::sys::signal::kill(pgid, nix::sys::signal::Signal::SIGKILL);
+            }
+            let _ = child.kill().await;
+        }
+
+        let output_tail = || async {
+            // Background processes may keep the pipes open; don't wait on them
+            for reader in readers {
+                let _ = tokio::time::timeout(OUTPUT_DRAIN_TIMEOUT, reader).await;
+            }
+            let tail = tail.lock().unwrap_or_else(PoisonError::into_inner);
+            Vec::from(tail.clone()).join("\n")
+        };
+
+        match ended {
+            Ended::Exited(Ok(status)) if status.success() => BackpressureCheckOutcome::Passed,
+            Ended::Exited(Ok(status)) => BackpressureCheckOutcome::Failed {
+                status,
+                output_tail: output_tail().await,
+            },
+            Ended::Exited(Err(e)) => BackpressureCheckOutcome::Error(e.to_string()),
+            Ended::TimedOut => BackpressureCheckOutcome::TimedOut {
+                output_tail: output_tail().await,
+            },
+            Ended::Interrupted => BackpressureCheckOutcome::Interrupted,
+        }
+    }
+}
+
+/// Reads `output` line by line, keeping the last [`OUTPUT_TAIL_LINES`] in `tail`.
+fn collect_tail(
+    output: impl AsyncRead + Unpin + Send + 'static,
+    tail: OutputTail,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut reader = BufReader::new(output);
+        let mut line = Vec::new();
+        while reader
+            .read_until(b'\n', &mut line)
+            .await
+            .is_ok_and(|read| read > 0)
+        {
+            let text = String::from_utf8_lossy(&line);
+            let text = truncate_with_ellipsis(text.trim_end(), OUTPUT_TAIL_LINE_CHARS);
+            line.clear();
+
+            let mut tail = tail.lock().unwrap_or_else(PoisonError::into_inner);
+            if tail.len() == OUTPUT_TAIL_LINES {
+                tail.pop_front();
+            }
+            tail.push_back(text);
+        }
+    })
+}
@@ -5,7 +5,7 @@
 //! timing, and hat activation tracking.
 
 use ralph_proto::{Event, HatId};
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::hash::{DefaultHasher, Hash, Hasher};
 use std::time::{Duration, Instant};
 
@@ -24,6 +24,9 @@ pub struct LoopState {
     pub iteration: u32,
     /// Number of consecutive failures.
     pub consecutive_failures: u32,
+    /// Success flags of the most recent iterations, oldest first. Only
+    /// populated when `event_loop.failure_window` is set.
+    pub recent_outcomes: VecDeque<bool>,
     /// Cumulative cost in USD (if tracked).
     pub cumulative_cost: f64,
     /// When the loop started.
@@ -45,12 +48,21 @@ pub struct LoopState {
     /// Whether a completion event has been observed in JSONL.
     pub completion_requested: bool,
 
+    /// Consecutive iterations with a verified completion event.
+    pub completion_confirmations: u32,
+
+    /// Iteration of the most recent verified completion event.
+    pub last_completion_iteration: Option<u32>,
+
     /// Per-hat activation counts (used for max_activations).
     pub hat_activation_counts: HashMap<HatId, u32>,
 
     /// Hats for which `<hat_id>.exhausted` has been emitted.
     pub exhausted_hats: HashSet<HatId>,
 
+    /// When each hat was last activated (used for cooldown_secs).
+    pub hat_last_activated: HashMap<HatId, Instant>,
+
     /// When the last Telegram check-in message was sent.
     /// `None` means no check-in has been sent yet.
     pub last_checkin_at: Option<Instant>,
@@ -62,6 +74,10 @@ pub struct LoopState {
     /// Topics seen during the loop's lifetime (for event chain validation).
     pub seen_topics: HashSet<String>,
 
+    /// Recent (hat, triggering topic) activations, oldest first, for cycle
+    /// detection. Cleared whenever a topic is seen for the first time.
+    pub recent_activations: Vec<(HatId, String)>,
+
     /// The last event signature emitted (for stale loop detection).
     pub last_emitted_signature: Option<EventSignature>,
 
@@ -71,6 +87,9 @@ pub struct LoopState {
     /// Set to true when a loop.cancel event is detected.
     pub cancellation_requested: bool,
 
+    /// Set to true when the `event_loop.until_event` topic is observed.
+    pub target_event_reached: bool,
+
     /// Session-scoped peak context-token count across all iterations.
     pub peak_input_tokens: u64,
 
@@ -82,6 +101,10 @@ pub struct LoopState {
 
     /// Human guidance messages that must be acknowledged before completion.
     pub unacknowledged_guidance: Vec<String>,
+
+    /// Stderr (or merged PTY output tail) from the last backend execution,
+    /// kept while it failed. Cleared on the next successful iteration.
+    pub last_backend_error: Option<String>,
 }
 
 impl Default for LoopState {
@@ -89,6 +112,7 @@ impl Default for LoopState {
         Self {
             iteration: 0,
             consecutive_failures: 0,
+            recent_outcomes: VecDeque::new(),
             cumulative_cost: 0.0,
             started_at: Instant::now(),
             last_hat: None,
@@ -99,18 +123,24 @@ impl Default for LoopState {
             abandoned_task_redispatches: 0,
             consecutive_malformed_events: 0,
             completion_requested: false,
+            completion_confirmations: 0,
+            last_completion_iteration: None,
             hat_activation_counts: HashMap::new(),
             exhausted_hats: HashSet::new(),
+            hat_last_activated: HashMap::new(),
             last_checkin_at: None,
             last_active_hat_ids: Vec::new(),
             seen_topics: HashSet::new(),
+            recent_activations: Vec::new(),
             last_emitted_signature: None,
             consecutive_same_signature: 0,
             cancellation_requested: false,
+            target_event_reached: false,
             peak_input_tokens: 0,
             last_input_tokens: None,
             hat_peak_input_tokens: HashMap::new(),
             unacknowledged_guidance: Vec::new(),
+            last_backend_error: None,
         }
     }
 }
@@ -121,6 +151,23 @@ impl LoopState {
         Self::default()
     }
 
+    /// Records an iteration outcome in the failure window, keeping at most
+    /// `window` entries.
+    pub fn record_outcome(&mut self, success: bool, window: u32) {
+        self.recent_outcomes.push_back(success);
+        while self.recent_outcomes.len() > window as usize {
+            self.recent_outcomes.pop_front();
+        }
+    }
+
+    /// Number of failed iterations currently inside the failure window.
+    pub fn windowed_failures(&self) -> u32 {
+        self.recent_outcomes
+            .iter()
+            .filter(|success| !**success)
+            .count() as u32
+    }
+
     /// Returns the elapsed time since the loop started.
     pub fn elapsed(&self) -> Duration {
         self.started_at.elapsed()
@@ -134,7 +181,10 @@ impl LoopState {
     ///
     /// Also tracks consecutive same-signature emissions for stale loop detection.
     pub fn record_event(&mut self, event: &Event) {
-        self.seen_topics.insert(event.topic.to_string());
+        if self.seen_topics.insert(event.topic.to_string()) {
+            // A new topic means the workflow progressed, so no cycle is stuck
+            self.recent_activations.clear();
+        }
 
         if !Self::event_counts_toward_stale_loop(event) {
             self.consecutive_same_signature = 0;
@@ -151,6 +201,38 @@ impl LoopState {
         }
     }
 
+    /// Records that `hat` was activated by `topic`, keeping at most `window`
+    /// activations for cycle detection.
+    pub fn record_activation(&mut self, hat: &HatId, topic: &str, window: u32) {
+        self.recent_activations
+            .push((hat.clone(), topic.to_string()));
+        let excess = self
+            .recent_activations
+            .len()
+            .saturating_sub(window as usize);
+        self.recent_activations.drain(..excess);
+    }
+
+    /// Returns the shortest cycle of activations that makes up the last
+    /// `repeats` back-to-back repetitions, if any.
+    ///
+    /// Only cycles spanning at least two hats count; a single hat re-running
+    /// on the same topic is normal solo-mode work.
+    pub fn repeated_cycle(&self, repeats: u32) -> Option<&[(HatId, String)]> {
+        let repeats = repeats.max(2) as usize;
+        let len = self.recent_activations.len();
+        (2..=len / repeats).find_map(|cycle_len| {
+            let tail = &self.recent_activations[len - cycle_len * repeats..];
+            let cycle = &tail[..cycle_len];
+            let repeating = tail
+                .iter()
+                .enumerate()
+                .all(|(i, activation)| activation == &cycle[i % cycle_len]);
+            let spans_hats = cycle.iter().any(|(hat, _)| hat != &cycle[0].0);
+            (repeating && spans_hats).then_some(cycle)
+        })
+    }
+
     /// Record this iteration's context-token usage for the hat that ran it.
     ///
     /// `tokens` is the iteration's adapter-reported live context occupancy.
@@ -196,6 +278,58 @@ mod tests {
     use super::LoopState;
     use ralph_proto::{Event, HatId};
 
+    fn activate(state: &mut LoopState, pattern: &[(&str, &str)], times: usize) {
+        for _ in 0..times {
+            for (hat, topic) in pattern {
+                state.record_activation(&HatId::new(*hat), topic, 12);
+            }
+        }
+    }
+
+    #[test]
+    fn ping_pong_between_hats_is_a_repeated_cycle() {
+        let mut state = LoopState::new();
+        let ping_pong = [("builder", "review.rejected"), ("reviewer", "build.done")];
+
+        activate(&mut state, &ping_pong, 2);
+        assert!(state.repeated_cycle(3).is_none());
+
+        activate(&mut state, &ping_pong, 1);
+        let cycle = state.repeated_cycle(3).expect("cycle detected");
+        assert_eq!(cycle.len(), 2);
+        assert_eq!(cycle[0].0.as_str(), "builder");
+    }
+
+    #[test]
+    fn single_hat_repeating_is_not_a_cycle() {
+        let mut state = LoopState::new();
+        activate(&mut state, &[("ralph", "build.task")], 10);
+        assert!(state.repeated_cycle(3).is_none());
+    }
+
+    #[test]
+    fn new_topic_resets_cycle_tracking() {
+        let mut state = LoopState::new();
+        let ping_pong = [("builder", "review.rejected"), ("reviewer", "build.done")];
+
+        activate(&mut state, &ping_pong, 2);
+        state.record_event(&Event::new("tests.added", "progress"));
+        activate(&mut state, &ping_pong, 1);
+        assert!(state.repeated_cycle(3).is_none());
+
+        // Already-seen topics don't reset anything
+        state.record_event(&Event::new("tests.added", "more"));
+        activate(&mut state, &ping_pong, 2);
+        assert!(state.repeated_cycle(3).is_some());
+    }
+
+    #[test]
+    fn activation_window_is_bounded() {
+        let mut state = LoopState::new();
+        activate(&mut state, &[("a", "x"), ("b", "y"), ("c", "z")], 10);
+        assert_eq!(state.recent_activations.len(), 12);
+    }
+
     #[test]
     fn repeated_task_complete_does_not_accumulate_stale_loop_count() {
         let mut state = LoopState::new();
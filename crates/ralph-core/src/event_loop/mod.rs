@@ -2,10 +2,12 @@
 //!
 //! The event loop coordinates the execution of hats via pub/sub messaging.
 
+mod backpressure_check;
 mod loop_state;
 #[cfg(test)]
 mod tests;
 
+pub use backpressure_check::{BackpressureCheck, BackpressureCheckOutcome};
 pub use loop_state::LoopState;
 
 use crate::config::{HatBackend, InjectMode, RalphConfig, ScratchpadConfig};
@@ -18,6 +20,7 @@ use crate::loop_context::LoopContext;
 use crate::memory_store::{MarkdownMemoryStore, format_memories_as_markdown, truncate_to_budget};
 use crate::skill_registry::SkillRegistry;
 use crate::text::floor_char_boundary;
+use crate::utils::format_duration;
 use ralph_proto::{CheckinContext, Event, EventBus, Hat, HatId, RobotService};
 use serde::{Deserialize, Serialize};
 use serde_json::{Map, Value};
@@ -26,7 +29,7 @@ use std::io::{BufRead, BufReader, Seek, SeekFrom, Write};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::sync::atomic::AtomicBool;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tracing::{debug, info, warn};
 
 /// Result of processing events from JSONL.
@@ -81,12 +84,15 @@ pub enum TerminationReason {
     ConsecutiveFailures,
     /// Loop thrashing detected (repeated blocked events).
     LoopThrashing,
-    /// Stale loop detected (same topic emitted 3+ times consecutively).
+    /// Stale loop detected (same topic+payload emitted more than
+    /// `event_loop.max_same_topic` times consecutively).
     LoopStale,
     /// Too many consecutive malformed JSONL lines in events file.
     ValidationFailure,
-    /// Manually stopped.
+    /// Stopped without completing (fallback recovery exhausted, idle timeout).
     Stopped,
+    /// Stopped on explicit user request (stop signal file, Telegram `/stop`).
+    UserStop,
     /// Interrupted by signal (SIGINT/SIGTERM).
     Interrupted,
     /// Restart requested via Telegram `/restart` command.
@@ -95,6 +101,8 @@ pub enum TerminationReason {
     WorkspaceGone,
     /// Loop was cancelled gracefully via loop.cancel event (human rejection, timeout).
     Cancelled,
+    /// The `--until-event` topic was published (staged run reached its milestone).
+    TargetEventReached,
 }
 
 impl TerminationReason {
@@ -105,6 +113,8 @@ impl TerminationReason {
     /// - 1: Consecutive failures or unrecoverable error (failure)
     /// - 2: Max iterations, max runtime, or max cost exceeded (limit)
     /// - 130: User interrupt (SIGINT = 128 + 2)
+    ///
+    /// Intentional stops (`UserStop`, `Cancelled`) exit cleanly with 0.
     pub fn exit_code(&self) -> i32 {
         match self {
             TerminationReason::CompletionPromise => 0,
@@ -120,8 +130,10 @@ impl TerminationReason {
             TerminationReason::Interrupted => 130,
             // Restart uses exit code 3 to signal the caller to exec-replace
             TerminationReason::RestartRequested => 3,
-            // Cancelled is a clean exit (0) — the loop stopped intentionally
-            TerminationReason::Cancelled => 0,
+            // Cancelled and UserStop are clean exits (0) — the loop stopped intentionally
+            TerminationReason::Cancelled
+            | TerminationReason::UserStop
+            | TerminationReason::TargetEventReached => 0,
         }
     }
 
@@ -140,19 +152,60 @@ impl TerminationReason {
             TerminationReason::LoopStale => "loop_stale",
             TerminationReason::ValidationFailure => "validation_failure",
             TerminationReason::Stopped => "stopped",
+            TerminationReason::UserStop => "user_stop",
             TerminationReason::Interrupted => "interrupted",
             TerminationReason::RestartRequested => "restart_requested",
             TerminationReason::WorkspaceGone => "workspace_gone",
             TerminationReason::Cancelled => "cancelled",
+            TerminationReason::TargetEventReached => "target_event_reached",
         }
     }
 
     /// Returns true if this is a successful completion (not an error or limit).
     pub fn is_success(&self) -> bool {
-        matches!(self, TerminationReason::CompletionPromise)
+        matches!(
+            self,
+            TerminationReason::CompletionPromise | TerminationReason::TargetEventReached
+        )
     }
 }
 
+impl std::fmt::Display for TerminationReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for TerminationReason {
+    type Err = String;
+
+    /// Parses the `as_str` form, e.g. from a `loop.terminate` payload.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "completed" => Ok(TerminationReason::CompletionPromise),
+            "max_iterations" => Ok(TerminationReason::MaxIterations),
+            "max_runtime" => Ok(TerminationReason::MaxRuntime),
+            "max_cost" => Ok(TerminationReason::MaxCost),
+            "consecutive_failures" => Ok(TerminationReason::ConsecutiveFailures),
+            "loop_thrashing" => Ok(TerminationReason::LoopThrashing),
+            "loop_stale" => Ok(TerminationReason::LoopStale),
+            "validation_failure" => Ok(TerminationReason::ValidationFailure),
+            "stopped" => Ok(TerminationReason::Stopped),
+            "user_stop" => Ok(TerminationReason::UserStop),
+            "interrupted" => Ok(TerminationReason::Interrupted),
+            "restart_requested" => Ok(TerminationReason::RestartRequested),
+            "workspace_gone" => Ok(TerminationReason::WorkspaceGone),
+            "cancelled" => Ok(TerminationReason::Cancelled),
+            "target_event_reached" => Ok(TerminationReason::TargetEventReached),
+            _ => Err(format!("Invalid termination reason: '{}'", s)),
+        }
+    }
+}
+
+/// Callback fired at each iteration boundary with the iteration number and
+/// selected hat.
+type IterationObserver = Box<dyn Fn(u32, &HatId) + Send + 'static>;
+
 /// The main event loop orchestrator.
 pub struct EventLoop {
     config: RalphConfig,
@@ -163,6 +216,8 @@ pub struct EventLoop {
     ralph: HatlessRalph,
     /// Cached human guidance messages that should persist across iterations.
     robot_guidance: Vec<String>,
+    /// `build.done` events held back until `features.backpressure_command` runs.
+    pending_backpressure_checks: Vec<BackpressureCheck>,
     /// Event reader for consuming events from JSONL file.
     /// Made pub(crate) to allow tests to override the path.
     pub(crate) event_reader: EventReader,
@@ -174,6 +229,8 @@ pub struct EventLoop {
     /// Robot service for human-in-the-loop communication.
     /// Injected externally when `human.enabled` is true and this is the primary loop.
     robot_service: Option<Box<dyn RobotService>>,
+    /// Callbacks fired when an iteration starts (see `add_iteration_observer`).
+    iteration_observers: Vec<IterationObserver>,
 }
 
 impl EventLoop {
@@ -181,14 +238,17 @@ impl EventLoop {
     pub fn new(config: RalphConfig) -> Self {
         // Try to create diagnostics collector, but fall back to disabled if it fails
         // (e.g., in tests without proper directory setup)
-        let diagnostics = crate::diagnostics::DiagnosticsCollector::new(std::path::Path::new("."))
-            .unwrap_or_else(|e| {
-                debug!(
-                    "Failed to initialize diagnostics: {}, using disabled collector",
-                    e
-                );
-                crate::diagnostics::DiagnosticsCollector::disabled()
-            });
+        let diagnostics = crate::diagnostics::DiagnosticsCollector::with_level(
+            std::path::Path::new("."),
+            config.core.diagnostics_level,
+        )
+        .unwrap_or_else(|e| {
+            debug!(
+                "Failed to initialize diagnostics: {}, using disabled collector",
+                e
+            );
+            crate::diagnostics::DiagnosticsCollector::disabled()
+        });
 
         Self::with_diagnostics(config, diagnostics)
     }
@@ -199,14 +259,17 @@ impl EventLoop {
     /// are located. Use this for multi-loop scenarios where each loop runs
     /// in an isolated workspace (git worktree).
     pub fn with_context(config: RalphConfig, context: LoopContext) -> Self {
-        let diagnostics = crate::diagnostics::DiagnosticsCollector::new(context.workspace())
-            .unwrap_or_else(|e| {
-                debug!(
-                    "Failed to initialize diagnostics: {}, using disabled collector",
-                    e
-                );
-                crate::diagnostics::DiagnosticsCollector::disabled()
-            });
+        let diagnostics = crate::diagnostics::DiagnosticsCollector::with_level(
+            context.workspace(),
+            config.core.diagnostics_level,
+        )
+        .unwrap_or_else(|e| {
+            debug!(
+                "Failed to initialize diagnostics: {}, using disabled collector",
+                e
+            );
+            crate::diagnostics::DiagnosticsCollector::disabled()
+        });
 
         Self::with_context_and_diagnostics(config, context, diagnostics)
     }
@@ -314,11 +377,13 @@ impl EventLoop {
             instruction_builder,
             ralph,
             robot_guidance: Vec::new(),
+            pending_backpressure_checks: Vec::new(),
             event_reader,
             diagnostics,
             loop_context: Some(context),
             skill_registry,
             robot_service: None,
+            iteration_observers: Vec::new(),
         }
     }
 
@@ -421,11 +486,13 @@ impl EventLoop {
             instruction_builder,
             ralph,
             robot_guidance: Vec::new(),
+            pending_backpressure_checks: Vec::new(),
             event_reader,
             diagnostics,
             loop_context: None,
             skill_registry,
             robot_service: None,
+            iteration_observers: Vec::new(),
         }
     }
 
@@ -482,6 +549,13 @@ impl EventLoop {
         &self.state
     }
 
+    /// Records diagnostic output from a failed backend execution so the loop
+    /// summary can show why it failed. Cleared by the next successful
+    /// `process_output`.
+    pub fn record_backend_error(&mut self, stderr: impl Into<String>) {
+        self.state.last_backend_error = Some(stderr.into());
+    }
+
     /// Record this iteration's context-token usage for `hat`.
     ///
     /// Passthrough to `LoopState::record_iteration_tokens` — preserves the
@@ -611,6 +685,25 @@ impl EventLoop {
         self.bus.add_observer(observer);
     }
 
+    /// Adds a callback fired at the start of each iteration, after the hat is
+    /// selected and before its prompt is built.
+    ///
+    /// Complements event observers for phase-level instrumentation such as
+    /// profiling or UI progress.
+    pub fn add_iteration_observer<F>(&mut self, observer: F)
+    where
+        F: Fn(u32, &HatId) + Send + 'static,
+    {
+        self.iteration_observers.push(Box::new(observer));
+    }
+
+    /// Notifies iteration observers that `iteration` is starting with `hat_id`.
+    pub fn notify_iteration_start(&self, iteration: u32, hat_id: &HatId) {
+        for observer in &self.iteration_observers {
+            observer(iteration, hat_id);
+        }
+    }
+
     /// Sets a single observer, clearing any existing observers.
     ///
     /// Prefer `add_observer` when multiple observers are needed.
@@ -645,18 +738,41 @@ impl EventLoop {
             return Some(TerminationReason::ConsecutiveFailures);
         }
 
+        if cfg.failure_window.is_some()
+            && self.state.windowed_failures() >= cfg.max_consecutive_failures
+        {
+            return Some(TerminationReason::ConsecutiveFailures);
+        }
+
         // Check for loop thrashing: planner keeps dispatching abandoned tasks
         if self.state.abandoned_task_redispatches >= 3 {
             return Some(TerminationReason::LoopThrashing);
         }
 
+        // Check for hat cycles: the same activations repeating with no new topics
+        if let Some(repeats) = cfg.max_cycle_repeats
+            && let Some(cycle) = self.state.repeated_cycle(repeats)
+        {
+            let cycle = cycle
+                .iter()
+                .map(|(hat, topic)| format!("{}({topic})", hat.as_str()))
+                .collect::<Vec<_>>()
+                .join(" → ");
+            warn!(
+                %cycle,
+                repeats,
+                "Loop thrashing detected: hat cycle repeated without new topics"
+            );
+            return Some(TerminationReason::LoopThrashing);
+        }
+
         // Check for validation failures: too many consecutive malformed JSONL lines
         if self.state.consecutive_malformed_events >= 3 {
             return Some(TerminationReason::ValidationFailure);
         }
 
-        // Check for stale loop: same event signature emitted 3+ times in a row
-        if self.state.consecutive_same_signature >= 3 {
+        // Check for stale loop: same event signature emitted too many times in a row
+        if self.state.consecutive_same_signature > cfg.max_same_topic {
             let topic = self
                 .state
                 .last_emitted_signature
@@ -676,7 +792,7 @@ impl EventLoop {
             std::path::Path::new(&self.config.core.workspace_root).join(".ralph/stop-requested");
         if stop_path.exists() {
             let _ = std::fs::remove_file(&stop_path);
-            return Some(TerminationReason::Stopped);
+            return Some(TerminationReason::UserStop);
         }
 
         // Check for restart signal from Telegram /restart command
@@ -716,6 +832,36 @@ impl EventLoop {
         Some(TerminationReason::Cancelled)
     }
 
+    /// Check if the `event_loop.until_event` topic was observed.
+    ///
+    /// Like cancellation, this skips required_events validation: the run is
+    /// meant to stop at a milestone, not to prove the workflow complete.
+    pub fn check_target_event(&mut self) -> Option<TerminationReason> {
+        if !self.state.target_event_reached {
+            return None;
+        }
+        self.state.target_event_reached = false;
+        info!(
+            topic = self
+                .config
+                .event_loop
+                .until_event
+                .as_deref()
+                .unwrap_or_default(),
+            "Target event reached, stopping loop"
+        );
+
+        self.diagnostics.log_orchestration(
+            self.state.iteration,
+            "loop",
+            crate::diagnostics::OrchestrationEvent::LoopTerminated {
+                reason: TerminationReason::TargetEventReached.as_str().to_string(),
+            },
+        );
+
+        Some(TerminationReason::TargetEventReached)
+    }
+
     /// Request completion from the text fallback path.
     ///
     /// When a backend outputs a completion promise as plain text (without
@@ -746,6 +892,7 @@ impl EventLoop {
                     "Rejecting LOOP_COMPLETE: required events not seen during loop lifetime"
                 );
                 self.state.completion_requested = false;
+                self.state.completion_confirmations = 0;
 
                 // Inject task.resume so the loop continues
                 let resume_payload = format!(
@@ -766,6 +913,7 @@ impl EventLoop {
                 "Rejecting LOOP_COMPLETE: human.guidance is unacknowledged"
             );
             self.state.completion_requested = false;
+            self.state.completion_confirmations = 0;
             self.bus.publish(Event::new(
                 "task.resume",
                 format!(
@@ -809,6 +957,7 @@ impl EventLoop {
                     "Rejecting completion event with {} open task(s)",
                     open_tasks.len()
                 );
+                self.state.completion_confirmations = 0;
                 self.bus.publish(Event::new(
                     "task.resume",
                     format!(
@@ -822,6 +971,10 @@ impl EventLoop {
             warn!("Completion event with pending scratchpad tasks - trusting agent decision");
         }
 
+        if !self.confirm_completion() {
+            return None;
+        }
+
         info!("Completion event detected - terminating");
 
         // Log loop terminated
@@ -836,6 +989,48 @@ impl EventLoop {
         Some(TerminationReason::CompletionPromise)
     }
 
+    /// Records a verified completion and returns true once enough consecutive
+    /// confirmations (`completion_confirmations_required`) have been seen.
+    ///
+    /// Until then, a `task.resume` event asks the agent to re-check its work
+    /// and emit the completion promise again on the next iteration.
+    fn confirm_completion(&mut self) -> bool {
+        let iteration = self.state.iteration;
+        let consecutive = self
+            .state
+            .last_completion_iteration
+            .is_some_and(|last| last + 1 == iteration);
+        self.state.completion_confirmations = if consecutive {
+            self.state.completion_confirmations + 1
+        } else {
+            1
+        };
+        self.state.last_completion_iteration = Some(iteration);
+
+        let confirmations = self.state.completion_confirmations;
+        let required = self
+            .config
+            .event_loop
+            .completion_confirmations_required
+            .max(1);
+        if confirmations >= required {
+            return true;
+        }
+
+        info!(
+            confirmations,
+            required, "Completion verified - awaiting further confirmation"
+        );
+        self.bus.publish(Event::new(
+            "task.resume",
+            format!(
+                "Completion verified ({confirmations}/{required} confirmations). Re-check that the work is done, then emit {} again to confirm.",
+                self.config.event_loop.completion_promise
+            ),
+        ));
+        false
+    }
+
     /// Initializes the loop by publishing the start event.
     pub fn initialize(&mut self, prompt_content: &str) {
         // Use configured starting_event or default to task.start for backward compatibility
@@ -995,7 +1190,17 @@ impl EventLoop {
     /// Use this after `process_output` to detect if the LLM failed to publish an event.
     /// If false after processing, the loop will terminate on the next iteration.
     pub fn has_pending_events(&self) -> bool {
-        self.bus.next_hat_with_pending().is_some() || self.bus.has_human_pending()
+        self.bus.next_hat_with_pending().is_some()
+            || self.bus.has_human_pending()
+            || !self.pending_backpressure_checks.is_empty()
+    }
+
+    /// Lists each hat's pending event topics without consuming them.
+    ///
+    /// Intended for dashboards and observers rendering a queue view; bus
+    /// state is left untouched. Human interaction events are not included.
+    pub fn peek_pending_summary(&self) -> Vec<(HatId, Vec<String>)> {
+        self.bus.pending_summary()
     }
 
     /// Checks if any pending events are human-related (human.response, human.guidance).
@@ -1107,6 +1312,36 @@ impl EventLoop {
         true
     }
 
+    /// Re-prompts a hat that finished its iteration without publishing an event.
+    ///
+    /// Unlike [`inject_fallback_event`](Self::inject_fallback_event), which
+    /// recovers a stalled loop, this is an immediate nudge naming the topics
+    /// the hat was expected to publish.
+    pub fn inject_publish_retry(&mut self, hat_id: &HatId) {
+        let expected = self.get_hat_publishes(hat_id);
+        let payload = if expected.is_empty() {
+            format!(
+                "You didn't publish an event. Emit the next event via `ralph emit`, \
+                 or `{}` if all work is done.",
+                self.config.event_loop.completion_promise
+            )
+        } else {
+            format!(
+                "You didn't publish an event; expected one of [{}]. \
+                 Emit exactly one via `ralph emit`, then stop.",
+                expected.join(", ")
+            )
+        };
+
+        let event = Event::new("task.resume", payload);
+        let event = if hat_id.as_str() == "ralph" {
+            event
+        } else {
+            event.with_target(hat_id.clone())
+        };
+        self.bus.publish(event);
+    }
+
     /// Builds the prompt for a hat's execution.
     ///
     /// Per "Hatless Ralph" architecture:
@@ -1135,11 +1370,7 @@ impl EventLoop {
                     .into_iter()
                     .partition(|e| e.topic.as_str() == "human.guidance");
 
-                let events_context = regular_events
-                    .iter()
-                    .map(|e| Self::format_event(e))
-                    .collect::<Vec<_>>()
-                    .join("\n");
+                let events: Vec<&Event> = regular_events.iter().collect();
 
                 // Solo mode: set scratchpad and iteration before guidance persistence
                 self.ralph
@@ -1151,11 +1382,10 @@ impl EventLoop {
                 self.apply_robot_guidance();
 
                 // Build base prompt and prepend memories + scratchpad + ready tasks
-                let base_prompt = self.ralph.build_prompt(&events_context, &[]);
+                let final_prompt = self.assemble_prompt(&events, |events_context| {
+                    self.ralph.build_prompt(events_context, &[])
+                });
                 self.ralph.clear_robot_guidance();
-                let with_skills = self.prepend_auto_inject_skills(base_prompt);
-                let with_scratchpad = self.prepend_scratchpad(with_skills);
-                let final_prompt = self.prepend_ready_tasks(with_scratchpad);
 
                 debug!("build_prompt: routing to HatlessRalph (solo mode)");
                 return Some(final_prompt);
@@ -1167,6 +1397,7 @@ impl EventLoop {
 
                 let mut all_events = Vec::new();
                 let mut system_events = Vec::new();
+                let mut cooling_down = false;
 
                 for id in &all_hat_ids {
                     let pending = self.bus.take_pending(id);
@@ -1174,6 +1405,18 @@ impl EventLoop {
                         continue;
                     }
 
+                    if let Some(remaining) = self.hat_cooldown_remaining(id) {
+                        debug!(
+                            hat = %id.as_str(),
+                            remaining_secs = remaining.as_secs(),
+                            pending = pending.len(),
+                            "Hat activation suppressed by cooldown, keeping events queued"
+                        );
+                        self.bus.requeue(id, pending);
+                        cooling_down = true;
+                        continue;
+                    }
+
                     let (drop_pending, exhausted_event) = self.check_hat_exhaustion(id, &pending);
                     if drop_pending {
                         // Drop the pending events that would have activated the hat.
@@ -1190,6 +1433,12 @@ impl EventLoop {
                 let mut human_events = self.bus.take_human_pending();
                 all_events.append(&mut human_events);
 
+                // Everything pending is held by cooldowns: don't spend an
+                // iteration on an empty coordinator prompt.
+                if cooling_down && all_events.is_empty() {
+                    return None;
+                }
+
                 // Publish orchestrator-generated system events after consuming pending events,
                 // so they become visible in the event log and can be handled next iteration.
                 for event in system_events {
@@ -1207,6 +1456,7 @@ impl EventLoop {
                 // Determine which hats are active based on regular events
                 let active_hat_ids = self.determine_active_hat_ids(&regular_events);
                 self.record_hat_activations(&active_hat_ids);
+                self.record_cycle_activations(&active_hat_ids, &regular_events);
                 self.state.last_active_hat_ids = active_hat_ids.clone();
 
                 // Resolve scratchpad config for the active hat (or global default).
@@ -1232,16 +1482,6 @@ impl EventLoop {
 
                 let active_hats = self.determine_active_hats(&regular_events);
 
-                // Format events for context
-                let events_context = effective_regular_events
-                    .iter()
-                    .map(|e| Self::format_event(e))
-                    .collect::<Vec<_>>()
-                    .join("\n");
-
-                // Build base prompt and prepend memories + scratchpad if available
-                let base_prompt = self.ralph.build_prompt(&events_context, &active_hats);
-
                 // Build prompt with active hats - filters instructions to only active hats
                 debug!(
                     "build_prompt: routing to HatlessRalph (multi-hat coordinator mode), active_hats: {:?}",
@@ -1251,11 +1491,14 @@ impl EventLoop {
                         .collect::<Vec<_>>()
                 );
 
+                // Build base prompt and prepend memories + scratchpad if available
+                let final_prompt = self
+                    .assemble_prompt(&effective_regular_events, |events_context| {
+                        self.ralph.build_prompt(events_context, &active_hats)
+                    });
+
                 // Clear guidance after active_hats references are no longer needed
                 self.ralph.clear_robot_guidance();
-                let with_skills = self.prepend_auto_inject_skills(base_prompt);
-                let with_scratchpad = self.prepend_scratchpad(with_skills);
-                let final_prompt = self.prepend_ready_tasks(with_scratchpad);
 
                 return Some(final_prompt);
             }
@@ -1265,9 +1508,10 @@ impl EventLoop {
         // next_hat() always returns "ralph" when custom hats are defined.
         // But we keep this code path for backward compatibility and tests.
         let events = self.bus.take_pending(&hat_id.clone());
+        let max_payload_bytes = self.config.event_loop.max_event_payload_bytes;
         let events_context = events
             .iter()
-            .map(|e| Self::format_event(e))
+            .map(|e| Self::format_event(e, max_payload_bytes))
             .collect::<Vec<_>>()
             .join("\n");
 
@@ -1390,6 +1634,102 @@ impl EventLoop {
         self.ralph.set_robot_guidance(self.robot_guidance.clone());
     }
 
+    /// Assembles the coordinator prompt for the given pending events.
+    ///
+    /// When `event_loop.context_budget` is set and the prompt is over it,
+    /// memories are trimmed first (lowest-priority sections go first), then the
+    /// oldest events are dropped. The newest event and the top-level prompt
+    /// (`task.start`/`task.resume`) are always kept.
+    fn assemble_prompt(&self, events: &[&Event], build_base: impl Fn(&str) -> String) -> String {
+        let max_payload_bytes = self.config.event_loop.max_event_payload_bytes;
+        let event_lines: Vec<String> = events
+            .iter()
+            .map(|e| Self::format_event(e, max_payload_bytes))
+            .collect();
+        let event_lines = event_lines.as_slice();
+        let assemble = |lines: &[String], memory_budget: Option<usize>| {
+            let base_prompt = build_base(&lines.join("\n"));
+            let with_skills = self.prepend_auto_inject_skills(base_prompt, memory_budget);
+            let with_recent_events = self.prepend_recent_events(with_skills);
+            let with_scratchpad = self.prepend_scratchpad(with_recent_events);
+            self.prepend_ready_tasks(with_scratchpad)
+        };
+
+        let prompt = assemble(event_lines, None);
+        let Some(budget) = self.config.event_loop.context_budget.filter(|b| *b > 0) else {
+            return prompt;
+        };
+        let budget_chars = budget.saturating_mul(4);
+        if prompt.len() <= budget_chars {
+            return prompt;
+        }
+        let original_len = prompt.len();
+
+        // 1. Trim memories, dropping them entirely if they can't cover the overflow
+        let without_memories = assemble(event_lines, Some(0));
+        let memories_len = prompt.len().saturating_sub(without_memories.len());
+        let overflow = prompt.len() - budget_chars;
+        let kept_memory_tokens = memories_len.saturating_sub(overflow) / 4;
+        let (mut prompt, memory_budget) = if memories_len == 0 {
+            (prompt, None)
+        } else if kept_memory_tokens == 0 {
+            (without_memories, Some(0))
+        } else {
+            let memory_budget = Some(kept_memory_tokens);
+            (assemble(event_lines, memory_budget), memory_budget)
+        };
+
+        // 2. Drop the oldest events
+        let mut dropped_events = 0;
+        if prompt.len() > budget_chars {
+            let mut overflow = prompt.len() - budget_chars;
+            let mut dropped = vec![false; events.len()];
+            for (index, event) in events.iter().enumerate().rev().skip(1).rev() {
+                if overflow == 0 {
+                    break;
+                }
+                let topic = event.topic.as_str();
+                if topic == "task.start" || topic == "task.resume" {
+                    continue;
+                }
+                overflow = overflow.saturating_sub(event_lines[index].len() + 1);
+                dropped[index] = true;
+                dropped_events += 1;
+            }
+
+            if dropped_events > 0 {
+                let mut kept = vec![format!(
+                    "[{dropped_events} older event(s) trimmed to fit the context budget]"
+                )];
+                kept.extend(
+                    event_lines
+                        .iter()
+                        .zip(&dropped)
+                        .filter(|(_, dropped)| !**dropped)
+                        .map(|(line, _)| line.clone()),
+                );
+                prompt = assemble(&kept, memory_budget);
+            }
+        }
+
+        let memories_trimmed = match memory_budget {
+            Some(0) => "dropped all memories".to_string(),
+            Some(tokens) => format!("trimmed memories to ~{tokens} tokens"),
+            None => "kept memories".to_string(),
+        };
+        warn!(
+            "Prompt (~{} tokens) exceeded event_loop.context_budget ({} tokens): {}, dropped {} of {} events; now ~{} tokens",
+            original_len.div_ceil(4),
+            budget,
+            memories_trimmed,
+            dropped_events,
+            event_lines.len(),
+            prompt.len().div_ceil(4)
+        );
+
+        prompt
+    }
+
     /// Prepends auto-injected skill content to the prompt.
     ///
     /// This generalizes the former `prepend_memories()` into a skill auto-injection
@@ -1399,11 +1739,14 @@ impl EventLoop {
     /// 1. Memory data + ralph-tools skill (special case: loads memory data from store, applies budget)
     /// 2. RObot interaction skill (gated by `robot.enabled`)
     /// 3. Other auto-inject skills from the registry (wrapped in XML tags)
-    fn prepend_auto_inject_skills(&self, prompt: String) -> String {
+    ///
+    /// `memory_budget` is passed through to memory injection (see
+    /// `inject_memories_and_tools_skill`).
+    fn prepend_auto_inject_skills(&self, prompt: String, memory_budget: Option<usize>) -> String {
         let mut prefix = String::new();
 
         // 1. Memory data + ralph-tools skill — special case with data loading
-        self.inject_memories_and_tools_skill(&mut prefix);
+        self.inject_memories_and_tools_skill(&mut prefix, memory_budget);
 
         // 2. RObot interaction skill — gated by robot.enabled
         self.inject_robot_skill(&mut prefix);
@@ -1420,6 +1763,33 @@ impl EventLoop {
         prefix
     }
 
+    /// Returns the memory block auto-injection prepends to prompts.
+    ///
+    /// This is exactly what `inject_memories_and_tools_skill` produces with no
+    /// context-budget tightening: primed memories (after `memories.budget`)
+    /// followed by the ralph-tools skills. Used by `ralph tools memory preview`.
+    pub fn memory_injection_preview(&self) -> String {
+        let mut prefix = String::new();
+        self.inject_memories_and_tools_skill(&mut prefix, None);
+        prefix
+    }
+
+    /// Builds the prompt `hat_id` would receive on its first activation.
+    ///
+    /// Initializes the loop with `prompt_content` and, for a custom hat,
+    /// publishes an event on its first trigger so the coordinator prompt
+    /// includes that hat's instructions. Used by `ralph run --dump-prompt`.
+    /// Returns `None` if the hat is unknown or has no triggers.
+    pub fn preview_prompt(&mut self, hat_id: &HatId, prompt_content: &str) -> Option<String> {
+        self.initialize(prompt_content);
+        if hat_id.as_str() != "ralph" {
+            let trigger = self.registry.get_config(hat_id)?.triggers.first()?;
+            let topic = trigger.replace('*', "preview");
+            self.bus.publish(Event::new(topic, prompt_content));
+        }
+        self.build_prompt(&HatId::new("ralph"))
+    }
+
     /// Injects memory data and the ralph-tools skill into the prefix.
     ///
     /// Special case: loads memory entries from the store, applies budget
@@ -1427,11 +1797,22 @@ impl EventLoop {
     /// both tasks and memories CLI usage).
     /// Memory data is gated by `memories.enabled && memories.inject == Auto`.
     /// The ralph-tools skill is injected when either memories or tasks are enabled.
-    fn inject_memories_and_tools_skill(&self, prefix: &mut String) {
+    ///
+    /// `memory_budget` tightens `memories.budget` (in tokens) when the prompt
+    /// must fit `event_loop.context_budget`; `Some(0)` omits memory data.
+    fn inject_memories_and_tools_skill(&self, prefix: &mut String, memory_budget: Option<usize>) {
         let memories_config = &self.config.memories;
+        let budget = match memory_budget {
+            Some(limit) if memories_config.budget > 0 => Some(limit.min(memories_config.budget)),
+            Some(limit) => Some(limit),
+            None => (memories_config.budget > 0).then_some(memories_config.budget),
+        };
 
         // Inject memory DATA if memories are enabled with auto-inject
-        if memories_config.enabled && memories_config.inject == InjectMode::Auto {
+        if memories_config.enabled
+            && memories_config.inject == InjectMode::Auto
+            && budget != Some(0)
+        {
             info!(
                 "Memory injection check: enabled={}, inject={:?}, workspace_root={:?}",
                 memories_config.enabled, memories_config.inject, self.config.core.workspace_root
@@ -1466,15 +1847,14 @@ impl EventLoop {
             } else {
                 let mut memories_content = format_memories_as_markdown(&memories);
 
-                if memories_config.budget > 0 {
+                if let Some(budget) = budget {
                     let original_len = memories_content.len();
-                    memories_content =
-                        truncate_to_budget(&memories_content, memories_config.budget);
+                    memories_content = truncate_to_budget(&memories_content, budget);
                     debug!(
                         "Applied budget: {} chars -> {} chars (budget: {})",
                         original_len,
                         memories_content.len(),
-                        memories_config.budget
+                        budget
                     );
                 }
 
@@ -1661,6 +2041,57 @@ impl EventLoop {
         final_prompt
     }
 
+    /// Prepends the last `event_loop.recent_events_in_prompt` logged events.
+    ///
+    /// Reads the run's events file and formats each record as a one-line
+    /// summary inside a `<recent-events>` block, so the active hat can see
+    /// what happened in earlier iterations. Payloads are shortened to keep
+    /// the section small.
+    fn prepend_recent_events(&self, prompt: String) -> String {
+        const MAX_PAYLOAD_CHARS: usize = 200;
+
+        let limit = self.config.event_loop.recent_events_in_prompt;
+        if limit == 0 {
+            return prompt;
+        }
+
+        let history = crate::event_logger::EventHistory::new(self.event_reader.path());
+        let records = match history.read_last(limit) {
+            Ok(records) => records,
+            Err(e) => {
+                debug!("Failed to read event history for injection: {}", e);
+                return prompt;
+            }
+        };
+        if records.is_empty() {
+            return prompt;
+        }
+
+        let mut section = String::from("<recent-events>\n");
+        for record in &records {
+            let payload = record
+                .payload
+                .split_whitespace()
+                .collect::<Vec<_>>()
+                .join(" ");
+            let payload = crate::text::truncate_with_ellipsis(&payload, MAX_PAYLOAD_CHARS);
+            let hat = if record.hat.is_empty() {
+                String::new()
+            } else {
+                format!(" {}", record.hat)
+            };
+            section.push_str(&format!(
+                "- [iteration {}{}] {}: {}\n",
+                record.iteration, hat, record.topic, payload
+            ));
+        }
+        section.push_str("</recent-events>\n\n");
+
+        debug!("Injecting {} recent event(s) into prompt", records.len());
+        section.push_str(&prompt);
+        section
+    }
+
     /// Prepends ready tasks to the prompt if tasks are enabled and any exist.
     ///
     /// Loads the task store and formats ready (unblocked, open) tasks into
@@ -1716,14 +2147,19 @@ impl EventLoop {
                 open.len(),
                 closed_count
             ));
-            for task in &ready {
+            if !ready.is_empty() {
+                section.push_str(
+                    "Ready tasks are listed in dispatch order (priority, then oldest first).\n",
+                );
+            }
+            for (index, task) in ready.iter().enumerate() {
                 let status_icon = match task.status {
                     TaskStatus::Open => "[ ]",
                     TaskStatus::InProgress => "[~]",
                     _ => "[?]",
                 };
                 section.push_str(&format!(
-                    "- {} [P{}] {} ({}){}\n",
+                    "- {} [P{}] {} ({}){}{}\n",
                     status_icon,
                     task.priority,
                     task.title,
@@ -1731,14 +2167,15 @@ impl EventLoop {
                     task.key
                         .as_deref()
                         .map(|key| format!(" — key: {key}"))
-                        .unwrap_or_default()
+                        .unwrap_or_default(),
+                    if index == 0 { " ← next" } else { "" }
                 ));
             }
             // Show blocked tasks separately so agent knows they exist
             let ready_ids: Vec<&str> = ready.iter().map(|t| t.id.as_str()).collect();
             let blocked: Vec<_> = open
                 .iter()
-                .filter(|t| !ready_ids.contains(&t.id.as_str()))
+                .filter(|t| !ready_ids.contains(&t.id.as_str()) && t.status != TaskStatus::Blocked)
                 .collect();
             if !blocked.is_empty() {
                 section.push_str("\nBlocked:\n");
@@ -1756,6 +2193,30 @@ impl EventLoop {
                     ));
                 }
             }
+            // Externally blocked tasks wait on a human or outside system; listing
+            // them apart keeps the agent from retrying them every iteration
+            let waiting: Vec<_> = open
+                .iter()
+                .filter(|t| t.status == TaskStatus::Blocked)
+                .collect();
+            if !waiting.is_empty() {
+                section.push_str(
+                    "\nWaiting on external input (do not work on these until unblocked):\n",
+                );
+                for task in waiting {
+                    section.push_str(&format!(
+                        "- [waiting] [P{}] {} ({}){} — reason: {}\n",
+                        task.priority,
+                        task.title,
+                        task.id,
+                        task.key
+                            .as_deref()
+                            .map(|key| format!(" — key: {key}"))
+                            .unwrap_or_default(),
+                        task.blocked_reason.as_deref().unwrap_or("unspecified")
+                    ));
+                }
+            }
         }
         section.push_str("</ready-tasks>\n\n");
 
@@ -1792,14 +2253,7 @@ impl EventLoop {
         let mut entrypoint_hat_ids = Vec::new();
         let mut progressed_hat_ids = Vec::new();
         for event in events {
-            // Prefer direct event target over topic-based lookup
-            let hat_id = if let Some(target) = &event.target
-                && self.registry.get(target).is_some()
-            {
-                target.clone()
-            } else if let Some(hat) = self.registry.get_for_topic(event.topic.as_str()) {
-                hat.id.clone()
-            } else {
+            let Some(hat_id) = self.hat_for_event(event) else {
                 continue;
             };
 
@@ -1824,6 +2278,33 @@ impl EventLoop {
         }
     }
 
+    /// Resolves the hat an event activates, preferring a direct event target
+    /// over topic-based lookup.
+    fn hat_for_event(&self, event: &Event) -> Option<HatId> {
+        if let Some(target) = &event.target
+            && self.registry.get(target).is_some()
+        {
+            Some(target.clone())
+        } else {
+            self.registry
+                .get_for_topic(event.topic.as_str())
+                .map(|hat| hat.id.clone())
+        }
+    }
+
+    /// Records which topic activated each active hat, for cycle detection.
+    fn record_cycle_activations(&mut self, active_hat_ids: &[HatId], events: &[Event]) {
+        let window = self.config.event_loop.cycle_window;
+        for event in events {
+            if let Some(hat_id) = self.hat_for_event(event)
+                && active_hat_ids.contains(&hat_id)
+            {
+                self.state
+                    .record_activation(&hat_id, event.topic.as_str(), window);
+            }
+        }
+    }
+
     fn effective_regular_events<'a>(&self, events: &'a [Event]) -> Vec<&'a Event> {
         let has_downstream_event = events
             .iter()
@@ -1861,7 +2342,9 @@ impl EventLoop {
     ///
     /// For top-level prompts (task.start, task.resume), wraps the payload in
     /// `<top-level-prompt>` XML tags to clearly delineate the user's original request.
-    fn format_event(event: &Event) -> String {
+    /// Other payloads longer than `max_payload_bytes` (`0` = unlimited) are cut
+    /// with a "(truncated, N bytes omitted)" marker; top-level prompts are kept whole.
+    fn format_event(event: &Event, max_payload_bytes: usize) -> String {
         let topic = &event.topic;
         let payload = &event.payload;
 
@@ -1870,6 +2353,14 @@ impl EventLoop {
                 "Event: {} - <top-level-prompt>\n{}\n</top-level-prompt>",
                 topic, payload
             )
+        } else if max_payload_bytes > 0 && payload.len() > max_payload_bytes {
+            let end = crate::text::floor_char_boundary(payload, max_payload_bytes);
+            format!(
+                "Event: {} - {}... (truncated, {} bytes omitted)",
+                topic,
+                &payload[..end],
+                payload.len() - end
+            )
         } else {
             format!("Event: {} - {}", topic, payload)
         }
@@ -1914,6 +2405,16 @@ impl EventLoop {
             "Hat exhausted (max_activations reached)"
         );
 
+        self.diagnostics.log_orchestration(
+            self.state.iteration,
+            "loop",
+            crate::diagnostics::OrchestrationEvent::HatExhausted {
+                hat: hat_id.to_string(),
+                max_activations: max,
+                activations: count,
+            },
+        );
+
         (
             true,
             Some(Event::new(
@@ -1923,8 +2424,43 @@ impl EventLoop {
         )
     }
 
+    /// Returns how long to wait until a pending event can be delivered, when
+    /// every pending event is held back by a hat's `cooldown_secs`.
+    ///
+    /// Returns `None` when there is work to do now or nothing is pending.
+    pub fn cooldown_wait(&self) -> Option<Duration> {
+        if self.registry.is_empty()
+            || self.bus.has_human_pending()
+            || !self.pending_backpressure_checks.is_empty()
+        {
+            return None;
+        }
+
+        let mut wait: Option<Duration> = None;
+        for id in self.bus.hat_ids() {
+            if self.bus.peek_pending(id).is_none_or(Vec::is_empty) {
+                continue;
+            }
+            let remaining = self.hat_cooldown_remaining(id)?;
+            wait = Some(wait.map_or(remaining, |w| w.min(remaining)));
+        }
+        wait
+    }
+
+    /// Returns how long a hat must still wait before re-activating, if its
+    /// `cooldown_secs` has not elapsed since its last activation.
+    fn hat_cooldown_remaining(&self, hat_id: &HatId) -> Option<Duration> {
+        let cooldown = Duration::from_secs(self.registry.get_config(hat_id)?.cooldown_secs?);
+        let last = self.state.hat_last_activated.get(hat_id)?;
+        cooldown
+            .checked_sub(last.elapsed())
+            .filter(|d| !d.is_zero())
+    }
+
     fn record_hat_activations(&mut self, active_hat_ids: &[HatId]) {
+        let now = Instant::now();
         for hat_id in active_hat_ids {
+            self.state.hat_last_activated.insert(hat_id.clone(), now);
             *self
                 .state
                 .hat_activation_counts
@@ -2002,6 +2538,47 @@ impl EventLoop {
         &mut self.bus
     }
 
+    /// Publishes an external event into the loop, for library consumers that
+    /// drive the loop manually instead of writing to the events JSONL file.
+    ///
+    /// When `target` is set the event is handed directly to that hat;
+    /// otherwise it is routed by topic like any other event. The event is
+    /// recorded for event-chain validation and diagnostics, and observers are
+    /// notified, but JSONL-only checks (scope enforcement, backpressure) are
+    /// not applied.
+    ///
+    /// Ordering: injected events join the back of the pending queues
+    /// immediately. `process_output` does not consume events, so anything
+    /// injected before or after it is delivered by the next `build_prompt`,
+    /// after events already pending and before any read later by
+    /// `process_events_from_jsonl`.
+    pub fn inject_event(
+        &mut self,
+        topic: impl Into<String>,
+        payload: impl Into<String>,
+        target: Option<HatId>,
+    ) {
+        let mut event = Event::new(topic.into(), payload);
+        if let Some(target) = target {
+            event = event.with_target(target);
+        }
+
+        self.state.record_event(&event);
+        self.diagnostics.log_orchestration(
+            self.state.iteration,
+            "inject",
+            crate::diagnostics::OrchestrationEvent::EventPublished {
+                topic: event.topic.to_string(),
+            },
+        );
+        debug!(
+            topic = %event.topic,
+            target = ?event.target,
+            "Publishing injected event"
+        );
+        self.bus.publish(event);
+    }
+
     /// Processes output from a hat execution.
     ///
     /// Returns the termination reason if the loop should stop.
@@ -2060,9 +2637,13 @@ impl EventLoop {
         // Track failures
         if success {
             self.state.consecutive_failures = 0;
+            self.state.last_backend_error = None;
         } else {
             self.state.consecutive_failures += 1;
         }
+        if let Some(window) = self.config.event_loop.failure_window {
+            self.state.record_outcome(success, window);
+        }
 
         let _ = output;
 
@@ -2149,8 +2730,10 @@ impl EventLoop {
 
     /// Verifies all tasks in scratchpad are complete or cancelled.
     ///
+    /// Checkboxes may use `-`, `*`, or `+` bullets; see [`is_pending_checkbox`].
+    ///
     /// Returns:
-    /// - `Ok(true)` if all tasks are `[x]` or `[~]`, or if scratchpad is disabled
+    /// - `Ok(true)` if all tasks are `[x]`/`[X]`, `[~]`, or `[-]`, or if scratchpad is disabled
     /// - `Ok(false)` if any tasks are `[ ]` (pending)
     /// - `Err(...)` if scratchpad doesn't exist or can't be read
     fn verify_scratchpad_complete(&self) -> Result<bool, std::io::Error> {
@@ -2170,9 +2753,7 @@ impl EventLoop {
 
         let content = std::fs::read_to_string(scratchpad_path)?;
 
-        let has_pending = content
-            .lines()
-            .any(|line| line.trim_start().starts_with("- [ ]"));
+        let has_pending = content.lines().any(is_pending_checkbox);
 
         Ok(!has_pending)
     }
@@ -2276,6 +2857,187 @@ impl EventLoop {
         vec![]
     }
 
+    /// Builds a deferred `features.backpressure_command` check for a
+    /// `build.done` that lacks evidence, or `None` when no command is configured.
+    fn backpressure_check_for(&self, payload: &str) -> Option<BackpressureCheck> {
+        let command = self
+            .config
+            .features
+            .backpressure_command
+            .as_deref()
+            .map(str::trim)
+            .filter(|c| !c.is_empty())?;
+
+        Some(BackpressureCheck {
+            payload: payload.to_string(),
+            command: command.to_string(),
+            timeout: Duration::from_secs(self.config.features.backpressure_command_timeout_secs),
+            workspace_root: self.config.core.workspace_root.clone(),
+        })
+    }
+
+    /// Takes the `build.done` events waiting on `features.backpressure_command`.
+    ///
+    /// The caller runs each check (see [`BackpressureCheck::run`]) and passes
+    /// the outcome to [`EventLoop::resolve_backpressure_check`].
+    pub fn take_backpressure_checks(&mut self) -> Vec<BackpressureCheck> {
+        std::mem::take(&mut self.pending_backpressure_checks)
+    }
+
+    /// Publishes the result of a deferred `features.backpressure_command` check.
+    ///
+    /// A passing command publishes the held `build.done` with
+    /// `backpressure_command: pass` appended; any other outcome publishes
+    /// `build.blocked` with the tail of the command's output. Interrupted
+    /// checks are dropped.
+    ///
+    /// Returns the published event so the caller can record it in the event
+    /// history.
+    pub fn resolve_backpressure_check(
+        &mut self,
+        check: BackpressureCheck,
+        outcome: BackpressureCheckOutcome,
+    ) -> Option<Event> {
+        let command = &check.command;
+        let (reason, output_tail) = match outcome {
+            BackpressureCheckOutcome::Passed => {
+                info!(command = %command, "backpressure_command passed, accepting build.done");
+                let event = Event::new(
+                    "build.done",
+                    format!(
+                        "{}\n\nbackpressure_command: pass (`{command}`)",
+                        check.payload
+                    ),
+                );
+                return Some(self.publish_backpressure_result(event));
+            }
+            BackpressureCheckOutcome::Interrupted => {
+                debug!(command = %command, "backpressure_command interrupted");
+                return None;
+            }
+            BackpressureCheckOutcome::Failed {
+                status,
+                output_tail,
+            } => (
+                format!("backpressure_command failed ({status})"),
+                output_tail,
+            ),
+            BackpressureCheckOutcome::TimedOut { output_tail } => (
+                format!(
+                    "backpressure_command timed out after {}s",
+                    check.timeout.as_secs()
+                ),
+                output_tail,
+            ),
+            BackpressureCheckOutcome::Error(e) => (
+                format!("backpressure_command could not run: {e}"),
+                String::new(),
+            ),
+        };
+        warn!(command = %command, "{reason}, build.done stays blocked");
+
+        self.diagnostics.log_orchestration(
+            self.state.iteration,
+            "jsonl",
+            crate::diagnostics::OrchestrationEvent::BackpressureTriggered {
+                reason: reason.clone(),
+            },
+        );
+
+        let mut payload = format!(
+            "Missing backpressure evidence and {reason}. Fix the failures or include 'tests: pass', 'lint: pass', 'typecheck: pass', 'audit: pass', 'coverage: pass', 'complexity: <score>', 'duplication: pass' in build.done payload."
+        );
+        if !output_tail.is_empty() {
+            payload.push_str(&format!(
+                "\n\nLast output of `{command}`:\n```\n{output_tail}\n```"
+            ));
+        }
+        Some(self.publish_backpressure_result(Event::new("build.blocked", payload)))
+    }
+
+    /// Records and publishes the event a backpressure check resolved to.
+    fn publish_backpressure_result(&mut self, event: Event) -> Event {
+        self.track_blocked_events(std::slice::from_ref(&event));
+        self.state.record_event(&event);
+        self.diagnostics.log_orchestration(
+            self.state.iteration,
+            "jsonl",
+            crate::diagnostics::OrchestrationEvent::EventPublished {
+                topic: event.topic.to_string(),
+            },
+        );
+        self.bus.publish(event.clone());
+        event
+    }
+
+    /// Tracks `build.blocked` events for per-task abandonment and hat-level
+    /// thrashing detection.
+    fn track_blocked_events(&mut self, events: &[Event]) {
+        // Track build.blocked events for thrashing detection
+        let blocked_events: Vec<_> = events
+            .iter()
+            .filter(|e| e.topic == "build.blocked".into())
+            .collect();
+
+        for blocked_event in &blocked_events {
+            let task_id = Self::extract_task_id(&blocked_event.payload);
+
+            let count = self
+                .state
+                .task_block_counts
+                .entry(task_id.clone())
+                .or_insert(0);
+            *count += 1;
+
+            debug!(
+                task_id = %task_id,
+                block_count = *count,
+                "Task blocked"
+            );
+
+            // After 3 blocks on same task, emit build.task.abandoned
+            if *count >= 3 && !self.state.abandoned_tasks.contains(&task_id) {
+                warn!(
+                    task_id = %task_id,
+                    "Task abandoned after 3 consecutive blocks"
+                );
+
+                self.state.abandoned_tasks.push(task_id.clone());
+
+                self.diagnostics.log_orchestration(
+                    self.state.iteration,
+                    "jsonl",
+                    crate::diagnostics::OrchestrationEvent::TaskAbandoned {
+                        reason: format!(
+                            "3 consecutive build.blocked events for task '{}'",
+                            task_id
+                        ),
+                    },
+                );
+
+                let abandoned_event = Event::new(
+                    "build.task.abandoned",
+                    format!(
+                        "Task '{}' abandoned after 3 consecutive build.blocked events",
+                        task_id
+                    ),
+                );
+
+                self.bus.publish(abandoned_event);
+            }
+        }
+
+        // Track hat-level blocking for legacy thrashing detection
+        let has_blocked_event = !blocked_events.is_empty();
+
+        if has_blocked_event {
+            self.state.consecutive_blocked += 1;
+        } else {
+            self.state.consecutive_blocked = 0;
+            self.state.last_blocked_hat = None;
+        }
+    }
+
     fn warn_on_mutation_evidence(&self, evidence: &crate::event_parser::BackpressureEvidence) {
         let threshold = self.config.event_loop.mutation_score_warn_threshold;
 
@@ -2506,8 +3268,10 @@ impl EventLoop {
 
         // Validate and transform events (apply backpressure for build.done)
         let mut validated_events = Vec::new();
+        let mut deferred_checks = 0usize;
         let completion_topic = self.config.event_loop.completion_promise.clone();
         let cancellation_topic = self.config.event_loop.cancellation_promise.clone();
+        let until_topic = self.config.event_loop.until_event.clone();
         let total_events = events.len();
         for (index, event) in events.into_iter().enumerate() {
             let payload = event.payload.clone().unwrap_or_default();
@@ -2523,6 +3287,15 @@ impl EventLoop {
                 continue;
             }
 
+            // Detect the --until-event milestone; the event is still routed as usual
+            if until_topic.as_deref() == Some(event.topic.as_str()) {
+                info!(
+                    topic = %event.topic,
+                    "Target event detected — scheduling termination after this iteration"
+                );
+                self.state.target_event_reached = true;
+            }
+
             if event.topic.as_str() == "human.guidance" {
                 self.state.unacknowledged_guidance.push(payload.clone());
                 validated_events.push(Event::new(event.topic.as_str(), &payload));
@@ -2625,6 +3398,10 @@ impl EventLoop {
                             "Backpressure checks failed. Fix tests/lint/typecheck/audit/coverage/complexity/duplication/specs before emitting build.done.",
                         ));
                     }
+                } else if let Some(check) = self.backpressure_check_for(&payload) {
+                    // No evidence: hold the event until the configured command runs
+                    self.pending_backpressure_checks.push(check);
+                    deferred_checks += 1;
                 } else {
                     // No evidence found - synthesize build.blocked
                     warn!("build.done rejected: missing backpressure evidence");
@@ -2748,68 +3525,13 @@ impl EventLoop {
             }
         }
 
-        // Track build.blocked events for thrashing detection
-        let blocked_events: Vec<_> = validated_events
-            .iter()
-            .filter(|e| e.topic == "build.blocked".into())
-            .collect();
-
-        for blocked_event in &blocked_events {
-            let task_id = Self::extract_task_id(&blocked_event.payload);
-
-            let count = self
-                .state
-                .task_block_counts
-                .entry(task_id.clone())
-                .or_insert(0);
-            *count += 1;
-
-            debug!(
-                task_id = %task_id,
-                block_count = *count,
-                "Task blocked"
-            );
-
-            // After 3 blocks on same task, emit build.task.abandoned
-            if *count >= 3 && !self.state.abandoned_tasks.contains(&task_id) {
-                warn!(
-                    task_id = %task_id,
-                    "Task abandoned after 3 consecutive blocks"
-                );
-
-                self.state.abandoned_tasks.push(task_id.clone());
-
-                self.diagnostics.log_orchestration(
-                    self.state.iteration,
-                    "jsonl",
-                    crate::diagnostics::OrchestrationEvent::TaskAbandoned {
-                        reason: format!(
-                            "3 consecutive build.blocked events for task '{}'",
-                            task_id
-                        ),
-                    },
-                );
-
-                let abandoned_event = Event::new(
-                    "build.task.abandoned",
-                    format!(
-                        "Task '{}' abandoned after 3 consecutive build.blocked events",
-                        task_id
-                    ),
-                );
-
-                self.bus.publish(abandoned_event);
-            }
-        }
-
-        // Track hat-level blocking for legacy thrashing detection
-        let has_blocked_event = !blocked_events.is_empty();
-
-        if has_blocked_event {
-            self.state.consecutive_blocked += 1;
-        } else {
-            self.state.consecutive_blocked = 0;
-            self.state.last_blocked_hat = None;
+        // A held build.done settles the blocked streak once its check resolves
+        if deferred_checks == 0
+            || validated_events
+                .iter()
+                .any(|e| e.topic == "build.blocked".into())
+        {
+            self.track_blocked_events(&validated_events);
         }
 
         // Handle human.interact blocking behavior:
@@ -3060,7 +3782,8 @@ impl EventLoop {
         }
 
         // Track whether any events will be published (before the loop consumes them).
-        let had_events = !validated_events.is_empty();
+        // Held build.done events count: they are published once their check resolves.
+        let had_events = !validated_events.is_empty() || deferred_checks > 0;
         let had_plan_events = validated_events
             .iter()
             .any(|event| event.topic.as_str().starts_with("plan."));
@@ -3407,20 +4130,15 @@ pub struct UserPrompt {
     pub text: String,
 }
 
-/// Formats a duration as human-readable string.
-fn format_duration(d: Duration) -> String {
-    let total_secs = d.as_secs();
-    let hours = total_secs / 3600;
-    let minutes = (total_secs % 3600) / 60;
-    let seconds = total_secs % 60;
-
-    if hours > 0 {
-        format!("{}h {}m {}s", hours, minutes, seconds)
-    } else if minutes > 0 {
-        format!("{}m {}s", minutes, seconds)
-    } else {
-        format!("{}s", seconds)
-    }
+/// Returns true if the line is a pending scratchpad checkbox (`[ ]`).
+///
+/// Accepts `-`, `*`, and `+` bullets. Every other marker counts as settled:
+/// `[x]`/`[X]` are done, `[~]` and `[-]` are cancelled.
+fn is_pending_checkbox(line: &str) -> bool {
+    let Some(rest) = line.trim_start().strip_prefix(['-', '*', '+']) else {
+        return false;
+    };
+    rest.starts_with(char::is_whitespace) && rest.trim_start().starts_with("[ ]")
 }
 
 /// Returns a human-readable status based on termination reason.
@@ -3435,13 +4153,15 @@ fn termination_status_text(reason: &TerminationReason) -> &'static str {
             "Loop thrashing detected - same hat repeatedly blocked."
         }
         TerminationReason::LoopStale => {
-            "Stale loop detected - same topic emitted 3+ times consecutively."
+            "Stale loop detected - same topic emitted repeatedly in a row."
         }
         TerminationReason::ValidationFailure => "Too many consecutive malformed JSONL events.",
-        TerminationReason::Stopped => "Manually stopped.",
+        TerminationReason::Stopped => "Stopped without completing.",
+        TerminationReason::UserStop => "Stopped by user request.",
         TerminationReason::Interrupted => "Interrupted by signal.",
         TerminationReason::RestartRequested => "Restarting by human request.",
         TerminationReason::WorkspaceGone => "Workspace directory removed externally.",
         TerminationReason::Cancelled => "Cancelled gracefully (human rejection or timeout).",
+        TerminationReason::TargetEventReached => "Stopped after the target event was published.",
     }
 }
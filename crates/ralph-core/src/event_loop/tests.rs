@@ -35,7 +35,11 @@ hats:
 
 #[test]
 fn test_guidance_persists_across_iterations_solo_mode() {
-    let config = RalphConfig::default();
+    // Guidance is persisted to the scratchpad under workspace_root; keep it
+    // out of the crate directory.
+    let dir = tempfile::tempdir().unwrap();
+    let mut config = RalphConfig::default();
+    config.core.workspace_root = dir.path().to_path_buf();
     let mut event_loop = EventLoop::new(config);
     let ralph_id = HatId::new("ralph");
 
@@ -73,7 +77,9 @@ hats:
     triggers: ["task.start"]
     publishes: ["task.plan"]
 "#;
-    let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+    let dir = tempfile::tempdir().unwrap();
+    let mut config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+    config.core.workspace_root = dir.path().to_path_buf();
     let mut event_loop = EventLoop::new(config);
     let ralph_id = HatId::new("ralph");
 
@@ -173,6 +179,264 @@ core:
     );
 }
 
+#[test]
+fn test_inject_event_routes_by_topic_and_target() {
+    let yaml = r#"
+hats:
+  builder:
+    name: "Builder"
+    description: "Builds things"
+    triggers: ["build.task"]
+    publishes: ["build.done"]
+  reviewer:
+    name: "Reviewer"
+    description: "Reviews things"
+    triggers: ["review.request"]
+    publishes: ["review.done"]
+"#;
+    let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+    let mut event_loop = EventLoop::new(config);
+    let builder_id = HatId::new("builder");
+    let reviewer_id = HatId::new("reviewer");
+
+    event_loop.inject_event("build.task", "from embedder", None);
+    event_loop.inject_event("external.note", "direct handoff", Some(reviewer_id.clone()));
+
+    assert!(
+        event_loop
+            .bus
+            .peek_pending(&builder_id)
+            .is_some_and(|events| events.iter().any(|e| e.payload == "from embedder")),
+        "Untargeted event should route to the subscribed hat"
+    );
+    assert!(
+        event_loop
+            .bus
+            .peek_pending(&reviewer_id)
+            .is_some_and(|events| events.iter().any(|e| e.payload == "direct handoff")),
+        "Targeted event should be delivered to its target"
+    );
+    assert!(event_loop.state.seen_topics.contains("build.task"));
+    assert!(event_loop.has_pending_events());
+}
+
+#[test]
+fn test_hat_cooldown_keeps_events_queued_until_elapsed() {
+    let yaml = r#"
+hats:
+  executor:
+    name: "Executor"
+    description: "Implements requested changes"
+    triggers: ["work.start"]
+    publishes: ["implementation.done"]
+  code_reviewer:
+    name: "Code Reviewer"
+    description: "Reviews changes"
+    triggers: ["implementation.done"]
+    publishes: ["review.done"]
+    cooldown_secs: 3600
+"#;
+    let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+    assert_eq!(
+        config.hats["code_reviewer"].cooldown_secs,
+        Some(3600),
+        "cooldown_secs should parse from YAML"
+    );
+    let mut event_loop = EventLoop::new(config);
+    let ralph = HatId::new("ralph");
+    let reviewer_id = HatId::new("code_reviewer");
+
+    // First activation is never throttled.
+    event_loop
+        .bus
+        .publish(Event::new("implementation.done", "first"));
+    let prompt = event_loop.build_prompt(&ralph).unwrap();
+    assert!(prompt.contains("first"), "First activation should run");
+    assert!(
+        event_loop
+            .state
+            .hat_last_activated
+            .contains_key(&reviewer_id)
+    );
+
+    // A second trigger inside the cooldown window stays queued, and no
+    // iteration is dispatched while it is the only pending work.
+    event_loop
+        .bus
+        .publish(Event::new("implementation.done", "second"));
+    let wait = event_loop
+        .cooldown_wait()
+        .expect("runner should wait out the cooldown");
+    assert!(wait > Duration::from_secs(3500));
+    assert!(
+        event_loop.build_prompt(&ralph).is_none(),
+        "Reviewer should not re-activate during cooldown"
+    );
+    assert!(
+        event_loop
+            .bus
+            .peek_pending(&reviewer_id)
+            .is_some_and(|events| events.iter().any(|e| e.payload == "second")),
+        "Suppressed event should be re-queued, not dropped"
+    );
+
+    // Once the cooldown has elapsed the queued event is delivered.
+    event_loop.state.hat_last_activated.remove(&reviewer_id);
+    assert!(event_loop.cooldown_wait().is_none());
+    let prompt = event_loop.build_prompt(&ralph).unwrap();
+    assert!(
+        prompt.contains("second"),
+        "Queued event should be delivered after cooldown"
+    );
+}
+
+#[test]
+fn test_max_same_topic_controls_stale_loop_threshold() {
+    let stale_after = |max_same_topic: u32| {
+        let mut config = RalphConfig::default();
+        config.event_loop.max_same_topic = max_same_topic;
+        let mut event_loop = EventLoop::new(config);
+        for published in 1..=10 {
+            event_loop
+                .state
+                .record_event(&Event::new("build.task", "same task"));
+            if event_loop.check_termination() == Some(TerminationReason::LoopStale) {
+                return published;
+            }
+        }
+        panic!("loop never went stale");
+    };
+
+    // Default keeps the historical behavior of stopping on the third repeat
+    assert_eq!(
+        stale_after(RalphConfig::default().event_loop.max_same_topic),
+        3
+    );
+    assert_eq!(stale_after(1), 2);
+    assert_eq!(stale_after(5), 6);
+}
+
+/// Runs the next hat and feeds `topics` back the way `ralph emit` does:
+/// appended to the events JSONL, then picked up after `process_output`.
+fn run_hat_emitting(
+    event_loop: &mut EventLoop,
+    events_path: &std::path::Path,
+    topics: &[&str],
+) -> Option<TerminationReason> {
+    let hat = event_loop
+        .next_hat()
+        .cloned()
+        .expect("a hat has pending work");
+    let _ = event_loop.build_prompt(&hat).unwrap();
+    for topic in topics {
+        write_event_to_jsonl(events_path, topic, "payload");
+    }
+    let reason = event_loop.process_output(&hat, "", true);
+    let _ = event_loop.process_events_from_jsonl();
+    reason.or_else(|| event_loop.check_termination())
+}
+
+/// Runs one executor -> reviewer round; the reviewer emits `review_topics`.
+fn run_review_round(
+    event_loop: &mut EventLoop,
+    events_path: &std::path::Path,
+    review_topics: &[&str],
+) -> Option<TerminationReason> {
+    run_hat_emitting(event_loop, events_path, &["implementation.done"])
+        .or_else(|| run_hat_emitting(event_loop, events_path, review_topics))
+}
+
+const PING_PONG_YAML: &str = r#"
+event_loop:
+  max_cycle_repeats: 3
+hats:
+  executor:
+    name: "Executor"
+    description: "Implements requested changes"
+    triggers: ["work.start", "review.changes_requested"]
+    publishes: ["implementation.done"]
+  code_reviewer:
+    name: "Code Reviewer"
+    description: "Reviews changes and requests fixes"
+    triggers: ["implementation.done"]
+    publishes: ["review.changes_requested", "review.notes"]
+"#;
+
+fn ping_pong_loop(temp_dir: &tempfile::TempDir) -> (EventLoop, std::path::PathBuf) {
+    let config: RalphConfig = serde_yaml::from_str(PING_PONG_YAML).unwrap();
+    let mut event_loop = EventLoop::new(config);
+    let events_path = temp_dir.path().join("events.jsonl");
+    event_loop.event_reader = crate::event_reader::EventReader::new(&events_path);
+
+    write_event_to_jsonl(&events_path, "work.start", "begin");
+    let _ = event_loop.process_events_from_jsonl();
+    (event_loop, events_path)
+}
+
+#[test]
+fn test_hat_cycle_without_new_topics_terminates_as_thrashing() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let (mut event_loop, events_path) = ping_pong_loop(&temp_dir);
+
+    // executor <-> reviewer ping-pong with no new topics after the first round
+    for round in 1..=3 {
+        assert_eq!(
+            run_review_round(&mut event_loop, &events_path, &["review.changes_requested"]),
+            None,
+            "round {round}"
+        );
+    }
+    assert!(
+        event_loop
+            .state()
+            .seen_topics
+            .contains("review.changes_requested")
+    );
+
+    assert_eq!(
+        run_review_round(&mut event_loop, &events_path, &["review.changes_requested"]),
+        Some(TerminationReason::LoopThrashing)
+    );
+}
+
+#[test]
+fn test_new_topic_resets_hat_cycle_thrashing_detection() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let (mut event_loop, events_path) = ping_pong_loop(&temp_dir);
+
+    for _ in 1..=2 {
+        assert_eq!(
+            run_review_round(&mut event_loop, &events_path, &["review.changes_requested"]),
+            None
+        );
+    }
+
+    // Same hats and activating topics as a thrashing round, but the reviewer
+    // also emits a topic the loop hasn't seen, so the workflow progressed
+    assert_eq!(
+        run_review_round(
+            &mut event_loop,
+            &events_path,
+            &["review.notes", "review.changes_requested"]
+        ),
+        None
+    );
+    assert!(event_loop.state().seen_topics.contains("review.notes"));
+
+    // Detection starts over: it takes another full run of repeats to trip
+    for round in 1..=2 {
+        assert_eq!(
+            run_review_round(&mut event_loop, &events_path, &["review.changes_requested"]),
+            None,
+            "round {round} after the new topic"
+        );
+    }
+    assert_eq!(
+        run_review_round(&mut event_loop, &events_path, &["review.changes_requested"]),
+        Some(TerminationReason::LoopThrashing)
+    );
+}
+
 #[test]
 fn test_hat_max_activations_emits_exhausted_event() {
     // Repro for issue #66: per-hat max_activations should prevent infinite reviewer loops.
@@ -789,9 +1053,11 @@ fn test_exit_codes_per_spec() {
     // - 2: Max iterations, max runtime, or max cost exceeded (limit)
     // - 130: User interrupt (SIGINT = 128 + 2)
     assert_eq!(TerminationReason::CompletionPromise.exit_code(), 0);
+    assert_eq!(TerminationReason::TargetEventReached.exit_code(), 0);
     assert_eq!(TerminationReason::ConsecutiveFailures.exit_code(), 1);
     assert_eq!(TerminationReason::LoopThrashing.exit_code(), 1);
     assert_eq!(TerminationReason::Stopped.exit_code(), 1);
+    assert_eq!(TerminationReason::UserStop.exit_code(), 0);
     assert_eq!(TerminationReason::MaxIterations.exit_code(), 2);
     assert_eq!(TerminationReason::MaxRuntime.exit_code(), 2);
     assert_eq!(TerminationReason::MaxCost.exit_code(), 2);
@@ -1342,6 +1608,7 @@ fn test_default_publishes_injects_when_no_events() {
         crate::config::HatConfig {
             name: "test-hat".to_string(),
             description: Some("Test hat for default publishes".to_string()),
+            emoji: None,
             triggers: vec!["task.start".to_string()],
             publishes: vec!["task.done".to_string()],
             instructions: "Test hat".to_string(),
@@ -1350,6 +1617,7 @@ fn test_default_publishes_injects_when_no_events() {
             backend: None,
             default_publishes: Some("task.done".to_string()),
             max_activations: None,
+            cooldown_secs: None,
             scratchpad: None,
             disallowed_tools: vec![],
             timeout: None,
@@ -1399,6 +1667,7 @@ fn test_default_publishes_completion_promise_requires_explicit_agent_event() {
         crate::config::HatConfig {
             name: "final-reviewer".to_string(),
             description: Some("Final reviewer".to_string()),
+            emoji: None,
             triggers: vec!["review.ready".to_string()],
             publishes: vec!["LOOP_COMPLETE".to_string()],
             instructions: "Verify the objective is complete".to_string(),
@@ -1407,6 +1676,7 @@ fn test_default_publishes_completion_promise_requires_explicit_agent_event() {
             backend: None,
             default_publishes: Some("LOOP_COMPLETE".to_string()),
             max_activations: None,
+            cooldown_secs: None,
             scratchpad: None,
             disallowed_tools: vec![],
             timeout: None,
@@ -1539,6 +1809,7 @@ fn test_default_publishes_not_injected_when_events_written() {
         crate::config::HatConfig {
             name: "test-hat".to_string(),
             description: Some("Test hat for default publishes".to_string()),
+            emoji: None,
             triggers: vec!["task.start".to_string()],
             publishes: vec!["task.done".to_string()],
             instructions: "Test hat".to_string(),
@@ -1547,6 +1818,7 @@ fn test_default_publishes_not_injected_when_events_written() {
             backend: None,
             default_publishes: Some("task.done".to_string()),
             max_activations: None,
+            cooldown_secs: None,
             scratchpad: None,
             disallowed_tools: vec![],
             timeout: None,
@@ -1739,6 +2011,7 @@ fn test_default_publishes_skipped_when_non_orphan_event_written() {
         crate::config::HatConfig {
             name: "hat-a".to_string(),
             description: Some("Hat triggered by task.start".to_string()),
+            emoji: None,
             triggers: vec!["task.start".to_string()],
             publishes: vec!["task.done".to_string()],
             instructions: "Do the task".to_string(),
@@ -1747,6 +2020,7 @@ fn test_default_publishes_skipped_when_non_orphan_event_written() {
             backend: None,
             default_publishes: Some("task.done".to_string()),
             max_activations: None,
+            cooldown_secs: None,
             scratchpad: None,
             disallowed_tools: vec![],
             timeout: None,
@@ -1810,6 +2084,7 @@ fn test_default_publishes_not_injected_when_not_configured() {
         crate::config::HatConfig {
             name: "test-hat".to_string(),
             description: Some("Test hat for default publishes".to_string()),
+            emoji: None,
             triggers: vec!["task.start".to_string()],
             publishes: vec!["task.done".to_string()],
             instructions: "Test hat".to_string(),
@@ -1818,6 +2093,7 @@ fn test_default_publishes_not_injected_when_not_configured() {
             backend: None,
             default_publishes: None, // No default configured
             max_activations: None,
+            cooldown_secs: None,
             scratchpad: None,
             disallowed_tools: vec![],
             timeout: None,
@@ -2305,6 +2581,30 @@ hats:
     );
 }
 
+#[test]
+fn test_peek_pending_summary_does_not_consume() {
+    let yaml = r#"
+hats:
+  security_reviewer:
+    name: "Security Reviewer"
+    triggers: ["review.security"]
+"#;
+    let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+    let mut event_loop = EventLoop::new(config);
+
+    event_loop
+        .bus
+        .publish(Event::new("review.security", "Check authentication"));
+
+    let expected = vec![(
+        HatId::new("security_reviewer"),
+        vec!["review.security".to_string()],
+    )];
+    assert_eq!(event_loop.peek_pending_summary(), expected);
+    assert_eq!(event_loop.peek_pending_summary(), expected);
+    assert_eq!(event_loop.get_active_hat_id().as_str(), "security_reviewer");
+}
+
 #[test]
 fn test_get_active_hat_id_no_pending_returns_ralph() {
     // Create EventLoop with hats but NO pending events
@@ -2749,6 +3049,93 @@ fn test_consecutive_failures_resets_on_success() {
     assert_eq!(event_loop.state.consecutive_failures, 0);
 }
 
+#[test]
+fn test_iteration_observers_receive_iteration_and_hat() {
+    let mut event_loop = EventLoop::new(RalphConfig::default());
+    let seen = Arc::new(std::sync::Mutex::new(Vec::new()));
+
+    for _ in 0..2 {
+        let seen = Arc::clone(&seen);
+        event_loop.add_iteration_observer(move |iteration, hat_id| {
+            seen.lock().unwrap().push((iteration, hat_id.to_string()));
+        });
+    }
+
+    event_loop.notify_iteration_start(3, &HatId::new("builder"));
+
+    assert_eq!(
+        *seen.lock().unwrap(),
+        vec![(3, "builder".to_string()), (3, "builder".to_string())]
+    );
+}
+
+#[test]
+fn test_failure_window_terminates_on_alternating_failures() {
+    let yaml = r"
+event_loop:
+  max_consecutive_failures: 3
+  failure_window: 6
+";
+    let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+    let mut event_loop = EventLoop::new(config);
+    event_loop.initialize("Test");
+
+    let ralph = HatId::new("ralph");
+
+    // fail, ok, fail, ok: never 3 in a row, only 2 in the window so far
+    for success in [false, true, false, true] {
+        event_loop.process_output(&ralph, "output", success);
+        assert_eq!(event_loop.check_termination(), None);
+    }
+
+    event_loop.process_output(&ralph, "output", false);
+    assert_eq!(event_loop.state.consecutive_failures, 1);
+    assert_eq!(
+        event_loop.check_termination(),
+        Some(TerminationReason::ConsecutiveFailures)
+    );
+}
+
+#[test]
+fn test_failure_window_drops_old_failures() {
+    let yaml = r"
+event_loop:
+  max_consecutive_failures: 2
+  failure_window: 3
+";
+    let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+    let mut event_loop = EventLoop::new(config);
+    event_loop.initialize("Test");
+
+    let ralph = HatId::new("ralph");
+
+    // The first failure slides out of the 3-iteration window before the second
+    for success in [false, true, true, false] {
+        event_loop.process_output(&ralph, "output", success);
+    }
+    assert_eq!(event_loop.state.windowed_failures(), 1);
+    assert_eq!(event_loop.check_termination(), None);
+}
+
+#[test]
+fn test_backend_error_kept_until_next_success() {
+    let config = RalphConfig::default();
+    let mut event_loop = EventLoop::new(config);
+    event_loop.initialize("Test");
+
+    let ralph = HatId::new("ralph");
+
+    event_loop.record_backend_error("error: rate limited");
+    event_loop.process_output(&ralph, "output", false);
+    assert_eq!(
+        event_loop.state.last_backend_error.as_deref(),
+        Some("error: rate limited")
+    );
+
+    event_loop.process_output(&ralph, "output", true);
+    assert!(event_loop.state.last_backend_error.is_none());
+}
+
 #[test]
 fn test_cost_based_termination() {
     // Kills: line 383 `>=` → `<`, lines 987 `add_cost` noop / `-=` / `*=`
@@ -2873,7 +3260,7 @@ fn test_stop_requested_termination_clears_signal() {
 
     assert_eq!(
         event_loop.check_termination(),
-        Some(TerminationReason::Stopped),
+        Some(TerminationReason::UserStop),
         "Should terminate when stop requested signal exists"
     );
     assert!(
@@ -2911,6 +3298,51 @@ fn test_format_event_wraps_top_level_prompts() {
     );
 }
 
+#[test]
+fn test_format_event_truncates_large_payloads() {
+    let mut config = RalphConfig::default();
+    config.event_loop.max_event_payload_bytes = 1024;
+    let mut event_loop = EventLoop::new(config);
+    let huge_prompt = format!("start-{}", "p".repeat(4096));
+    event_loop.initialize(&huge_prompt);
+
+    let ralph = HatId::new("ralph");
+    let prompt = event_loop.build_prompt(&ralph).unwrap();
+    assert!(
+        prompt.contains(&huge_prompt),
+        "top-level prompts keep their full content"
+    );
+
+    let payload = format!("head-{}-tail", "x".repeat(3 * 1024 * 1024));
+    event_loop
+        .bus
+        .publish(Event::new("file.pasted", payload.clone()));
+    let prompt = event_loop.build_prompt(&ralph).unwrap();
+
+    assert!(prompt.contains("Event: file.pasted - head-"));
+    assert!(!prompt.contains("-tail"), "payload tail should be cut");
+    assert!(prompt.contains(&format!(
+        "(truncated, {} bytes omitted)",
+        payload.len() - 1024
+    )));
+    assert!(prompt.len() < 1024 * 1024);
+}
+
+#[test]
+fn test_format_event_truncation_respects_char_boundaries() {
+    let event = Event::new("build.done", "é".repeat(10));
+    let formatted = EventLoop::format_event(&event, 5);
+    // 5 bytes falls inside the third 'é'; cut back to a boundary
+    assert_eq!(
+        formatted,
+        "Event: build.done - éé... (truncated, 16 bytes omitted)"
+    );
+    assert_eq!(
+        EventLoop::format_event(&event, 0),
+        format!("Event: build.done - {}", "é".repeat(10))
+    );
+}
+
 #[test]
 fn test_check_ralph_completion_detection() {
     // Kills: line 1241 return `true` / `false`
@@ -3188,23 +3620,9 @@ fn test_build_done_backpressure_rejects_high_complexity() {
     );
 }
 
-#[test]
-fn test_build_done_backpressure_rejects_duplication() {
-    use tempfile::tempdir;
-
-    let temp_dir = tempdir().unwrap();
-    let events_path = temp_dir.path().join("events.jsonl");
-
-    let config = RalphConfig::default();
-    let mut event_loop = EventLoop::new(config);
-    event_loop.event_reader = crate::event_reader::EventReader::new(&events_path);
-
-    let payload = "tests: pass\nlint: pass\ntypecheck: pass\naudit: pass\ncoverage: pass\ncomplexity: 7\nduplication: fail";
-    write_event_to_jsonl(&events_path, "build.done", payload);
-    let _ = event_loop.process_events_from_jsonl();
-
+fn pending_payloads(event_loop: &EventLoop, topic: &str) -> Vec<String> {
     let empty = Vec::new();
-    let pending_topics: Vec<String> = event_loop
+    event_loop
         .bus
         .hat_ids()
         .flat_map(|id| {
@@ -3213,15 +3631,254 @@ fn test_build_done_backpressure_rejects_duplication() {
                 .peek_pending(id)
                 .unwrap_or(&empty)
                 .iter()
-                .map(|e| e.topic.to_string())
+                .filter(|e| e.topic.as_str() == topic)
+                .map(|e| e.payload.clone())
                 .collect::<Vec<_>>()
         })
-        .collect();
+        .collect()
+}
 
-    assert!(
-        pending_topics.contains(&"build.blocked".to_string()),
-        "build.done with duplication should be blocked. Got: {:?}",
-        pending_topics
+fn pending_topics(event_loop: &EventLoop) -> Vec<String> {
+    let empty = Vec::new();
+    event_loop
+        .bus
+        .hat_ids()
+        .flat_map(|id| {
+            event_loop
+                .bus
+                .peek_pending(id)
+                .unwrap_or(&empty)
+                .iter()
+                .map(|e| e.topic.to_string())
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+async fn run_backpressure_checks(event_loop: &mut EventLoop) {
+    let (_interrupt_tx, interrupt_rx) = tokio::sync::watch::channel(false);
+    for check in event_loop.take_backpressure_checks() {
+        let outcome = check.run(interrupt_rx.clone()).await;
+        event_loop.resolve_backpressure_check(check, outcome);
+    }
+}
+
+#[test]
+fn test_build_done_backpressure_command_is_deferred() {
+    use tempfile::tempdir;
+
+    let temp_dir = tempdir().unwrap();
+    let events_path = temp_dir.path().join("events.jsonl");
+
+    let mut config = RalphConfig::default();
+    config.core.workspace_root = temp_dir.path().to_path_buf();
+    config.features.backpressure_command = Some("sleep 30".to_string());
+    let mut event_loop = EventLoop::new(config);
+    event_loop.event_reader = crate::event_reader::EventReader::new(&events_path);
+
+    write_event_to_jsonl(&events_path, "build.done", "Implemented the feature");
+    let started = std::time::Instant::now();
+    let processed = event_loop.process_events_from_jsonl().unwrap();
+
+    // Event processing must not run the command itself
+    assert!(started.elapsed() < std::time::Duration::from_secs(5));
+    assert!(processed.had_events);
+    assert!(event_loop.has_pending_events());
+    let topics = pending_topics(&event_loop);
+    assert!(!topics.contains(&"build.done".to_string()));
+    assert!(!topics.contains(&"build.blocked".to_string()));
+
+    let checks = event_loop.take_backpressure_checks();
+    assert_eq!(checks.len(), 1);
+    assert_eq!(checks[0].payload, "Implemented the feature");
+    assert!(!event_loop.has_pending_events());
+}
+
+#[tokio::test]
+async fn test_build_done_backpressure_command_interrupt_drops_check() {
+    use tempfile::tempdir;
+
+    let temp_dir = tempdir().unwrap();
+    let events_path = temp_dir.path().join("events.jsonl");
+
+    let mut config = RalphConfig::default();
+    config.core.workspace_root = temp_dir.path().to_path_buf();
+    config.features.backpressure_command = Some("sleep 30".to_string());
+    let mut event_loop = EventLoop::new(config);
+    event_loop.event_reader = crate::event_reader::EventReader::new(&events_path);
+
+    write_event_to_jsonl(&events_path, "build.done", "Implemented the feature");
+    let _ = event_loop.process_events_from_jsonl();
+
+    let (interrupt_tx, interrupt_rx) = tokio::sync::watch::channel(false);
+    let check = event_loop.take_backpressure_checks().remove(0);
+    let started = std::time::Instant::now();
+    let run = check.run(interrupt_rx);
+    let interrupt = async {
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        interrupt_tx.send(true).unwrap();
+    };
+    let (outcome, ()) = tokio::join!(run, interrupt);
+
+    assert!(started.elapsed() < std::time::Duration::from_secs(10));
+    assert!(matches!(outcome, BackpressureCheckOutcome::Interrupted));
+    assert!(
+        event_loop
+            .resolve_backpressure_check(check, outcome)
+            .is_none()
+    );
+    assert!(pending_topics(&event_loop).is_empty());
+}
+
+#[tokio::test]
+async fn test_build_done_backpressure_command_records_only_its_result() {
+    use tempfile::tempdir;
+
+    let temp_dir = tempdir().unwrap();
+    let events_path = temp_dir.path().join("events.jsonl");
+
+    let mut config = RalphConfig::default();
+    config.core.workspace_root = temp_dir.path().to_path_buf();
+    config.features.backpressure_command = Some("test -d .".to_string());
+    let mut event_loop = EventLoop::new(config);
+    event_loop.event_reader = crate::event_reader::EventReader::new(&events_path);
+
+    write_event_to_jsonl(&events_path, "build.done", "Implemented the feature");
+    let _ = event_loop.process_events_from_jsonl();
+    run_backpressure_checks(&mut event_loop).await;
+
+    let topics = pending_topics(&event_loop);
+    assert!(
+        topics.contains(&"build.done".to_string()),
+        "build.done should be accepted when backpressure_command passes. Got: {:?}",
+        topics
+    );
+    assert!(!topics.contains(&"build.blocked".to_string()));
+
+    let payloads = pending_payloads(&event_loop, "build.done");
+    assert_eq!(payloads.len(), 1);
+    assert!(payloads[0].contains("backpressure_command: pass"));
+    assert!(
+        !payloads[0].contains("lint: pass"),
+        "checks the command did not run must not be reported: {}",
+        payloads[0]
+    );
+}
+
+#[tokio::test]
+async fn test_build_done_backpressure_command_timeout_blocks() {
+    use tempfile::tempdir;
+
+    let temp_dir = tempdir().unwrap();
+    let events_path = temp_dir.path().join("events.jsonl");
+
+    let mut config = RalphConfig::default();
+    config.core.workspace_root = temp_dir.path().to_path_buf();
+    config.features.backpressure_command = Some("sleep 30".to_string());
+    config.features.backpressure_command_timeout_secs = 1;
+    let mut event_loop = EventLoop::new(config);
+    event_loop.event_reader = crate::event_reader::EventReader::new(&events_path);
+
+    write_event_to_jsonl(&events_path, "build.done", "Implemented the feature");
+    let _ = event_loop.process_events_from_jsonl();
+    let started = std::time::Instant::now();
+    run_backpressure_checks(&mut event_loop).await;
+
+    assert!(started.elapsed() < std::time::Duration::from_secs(10));
+    let topics = pending_topics(&event_loop);
+    assert!(
+        topics.contains(&"build.blocked".to_string()),
+        "build.done should stay blocked when backpressure_command times out. Got: {:?}",
+        topics
+    );
+    assert!(!topics.contains(&"build.done".to_string()));
+}
+
+#[tokio::test]
+async fn test_build_done_backpressure_command_failure_blocks() {
+    use tempfile::tempdir;
+
+    let temp_dir = tempdir().unwrap();
+    let events_path = temp_dir.path().join("events.jsonl");
+
+    let mut config = RalphConfig::default();
+    config.core.workspace_root = temp_dir.path().to_path_buf();
+    config.features.backpressure_command =
+        Some("echo 'tests: 3 failed'; echo 'error: lint' >&2; exit 1".to_string());
+    let mut event_loop = EventLoop::new(config);
+    event_loop.event_reader = crate::event_reader::EventReader::new(&events_path);
+
+    write_event_to_jsonl(&events_path, "build.done", "Implemented the feature");
+    let _ = event_loop.process_events_from_jsonl();
+    run_backpressure_checks(&mut event_loop).await;
+
+    let topics = pending_topics(&event_loop);
+    assert!(
+        topics.contains(&"build.blocked".to_string()),
+        "build.done should stay blocked when backpressure_command fails. Got: {:?}",
+        topics
+    );
+    assert!(!topics.contains(&"build.done".to_string()));
+
+    // The rejection carries what the command printed so the hat can fix it
+    let payloads = pending_payloads(&event_loop, "build.blocked");
+    assert_eq!(payloads.len(), 1);
+    assert!(payloads[0].contains("tests: 3 failed"), "{}", payloads[0]);
+    assert!(payloads[0].contains("error: lint"), "{}", payloads[0]);
+}
+
+#[tokio::test]
+async fn test_backpressure_check_keeps_only_output_tail() {
+    let check = BackpressureCheck {
+        payload: "Implemented the feature".to_string(),
+        command: "seq 1 50; exit 1".to_string(),
+        timeout: std::time::Duration::from_secs(10),
+        workspace_root: std::env::temp_dir(),
+    };
+    let (_interrupt_tx, interrupt_rx) = tokio::sync::watch::channel(false);
+
+    let BackpressureCheckOutcome::Failed { output_tail, .. } = check.run(interrupt_rx).await else {
+        panic!("a failing command should report Failed");
+    };
+    let lines: Vec<&str> = output_tail.lines().collect();
+    assert_eq!(lines.first(), Some(&"31"));
+    assert_eq!(lines.last(), Some(&"50"));
+}
+
+#[test]
+fn test_build_done_backpressure_rejects_duplication() {
+    use tempfile::tempdir;
+
+    let temp_dir = tempdir().unwrap();
+    let events_path = temp_dir.path().join("events.jsonl");
+
+    let config = RalphConfig::default();
+    let mut event_loop = EventLoop::new(config);
+    event_loop.event_reader = crate::event_reader::EventReader::new(&events_path);
+
+    let payload = "tests: pass\nlint: pass\ntypecheck: pass\naudit: pass\ncoverage: pass\ncomplexity: 7\nduplication: fail";
+    write_event_to_jsonl(&events_path, "build.done", payload);
+    let _ = event_loop.process_events_from_jsonl();
+
+    let empty = Vec::new();
+    let pending_topics: Vec<String> = event_loop
+        .bus
+        .hat_ids()
+        .flat_map(|id| {
+            event_loop
+                .bus
+                .peek_pending(id)
+                .unwrap_or(&empty)
+                .iter()
+                .map(|e| e.topic.to_string())
+                .collect::<Vec<_>>()
+        })
+        .collect();
+
+    assert!(
+        pending_topics.contains(&"build.blocked".to_string()),
+        "build.done with duplication should be blocked. Got: {:?}",
+        pending_topics
     );
     assert!(
         !pending_topics.contains(&"build.done".to_string()),
@@ -3668,6 +4325,7 @@ fn test_termination_reason_mappings() {
             false,
         ),
         (TerminationReason::Stopped, "stopped", 1, false),
+        (TerminationReason::UserStop, "user_stop", 0, false),
         (TerminationReason::Interrupted, "interrupted", 130, false),
         (
             TerminationReason::RestartRequested,
@@ -3709,7 +4367,8 @@ fn test_termination_status_texts() {
             TerminationReason::ValidationFailure,
             "Too many consecutive malformed JSONL events.",
         ),
-        (TerminationReason::Stopped, "Manually stopped."),
+        (TerminationReason::Stopped, "Stopped without completing."),
+        (TerminationReason::UserStop, "Stopped by user request."),
         (TerminationReason::Interrupted, "Interrupted by signal."),
         (
             TerminationReason::RestartRequested,
@@ -3885,6 +4544,27 @@ fn test_verify_tasks_complete_missing_and_pending() {
     assert!(!event_loop.verify_tasks_complete().unwrap());
 }
 
+#[test]
+fn test_verify_tasks_complete_treats_blocked_as_incomplete() {
+    use crate::loop_context::LoopContext;
+    use crate::task::Task;
+    use crate::task_store::TaskStore;
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let loop_context = LoopContext::primary(temp_dir.path().to_path_buf());
+    let event_loop = EventLoop::with_context(RalphConfig::default(), loop_context);
+
+    let tasks_path = temp_dir.path().join(".ralph/agent/tasks.jsonl");
+    let mut store = TaskStore::load(&tasks_path).unwrap();
+    let task = Task::new("Needs human".to_string(), 1);
+    let id = task.id.clone();
+    store.add(task);
+    store.block(&id, "Awaiting design review");
+    store.save().unwrap();
+
+    assert!(!event_loop.verify_tasks_complete().unwrap());
+}
+
 #[test]
 fn test_verify_scratchpad_complete_variants() {
     use crate::loop_context::LoopContext;
@@ -3905,6 +4585,46 @@ fn test_verify_scratchpad_complete_variants() {
     assert!(event_loop.verify_scratchpad_complete().unwrap());
 }
 
+#[test]
+fn test_verify_scratchpad_complete_alternate_checkbox_styles() {
+    use crate::loop_context::LoopContext;
+    use std::fs;
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let loop_context = LoopContext::primary(temp_dir.path().to_path_buf());
+    let event_loop = EventLoop::with_context(RalphConfig::default(), loop_context);
+    let scratchpad_path = temp_dir.path().join(".ralph/agent/scratchpad.md");
+    fs::create_dir_all(scratchpad_path.parent().unwrap()).unwrap();
+
+    for pending in ["* [ ] Star", "+ [ ] Plus", "  - [ ] Nested"] {
+        fs::write(&scratchpad_path, format!("## Tasks\n{pending}\n")).unwrap();
+        assert!(
+            !event_loop.verify_scratchpad_complete().unwrap(),
+            "{pending:?} should be pending"
+        );
+    }
+
+    fs::write(
+        &scratchpad_path,
+        "## Tasks\n- [X] Upper\n* [x] Star done\n+ [~] Plus cancelled\n- [-] Dash cancelled\n",
+    )
+    .unwrap();
+    assert!(event_loop.verify_scratchpad_complete().unwrap());
+}
+
+#[test]
+fn test_is_pending_checkbox() {
+    assert!(is_pending_checkbox("- [ ] task"));
+    assert!(is_pending_checkbox("*  [ ] task"));
+    assert!(is_pending_checkbox("\t+ [ ] task"));
+    assert!(!is_pending_checkbox("- [x] task"));
+    assert!(!is_pending_checkbox("- [X] task"));
+    assert!(!is_pending_checkbox("- [~] task"));
+    assert!(!is_pending_checkbox("- [-] task"));
+    assert!(!is_pending_checkbox("-[ ] no space"));
+    assert!(!is_pending_checkbox("[ ] no bullet"));
+}
+
 #[test]
 fn test_termination_reason_exit_codes() {
     let cases = [
@@ -3913,6 +4633,7 @@ fn test_termination_reason_exit_codes() {
         (TerminationReason::LoopThrashing, 1),
         (TerminationReason::ValidationFailure, 1),
         (TerminationReason::Stopped, 1),
+        (TerminationReason::UserStop, 0),
         (TerminationReason::MaxIterations, 2),
         (TerminationReason::MaxRuntime, 2),
         (TerminationReason::MaxCost, 2),
@@ -3944,6 +4665,7 @@ fn test_termination_reason_strings_and_flags() {
             false,
         ),
         (TerminationReason::Stopped, "stopped", false),
+        (TerminationReason::UserStop, "user_stop", false),
         (TerminationReason::Interrupted, "interrupted", false),
         (
             TerminationReason::RestartRequested,
@@ -3962,6 +4684,53 @@ fn test_termination_reason_strings_and_flags() {
     }
 }
 
+#[test]
+fn test_termination_reason_from_str_round_trips() {
+    let reasons = [
+        TerminationReason::CompletionPromise,
+        TerminationReason::MaxIterations,
+        TerminationReason::MaxRuntime,
+        TerminationReason::MaxCost,
+        TerminationReason::ConsecutiveFailures,
+        TerminationReason::LoopThrashing,
+        TerminationReason::LoopStale,
+        TerminationReason::ValidationFailure,
+        TerminationReason::Stopped,
+        TerminationReason::UserStop,
+        TerminationReason::Interrupted,
+        TerminationReason::RestartRequested,
+        TerminationReason::WorkspaceGone,
+        TerminationReason::Cancelled,
+        TerminationReason::TargetEventReached,
+    ];
+
+    for reason in reasons {
+        // Exhaustive match so new variants must be added to this test.
+        match reason {
+            TerminationReason::CompletionPromise
+            | TerminationReason::MaxIterations
+            | TerminationReason::MaxRuntime
+            | TerminationReason::MaxCost
+            | TerminationReason::ConsecutiveFailures
+            | TerminationReason::LoopThrashing
+            | TerminationReason::LoopStale
+            | TerminationReason::ValidationFailure
+            | TerminationReason::Stopped
+            | TerminationReason::UserStop
+            | TerminationReason::Interrupted
+            | TerminationReason::RestartRequested
+            | TerminationReason::WorkspaceGone
+            | TerminationReason::Cancelled
+            | TerminationReason::TargetEventReached => {}
+        }
+        let parsed: TerminationReason = reason.as_str().parse().unwrap();
+        assert_eq!(parsed, reason);
+        assert_eq!(reason.to_string(), reason.as_str());
+    }
+
+    assert!("not_a_reason".parse::<TerminationReason>().is_err());
+}
+
 #[test]
 fn test_has_pending_human_events_detects_guidance() {
     let mut event_loop = EventLoop::new(RalphConfig::default());
@@ -4044,6 +4813,51 @@ hats:
     assert!(ralph_pending.is_none_or(|events| events.is_empty()));
 }
 
+#[test]
+fn test_inject_publish_retry_lists_expected_topics() {
+    let yaml = r#"
+hats:
+  planner:
+    name: "Planner"
+    triggers: ["task.resume"]
+    publishes: ["task.plan", "plan.blocked"]
+"#;
+    let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+    let mut event_loop = EventLoop::new(config);
+    let planner_id = HatId::new("planner");
+
+    event_loop.inject_publish_retry(&planner_id);
+
+    let pending = event_loop
+        .bus
+        .peek_pending(&planner_id)
+        .expect("planner pending");
+    assert_eq!(pending.len(), 1);
+    assert_eq!(pending[0].topic.as_str(), "task.resume");
+    assert!(
+        pending[0]
+            .payload
+            .contains("You didn't publish an event; expected one of [task.plan, plan.blocked]"),
+        "unexpected payload: {}",
+        pending[0].payload
+    );
+}
+
+#[test]
+fn test_inject_publish_retry_for_ralph_mentions_completion_promise() {
+    let mut event_loop = EventLoop::new(RalphConfig::default());
+    let ralph_id = HatId::new("ralph");
+
+    event_loop.inject_publish_retry(&ralph_id);
+
+    let pending = event_loop
+        .bus
+        .peek_pending(&ralph_id)
+        .expect("ralph pending");
+    assert!(pending[0].target.is_none());
+    assert!(pending[0].payload.contains("`LOOP_COMPLETE`"));
+}
+
 #[test]
 fn test_inject_fallback_event_defaults_to_ralph() {
     let mut event_loop = EventLoop::new(RalphConfig::default());
@@ -4532,6 +5346,35 @@ fn test_loop_cancel_terminates_without_chain_validation() {
     );
 }
 
+#[test]
+fn test_until_event_terminates_when_topic_published() {
+    use tempfile::TempDir;
+
+    let temp_dir = TempDir::new().unwrap();
+    let events_path = temp_dir.path().join("events.jsonl");
+
+    let mut config = RalphConfig::default();
+    config.event_loop.until_event = Some("spec.approved".to_string());
+    config.event_loop.required_events = vec!["all.built".to_string()];
+    let mut event_loop = EventLoop::new(config);
+    event_loop.initialize("Test");
+    event_loop.event_reader = crate::event_reader::EventReader::new(&events_path);
+
+    // Other topics don't stop the loop
+    write_event_to_jsonl(&events_path, "spec.draft", "first draft");
+    let _ = event_loop.process_events_from_jsonl();
+    assert_eq!(event_loop.check_target_event(), None);
+
+    write_event_to_jsonl(&events_path, "spec.approved", "looks good");
+    let _ = event_loop.process_events_from_jsonl();
+    let reason = event_loop.check_target_event();
+    assert_eq!(reason, Some(TerminationReason::TargetEventReached));
+    assert!(reason.unwrap().is_success());
+
+    // The flag is consumed once reported
+    assert_eq!(event_loop.check_target_event(), None);
+}
+
 #[test]
 fn test_default_publishes_satisfies_required_events_for_completion() {
     use std::collections::HashMap;
@@ -4549,6 +5392,7 @@ fn test_default_publishes_satisfies_required_events_for_completion() {
         crate::config::HatConfig {
             name: "planner".to_string(),
             description: Some("Plans work".to_string()),
+            emoji: None,
             triggers: vec!["research.complete".to_string()],
             publishes: vec!["plan.draft".to_string()],
             instructions: "Plan".to_string(),
@@ -4557,6 +5401,7 @@ fn test_default_publishes_satisfies_required_events_for_completion() {
             backend_args: None,
             default_publishes: Some("plan.draft".to_string()),
             max_activations: None,
+            cooldown_secs: None,
             scratchpad: None,
             disallowed_tools: vec![],
             timeout: None,
@@ -4607,6 +5452,7 @@ fn test_default_publishes_completion_promise_injects_recovery() {
         crate::config::HatConfig {
             name: "FinalCommitter".to_string(),
             description: Some("Verifies all work is complete".to_string()),
+            emoji: None,
             triggers: vec!["all.built".to_string()],
             publishes: vec!["LOOP_COMPLETE".to_string()],
             instructions: "Verify and complete".to_string(),
@@ -4615,6 +5461,7 @@ fn test_default_publishes_completion_promise_injects_recovery() {
             backend_args: None,
             default_publishes: Some("LOOP_COMPLETE".to_string()),
             max_activations: None,
+            cooldown_secs: None,
             scratchpad: None,
             disallowed_tools: vec![],
             timeout: None,
@@ -5313,6 +6160,66 @@ fn test_text_fallback_completions_with_missing_required_events() {
     );
 }
 
+#[test]
+fn test_completion_confirmations_required_waits_for_consecutive_confirmation() {
+    use tempfile::TempDir;
+
+    let temp_dir = TempDir::new().unwrap();
+    let scratchpad_path = temp_dir.path().join("scratchpad.md");
+    std::fs::write(&scratchpad_path, "## Tasks\n- [x] All done\n").unwrap();
+
+    let mut config = RalphConfig::default();
+    config.core.scratchpad.path = scratchpad_path.to_string_lossy().to_string();
+    config.event_loop.completion_confirmations_required = 2;
+    let mut event_loop = EventLoop::new(config);
+    event_loop.initialize("Test");
+    let ralph_id = HatId::new("ralph");
+
+    // First verified completion asks for confirmation instead of terminating
+    event_loop.process_output(&ralph_id, "", true);
+    event_loop.request_completion_from_text_fallback();
+    assert_eq!(event_loop.check_completion_event(), None);
+    assert_eq!(event_loop.state().completion_confirmations, 1);
+    assert!(event_loop.has_pending_events());
+
+    // A gap between completions restarts the count
+    event_loop.process_output(&ralph_id, "", true);
+    event_loop.process_output(&ralph_id, "", true);
+    event_loop.request_completion_from_text_fallback();
+    assert_eq!(event_loop.check_completion_event(), None);
+    assert_eq!(event_loop.state().completion_confirmations, 1);
+
+    // The next consecutive completion terminates
+    event_loop.process_output(&ralph_id, "", true);
+    event_loop.request_completion_from_text_fallback();
+    assert_eq!(
+        event_loop.check_completion_event(),
+        Some(TerminationReason::CompletionPromise)
+    );
+}
+
+#[test]
+fn test_completion_confirmations_required_one_terminates_immediately() {
+    use tempfile::TempDir;
+
+    let temp_dir = TempDir::new().unwrap();
+    let scratchpad_path = temp_dir.path().join("scratchpad.md");
+    std::fs::write(&scratchpad_path, "## Tasks\n- [x] All done\n").unwrap();
+
+    let mut config = RalphConfig::default();
+    config.core.scratchpad.path = scratchpad_path.to_string_lossy().to_string();
+    config.event_loop.completion_confirmations_required = 1;
+    let mut event_loop = EventLoop::new(config);
+    event_loop.initialize("Test");
+
+    event_loop.process_output(&HatId::new("ralph"), "", true);
+    event_loop.request_completion_from_text_fallback();
+    assert_eq!(
+        event_loop.check_completion_event(),
+        Some(TerminationReason::CompletionPromise)
+    );
+}
+
 #[test]
 fn test_text_fallback_completions_succeeds_when_all_checks_pass() {
     use std::fs;
@@ -5338,3 +6245,254 @@ fn test_text_fallback_completions_succeeds_when_all_checks_pass() {
         "Text fallback completion should succeed when all safety checks pass"
     );
 }
+
+#[test]
+fn test_ready_tasks_injected_in_priority_order() {
+    use crate::task::Task;
+    use crate::task_store::TaskStore;
+    use tempfile::tempdir;
+
+    let temp_dir = tempdir().unwrap();
+    let mut config = RalphConfig::default();
+    config.core.workspace_root = temp_dir.path().to_path_buf();
+    config.tasks.enabled = true;
+    let event_loop = EventLoop::new(config);
+
+    let tasks_path = temp_dir.path().join(".ralph/agent/tasks.jsonl");
+    std::fs::create_dir_all(tasks_path.parent().unwrap()).unwrap();
+    let mut store = TaskStore::load(&tasks_path).unwrap();
+    store.add(Task::new("Polish docs".to_string(), 3));
+    store.add(Task::new("Fix crash".to_string(), 1));
+    store.save().unwrap();
+
+    let prompt = event_loop.prepend_ready_tasks("PROMPT".to_string());
+    let fix = prompt.find("Fix crash").expect("ready task listed");
+    let docs = prompt.find("Polish docs").expect("ready task listed");
+    assert!(
+        fix < docs,
+        "higher priority task should be listed first:\n{prompt}"
+    );
+    assert!(prompt.contains("[P1] Fix crash"));
+    assert!(
+        prompt
+            .lines()
+            .any(|l| l.contains("Fix crash") && l.ends_with("← next")),
+        "first ready task should be marked next:\n{prompt}"
+    );
+}
+
+#[test]
+fn test_blocked_tasks_listed_separately_and_keep_loop_open() {
+    use crate::task::Task;
+    use crate::task_store::TaskStore;
+    use tempfile::tempdir;
+
+    let temp_dir = tempdir().unwrap();
+    let mut config = RalphConfig::default();
+    config.core.workspace_root = temp_dir.path().to_path_buf();
+    config.tasks.enabled = true;
+    let event_loop = EventLoop::new(config);
+
+    let tasks_path = temp_dir.path().join(".ralph/agent/tasks.jsonl");
+    std::fs::create_dir_all(tasks_path.parent().unwrap()).unwrap();
+    let mut store = TaskStore::load(&tasks_path).unwrap();
+    let task = Task::new("Rotate API keys".to_string(), 1);
+    let id = task.id.clone();
+    store.add(task);
+    store.block(&id, "Waiting on ops to grant vault access");
+    store.save().unwrap();
+
+    let prompt = event_loop.prepend_ready_tasks("PROMPT".to_string());
+    assert!(prompt.contains("Waiting on external input"), "{prompt}");
+    assert!(
+        prompt.contains("[waiting] [P1] Rotate API keys")
+            && prompt.contains("reason: Waiting on ops to grant vault access"),
+        "{prompt}"
+    );
+    assert!(!prompt.contains("[blocked]"), "{prompt}");
+    assert!(!prompt.contains("← next"), "{prompt}");
+}
+
+fn context_budget_loop(workspace: &std::path::Path, context_budget: Option<usize>) -> EventLoop {
+    let mut config = RalphConfig::default();
+    config.memories.enabled = true;
+    config.core.workspace_root = workspace.to_path_buf();
+    config.core.scratchpad.path = workspace
+        .join(".ralph/agent/scratchpad.md")
+        .to_string_lossy()
+        .to_string();
+    config.event_loop.context_budget = context_budget;
+
+    let mut event_loop =
+        EventLoop::with_context(config, LoopContext::primary(workspace.to_path_buf()));
+    event_loop.initialize("Budget test");
+    for i in 0..10 {
+        event_loop.bus.publish(Event::new(
+            "work.item",
+            format!("event-{i}-{}", "x".repeat(1000)),
+        ));
+    }
+    event_loop
+}
+
+#[test]
+fn test_context_budget_trims_memories_then_oldest_events() {
+    use crate::memory::{Memory, MemoryType};
+    use crate::memory_store::MarkdownMemoryStore;
+
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let store = MarkdownMemoryStore::with_default_path(temp_dir.path());
+    for i in 0..10 {
+        store
+            .append(&Memory::new(
+                MemoryType::Pattern,
+                format!("memory-{i} use the shared fixture helpers"),
+                vec![],
+            ))
+            .unwrap();
+    }
+
+    let ralph = HatId::new("ralph");
+    let full_prompt = context_budget_loop(temp_dir.path(), None)
+        .build_prompt(&ralph)
+        .unwrap();
+    assert!(full_prompt.contains("memory-0"));
+    assert!(full_prompt.contains("event-0-"));
+
+    // A budget ~3000 chars short of the full prompt forces dropping memories and old events
+    let budget = (full_prompt.len() - 3000) / 4;
+    let trimmed = context_budget_loop(temp_dir.path(), Some(budget))
+        .build_prompt(&ralph)
+        .unwrap();
+
+    assert!(trimmed.len() < full_prompt.len());
+    assert!(!trimmed.contains("memory-0"), "memories are trimmed first");
+    assert!(
+        !trimmed.contains("event-0-"),
+        "oldest event should be dropped"
+    );
+    assert!(trimmed.contains("event-9-"), "newest event must be kept");
+    assert!(trimmed.contains("trimmed to fit the context budget"));
+    assert!(trimmed.contains("Budget test"));
+}
+
+#[test]
+fn test_memory_injection_preview_matches_prompt_prefix() {
+    use crate::memory::{Memory, MemoryType};
+    use crate::memory_store::MarkdownMemoryStore;
+
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    MarkdownMemoryStore::with_default_path(temp_dir.path())
+        .append(&Memory::new(
+            MemoryType::Fix,
+            "run cargo test with --offline".to_string(),
+            vec![],
+        ))
+        .unwrap();
+
+    let mut event_loop = context_budget_loop(temp_dir.path(), None);
+    let preview = event_loop.memory_injection_preview();
+    assert!(preview.contains("run cargo test with --offline"));
+    assert!(preview.contains("<ralph-tools-skill>"));
+
+    let prompt = event_loop.build_prompt(&HatId::new("ralph")).unwrap();
+    assert!(
+        prompt.starts_with(&preview),
+        "preview must be the injected prefix"
+    );
+}
+
+#[test]
+fn test_context_budget_leaves_prompt_alone_when_it_fits() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let ralph = HatId::new("ralph");
+
+    let unbounded = context_budget_loop(temp_dir.path(), None)
+        .build_prompt(&ralph)
+        .unwrap();
+    let within_budget = context_budget_loop(temp_dir.path(), Some(unbounded.len()))
+        .build_prompt(&ralph)
+        .unwrap();
+
+    assert_eq!(unbounded, within_budget);
+}
+
+#[test]
+fn test_build_prompt_includes_recent_events() {
+    use tempfile::TempDir;
+
+    let temp_dir = TempDir::new().unwrap();
+    let events_path = temp_dir.path().join("events.jsonl");
+
+    let mut config = RalphConfig::default();
+    config.core.workspace_root = temp_dir.path().to_path_buf();
+    config.event_loop.recent_events_in_prompt = 2;
+    let mut event_loop = EventLoop::new(config);
+    event_loop.event_reader = crate::event_reader::EventReader::new(&events_path);
+
+    write_event_to_jsonl(&events_path, "build.task", "Oldest step");
+    write_event_to_jsonl(&events_path, "build.done", "tests: pass");
+    write_event_to_jsonl(&events_path, "review.ready", "Ready\nfor review");
+    event_loop.initialize("Test");
+
+    let prompt = event_loop.build_prompt(&HatId::new("ralph")).unwrap();
+    assert!(prompt.contains("<recent-events>"), "prompt: {prompt}");
+    assert!(prompt.contains("build.done: tests: pass"));
+    assert!(prompt.contains("review.ready: Ready for review"));
+    assert!(
+        !prompt.contains("Oldest step"),
+        "only the last N events should be injected"
+    );
+}
+
+#[test]
+fn test_build_prompt_recent_events_disabled_with_zero() {
+    use tempfile::TempDir;
+
+    let temp_dir = TempDir::new().unwrap();
+    let events_path = temp_dir.path().join("events.jsonl");
+
+    let mut config = RalphConfig::default();
+    config.core.workspace_root = temp_dir.path().to_path_buf();
+    config.event_loop.recent_events_in_prompt = 0;
+    let mut event_loop = EventLoop::new(config);
+    event_loop.event_reader = crate::event_reader::EventReader::new(&events_path);
+
+    write_event_to_jsonl(&events_path, "build.done", "tests: pass");
+    event_loop.initialize("Test");
+
+    let prompt = event_loop.build_prompt(&HatId::new("ralph")).unwrap();
+    assert!(!prompt.contains("<recent-events>"));
+}
+
+#[test]
+fn test_preview_prompt_includes_custom_hat_instructions() {
+    let yaml = r#"
+hats:
+  reviewer:
+    name: "Reviewer"
+    description: "Reviews changes"
+    triggers: ["review.*"]
+    publishes: ["review.done"]
+    instructions: "Check every diff for missing tests."
+"#;
+    let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+    let mut event_loop = EventLoop::new(config);
+
+    let prompt = event_loop
+        .preview_prompt(&HatId::new("reviewer"), "Ship the feature")
+        .expect("reviewer prompt");
+
+    assert!(prompt.contains("Check every diff for missing tests."));
+    assert!(prompt.contains("Ship the feature"));
+}
+
+#[test]
+fn test_preview_prompt_unknown_hat_returns_none() {
+    let mut event_loop = EventLoop::new(RalphConfig::default());
+    assert!(
+        event_loop
+            .preview_prompt(&HatId::new("missing"), "Task")
+            .is_none()
+    );
+}
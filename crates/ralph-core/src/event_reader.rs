@@ -6,6 +6,17 @@ use std::io::{BufRead, BufReader, Seek, SeekFrom};
 use std::path::PathBuf;
 use tracing::warn;
 
+/// Current schema version for serialized event records.
+///
+/// Bump to 2 once the backpressure-evidence payload format is formalized so
+/// replay and diff tooling can branch on the record layout.
+pub const EVENT_SCHEMA_VERSION: u8 = 1;
+
+/// Schema version assumed for records written before versioning existed.
+pub(crate) fn default_event_schema() -> u8 {
+    1
+}
+
 /// Result of parsing events from a JSONL file.
 ///
 /// Contains both successfully parsed events and information about lines
@@ -107,6 +118,10 @@ pub struct Event {
     /// Total number of events in the wave.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub wave_total: Option<u32>,
+
+    /// Record schema version (1 when the line has no `schema` field).
+    #[serde(default = "default_event_schema")]
+    pub schema: u8,
 }
 
 impl Event {
@@ -572,6 +587,7 @@ mod tests {
             wave_id: None,
             wave_index: None,
             wave_total: None,
+            schema: 1,
         };
         let proto: ralph_proto::Event = event.into();
         assert_eq!(proto.topic.as_str(), "build.done");
@@ -588,6 +604,7 @@ mod tests {
             wave_id: Some("w-abc".to_string()),
             wave_index: Some(2),
             wave_total: Some(5),
+            schema: 1,
         };
         let proto: ralph_proto::Event = event.into();
         assert_eq!(proto.topic.as_str(), "review.file");
@@ -607,11 +624,31 @@ mod tests {
             wave_id: None,
             wave_index: None,
             wave_total: None,
+            schema: 1,
         };
         let proto: ralph_proto::Event = event.into();
         assert_eq!(proto.payload, "");
     }
 
+    #[test]
+    fn test_schema_defaults_to_one_when_missing() {
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(file, r#"{{"topic":"legacy","ts":"2024-01-01T00:00:00Z"}}"#).unwrap();
+        writeln!(
+            file,
+            r#"{{"topic":"versioned","ts":"2024-01-01T00:00:01Z","schema":2}}"#
+        )
+        .unwrap();
+        file.flush().unwrap();
+
+        let mut reader = EventReader::new(file.path());
+        let result = reader.read_new_events().unwrap();
+
+        assert_eq!(result.events.len(), 2);
+        assert_eq!(result.events[0].schema, 1);
+        assert_eq!(result.events[1].schema, 2);
+    }
+
     #[test]
     fn test_mixed_valid_invalid_handling() {
         // Test that valid events are captured alongside malformed ones
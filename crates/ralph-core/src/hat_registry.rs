@@ -2,7 +2,7 @@
 
 use crate::config::{HatConfig, RalphConfig};
 use ralph_proto::{Hat, HatId, Topic};
-use std::collections::{BTreeMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 /// Registry for managing and creating hats from configuration.
 #[derive(Debug, Default)]
@@ -13,6 +13,12 @@ pub struct HatRegistry {
     /// Contains all first segments of subscription patterns (e.g., "task" from "task.*").
     /// Also contains "*" if any global wildcard exists.
     prefix_index: HashSet<String>,
+    /// Exact (wildcard-free) subscriptions mapped to the alphabetically first
+    /// subscribing hat. An exact match always beats any wildcard match.
+    exact_index: HashMap<String, HatId>,
+    /// Wildcard subscriptions with their precomputed specificity, sorted most
+    /// specific first and then by hat ID, so the first match is the winner.
+    wildcard_index: Vec<(usize, Topic, HatId)>,
 }
 
 impl HatRegistry {
@@ -47,32 +53,57 @@ impl HatRegistry {
 
     /// Registers a hat with the registry.
     pub fn register(&mut self, hat: Hat) {
-        self.index_hat_subscriptions(&hat);
         self.hats.insert(hat.id.clone(), hat);
+        self.rebuild_indexes();
     }
 
     /// Registers a hat with its configuration.
     pub fn register_with_config(&mut self, hat: Hat, config: HatConfig) {
         let id = hat.id.clone();
-        self.index_hat_subscriptions(&hat);
         self.hats.insert(id.clone(), hat);
         self.configs.insert(id, config);
+        self.rebuild_indexes();
     }
 
-    /// Indexes a hat's subscriptions for O(1) prefix lookup.
-    fn index_hat_subscriptions(&mut self, hat: &Hat) {
-        for sub in &hat.subscriptions {
-            let pattern = sub.as_str();
-            // Global wildcard matches everything - mark it specially
-            if pattern == "*" {
-                self.prefix_index.insert("*".to_string());
-            } else {
-                // Extract first segment (e.g., "task" from "task.*" or "task.start")
-                if let Some(prefix) = pattern.split('.').next() {
-                    self.prefix_index.insert(prefix.to_string());
+    /// Rebuilds the subscription lookup indexes from the registered hats.
+    ///
+    /// Rebuilding (rather than appending) keeps the indexes correct when a
+    /// hat ID is registered again with different subscriptions.
+    fn rebuild_indexes(&mut self) {
+        self.prefix_index.clear();
+        self.exact_index.clear();
+        self.wildcard_index.clear();
+
+        // BTreeMap iteration is sorted by hat ID, so the first exact
+        // subscriber seen for a pattern is the tie-break winner
+        for hat in self.hats.values() {
+            for sub in &hat.subscriptions {
+                let pattern = sub.as_str();
+                // Global wildcard matches everything - mark it specially
+                if pattern == "*" {
+                    self.prefix_index.insert("*".to_string());
+                } else {
+                    // Extract first segment (e.g., "task" from "task.*" or "task.start")
+                    if let Some(prefix) = pattern.split('.').next() {
+                        self.prefix_index.insert(prefix.to_string());
+                    }
+                }
+
+                if pattern.contains('*') {
+                    self.wildcard_index
+                        .push((sub.specificity(), sub.clone(), hat.id.clone()));
+                } else {
+                    self.exact_index
+                        .entry(pattern.to_string())
+                        .or_insert_with(|| hat.id.clone());
                 }
             }
         }
+
+        self.wildcard_index
+            .sort_by(|(a_spec, _, a_id), (b_spec, _, b_id)| {
+                b_spec.cmp(a_spec).then(a_id.cmp(b_id))
+            });
     }
 
     /// Gets a hat by ID.
@@ -114,15 +145,22 @@ impl HatRegistry {
             .collect()
     }
 
-    /// Finds the first hat that would be triggered by a topic.
+    /// Finds the hat that would be triggered by a topic.
     /// Returns the hat ID if found, used for event logging.
-    /// BTreeMap iteration is already sorted by key.
+    /// Uses the same longest-match resolution as [`Self::get_for_topic`].
     pub fn find_by_trigger(&self, topic: &str) -> Option<&HatId> {
+        self.get_for_topic(topic).map(|hat| &hat.id)
+    }
+
+    /// Finds every hat subscribed to a topic, in hat ID order.
+    /// Used for event logging when several hats share a trigger pattern.
+    pub fn find_all_by_trigger(&self, topic: &str) -> Vec<&HatId> {
         let topic = Topic::new(topic);
         self.hats
             .values()
-            .find(|hat| hat.is_subscribed(&topic))
+            .filter(|hat| hat.is_subscribed(&topic))
             .map(|hat| &hat.id)
+            .collect()
     }
 
     /// Returns true if any hat is subscribed to the given topic.
@@ -144,7 +182,10 @@ impl HatRegistry {
             .any(|pub_topic| pub_topic.matches_str(topic))
     }
 
-    /// Returns the first hat subscribed to the given topic.
+    /// Returns the hat whose subscription most specifically matches the topic.
+    ///
+    /// Longest match wins: an exact `build.done` beats `build.*`, which beats a
+    /// global `*`. Ties go to the alphabetically first hat ID.
     ///
     /// Uses prefix index for O(1) early-exit when the topic prefix doesn't match
     /// any subscription pattern.
@@ -160,8 +201,17 @@ impl HatRegistry {
             }
         }
 
-        // Fall back to full linear scan (BTreeMap is already sorted by key)
-        self.hats.values().find(|hat| hat.is_subscribed_str(topic))
+        // Patterns only match topics with the same segment count, so an exact
+        // subscription is always the most specific match
+        if let Some(id) = self.exact_index.get(topic) {
+            return self.hats.get(id);
+        }
+
+        // Wildcards are sorted most specific first, then by hat ID
+        self.wildcard_index
+            .iter()
+            .find(|(_, pattern, _)| pattern.matches_str(topic))
+            .and_then(|(_, _, id)| self.hats.get(id))
     }
 }
 
@@ -322,6 +372,21 @@ hats:
         );
     }
 
+    #[test]
+    fn test_reregistering_hat_replaces_its_subscriptions() {
+        let mut registry = HatRegistry::new();
+        registry.register(Hat::new("builder", "Builder").subscribe("build.task"));
+        registry.register(Hat::new("builder", "Builder").subscribe("review.*"));
+
+        assert!(registry.get_for_topic("build.task").is_none());
+        assert_eq!(
+            registry
+                .get_for_topic("review.done")
+                .map(|hat| hat.id.as_str()),
+            Some("builder")
+        );
+    }
+
     #[test]
     fn test_get_for_topic_returns_alphabetically_first_hat() {
         // Two hats subscribing to same wildcard pattern → get_for_topic returns alphabetically first
@@ -353,6 +418,60 @@ hats:
         }
     }
 
+    #[test]
+    fn test_get_for_topic_prefers_glob_over_global_wildcard() {
+        // "alpha" sorts first but only matches via "*"
+        let yaml = r#"
+hats:
+  alpha:
+    name: "Catch-all"
+    triggers: ["*"]
+  builder:
+    name: "Builder"
+    triggers: ["build.*"]
+"#;
+        let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+        let registry = HatRegistry::from_config(&config);
+
+        assert_eq!(
+            registry.get_for_topic("build.done").unwrap().id.as_str(),
+            "builder"
+        );
+        assert_eq!(
+            registry.find_by_trigger("build.done").unwrap().as_str(),
+            "builder"
+        );
+        // Unrelated topics still fall back to the wildcard hat
+        assert_eq!(
+            registry.get_for_topic("review.done").unwrap().id.as_str(),
+            "alpha"
+        );
+    }
+
+    #[test]
+    fn test_get_for_topic_prefers_exact_over_glob() {
+        let yaml = r#"
+hats:
+  any_build:
+    name: "Any Build"
+    triggers: ["build.*"]
+  finisher:
+    name: "Finisher"
+    triggers: ["build.done"]
+"#;
+        let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+        let registry = HatRegistry::from_config(&config);
+
+        assert_eq!(
+            registry.get_for_topic("build.done").unwrap().id.as_str(),
+            "finisher"
+        );
+        assert_eq!(
+            registry.get_for_topic("build.failed").unwrap().id.as_str(),
+            "any_build"
+        );
+    }
+
     #[test]
     fn test_find_by_trigger_returns_alphabetically_first_hat() {
         let yaml = r#"
@@ -376,6 +495,32 @@ hats:
         );
     }
 
+    #[test]
+    fn test_find_all_by_trigger_returns_every_matching_hat() {
+        let yaml = r#"
+hats:
+  security:
+    name: "Security"
+    triggers: ["review.*"]
+  perf:
+    name: "Perf"
+    triggers: ["review.*"]
+  builder:
+    name: "Builder"
+    triggers: ["build.*"]
+"#;
+        let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+        let registry = HatRegistry::from_config(&config);
+
+        let ids: Vec<&str> = registry
+            .find_all_by_trigger("review.request")
+            .into_iter()
+            .map(HatId::as_str)
+            .collect();
+        assert_eq!(ids, vec!["perf", "security"]);
+        assert!(registry.find_all_by_trigger("deploy.start").is_empty());
+    }
+
     #[test]
     fn test_subscribers_returns_deterministic_order() {
         let yaml = r#"
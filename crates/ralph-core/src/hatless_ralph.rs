@@ -5,7 +5,7 @@
 use crate::config::{CoreConfig, ScratchpadConfig};
 use crate::hat_registry::HatRegistry;
 use ralph_proto::{HatId, Topic};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::Path;
 
 /// Hatless Ralph - the constant coordinator.
@@ -53,6 +53,8 @@ pub struct EventReceiver {
 
 /// Information about a hat for prompt generation.
 pub struct HatInfo {
+    /// Hat ID, unique within the topology.
+    pub hat_id: HatId,
     pub name: String,
     pub description: String,
     pub subscribes_to: Vec<String>,
@@ -195,6 +197,7 @@ impl HatTopology {
                     .unwrap_or_default();
 
                 HatInfo {
+                    hat_id: hat.id.clone(),
                     name: hat.name.clone(),
                     description: hat.description.clone(),
                     subscribes_to: hat
@@ -216,6 +219,178 @@ impl HatTopology {
 
         Self { hats }
     }
+
+    /// Renders the topology as a Graphviz DOT digraph.
+    ///
+    /// Hats are nodes and each publish→trigger relationship is an edge labeled
+    /// with the connecting topic. Ralph is the catch-all: it feeds triggers that
+    /// no hat publishes and receives published events that no hat subscribes to.
+    pub fn to_dot(&self) -> String {
+        let published: HashSet<&str> = self
+            .hats
+            .iter()
+            .flat_map(|hat| hat.publishes.iter().map(String::as_str))
+            .collect();
+
+        let mut dot = String::from("digraph hats {\n");
+        dot.push_str("    rankdir=LR;\n");
+        dot.push_str("    \"Ralph\" [shape=doublecircle];\n");
+
+        for hat in &self.hats {
+            dot.push_str(&format!(
+                "    \"{}\" [shape=box, label=\"{}\"];\n",
+                escape_dot(hat.hat_id.as_str()),
+                escape_dot(&hat.name)
+            ));
+        }
+
+        for hat in &self.hats {
+            for trigger in &hat.subscribes_to {
+                if !published.contains(trigger.as_str()) {
+                    dot.push_str(&format!(
+                        "    \"Ralph\" -> \"{}\" [label=\"{}\"];\n",
+                        escape_dot(hat.hat_id.as_str()),
+                        escape_dot(trigger)
+                    ));
+                }
+            }
+        }
+
+        for hat in &self.hats {
+            for topic in &hat.publishes {
+                match hat.event_receivers.get(topic) {
+                    Some(receivers) if !receivers.is_empty() => {
+                        for receiver in receivers {
+                            dot.push_str(&format!(
+                                "    \"{}\" -> \"{}\" [label=\"{}\"];\n",
+                                escape_dot(hat.hat_id.as_str()),
+                                escape_dot(receiver.hat_id.as_str()),
+                                escape_dot(topic)
+                            ));
+                        }
+                    }
+                    _ => {
+                        dot.push_str(&format!(
+                            "    \"{}\" -> \"Ralph\" [label=\"{}\"];\n",
+                            escape_dot(hat.hat_id.as_str()),
+                            escape_dot(topic)
+                        ));
+                    }
+                }
+            }
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Renders the topology as a Mermaid `flowchart`.
+    ///
+    /// Uses the same edges as [`HatTopology::to_dot`], with each edge labeled by
+    /// the topic that connects the publisher to the subscriber.
+    pub fn to_mermaid(&self) -> String {
+        let published: HashSet<&str> = self
+            .hats
+            .iter()
+            .flat_map(|hat| hat.publishes.iter().map(String::as_str))
+            .collect();
+
+        let node_ids = self.mermaid_node_ids();
+        let node = |id: &HatId| node_ids.get(id.as_str()).map_or("Ralph", String::as_str);
+
+        let mut mermaid = String::from("flowchart LR\n");
+        mermaid.push_str("    Ralph((Ralph))\n");
+
+        for hat in &self.hats {
+            mermaid.push_str(&format!(
+                "    {}[\"{}\"]\n",
+                node(&hat.hat_id),
+                hat.name.replace('"', "#quot;")
+            ));
+        }
+
+        for hat in &self.hats {
+            for trigger in &hat.subscribes_to {
+                if !published.contains(trigger.as_str()) {
+                    mermaid.push_str(&format!(
+                        "    Ralph -->|{}| {}\n",
+                        trigger,
+                        node(&hat.hat_id)
+                    ));
+                }
+            }
+        }
+
+        for hat in &self.hats {
+            for topic in &hat.publishes {
+                match hat.event_receivers.get(topic) {
+                    Some(receivers) if !receivers.is_empty() => {
+                        for receiver in receivers {
+                            mermaid.push_str(&format!(
+                                "    {} -->|{}| {}\n",
+                                node(&hat.hat_id),
+                                topic,
+                                node(&receiver.hat_id)
+                            ));
+                        }
+                    }
+                    _ => {
+                        mermaid.push_str(&format!(
+                            "    {} -->|{}| Ralph\n",
+                            node(&hat.hat_id),
+                            topic
+                        ));
+                    }
+                }
+            }
+        }
+
+        mermaid
+    }
+
+    /// Assigns each hat a unique Mermaid node ID derived from its hat ID.
+    fn mermaid_node_ids(&self) -> HashMap<String, String> {
+        unique_mermaid_ids(self.hats.iter().map(|hat| hat.hat_id.as_str()))
+    }
+}
+
+/// Assigns each name a unique Mermaid node ID, keyed by the original name.
+///
+/// IDs keep only alphanumerics; a name with none becomes `hat`. IDs that
+/// collide after sanitizing (e.g. `code-review` and `code_review`) or that
+/// clash with the reserved `Ralph` node get a numeric suffix.
+pub fn unique_mermaid_ids<'a>(names: impl IntoIterator<Item = &'a str>) -> HashMap<String, String> {
+    let mut taken: HashSet<String> = HashSet::from(["Ralph".to_string()]);
+    let mut ids = HashMap::new();
+    for name in names {
+        if ids.contains_key(name) {
+            continue;
+        }
+        let base = mermaid_id(name);
+        let base = if base.is_empty() {
+            "hat".to_string()
+        } else {
+            base
+        };
+        let mut id = base.clone();
+        let mut suffix = 2;
+        while !taken.insert(id.clone()) {
+            id = format!("{base}{suffix}");
+            suffix += 1;
+        }
+        ids.insert(name.to_string(), id);
+    }
+    ids
+}
+
+/// Escapes a string for use inside a quoted DOT identifier or label.
+fn escape_dot(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Derives a Mermaid node ID by keeping only alphanumerics.
+fn mermaid_id(name: &str) -> String {
+    name.chars().filter(|c| c.is_alphanumeric()).collect()
 }
 
 impl HatlessRalph {
@@ -485,7 +660,7 @@ You MUST complete only one atomic task for the overall objective. Leave work for
 **First thing every iteration:**
 1. Review your `<scratchpad>` (auto-injected above) for context on your thinking
 2. Review your `<ready-tasks>` (auto-injected above) to see what work exists
-3. If tasks exist, pick one. If not, create them from your plan.
+3. If tasks exist, pick the first ready task (marked `← next`). If not, create them from your plan.
 "
                 .to_string()
             } else {
@@ -496,7 +671,7 @@ You MUST complete only one atomic task for the overall objective. Leave work for
 
 **First thing every iteration:**
 1. Review your `<ready-tasks>` (auto-injected above) to see what work exists
-2. If tasks exist, pick one. If not, create them from your plan.
+2. If tasks exist, pick the first ready task (marked `← next`). If not, create them from your plan.
 "
                 .to_string()
             }
@@ -1013,7 +1188,6 @@ You MUST continue.\n\
     /// Validates that all hats are reachable from task.start.
     /// Logs warnings for unreachable hats but doesn't fail.
     fn validate_topology_reachability(&self, topology: &HatTopology) {
-        use std::collections::HashSet;
         use tracing::warn;
 
         // Collect all events that are published (reachable)
@@ -1184,6 +1358,121 @@ mod tests {
         assert!(prompt.contains("LOOP_COMPLETE"));
     }
 
+    #[test]
+    fn test_topology_to_dot() {
+        let yaml = r#"
+hats:
+  planner:
+    name: "Planner"
+    triggers: ["planning.start", "build.done"]
+    publishes: ["build.task"]
+  builder:
+    name: "Builder"
+    triggers: ["build.task"]
+    publishes: ["build.done", "build.blocked"]
+"#;
+        let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+        let registry = HatRegistry::from_config(&config);
+        let dot = HatTopology::from_registry(&registry).to_dot();
+
+        assert!(dot.starts_with("digraph hats {\n"));
+        assert!(dot.ends_with("}\n"));
+        assert!(dot.contains("\"planner\" [shape=box, label=\"Planner\"];"));
+        assert!(dot.contains("\"builder\" [shape=box, label=\"Builder\"];"));
+        // Publish → trigger edges between hats
+        assert!(dot.contains("\"planner\" -> \"builder\" [label=\"build.task\"];"));
+        assert!(dot.contains("\"builder\" -> \"planner\" [label=\"build.done\"];"));
+        // Ralph feeds unpublished triggers and catches unhandled events
+        assert!(dot.contains("\"Ralph\" -> \"planner\" [label=\"planning.start\"];"));
+        assert!(dot.contains("\"builder\" -> \"Ralph\" [label=\"build.blocked\"];"));
+        assert!(!dot.contains("\"Ralph\" -> \"builder\""));
+    }
+
+    #[test]
+    fn test_topology_to_dot_keys_nodes_by_hat_id() {
+        // Two hats sharing a display name stay distinct nodes
+        let yaml = r#"
+hats:
+  code-review:
+    name: "Reviewer"
+    triggers: ["review.start"]
+    publishes: ["review.done"]
+  code_review:
+    name: "Reviewer"
+    triggers: ["review.done"]
+    publishes: ["review.approved"]
+"#;
+        let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+        let registry = HatRegistry::from_config(&config);
+        let dot = HatTopology::from_registry(&registry).to_dot();
+
+        assert!(dot.contains("\"code-review\" [shape=box, label=\"Reviewer\"];"));
+        assert!(dot.contains("\"code_review\" [shape=box, label=\"Reviewer\"];"));
+        assert!(dot.contains("\"code-review\" -> \"code_review\" [label=\"review.done\"];"));
+        assert!(dot.contains("\"code_review\" -> \"Ralph\" [label=\"review.approved\"];"));
+    }
+
+    #[test]
+    fn test_topology_to_mermaid() {
+        let yaml = r#"
+hats:
+  planner:
+    name: "Code Planner"
+    triggers: ["planning.start", "build.done"]
+    publishes: ["build.task"]
+  builder:
+    name: "Builder"
+    triggers: ["build.task"]
+    publishes: ["build.done", "build.blocked"]
+"#;
+        let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+        let registry = HatRegistry::from_config(&config);
+        let mermaid = HatTopology::from_registry(&registry).to_mermaid();
+
+        assert!(mermaid.starts_with("flowchart LR\n"));
+        assert!(mermaid.contains("planner[\"Code Planner\"]"));
+        assert!(mermaid.contains("builder[\"Builder\"]"));
+        assert!(mermaid.contains("planner -->|build.task| builder"));
+        assert!(mermaid.contains("builder -->|build.done| planner"));
+        assert!(mermaid.contains("Ralph -->|planning.start| planner"));
+        assert!(mermaid.contains("builder -->|build.blocked| Ralph"));
+    }
+
+    #[test]
+    fn test_topology_to_mermaid_keys_nodes_by_hat_id() {
+        // Same display name, and IDs that only differ in punctuation
+        let yaml = r#"
+hats:
+  code-review:
+    name: "Reviewer"
+    triggers: ["review.start"]
+    publishes: ["review.done"]
+  code_review:
+    name: "Reviewer"
+    triggers: ["review.done"]
+    publishes: ["review.approved"]
+"#;
+        let config: RalphConfig = serde_yaml::from_str(yaml).unwrap();
+        let registry = HatRegistry::from_config(&config);
+        let topology = HatTopology::from_registry(&registry);
+
+        let mermaid = topology.to_mermaid();
+        let ids = topology.mermaid_node_ids();
+        let first = &ids["code-review"];
+        let second = &ids["code_review"];
+        assert_ne!(first, second);
+        assert!(mermaid.contains(&format!("{first} -->|review.done| {second}")));
+        assert!(mermaid.contains(&format!("{second} -->|review.approved| Ralph")));
+    }
+
+    #[test]
+    fn test_mermaid_id() {
+        assert_eq!(mermaid_id("My Hat"), "MyHat");
+        assert_eq!(mermaid_id("cool-hat"), "coolhat");
+        assert_eq!(mermaid_id("Hat!@#"), "Hat");
+        assert_eq!(mermaid_id("123"), "123");
+    }
+
     #[test]
     fn test_prompt_with_hats() {
         // Test multi-hat mode WITHOUT starting_event (no fast path)
@@ -363,6 +363,8 @@ mod tests {
             scratchpad: scratchpad.clone(),
             specs_dir: "./specifications/".to_string(),
             guardrails: vec!["Custom rule one".to_string(), "Custom rule two".to_string()],
+            prompt_warning_chars: 100_000,
+            diagnostics_level: crate::diagnostics::DiagnosticsLevel::default(),
             workspace_root: std::path::PathBuf::from("."),
         };
         let builder = InstructionBuilder::new(custom_core);
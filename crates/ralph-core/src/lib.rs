@@ -68,13 +68,14 @@ pub use config::{
     resolve_context_window_for_backend,
 };
 // Re-export loop_name types (also available via FeaturesConfig.loop_naming)
-pub use diagnostics::DiagnosticsCollector;
+pub use diagnostics::{DiagnosticsCollector, DiagnosticsLevel};
 pub use event_logger::{EventHistory, EventLogger, EventRecord};
 pub use event_loop::{
-    EventLoop, LoopState, ProcessedEvents, ProcessedEventsWithWaves, TerminationReason, UserPrompt,
+    BackpressureCheck, BackpressureCheckOutcome, EventLoop, LoopState, ProcessedEvents,
+    ProcessedEventsWithWaves, TerminationReason, UserPrompt,
 };
 pub use event_parser::EventParser;
-pub use event_reader::{Event, EventReader, MalformedLine, ParseResult};
+pub use event_reader::{EVENT_SCHEMA_VERSION, Event, EventReader, MalformedLine, ParseResult};
 pub use file_lock::{FileLock, LockGuard as FileLockGuard, LockedFile};
 pub use git_ops::{
     AutoCommitResult, GitOpsError, auto_commit_changes, clean_stashes, get_commit_summary,
@@ -83,7 +84,7 @@ pub use git_ops::{
 };
 pub use handoff::{HandoffError, HandoffResult, HandoffWriter};
 pub use hat_registry::HatRegistry;
-pub use hatless_ralph::{HatInfo, HatTopology, HatlessRalph};
+pub use hatless_ralph::{HatInfo, HatTopology, HatlessRalph, unique_mermaid_ids};
 pub use hooks::{
     HookDefaults, HookEngine, HookExecutor, HookExecutorContract, HookExecutorError,
     HookInvocationPayload, HookMutationConfig, HookOnError, HookPayloadBuilderInput,
@@ -94,7 +95,9 @@ pub use hooks::{
 };
 pub use instructions::InstructionBuilder;
 pub use landing::{LandingConfig, LandingError, LandingHandler, LandingResult};
-pub use loop_completion::{CompletionAction, CompletionError, LoopCompletionHandler};
+pub use loop_completion::{
+    CompletionAction, CompletionError, CompletionHook, LoopCompletionHandler,
+};
 pub use loop_context::LoopContext;
 pub use loop_history::{HistoryError, HistoryEvent, HistoryEventType, HistorySummary, LoopHistory};
 pub use loop_lock::{LockError, LockGuard, LockMetadata, LoopLock};
@@ -138,7 +141,7 @@ pub use wave_detection::{DetectedWave, detect_wave_events};
 pub use wave_prompt::{WaveWorkerContext, build_wave_worker_prompt};
 pub use wave_tracker::{CompletedWave, WaveFailure, WaveProgress, WaveResult, WaveTracker};
 pub use workspace::{
-    CleanupPolicy, TaskWorkspace, VerificationResult, WorkspaceError, WorkspaceInfo,
+    CleanupPolicy, TaskRunResult, TaskWorkspace, VerificationResult, WorkspaceError, WorkspaceInfo,
     WorkspaceManager,
 };
 pub use worktree::{
@@ -10,6 +10,10 @@
 //! - **Worktree loop with auto-merge**: Enqueue to merge queue for merge-ralph
 //! - **Worktree loop without auto-merge**: Log completion, leave worktree for manual merge
 //!
+//! Registered completion hooks (e.g., push the branch, post a summary) run
+//! after a successful completion. Hook failures are logged and returned as
+//! [`CompletionError::HookFailed`] but only abort when the hook is fatal.
+//!
 //! # Example
 //!
 //! ```no_run
@@ -33,10 +37,13 @@
 //! assert!(matches!(action, CompletionAction::Enqueued { .. }));
 //! ```
 
+use crate::event_loop::{LoopState, TerminationReason};
 use crate::git_ops::auto_commit_changes;
 use crate::landing::{LandingHandler, LandingResult};
 use crate::loop_context::LoopContext;
 use crate::merge_queue::{MergeQueue, MergeQueueError};
+use std::path::Path;
+use std::process::{Command, Stdio};
 use tracing::{debug, info, warn};
 
 /// Action taken upon loop completion.
@@ -100,6 +107,25 @@ pub enum CompletionError {
     /// Failed to enqueue to merge queue.
     #[error("Failed to enqueue to merge queue: {0}")]
     EnqueueFailed(#[from] MergeQueueError),
+
+    /// A registered completion hook failed.
+    #[error("Completion hook '{name}' failed: {message}")]
+    HookFailed {
+        /// Name the hook was registered under.
+        name: String,
+        /// Error reported by the hook.
+        message: String,
+    },
+}
+
+/// Post-completion action run after a successful loop completion.
+pub type CompletionHook = Box<dyn Fn(&LoopState) -> Result<(), String> + Send + Sync>;
+
+/// A completion hook along with its registration details.
+struct RegisteredHook {
+    name: String,
+    hook: CompletionHook,
+    fatal: bool,
 }
 
 /// Handler for loop completion events.
@@ -109,6 +135,9 @@ pub enum CompletionError {
 pub struct LoopCompletionHandler {
     /// Whether auto-merge is enabled (default: true).
     auto_merge: bool,
+
+    /// Hooks run after a successful completion, in registration order.
+    hooks: Vec<RegisteredHook>,
 }
 
 impl Default for LoopCompletionHandler {
@@ -125,7 +154,81 @@ impl LoopCompletionHandler {
     /// * `auto_merge` - If true, completed worktree loops are enqueued for merge-ralph.
     ///   If false, worktrees are left for manual merge.
     pub fn new(auto_merge: bool) -> Self {
-        Self { auto_merge }
+        Self {
+            auto_merge,
+            hooks: Vec::new(),
+        }
+    }
+
+    /// Registers a non-fatal completion hook.
+    ///
+    /// Failures are logged and reported by [`run_hooks`](Self::run_hooks)
+    /// without stopping later hooks.
+    pub fn with_hook<F>(self, name: impl Into<String>, hook: F) -> Self
+    where
+        F: Fn(&LoopState) -> Result<(), String> + Send + Sync + 'static,
+    {
+        self.register_hook(name.into(), Box::new(hook), false)
+    }
+
+    /// Registers a completion hook whose failure aborts the remaining hooks.
+    pub fn with_fatal_hook<F>(self, name: impl Into<String>, hook: F) -> Self
+    where
+        F: Fn(&LoopState) -> Result<(), String> + Send + Sync + 'static,
+    {
+        self.register_hook(name.into(), Box::new(hook), true)
+    }
+
+    /// Registers a non-fatal hook for each `features.completion_commands` entry.
+    ///
+    /// Each command runs through `sh -c` in `workspace_root` with
+    /// `RALPH_ITERATIONS` set; a failing command reports its exit status and
+    /// the last line of its stderr.
+    pub fn with_completion_commands(self, commands: &[String], workspace_root: &Path) -> Self {
+        commands.iter().fold(self, |handler, command| {
+            let command_line = command.clone();
+            let workspace_root = workspace_root.to_path_buf();
+            handler.with_hook(command.clone(), move |state| {
+                run_completion_command(&command_line, &workspace_root, state)
+            })
+        })
+    }
+
+    fn register_hook(mut self, name: String, hook: CompletionHook, fatal: bool) -> Self {
+        self.hooks.push(RegisteredHook { name, hook, fatal });
+        self
+    }
+
+    /// Runs registered completion hooks for the given termination reason.
+    ///
+    /// Hooks only run on [`TerminationReason::CompletionPromise`]. Returns the
+    /// errors from non-fatal hooks that failed, or the first fatal failure;
+    /// the caller decides how to report them.
+    pub fn run_hooks(
+        &self,
+        reason: &TerminationReason,
+        state: &LoopState,
+    ) -> Result<Vec<CompletionError>, CompletionError> {
+        if !matches!(reason, TerminationReason::CompletionPromise) {
+            return Ok(Vec::new());
+        }
+
+        let mut failures = Vec::new();
+        for registered in &self.hooks {
+            let Err(message) = (registered.hook)(state) else {
+                debug!(hook = %registered.name, "Completion hook succeeded");
+                continue;
+            };
+            let error = CompletionError::HookFailed {
+                name: registered.name.clone(),
+                message,
+            };
+            if registered.fatal {
+                return Err(error);
+            }
+            failures.push(error);
+        }
+        Ok(failures)
     }
 
     /// Handles loop completion, taking appropriate action based on context.
@@ -255,6 +358,32 @@ impl LoopCompletionHandler {
     }
 }
 
+/// Runs a `features.completion_commands` entry, returning a short failure
+/// description when it cannot start or exits unsuccessfully.
+fn run_completion_command(
+    command: &str,
+    workspace_root: &Path,
+    state: &LoopState,
+) -> Result<(), String> {
+    let output = Command::new("sh")
+        .args(["-c", command])
+        .current_dir(workspace_root)
+        .env("RALPH_ITERATIONS", state.iteration.to_string())
+        .stdin(Stdio::null())
+        .output()
+        .map_err(|e| format!("failed to start: {e}"))?;
+
+    if output.status.success() {
+        return Ok(());
+    }
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    match stderr.lines().rev().find(|line| !line.trim().is_empty()) {
+        Some(line) => Err(format!("{}: {}", output.status, line.trim())),
+        None => Err(output.status.to_string()),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -508,4 +637,85 @@ mod tests {
             "No new commit should be made when working tree is clean"
         );
     }
+
+    #[test]
+    fn test_run_hooks_only_on_completion_promise() {
+        let calls = std::sync::Arc::new(std::sync::atomic::AtomicU32::new(0));
+        let counter = std::sync::Arc::clone(&calls);
+        let handler = LoopCompletionHandler::new(true).with_hook("count", move |_| {
+            counter.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            Ok(())
+        });
+        let state = LoopState::new();
+
+        let failures = handler
+            .run_hooks(&TerminationReason::MaxIterations, &state)
+            .unwrap();
+        assert!(failures.is_empty());
+        assert_eq!(calls.load(std::sync::atomic::Ordering::SeqCst), 0);
+
+        handler
+            .run_hooks(&TerminationReason::CompletionPromise, &state)
+            .unwrap();
+        assert_eq!(calls.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_run_hooks_non_fatal_failure_continues() {
+        let handler = LoopCompletionHandler::new(true)
+            .with_hook("push", |_| Err("remote rejected".to_string()))
+            .with_hook("notify", |state| {
+                assert_eq!(state.iteration, 0);
+                Ok(())
+            });
+
+        let failures = handler
+            .run_hooks(&TerminationReason::CompletionPromise, &LoopState::new())
+            .unwrap();
+
+        assert_eq!(failures.len(), 1);
+        assert_eq!(
+            failures[0].to_string(),
+            "Completion hook 'push' failed: remote rejected"
+        );
+    }
+
+    #[test]
+    fn test_run_hooks_fatal_failure_stops() {
+        let handler = LoopCompletionHandler::new(true)
+            .with_fatal_hook("push", |_| Err("remote rejected".to_string()))
+            .with_hook("notify", |_| panic!("should not run after fatal hook"));
+
+        let err = handler
+            .run_hooks(&TerminationReason::CompletionPromise, &LoopState::new())
+            .unwrap_err();
+
+        assert!(matches!(err, CompletionError::HookFailed { ref name, .. } if name == "push"));
+    }
+
+    #[test]
+    fn test_completion_commands_run_as_non_fatal_hooks() {
+        let temp = TempDir::new().unwrap();
+        let commands = vec![
+            "echo boom >&2; exit 3".to_string(),
+            "echo \"$RALPH_ITERATIONS\" > done.txt".to_string(),
+        ];
+        let handler =
+            LoopCompletionHandler::new(true).with_completion_commands(&commands, temp.path());
+        let mut state = LoopState::new();
+        state.iteration = 4;
+
+        let failures = handler
+            .run_hooks(&TerminationReason::CompletionPromise, &state)
+            .unwrap();
+
+        assert_eq!(failures.len(), 1);
+        let message = failures[0].to_string();
+        assert!(message.contains("exit status: 3"), "{message}");
+        assert!(message.ends_with(": boom"), "{message}");
+        assert_eq!(
+            std::fs::read_to_string(temp.path().join("done.txt")).unwrap(),
+            "4\n"
+        );
+    }
 }
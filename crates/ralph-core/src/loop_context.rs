@@ -55,7 +55,9 @@
 //! ```
 
 use crate::text::truncate_with_ellipsis;
+use ralph_proto::HatId;
 use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
 
 /// Context for resolving paths within a Ralph loop.
 ///
@@ -77,6 +79,18 @@ pub struct LoopContext {
 
     /// Whether this is the primary loop (holds loop.lock).
     is_primary: bool,
+
+    /// State of the current iteration, shared across clones so observers
+    /// see what the loop runner records.
+    iteration_state: Arc<RwLock<IterationState>>,
+}
+
+/// Per-iteration state recorded by the loop runner.
+#[derive(Debug, Default)]
+struct IterationState {
+    iteration: u32,
+    active_hat: Option<HatId>,
+    current_prompt: Option<String>,
 }
 
 impl LoopContext {
@@ -90,6 +104,7 @@ impl LoopContext {
             repo_root: workspace.clone(),
             workspace,
             is_primary: true,
+            iteration_state: Arc::default(),
         }
     }
 
@@ -113,6 +128,7 @@ impl LoopContext {
             workspace: worktree_path,
             repo_root,
             is_primary: false,
+            iteration_state: Arc::default(),
         }
     }
 
@@ -128,6 +144,41 @@ impl LoopContext {
         self.is_primary
     }
 
+    /// Records the iteration about to execute.
+    ///
+    /// Called by the loop runner once the prompt is built. Every clone of
+    /// this context observes the update.
+    pub fn set_current_iteration(&self, iteration: u32, hat: &HatId, prompt: &str) {
+        let mut state = self
+            .iteration_state
+            .write()
+            .unwrap_or_else(|e| e.into_inner());
+        state.iteration = iteration;
+        state.active_hat = Some(hat.clone());
+        state.current_prompt = Some(prompt.to_string());
+    }
+
+    /// Returns the current iteration number (0 before the first iteration).
+    pub fn iteration(&self) -> u32 {
+        self.read_iteration_state().iteration
+    }
+
+    /// Returns the hat selected for the current iteration, if any.
+    pub fn active_hat(&self) -> Option<HatId> {
+        self.read_iteration_state().active_hat.clone()
+    }
+
+    /// Returns the prompt built for the current iteration, if any.
+    pub fn current_prompt(&self) -> Option<String> {
+        self.read_iteration_state().current_prompt.clone()
+    }
+
+    fn read_iteration_state(&self) -> std::sync::RwLockReadGuard<'_, IterationState> {
+        self.iteration_state
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+    }
+
     /// Returns the workspace root for this loop.
     ///
     /// This is the directory where the loop executes:
@@ -597,6 +648,25 @@ mod tests {
         assert_eq!(ctx.repo_root(), Path::new("/project"));
     }
 
+    #[test]
+    fn test_iteration_state_shared_across_clones() {
+        let ctx = LoopContext::primary(PathBuf::from("/project"));
+        let observer_ctx = ctx.clone();
+
+        assert_eq!(observer_ctx.iteration(), 0);
+        assert!(observer_ctx.active_hat().is_none());
+        assert!(observer_ctx.current_prompt().is_none());
+
+        ctx.set_current_iteration(3, &HatId::new("builder"), "Build the thing");
+
+        assert_eq!(observer_ctx.iteration(), 3);
+        assert_eq!(observer_ctx.active_hat(), Some(HatId::new("builder")));
+        assert_eq!(
+            observer_ctx.current_prompt().as_deref(),
+            Some("Build the thing")
+        );
+    }
+
     #[test]
     fn test_worktree_context() {
         let ctx = LoopContext::worktree(
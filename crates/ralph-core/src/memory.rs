@@ -8,6 +8,7 @@
 //! that is both human-readable and machine-parseable.
 
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 /// Classification of a memory.
 ///
@@ -110,8 +111,11 @@ impl std::str::FromStr for MemoryType {
 /// ### mem-1737372000-a1b2
 /// > The actual memory content
 /// > Can span multiple lines
-/// <!-- tags: tag1, tag2 | created: 2025-01-20 -->
+/// <!-- tags: tag1, tag2 | created: 2025-01-20 | iteration: 3 | source: planner -->
 /// ```
+///
+/// `iteration` and any other `key: value` pairs in the metadata comment are
+/// optional and preserved on round-trip.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Memory {
     /// Unique identifier (format: `mem-{unix_timestamp}-{4_hex_chars}`)
@@ -128,6 +132,14 @@ pub struct Memory {
 
     /// Creation date (format: YYYY-MM-DD)
     pub created: String,
+
+    /// Loop iteration that produced this memory, if recorded
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub iteration: Option<u32>,
+
+    /// Additional `key: value` metadata from the metadata comment
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub metadata: BTreeMap<String, String>,
 }
 
 impl Memory {
@@ -142,7 +154,30 @@ impl Memory {
             content,
             tags,
             created: chrono::Utc::now().format("%Y-%m-%d").to_string(),
+            iteration: None,
+            metadata: BTreeMap::new(),
+        }
+    }
+
+    /// Formats the `<!-- tags: ... | created: ... -->` metadata comment.
+    ///
+    /// Optional fields are appended as extra `| key: value` segments and
+    /// omitted when unset, so memories without them keep the original format.
+    #[must_use]
+    pub fn metadata_comment(&self) -> String {
+        let mut comment = format!(
+            "<!-- tags: {} | created: {}",
+            self.tags.join(", "),
+            self.created
+        );
+        if let Some(iteration) = self.iteration {
+            comment.push_str(&format!(" | iteration: {}", iteration));
+        }
+        for (key, value) in &self.metadata {
+            comment.push_str(&format!(" | {}: {}", key, value));
         }
+        comment.push_str(" -->");
+        comment
     }
 
     /// Generates a unique memory ID.
@@ -293,6 +328,8 @@ mod tests {
             content: "Uses barrel exports for modules".to_string(),
             tags: vec!["imports".to_string(), "structure".to_string()],
             created: "2025-01-20".to_string(),
+            iteration: None,
+            metadata: std::collections::BTreeMap::new(),
         };
 
         // Match in content
@@ -315,6 +352,8 @@ mod tests {
             content: "Docker fix".to_string(),
             tags: vec!["docker".to_string(), "debugging".to_string()],
             created: "2025-01-20".to_string(),
+            iteration: None,
+            metadata: std::collections::BTreeMap::new(),
         };
 
         assert!(memory.has_any_tag(&["docker".to_string()]));
@@ -346,6 +385,8 @@ mod tests {
             content: "Chose Postgres".to_string(),
             tags: vec!["database".to_string()],
             created: "2025-01-20".to_string(),
+            iteration: None,
+            metadata: std::collections::BTreeMap::new(),
         };
 
         let json = serde_json::to_string(&memory).unwrap();
@@ -5,9 +5,11 @@
 //! - `## Section` headers to denote memory types
 //! - `### mem-{id}` headers for individual memories
 //! - `> content` blockquotes for memory content
-//! - `<!-- tags: ... | created: ... -->` HTML comments for metadata
+//! - `<!-- tags: ... | created: ... -->` HTML comments for metadata, optionally
+//!   followed by `| iteration: N` and arbitrary `| key: value` segments
 
 use regex::Regex;
+use std::collections::BTreeMap;
 use std::sync::LazyLock;
 
 use crate::memory::{Memory, MemoryType};
@@ -24,9 +26,45 @@ static MEMORY_ID_RE: LazyLock<Regex> =
 static CONTENT_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^> (.+)$").unwrap());
 
 /// Regex to match metadata HTML comments like `<!-- tags: a, b | created: 2025-01-20 -->`
-static METADATA_RE: LazyLock<Regex> = LazyLock::new(|| {
-    Regex::new(r"<!-- tags: ([^|]*) \| created: (\d{4}-\d{2}-\d{2}) -->").unwrap()
-});
+static METADATA_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"<!-- (tags:.*?) -->").unwrap());
+
+/// Metadata collected from a memory's `<!-- tags: ... -->` comment.
+#[derive(Default)]
+struct PendingMetadata {
+    tags: Vec<String>,
+    created: Option<String>,
+    iteration: Option<u32>,
+    extra: BTreeMap<String, String>,
+}
+
+impl PendingMetadata {
+    /// Parses the `key: value | key: value` body of a metadata comment.
+    fn parse(body: &str) -> Self {
+        let mut metadata = Self::default();
+        for segment in body.split('|') {
+            let Some((key, value)) = segment.split_once(':') else {
+                continue;
+            };
+            let (key, value) = (key.trim(), value.trim());
+            match key {
+                "tags" => {
+                    metadata.tags = value
+                        .split(',')
+                        .map(|s| s.trim().to_string())
+                        .filter(|s| !s.is_empty())
+                        .collect();
+                }
+                "created" => metadata.created = Some(value.to_string()),
+                "iteration" => metadata.iteration = value.parse().ok(),
+                _ if !key.is_empty() => {
+                    metadata.extra.insert(key.to_string(), value.to_string());
+                }
+                _ => {}
+            }
+        }
+        metadata
+    }
+}
 
 /// Parse a memories markdown file into a vector of Memory structs.
 ///
@@ -51,8 +89,7 @@ pub fn parse_memories(markdown: &str) -> Vec<Memory> {
     let mut current_type = MemoryType::Pattern;
     let mut current_id: Option<String> = None;
     let mut current_content: Vec<String> = Vec::new();
-    let mut current_tags: Vec<String> = Vec::new();
-    let mut current_created: Option<String> = None;
+    let mut current_metadata = PendingMetadata::default();
 
     for line in markdown.lines() {
         if let Some(caps) = SECTION_RE.captures(line) {
@@ -62,8 +99,7 @@ pub fn parse_memories(markdown: &str) -> Vec<Memory> {
                 &mut current_id,
                 current_type,
                 &mut current_content,
-                &mut current_tags,
-                &mut current_created,
+                &mut current_metadata,
             );
             current_type = MemoryType::from_section(&caps[1]).unwrap_or(MemoryType::Pattern);
         } else if let Some(caps) = MEMORY_ID_RE.captures(line) {
@@ -73,19 +109,13 @@ pub fn parse_memories(markdown: &str) -> Vec<Memory> {
                 &mut current_id,
                 current_type,
                 &mut current_content,
-                &mut current_tags,
-                &mut current_created,
+                &mut current_metadata,
             );
             current_id = Some(caps[1].to_string());
         } else if let Some(caps) = CONTENT_RE.captures(line) {
             current_content.push(caps[1].to_string());
         } else if let Some(caps) = METADATA_RE.captures(line) {
-            current_tags = caps[1]
-                .split(',')
-                .map(|s| s.trim().to_string())
-                .filter(|s| !s.is_empty())
-                .collect();
-            current_created = Some(caps[2].to_string());
+            current_metadata = PendingMetadata::parse(&caps[1]);
         }
     }
 
@@ -95,8 +125,7 @@ pub fn parse_memories(markdown: &str) -> Vec<Memory> {
         &mut current_id,
         current_type,
         &mut current_content,
-        &mut current_tags,
-        &mut current_created,
+        &mut current_metadata,
     );
 
     memories
@@ -108,9 +137,9 @@ fn flush_memory(
     current_id: &mut Option<String>,
     current_type: MemoryType,
     current_content: &mut Vec<String>,
-    current_tags: &mut Vec<String>,
-    current_created: &mut Option<String>,
+    current_metadata: &mut PendingMetadata,
 ) {
+    let metadata = std::mem::take(current_metadata);
     if let Some(id) = current_id.take()
         && !current_content.is_empty()
     {
@@ -118,10 +147,12 @@ fn flush_memory(
             id,
             memory_type: current_type,
             content: current_content.join("\n"),
-            tags: std::mem::take(current_tags),
-            created: current_created
-                .take()
+            tags: metadata.tags,
+            created: metadata
+                .created
                 .unwrap_or_else(|| chrono::Utc::now().format("%Y-%m-%d").to_string()),
+            iteration: metadata.iteration,
+            metadata: metadata.extra,
         });
     }
     current_content.clear();
@@ -301,4 +332,49 @@ mod tests {
         assert_eq!(memories.len(), 1);
         assert_eq!(memories[0].id, "mem-1737372100-c3d4");
     }
+
+    #[test]
+    fn test_parse_optional_metadata() {
+        let markdown = r"# Memories
+
+## Fixes
+
+### mem-1737372000-a1b2
+> Run cargo with --offline
+<!-- tags: cargo | created: 2025-01-20 | iteration: 7 | source: builder -->
+";
+
+        let memories = parse_memories(markdown);
+        assert_eq!(memories.len(), 1);
+        let mem = &memories[0];
+        assert_eq!(mem.tags, vec!["cargo"]);
+        assert_eq!(mem.created, "2025-01-20");
+        assert_eq!(mem.iteration, Some(7));
+        assert_eq!(
+            mem.metadata.get("source").map(String::as_str),
+            Some("builder")
+        );
+    }
+
+    #[test]
+    fn test_metadata_round_trips_through_markdown() {
+        use crate::memory_store::format_memories_as_markdown;
+
+        let mut memory = Memory::new(
+            MemoryType::Decision,
+            "Chose Postgres".to_string(),
+            vec!["database".to_string()],
+        );
+        memory.iteration = Some(3);
+        memory
+            .metadata
+            .insert("source".to_string(), "planner".to_string());
+
+        let parsed = parse_memories(&format_memories_as_markdown(std::slice::from_ref(&memory)));
+        assert_eq!(parsed.len(), 1);
+        assert_eq!(parsed[0].id, memory.id);
+        assert_eq!(parsed[0].tags, memory.tags);
+        assert_eq!(parsed[0].iteration, Some(3));
+        assert_eq!(parsed[0].metadata, memory.metadata);
+    }
 }
@@ -244,11 +244,10 @@ impl MarkdownMemoryStore {
             .collect();
 
         format!(
-            "\n### {}\n{}\n<!-- tags: {} | created: {} -->\n",
+            "\n### {}\n{}\n{}\n",
             memory.id,
             content_lines.join("\n"),
-            memory.tags.join(", "),
-            memory.created,
+            memory.metadata_comment(),
         )
     }
 
@@ -307,11 +306,10 @@ pub fn format_memories_as_markdown(memories: &[Memory]) -> String {
 
         for memory in type_memories {
             output.push_str(&format!(
-                "\n### {}\n> {}\n<!-- tags: {} | created: {} -->\n",
+                "\n### {}\n> {}\n{}\n",
                 memory.id,
                 memory.content.replace('\n', "\n> "),
-                memory.tags.join(", "),
-                memory.created
+                memory.metadata_comment()
             ));
         }
     }
@@ -321,8 +319,10 @@ pub fn format_memories_as_markdown(memories: &[Memory]) -> String {
 
 /// Truncates memory content to approximately fit within a token budget.
 ///
-/// Uses a simple heuristic of ~4 characters per token. Tries to end
-/// at a natural break point (end of a memory block).
+/// Uses a simple heuristic of ~4 characters per token. Cuts at the end of the
+/// last complete memory block (the line ending in `-->`) when one fits, and
+/// otherwise at the last whitespace before the budget, always on a valid UTF-8
+/// character boundary. The notice reports how many memories were omitted.
 ///
 /// # Arguments
 /// * `content` - The markdown content to truncate
@@ -345,26 +345,36 @@ pub fn truncate_to_budget(content: &str, budget: usize) -> String {
 
     // Ensure we truncate at a valid UTF-8 character boundary
     let safe_budget = floor_char_boundary(content, char_budget);
-
-    // Find a good break point (end of a memory block)
     let truncated = &content[..safe_budget];
 
-    // Try to find the last complete memory block (ends with -->)
-    if let Some(last_complete) = truncated.rfind("-->") {
+    let end = if let Some(last_complete) = truncated.rfind("-->") {
+        // End of the last complete memory block, including its newline
         let end = last_complete + 3;
-        // Find the next newline after -->
-        let final_end = truncated[end..].find('\n').map_or(end, |n| end + n + 1);
-        format!(
-            "{}\n\n<!-- truncated: budget {} tokens exceeded -->",
-            &content[..final_end],
-            budget
-        )
+        truncated[end..].find('\n').map_or(end, |n| end + n + 1)
     } else {
-        format!(
-            "{}\n\n<!-- truncated: budget {} tokens exceeded -->",
-            truncated, budget
-        )
-    }
+        // No complete block fits: avoid splitting a word if we can
+        truncated
+            .rfind(char::is_whitespace)
+            .filter(|&pos| pos > 0)
+            .unwrap_or(safe_budget)
+    };
+
+    let omitted = content[end..]
+        .lines()
+        .filter(|line| line.starts_with("### "))
+        .count();
+    let note = if omitted > 0 {
+        format!(" ({} memories omitted due to budget)", omitted)
+    } else {
+        String::new()
+    };
+
+    format!(
+        "{}\n\n<!-- truncated: budget {} tokens exceeded{} -->",
+        content[..end].trim_end(),
+        budget,
+        note
+    )
 }
 
 #[cfg(test)]
@@ -691,6 +701,8 @@ mod tests {
             content: "Use barrel exports".to_string(),
             tags: vec!["imports".to_string()],
             created: "2025-01-20".to_string(),
+            iteration: None,
+            metadata: std::collections::BTreeMap::new(),
         };
 
         let output = format_memories_as_markdown(&[memory]);
@@ -710,6 +722,8 @@ mod tests {
             content: "A pattern".to_string(),
             tags: vec![],
             created: "2025-01-20".to_string(),
+            iteration: None,
+            metadata: std::collections::BTreeMap::new(),
         };
         let decision = Memory {
             id: "mem-2-d".to_string(),
@@ -717,6 +731,8 @@ mod tests {
             content: "A decision".to_string(),
             tags: vec![],
             created: "2025-01-20".to_string(),
+            iteration: None,
+            metadata: std::collections::BTreeMap::new(),
         };
 
         let output = format_memories_as_markdown(&[pattern, decision]);
@@ -745,6 +761,44 @@ mod tests {
         assert_eq!(result, content);
     }
 
+    #[test]
+    fn test_truncate_to_budget_counts_omitted_memories() {
+        let memories: Vec<Memory> = (0..3)
+            .map(|i| Memory {
+                id: format!("mem-173737200{}-a1b2", i),
+                memory_type: MemoryType::Pattern,
+                content: format!("Memory number {}", i),
+                tags: vec![],
+                created: "2025-01-20".to_string(),
+                iteration: None,
+                metadata: std::collections::BTreeMap::new(),
+            })
+            .collect();
+        let content = format_memories_as_markdown(&memories);
+        let first_end = content.find("-->").unwrap() + 4;
+
+        let result = truncate_to_budget(&content, (first_end + 8) / 4);
+
+        assert!(result.contains("mem-1737372000-a1b2"));
+        assert!(!result.contains("mem-1737372001-a1b2"));
+        assert!(
+            result.contains("(2 memories omitted due to budget)"),
+            "{result}"
+        );
+    }
+
+    #[test]
+    fn test_truncate_to_budget_multibyte_cuts_at_whitespace() {
+        // Budget edge (40 bytes) lands inside a 3-byte character
+        let content = format!("{} 日本語のメモ {}", "a".repeat(30), "b".repeat(40));
+
+        let result = truncate_to_budget(&content, 10);
+
+        assert!(result.starts_with(&"a".repeat(30)));
+        assert!(!result.contains('日'), "partial word kept: {result}");
+        assert!(result.ends_with("<!-- truncated: budget 10 tokens exceeded -->"));
+    }
+
     #[test]
     fn test_truncate_to_budget_adds_notice() {
         let content = "x".repeat(1000); // 1000 chars = ~250 tokens
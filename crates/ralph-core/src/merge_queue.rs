@@ -378,6 +378,14 @@ impl MergeQueue {
         Ok(Self::derive_state(&events))
     }
 
+    /// Returns the full event history (oldest first).
+    ///
+    /// Every mutation is appended to the log as it happens, so this is the
+    /// auditable record of how each entry reached its current state.
+    pub fn events(&self) -> Result<Vec<MergeEvent>, MergeQueueError> {
+        self.read_all_events()
+    }
+
     /// Lists entries filtered by state.
     pub fn list_by_state(&self, state: MergeState) -> Result<Vec<MergeEntry>, MergeQueueError> {
         let entries = self.list()?;
@@ -1010,6 +1018,55 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_persistence_round_trip_after_crash() {
+        let temp_dir = TempDir::new().unwrap();
+
+        // Simulate a process that dies mid-merge
+        {
+            let queue = MergeQueue::new(temp_dir.path());
+            queue.enqueue("loop-a", "first").unwrap();
+            queue.enqueue("loop-b", "second").unwrap();
+            queue.mark_merging("loop-a", 4242).unwrap();
+            queue.mark_needs_review("loop-a", "conflict").unwrap();
+            queue.discard("loop-b", Some("superseded")).unwrap();
+        }
+
+        let queue = MergeQueue::new(temp_dir.path());
+        let entries = queue.list().unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].state, MergeState::NeedsReview);
+        assert_eq!(entries[0].failure_reason.as_deref(), Some("conflict"));
+        assert_eq!(entries[1].state, MergeState::Discarded);
+        assert_eq!(entries[1].discard_reason.as_deref(), Some("superseded"));
+
+        // Full history is retained in order
+        let events = queue.events().unwrap();
+        let kinds: Vec<(&str, &str)> = events
+            .iter()
+            .map(|e| {
+                let kind = match e.event {
+                    MergeEventType::Queued { .. } => "queued",
+                    MergeEventType::Merging { .. } => "merging",
+                    MergeEventType::Merged { .. } => "merged",
+                    MergeEventType::NeedsReview { .. } => "needs_review",
+                    MergeEventType::Discarded { .. } => "discarded",
+                };
+                (e.loop_id.as_str(), kind)
+            })
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                ("loop-a", "queued"),
+                ("loop-b", "queued"),
+                ("loop-a", "merging"),
+                ("loop-a", "needs_review"),
+                ("loop-b", "discarded"),
+            ]
+        );
+    }
+
     #[test]
     fn test_event_serialization() {
         let event = MergeEvent {
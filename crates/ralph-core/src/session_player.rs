@@ -322,7 +322,7 @@ impl SessionPlayer {
 ///
 /// Handles CSI sequences (\x1b[...m), OSC sequences (\x1b]...\x07),
 /// and simple escape sequences (\x1b followed by a single char).
-fn strip_ansi(bytes: &[u8]) -> Vec<u8> {
+pub(crate) fn strip_ansi(bytes: &[u8]) -> Vec<u8> {
     let mut result = Vec::with_capacity(bytes.len());
     let mut i = 0;
 
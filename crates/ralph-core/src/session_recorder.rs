@@ -104,7 +104,10 @@ impl Record {
 /// Records session events to a JSONL output.
 ///
 /// The recorder is thread-safe and can be used as an EventBus observer.
-/// It writes each event as a JSON line immediately for crash resilience.
+/// It writes each event as a JSON line immediately for crash resilience, and
+/// flushes the writer again when dropped so buffered output is never lost.
+/// Call [`SessionRecorder::finalize`] on termination to also record why the
+/// session ended.
 ///
 /// # Example
 ///
@@ -124,7 +127,7 @@ impl Record {
 /// let output_str = String::from_utf8_lossy(&output);
 /// assert!(output_str.contains("bus.publish"));
 /// ```
-pub struct SessionRecorder<W> {
+pub struct SessionRecorder<W: Write> {
     /// The output writer, wrapped in a mutex for thread-safe access.
     writer: Mutex<W>,
 
@@ -202,6 +205,19 @@ impl<W: Write> SessionRecorder<W> {
             .map_err(|_| io::Error::new(io::ErrorKind::Other, "Failed to acquire writer lock"))?
             .flush()
     }
+
+    /// Records the `_meta.termination` record and flushes the writer.
+    ///
+    /// Called once when the loop terminates, regardless of the reason.
+    pub fn finalize(&self, reason: &str, iterations: u32) -> io::Result<()> {
+        self.write_record(&Record::meta_termination(
+            reason,
+            iterations,
+            self.elapsed().as_secs_f64(),
+            self.ux_write_count(),
+        ));
+        self.flush()
+    }
 }
 
 impl<W: Write + Send + 'static> SessionRecorder<W> {
@@ -363,4 +379,46 @@ mod tests {
             "session records should be flushed before normal drop/teardown"
         );
     }
+
+    #[test]
+    fn test_records_reach_disk_when_dropped_without_finalize() {
+        use std::io::BufWriter;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("session.jsonl");
+        {
+            let file = std::fs::File::create(&path).unwrap();
+            let recorder = SessionRecorder::new(BufWriter::new(file));
+            for i in 0..3 {
+                recorder.record_bus_event(&Event::new("build.task", format!("task {i}")));
+            }
+        }
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        let records: Vec<Record> = content
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(records.len(), 3);
+        assert!(records.iter().all(|r| r.event == "bus.publish"));
+        assert_eq!(records[2].data["payload"], "task 2");
+    }
+
+    #[test]
+    fn test_finalize_appends_termination_record() {
+        let recorder = SessionRecorder::new(Vec::new());
+        recorder.record_bus_event(&Event::new("build.task", "task 0"));
+        recorder.finalize("completed", 1).unwrap();
+
+        let output = recorder.writer.lock().unwrap().clone();
+        let records: Vec<Record> = String::from_utf8(output)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[1].event, "_meta.termination");
+        assert_eq!(records[1].data["reason"], "completed");
+        assert_eq!(records[1].data["iterations"], 1);
+    }
 }
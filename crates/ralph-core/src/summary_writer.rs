@@ -7,11 +7,11 @@ use crate::event_logger::EventHistory;
 use crate::event_loop::{LoopState, TerminationReason};
 use crate::landing::LandingResult;
 use crate::loop_context::LoopContext;
+use crate::utils::format_duration;
 use std::collections::HashMap;
 use std::fs;
 use std::io;
 use std::path::{Path, PathBuf};
-use std::time::Duration;
 
 /// Writes the loop summary file on termination.
 ///
@@ -153,6 +153,15 @@ impl SummaryWriter {
         content.push_str("## Events\n\n");
         content.push_str(&self.summarize_events());
 
+        // Backend error section (only while the last execution failed)
+        if let Some(stderr) = &state.last_backend_error {
+            content.push('\n');
+            content.push_str("## Last Backend Error\n\n");
+            content.push_str("```text\n");
+            content.push_str(stderr.trim_end());
+            content.push_str("\n```\n");
+        }
+
         // Final commit section
         if let Some(commit) = final_commit {
             content.push('\n');
@@ -218,11 +227,13 @@ impl SummaryWriter {
             TerminationReason::LoopThrashing => "Failed: loop thrashing detected",
             TerminationReason::LoopStale => "Failed: stale loop detected",
             TerminationReason::ValidationFailure => "Failed: too many malformed JSONL events",
-            TerminationReason::Stopped => "Stopped manually",
+            TerminationReason::Stopped => "Stopped without completing",
+            TerminationReason::UserStop => "Stopped by user request",
             TerminationReason::Interrupted => "Interrupted by signal",
             TerminationReason::RestartRequested => "Restarting by human request",
             TerminationReason::WorkspaceGone => "Failed: workspace directory removed",
             TerminationReason::Cancelled => "Cancelled gracefully (human rejection or timeout)",
+            TerminationReason::TargetEventReached => "Stopped: target event reached",
         }
     }
 
@@ -283,32 +294,17 @@ impl SummaryWriter {
     }
 }
 
-/// Formats a duration as human-readable string (e.g., "23m 45s" or "1h 5m 30s").
-fn format_duration(d: Duration) -> String {
-    let total_secs = d.as_secs();
-    let hours = total_secs / 3600;
-    let minutes = (total_secs % 3600) / 60;
-    let seconds = total_secs % 60;
-
-    if hours > 0 {
-        format!("{}h {}m {}s", hours, minutes, seconds)
-    } else if minutes > 0 {
-        format!("{}m {}s", minutes, seconds)
-    } else {
-        format!("{}s", seconds)
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::time::Instant;
+    use std::time::{Duration, Instant};
     use tempfile::TempDir;
 
     fn test_state() -> LoopState {
         LoopState {
             iteration: 12,
             consecutive_failures: 0,
+            recent_outcomes: std::collections::VecDeque::new(),
             cumulative_cost: 1.50,
             started_at: Instant::now(),
             last_hat: None,
@@ -319,18 +315,24 @@ mod tests {
             abandoned_task_redispatches: 0,
             consecutive_malformed_events: 0,
             completion_requested: false,
+            completion_confirmations: 0,
+            last_completion_iteration: None,
             hat_activation_counts: std::collections::HashMap::new(),
             exhausted_hats: std::collections::HashSet::new(),
+            hat_last_activated: std::collections::HashMap::new(),
             last_checkin_at: None,
             last_active_hat_ids: Vec::new(),
             seen_topics: std::collections::HashSet::new(),
+            recent_activations: Vec::new(),
             last_emitted_signature: None,
             consecutive_same_signature: 0,
             cancellation_requested: false,
+            target_event_reached: false,
             peak_input_tokens: 0,
             last_input_tokens: None,
             hat_peak_input_tokens: std::collections::HashMap::new(),
             unacknowledged_guidance: Vec::new(),
+            last_backend_error: None,
         }
     }
 
@@ -413,6 +415,30 @@ More text here.
         assert!(content.contains("abc1234: feat(auth): add tokens"));
     }
 
+    #[test]
+    fn test_generate_content_includes_last_backend_error() {
+        let writer = SummaryWriter::default();
+        let mut state = test_state();
+        let content = writer.generate_content_with_landing(
+            &TerminationReason::ConsecutiveFailures,
+            &state,
+            None,
+            None,
+            None,
+        );
+        assert!(!content.contains("## Last Backend Error"));
+
+        state.last_backend_error = Some("error: invalid API key\n".to_string());
+        let content = writer.generate_content_with_landing(
+            &TerminationReason::ConsecutiveFailures,
+            &state,
+            None,
+            None,
+            None,
+        );
+        assert!(content.contains("## Last Backend Error\n\n```text\nerror: invalid API key\n```"));
+    }
+
     #[test]
     fn test_write_creates_directory() {
         let tmp = TempDir::new().unwrap();
@@ -17,6 +17,8 @@ pub enum TaskStatus {
     Closed,
     /// Failed/abandoned
     Failed,
+    /// Waiting on a human or external system (see `Task::blocked_reason`)
+    Blocked,
 }
 
 impl TaskStatus {
@@ -55,6 +57,10 @@ pub struct Task {
     #[serde(default)]
     pub blocked_by: Vec<String>,
 
+    /// Why the task is externally blocked (set while status is `Blocked`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub blocked_reason: Option<String>,
+
     /// Loop ID that created this task (from RALPH_LOOP_ID env var).
     /// Used to filter tasks by ownership when multiple loops share a task list.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -83,6 +89,7 @@ impl Task {
             status: TaskStatus::Open,
             priority: priority.clamp(1, 5),
             blocked_by: Vec::new(),
+            blocked_reason: None,
             loop_id: None,
             created: chrono::Utc::now().to_rfc3339(),
             started: None,
@@ -141,6 +148,7 @@ impl Task {
     /// Marks the task as in progress and records a start timestamp if absent.
     pub fn start(&mut self) {
         self.status = TaskStatus::InProgress;
+        self.blocked_reason = None;
         if self.started.is_none() {
             self.started = Some(chrono::Utc::now().to_rfc3339());
         }
@@ -150,8 +158,25 @@ impl Task {
     /// Reopens a terminal task for further work.
     pub fn reopen(&mut self) {
         self.status = TaskStatus::Open;
+        self.blocked_reason = None;
         self.closed = None;
     }
+
+    /// Marks the task as blocked on a human or external system.
+    pub fn block(&mut self, reason: String) {
+        self.status = TaskStatus::Blocked;
+        self.blocked_reason = Some(reason);
+    }
+
+    /// Returns a blocked task to `Open` so it can be picked up again.
+    ///
+    /// Tasks that are not `Blocked` are left unchanged.
+    pub fn unblock(&mut self) {
+        if self.status == TaskStatus::Blocked {
+            self.status = TaskStatus::Open;
+            self.blocked_reason = None;
+        }
+    }
 }
 
 #[cfg(test)]
@@ -223,6 +248,38 @@ mod tests {
 
         task.status = TaskStatus::Failed;
         assert!(!task.is_ready(&[]));
+
+        task.status = TaskStatus::Blocked;
+        assert!(!task.is_ready(&[]));
+    }
+
+    #[test]
+    fn test_block_and_unblock() {
+        let mut task = Task::new("Deploy".to_string(), 2);
+        task.block("Waiting on prod credentials".to_string());
+        assert_eq!(task.status, TaskStatus::Blocked);
+        assert!(!task.status.is_terminal());
+        assert_eq!(
+            task.blocked_reason.as_deref(),
+            Some("Waiting on prod credentials")
+        );
+
+        let json = serde_json::to_string(&task).unwrap();
+        assert!(json.contains(r#""status":"blocked""#));
+        let parsed: Task = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.blocked_reason, task.blocked_reason);
+
+        task.unblock();
+        assert_eq!(task.status, TaskStatus::Open);
+        assert!(task.blocked_reason.is_none());
+    }
+
+    #[test]
+    fn test_unblock_ignores_non_blocked_task() {
+        let mut task = Task::new("Test".to_string(), 1);
+        task.start();
+        task.unblock();
+        assert_eq!(task.status, TaskStatus::InProgress);
     }
 
     #[test]
@@ -231,6 +288,7 @@ mod tests {
         assert!(!TaskStatus::InProgress.is_terminal());
         assert!(TaskStatus::Closed.is_terminal());
         assert!(TaskStatus::Failed.is_terminal());
+        assert!(!TaskStatus::Blocked.is_terminal());
     }
 
     #[test]
@@ -26,7 +26,7 @@ use std::path::Path;
 /// A suite of benchmark tasks loaded from a JSON file.
 ///
 /// The suite contains multiple tasks that can be run sequentially during
-/// batch benchmarking.
+/// batch benchmarking, or concurrently via `TaskSuite::run_parallel`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TaskSuite {
     /// List of task definitions.
@@ -296,6 +296,12 @@ impl TaskDefinitionBuilder {
         self
     }
 
+    /// Sets the verification command timeout in seconds.
+    pub fn verification_timeout(mut self, seconds: u64) -> Self {
+        self.verification.timeout_seconds = seconds;
+        self
+    }
+
     /// Sets the full verification configuration.
     pub fn verification(mut self, verification: Verification) -> Self {
         self.verification = verification;
@@ -381,7 +387,7 @@ impl TaskDefinitionBuilder {
 }
 
 /// Verification configuration for a task.
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Verification {
     /// Bash command to verify task success.
     ///
@@ -392,6 +398,26 @@ pub struct Verification {
     /// Exit code that indicates success (default: 0).
     #[serde(default)]
     pub success_exit_code: i32,
+
+    /// Timeout in seconds for the verification command (default: 120).
+    ///
+    /// A command still running after this long is killed and recorded as failed.
+    #[serde(default = "default_verification_timeout_seconds")]
+    pub timeout_seconds: u64,
+}
+
+fn default_verification_timeout_seconds() -> u64 {
+    120 // 2 minutes
+}
+
+impl Default for Verification {
+    fn default() -> Self {
+        Self {
+            command: String::new(),
+            success_exit_code: 0,
+            timeout_seconds: default_verification_timeout_seconds(),
+        }
+    }
 }
 
 impl Verification {
@@ -399,7 +425,7 @@ impl Verification {
     pub fn new(command: impl Into<String>) -> Self {
         Self {
             command: command.into(),
-            success_exit_code: 0,
+            ..Self::default()
         }
     }
 
@@ -408,8 +434,15 @@ impl Verification {
         Self {
             command: command.into(),
             success_exit_code: exit_code,
+            ..Self::default()
         }
     }
+
+    /// Sets the verification timeout in seconds.
+    pub fn with_timeout_seconds(mut self, seconds: u64) -> Self {
+        self.timeout_seconds = seconds;
+        self
+    }
 }
 
 /// Setup configuration for task workspace.
@@ -588,7 +621,8 @@ mod tests {
                     "prompt_file": "tasks/fizzbuzz/PROMPT.md",
                     "completion_promise": "TESTS_PASSING",
                     "verification": {
-                        "command": "pytest test_fizzbuzz.py -v"
+                        "command": "pytest test_fizzbuzz.py -v",
+                        "timeout_seconds": 30
                     },
                     "complexity": "medium",
                     "max_iterations": 15,
@@ -613,12 +647,14 @@ mod tests {
         assert_eq!(hello.complexity, "simple");
         assert_eq!(hello.max_iterations, 5);
         assert_eq!(hello.expected_iterations, Some(1));
+        assert_eq!(hello.verification.timeout_seconds, 120);
 
         let fizzbuzz = &suite.tasks[1];
         assert_eq!(fizzbuzz.name, "fizzbuzz-tdd");
         assert!(fizzbuzz.description.is_some());
         assert_eq!(fizzbuzz.setup.files.len(), 1);
         assert!(fizzbuzz.tags.contains(&"tdd".to_string()));
+        assert_eq!(fizzbuzz.verification.timeout_seconds, 30);
 
         assert_eq!(
             suite.metadata.name,
@@ -246,6 +246,24 @@ impl TaskStore {
         None
     }
 
+    /// Blocks a task by ID on an external reason and returns a reference to it.
+    pub fn block(&mut self, id: &str, reason: impl Into<String>) -> Option<&Task> {
+        if let Some(task) = self.get_mut(id) {
+            task.block(reason.into());
+            return self.get(id);
+        }
+        None
+    }
+
+    /// Unblocks a task by ID (back to `Open`) and returns a reference to it.
+    pub fn unblock(&mut self, id: &str) -> Option<&Task> {
+        if let Some(task) = self.get_mut(id) {
+            task.unblock();
+            return self.get(id);
+        }
+        None
+    }
+
     /// Reopens a task by ID and returns a reference to it.
     pub fn reopen(&mut self, id: &str) -> Option<&Task> {
         if let Some(task) = self.get_mut(id) {
@@ -295,17 +313,38 @@ impl TaskStore {
             .collect()
     }
 
-    /// Returns all ready tasks (open with no pending blockers).
+    /// Returns all ready tasks (open with no pending blockers) in dispatch order.
+    ///
+    /// Tasks are ordered by priority (1 = highest first), with ties broken by
+    /// creation order (FIFO).
     pub fn ready(&self) -> Vec<&Task> {
-        self.tasks
+        let mut ready: Vec<&Task> = self
+            .tasks
             .iter()
             .filter(|t| t.is_ready(&self.tasks))
+            .collect();
+        // Stable sort keeps insertion (creation) order within a priority
+        ready.sort_by_key(|t| t.priority);
+        ready
+    }
+
+    /// Returns the next task to dispatch: the highest-priority ready task,
+    /// oldest first among equal priorities.
+    pub fn next_ready(&self) -> Option<&Task> {
+        self.ready().into_iter().next()
+    }
+
+    /// Returns all externally blocked tasks (status `Blocked`).
+    pub fn blocked(&self) -> Vec<&Task> {
+        self.tasks
+            .iter()
+            .filter(|t| t.status == TaskStatus::Blocked)
             .collect()
     }
 
     /// Returns true if there are any open tasks.
     ///
-    /// A task is considered open if it is not Closed. This includes Failed tasks.
+    /// A task is considered open if it is not Closed. This includes Failed and Blocked tasks.
     pub fn has_open_tasks(&self) -> bool {
         self.tasks.iter().any(|t| t.status != TaskStatus::Closed)
     }
@@ -450,6 +489,68 @@ mod tests {
         assert_eq!(ready[0].title, "Ready");
     }
 
+    #[test]
+    fn test_next_ready_orders_by_priority_then_fifo() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("tasks.jsonl");
+        let mut store = TaskStore::load(&path).unwrap();
+
+        let blocker = Task::new("Blocker".to_string(), 5);
+        let blocker_id = blocker.id.clone();
+        store.add(blocker);
+        store.add(Task::new("Low".to_string(), 4));
+        store.add(Task::new("High A".to_string(), 2));
+        store.add(Task::new("High B".to_string(), 2));
+        store.add(Task::new("Top but blocked".to_string(), 1).with_blocker(blocker_id));
+
+        let titles: Vec<&str> = store.ready().iter().map(|t| t.title.as_str()).collect();
+        assert_eq!(titles, vec!["High A", "High B", "Low", "Blocker"]);
+        assert_eq!(store.next_ready().unwrap().title, "High A");
+    }
+
+    #[test]
+    fn test_next_ready_none_when_nothing_ready() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("tasks.jsonl");
+        let mut store = TaskStore::load(&path).unwrap();
+        assert!(store.next_ready().is_none());
+
+        let mut task = Task::new("Started".to_string(), 1);
+        task.start();
+        store.add(task);
+        assert!(store.next_ready().is_none());
+    }
+
+    #[test]
+    fn test_block_and_unblock_keep_task_open() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("tasks.jsonl");
+        let mut store = TaskStore::load(&path).unwrap();
+
+        let task = Task::new("Ship release".to_string(), 1);
+        let id = task.id.clone();
+        store.add(task);
+
+        let blocked = store.block(&id, "Needs sign-off from legal").unwrap();
+        assert_eq!(blocked.status, TaskStatus::Blocked);
+        assert!(store.ready().is_empty());
+        assert_eq!(store.blocked().len(), 1);
+        assert!(store.has_open_tasks());
+        assert!(store.has_pending_tasks());
+
+        store.save().unwrap();
+        let mut reloaded = TaskStore::load(&path).unwrap();
+        assert_eq!(
+            reloaded.get(&id).unwrap().blocked_reason.as_deref(),
+            Some("Needs sign-off from legal")
+        );
+
+        reloaded.unblock(&id).unwrap();
+        assert_eq!(reloaded.next_ready().unwrap().id, id);
+        assert!(reloaded.blocked().is_empty());
+        assert!(reloaded.block("missing", "reason").is_none());
+    }
+
     #[test]
     fn test_ensure_deduplicates_by_key() {
         let tmp = TempDir::new().unwrap();
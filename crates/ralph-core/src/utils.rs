@@ -25,10 +25,87 @@ pub fn format_elapsed(duration: Duration) -> String {
     format!("{mins:02}:{secs:02}")
 }
 
+/// Formats a duration as a human-readable string (e.g. `"45s"`, `"2m 5s"`,
+/// `"1h 2m 5s"`). Sub-second detail is truncated.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use ralph_core::utils::format_duration;
+///
+/// assert_eq!(format_duration(Duration::from_secs(125)), "2m 5s");
+/// ```
+pub fn format_duration(duration: Duration) -> String {
+    let total_secs = duration.as_secs();
+    let hours = total_secs / 3600;
+    let minutes = (total_secs % 3600) / 60;
+    let seconds = total_secs % 60;
+
+    if hours > 0 {
+        format!("{hours}h {minutes}m {seconds}s")
+    } else if minutes > 0 {
+        format!("{minutes}m {seconds}s")
+    } else {
+        format!("{seconds}s")
+    }
+}
+
+/// Like [`format_duration`], but keeps sub-second detail for short durations:
+/// `"850ms"` below one second and `"12.3s"` below one minute.
+///
+/// Useful for per-iteration timings, where fast iterations would otherwise
+/// all read `"0s"`.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use ralph_core::utils::format_duration_precise;
+///
+/// assert_eq!(format_duration_precise(Duration::from_millis(850)), "850ms");
+/// assert_eq!(format_duration_precise(Duration::from_millis(12_340)), "12.3s");
+/// ```
+pub fn format_duration_precise(duration: Duration) -> String {
+    if duration < Duration::from_secs(1) {
+        format!("{}ms", duration.as_millis())
+    } else if duration < Duration::from_mins(1) {
+        // Truncate to tenths so "59.99s" never reads as "60.0s"
+        let tenths = duration.as_millis() / 100;
+        format!("{}.{}s", tenths / 10, tenths % 10)
+    } else {
+        format_duration(duration)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn format_duration_boundaries() {
+        assert_eq!(format_duration(Duration::ZERO), "0s");
+        assert_eq!(format_duration(Duration::from_secs(59)), "59s");
+        assert_eq!(format_duration(Duration::from_mins(1)), "1m 0s");
+        assert_eq!(format_duration(Duration::from_secs(3599)), "59m 59s");
+        assert_eq!(format_duration(Duration::from_hours(1)), "1h 0m 0s");
+        assert_eq!(format_duration(Duration::from_millis(1999)), "1s");
+    }
+
+    #[test]
+    fn format_duration_precise_boundaries() {
+        assert_eq!(format_duration_precise(Duration::ZERO), "0ms");
+        assert_eq!(format_duration_precise(Duration::from_millis(850)), "850ms");
+        assert_eq!(format_duration_precise(Duration::from_millis(999)), "999ms");
+        assert_eq!(format_duration_precise(Duration::from_secs(1)), "1.0s");
+        assert_eq!(
+            format_duration_precise(Duration::from_millis(59_999)),
+            "59.9s"
+        );
+        assert_eq!(format_duration_precise(Duration::from_mins(1)), "1m 0s");
+        assert_eq!(format_duration_precise(Duration::from_hours(1)), "1h 0m 0s");
+    }
+
     #[test]
     fn format_elapsed_zero() {
         assert_eq!(format_elapsed(Duration::from_secs(0)), "00:00");
@@ -132,6 +132,7 @@ mod tests {
             wave_id: Some(wave_id.to_string()),
             wave_index: Some(index),
             wave_total: Some(total),
+            schema: 1,
         }
     }
 
@@ -189,6 +190,7 @@ mod tests {
             wave_id: None,
             wave_index: None,
             wave_total: None,
+            schema: 1,
         }];
 
         assert!(detect_wave_events(&events, &registry).is_none());
@@ -103,6 +103,7 @@ mod tests {
             wave_id: Some("w-test1234".to_string()),
             wave_index: Some(0),
             wave_total: Some(3),
+            schema: 1,
         }
     }
 
@@ -28,12 +28,15 @@
 //! # Ok::<(), ralph_core::workspace::WorkspaceError>(())
 //! ```
 
-use crate::task_definition::{TaskDefinition, Verification};
+use crate::task_definition::{TaskDefinition, TaskSuite, Verification};
 use std::fs;
-use std::io;
+use std::io::{self, Read};
 use std::path::{Path, PathBuf};
-use std::process::Command;
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::process::{Child, Command, Stdio};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 /// Cleanup policy for workspace directories.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -87,11 +90,18 @@ pub struct TaskWorkspace {
     cleaned_up: bool,
 }
 
+/// Per-process counter that keeps workspace directory names unique.
+static WORKSPACE_SEQ: AtomicU64 = AtomicU64::new(0);
+
 impl TaskWorkspace {
     /// Creates a new isolated workspace for the given task.
     ///
     /// The workspace is created at:
-    /// `{base_dir}/ralph-bench-{task_name}-{timestamp}/`
+    /// `{base_dir}/ralph-bench-{task_name}-{timestamp}_{pid}_{seq}/`
+    ///
+    /// `seq` counts workspaces created by this process, so tasks created in the
+    /// same millisecond (by [`TaskSuite::run_parallel`] or by separate
+    /// processes) never share a directory.
     ///
     /// # Arguments
     ///
@@ -107,7 +117,13 @@ impl TaskWorkspace {
             .map(|d| d.as_millis() as u64)
             .unwrap_or(0);
 
-        let dir_name = format!("ralph-bench-{}-{}", task.name, timestamp);
+        let dir_name = format!(
+            "ralph-bench-{}-{}_{}_{}",
+            task.name,
+            timestamp,
+            std::process::id(),
+            WORKSPACE_SEQ.fetch_add(1, Ordering::Relaxed)
+        );
         let path = base_dir.join(&dir_name);
 
         // Create workspace directory
@@ -313,12 +329,17 @@ pub struct VerificationResult {
 
     /// Stderr output from the command.
     pub stderr: String,
+
+    /// Whether the command was killed for exceeding its timeout.
+    pub timed_out: bool,
 }
 
 impl VerificationResult {
     /// Returns a human-readable summary of the result.
     pub fn summary(&self) -> String {
-        if self.passed {
+        if self.timed_out {
+            "FAILED (timed out)".to_string()
+        } else if self.passed {
             format!("PASSED (exit code {})", self.exit_code)
         } else {
             format!(
@@ -333,6 +354,8 @@ impl TaskWorkspace {
     /// Runs a verification command in the workspace directory.
     ///
     /// The command is executed via `bash -c` in the workspace's root directory.
+    /// If it runs longer than `verification.timeout_seconds`, it is killed and
+    /// the result is recorded as a failure with a "timed out" note in stderr.
     ///
     /// # Arguments
     ///
@@ -358,6 +381,7 @@ impl TaskWorkspace {
                 expected_exit_code: 0,
                 stdout: String::new(),
                 stderr: String::new(),
+                timed_out: false,
             });
         }
 
@@ -367,17 +391,63 @@ impl TaskWorkspace {
             verification.command
         );
 
-        let output = Command::new("bash")
+        let mut command = Command::new("bash");
+        command
             .args(["-c", &verification.command])
             .current_dir(&self.path)
-            .output()
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+
+        // Run in its own process group so a timeout kills the whole command tree
+        #[cfg(unix)]
+        {
+            use std::os::unix::process::CommandExt;
+            command.process_group(0);
+        }
+
+        let mut child = command
+            .spawn()
             .map_err(|e| WorkspaceError::Verification(format!("Failed to execute: {}", e)))?;
 
-        let exit_code = output.status.code().unwrap_or(-1);
-        let passed = exit_code == verification.success_exit_code;
+        // Drain pipes on background threads so a chatty command can't block on a full pipe
+        let stdout_reader = spawn_pipe_reader(child.stdout.take());
+        let stderr_reader = spawn_pipe_reader(child.stderr.take());
+
+        let timeout = Duration::from_secs(verification.timeout_seconds);
+        let status = wait_with_timeout(&mut child, timeout)
+            .map_err(|e| WorkspaceError::Verification(format!("Failed to wait: {}", e)))?;
 
-        let stdout = String::from_utf8_lossy(&output.stdout).to_string();
-        let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+        let stdout = stdout_reader.join().unwrap_or_default();
+        let mut stderr = stderr_reader.join().unwrap_or_default();
+
+        let Some(status) = status else {
+            tracing::debug!(
+                "Verification timed out after {}s: {}",
+                verification.timeout_seconds,
+                verification.command
+            );
+
+            if !stderr.is_empty() && !stderr.ends_with('\n') {
+                stderr.push('\n');
+            }
+            stderr.push_str(&format!(
+                "Verification timed out after {}s",
+                verification.timeout_seconds
+            ));
+
+            return Ok(VerificationResult {
+                passed: false,
+                exit_code: -1,
+                expected_exit_code: verification.success_exit_code,
+                stdout,
+                stderr,
+                timed_out: true,
+            });
+        };
+
+        let exit_code = status.code().unwrap_or(-1);
+        let passed = exit_code == verification.success_exit_code;
 
         tracing::debug!(
             "Verification result: {} (exit code {}, expected {})",
@@ -392,10 +462,54 @@ impl TaskWorkspace {
             expected_exit_code: verification.success_exit_code,
             stdout,
             stderr,
+            timed_out: false,
         })
     }
 }
 
+/// Reads a child pipe to completion on a background thread.
+fn spawn_pipe_reader<R: Read + Send + 'static>(pipe: Option<R>) -> thread::JoinHandle<String> {
+    thread::spawn(move || {
+        let mut buf = Vec::new();
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_end(&mut buf);
+        }
+        String::from_utf8_lossy(&buf).to_string()
+    })
+}
+
+/// Waits for the child to exit, killing it once `timeout` elapses.
+///
+/// Returns `Ok(None)` if the child was killed due to the timeout.
+fn wait_with_timeout(
+    child: &mut Child,
+    timeout: Duration,
+) -> io::Result<Option<std::process::ExitStatus>> {
+    let deadline = Instant::now() + timeout;
+    loop {
+        if let Some(status) = child.try_wait()? {
+            return Ok(Some(status));
+        }
+        if Instant::now() >= deadline {
+            kill_process_tree(child);
+            let _ = child.wait();
+            return Ok(None);
+        }
+        thread::sleep(Duration::from_millis(50));
+    }
+}
+
+/// Kills the child and, on Unix, every process in its process group.
+fn kill_process_tree(child: &mut Child) {
+    #[cfg(unix)]
+    {
+        #[allow(clippy::cast_possible_wrap)]
+        let pgid = nix::unistd::Pid::from_raw(-(child.id() as i32));
+        let _ = nix::sys::signal::kill(pgid, nix::sys::signal::Signal::SIGKILL);
+    }
+    let _ = child.kill();
+}
+
 /// Manages workspace cleanup according to a policy.
 #[derive(Debug)]
 pub struct WorkspaceManager {
@@ -556,6 +670,135 @@ pub struct WorkspaceInfo {
     pub timestamp: Option<u64>,
 }
 
+/// Outcome of running a single task via [`TaskSuite::run_parallel`].
+#[derive(Debug)]
+pub struct TaskRunResult<T> {
+    /// Name of the task.
+    pub task_name: String,
+
+    /// Path to the task's workspace, if it was created.
+    pub workspace_path: Option<PathBuf>,
+
+    /// Value returned by the task runner, if it ran.
+    pub output: Option<T>,
+
+    /// Verification result, or the error that prevented the task from being verified.
+    pub verification: Result<VerificationResult, WorkspaceError>,
+
+    /// Whether the workspace was removed by the cleanup policy.
+    pub cleaned_up: bool,
+}
+
+impl<T> TaskRunResult<T> {
+    /// Returns true if the task ran and its verification passed.
+    pub fn passed(&self) -> bool {
+        self.verification.as_ref().is_ok_and(|v| v.passed)
+    }
+}
+
+impl TaskSuite {
+    /// Runs every task in the suite, up to `concurrency` at a time.
+    ///
+    /// Each task gets its own [`TaskWorkspace`] (and therefore its own `.git`),
+    /// so concurrent tasks never share a working tree. For each task this creates
+    /// and sets up the workspace, calls `run_task`, runs the verification command,
+    /// and applies the manager's cleanup policy based on whether it passed.
+    ///
+    /// Results are returned in suite order regardless of completion order.
+    /// `CleanupPolicy::Rotate` is applied once after all tasks finish, since
+    /// rotating mid-run could delete workspaces that are still in use.
+    ///
+    /// `run_task` must not change process-wide state such as the current
+    /// directory. That is why `ralph-bench run` still runs tasks one at a time.
+    pub fn run_parallel<T, F>(
+        &self,
+        manager: &WorkspaceManager,
+        tasks_dir: &Path,
+        concurrency: usize,
+        run_task: F,
+    ) -> Vec<TaskRunResult<T>>
+    where
+        T: Send,
+        F: Fn(&TaskDefinition, &TaskWorkspace) -> T + Sync,
+    {
+        let next = AtomicUsize::new(0);
+        let slots: Mutex<Vec<Option<TaskRunResult<T>>>> =
+            Mutex::new(self.tasks.iter().map(|_| None).collect());
+        let workers = concurrency.clamp(1, self.tasks.len().max(1));
+
+        thread::scope(|scope| {
+            for _ in 0..workers {
+                scope.spawn(|| {
+                    loop {
+                        let index = next.fetch_add(1, Ordering::SeqCst);
+                        let Some(task) = self.tasks.get(index) else {
+                            break;
+                        };
+                        let result = run_isolated_task(manager, task, tasks_dir, &run_task);
+                        slots.lock().unwrap_or_else(|e| e.into_inner())[index] = Some(result);
+                    }
+                });
+            }
+        });
+
+        if let CleanupPolicy::Rotate(keep_last_n) = manager.policy()
+            && let Err(e) = manager.rotate_workspaces(keep_last_n)
+        {
+            tracing::warn!("Failed to rotate workspaces: {}", e);
+        }
+
+        slots
+            .into_inner()
+            .unwrap_or_else(|e| e.into_inner())
+            .into_iter()
+            .flatten()
+            .collect()
+    }
+}
+
+/// Runs one task in a fresh workspace and applies the per-task cleanup policy.
+fn run_isolated_task<T>(
+    manager: &WorkspaceManager,
+    task: &TaskDefinition,
+    tasks_dir: &Path,
+    run_task: &impl Fn(&TaskDefinition, &TaskWorkspace) -> T,
+) -> TaskRunResult<T> {
+    let mut result = TaskRunResult {
+        task_name: task.name.clone(),
+        workspace_path: None,
+        output: None,
+        verification: Err(WorkspaceError::Verification("not run".to_string())),
+        cleaned_up: false,
+    };
+
+    let mut workspace = match manager.create_workspace(task) {
+        Ok(workspace) => workspace,
+        Err(e) => {
+            result.verification = Err(e);
+            return result;
+        }
+    };
+    result.workspace_path = Some(workspace.path().to_path_buf());
+
+    if let Err(e) = workspace.setup(task, tasks_dir) {
+        result.verification = Err(e);
+        return result;
+    }
+
+    result.output = Some(run_task(task, &workspace));
+    result.verification = workspace.run_verification(&task.verification);
+
+    // Rotation is deferred to the end of the suite run
+    if !matches!(manager.policy(), CleanupPolicy::Rotate(_)) {
+        match manager.apply_cleanup(&mut workspace, result.passed()) {
+            Ok(cleaned_up) => result.cleaned_up = cleaned_up,
+            Err(e) => tracing::warn!("Failed to clean up workspace for '{}': {}", task.name, e),
+        }
+    }
+
+    result
+}
+
 /// Errors that can occur during workspace operations.
 #[derive(Debug, thiserror::Error)]
 pub enum WorkspaceError {
@@ -605,17 +848,19 @@ fn copy_dir_recursive(src: &Path, dst: &Path) -> io::Result<()> {
 
 /// Extracts timestamp from workspace directory name.
 ///
-/// Format: `ralph-bench-{task_name}-{timestamp}`
+/// Format: `ralph-bench-{task_name}-{timestamp}_{pid}_{seq}`, or
+/// `ralph-bench-{task_name}-{timestamp}` for older workspaces.
 fn extract_timestamp(dir_name: &str) -> Option<u64> {
     dir_name
         .rsplit('-')
         .next()
+        .and_then(|s| s.split('_').next())
         .and_then(|s| s.parse::<u64>().ok())
 }
 
 /// Extracts task name from workspace directory name.
 ///
-/// Format: `ralph-bench-{task_name}-{timestamp}`
+/// Format: `ralph-bench-{task_name}-{timestamp}_{pid}_{seq}`
 fn extract_task_name(dir_name: &str) -> Option<String> {
     let stripped = dir_name.strip_prefix("ralph-bench-")?;
     // Find the last dash before the timestamp
@@ -652,6 +897,20 @@ mod tests {
         assert_eq!(workspace.task_name(), "hello-world");
     }
 
+    #[test]
+    fn test_workspace_create_same_task_twice_gets_distinct_dirs() {
+        let temp_dir = TempDir::new().unwrap();
+        let task = make_test_task("twin");
+
+        let first = TaskWorkspace::create(&task, temp_dir.path()).unwrap();
+        let second = TaskWorkspace::create(&task, temp_dir.path()).unwrap();
+
+        assert_ne!(first.path(), second.path());
+        let name = second.path().file_name().unwrap().to_str().unwrap();
+        assert_eq!(extract_task_name(name).as_deref(), Some("twin"));
+        assert_eq!(extract_timestamp(name), Some(second.created_at()));
+    }
+
     #[test]
     fn test_workspace_cleanup() {
         let temp_dir = TempDir::new().unwrap();
@@ -775,6 +1034,10 @@ mod tests {
             extract_timestamp("ralph-bench-fizz-buzz-tdd-1704067300000"),
             Some(1_704_067_300_000)
         );
+        assert_eq!(
+            extract_timestamp("ralph-bench-hello-world-1704067200000_4242_7"),
+            Some(1_704_067_200_000)
+        );
         assert_eq!(extract_timestamp("ralph-bench-invalid"), None);
         assert_eq!(extract_timestamp("other-dir"), None);
     }
@@ -789,6 +1052,10 @@ mod tests {
             extract_task_name("ralph-bench-simple-1704067200000"),
             Some("simple".to_string())
         );
+        assert_eq!(
+            extract_task_name("ralph-bench-hello-world-1704067200000_4242_7"),
+            Some("hello-world".to_string())
+        );
     }
 
     #[test]
@@ -915,6 +1182,7 @@ mod tests {
         let verification = Verification {
             command: "cat hello.txt | grep -q 'Hello, World!'".to_string(),
             success_exit_code: 0,
+            ..Verification::default()
         };
 
         let result = workspace.run_verification(&verification).unwrap();
@@ -933,6 +1201,7 @@ mod tests {
         let verification = Verification {
             command: "cat nonexistent.txt".to_string(),
             success_exit_code: 0,
+            ..Verification::default()
         };
 
         let result = workspace.run_verification(&verification).unwrap();
@@ -950,6 +1219,7 @@ mod tests {
         let verification = Verification {
             command: "exit 42".to_string(),
             success_exit_code: 42,
+            ..Verification::default()
         };
 
         let result = workspace.run_verification(&verification).unwrap();
@@ -967,6 +1237,7 @@ mod tests {
         let verification = Verification {
             command: String::new(),
             success_exit_code: 0,
+            ..Verification::default()
         };
 
         let result = workspace.run_verification(&verification).unwrap();
@@ -982,6 +1253,7 @@ mod tests {
         let verification = Verification {
             command: "echo 'stdout message' && echo 'stderr message' >&2".to_string(),
             success_exit_code: 0,
+            ..Verification::default()
         };
 
         let result = workspace.run_verification(&verification).unwrap();
@@ -998,6 +1270,7 @@ mod tests {
             expected_exit_code: 0,
             stdout: String::new(),
             stderr: String::new(),
+            timed_out: false,
         };
         assert_eq!(passed_result.summary(), "PASSED (exit code 0)");
 
@@ -1007,7 +1280,148 @@ mod tests {
             expected_exit_code: 0,
             stdout: String::new(),
             stderr: String::new(),
+            timed_out: false,
         };
         assert_eq!(failed_result.summary(), "FAILED (exit code 1, expected 0)");
     }
+
+    #[test]
+    fn test_run_verification_timeout() {
+        let temp_dir = TempDir::new().unwrap();
+        let task = make_test_task("verify-timeout");
+        let workspace = TaskWorkspace::create(&task, temp_dir.path()).unwrap();
+
+        let verification = Verification::new("echo started && sleep 30").with_timeout_seconds(1);
+
+        let started = Instant::now();
+        let result = workspace.run_verification(&verification).unwrap();
+
+        assert!(started.elapsed() < Duration::from_secs(10));
+        assert!(!result.passed);
+        assert!(result.timed_out);
+        assert!(result.stdout.contains("started"));
+        assert!(result.stderr.contains("timed out after 1s"));
+        assert_eq!(result.summary(), "FAILED (timed out)");
+    }
+
+    fn make_suite_dir(temp_dir: &TempDir) -> PathBuf {
+        let tasks_dir = temp_dir.path().join("tasks");
+        fs::create_dir_all(tasks_dir.join("tasks/test")).unwrap();
+        fs::write(tasks_dir.join("tasks/test/PROMPT.md"), "Do the thing").unwrap();
+        tasks_dir
+    }
+
+    #[test]
+    fn test_run_parallel_preserves_task_order() {
+        let temp_dir = TempDir::new().unwrap();
+        let tasks_dir = make_suite_dir(&temp_dir);
+        let manager = WorkspaceManager::new(temp_dir.path().join("ws"), CleanupPolicy::Never);
+
+        let suite = TaskSuite {
+            tasks: ["slow", "medium", "fast"]
+                .iter()
+                .map(|name| {
+                    TaskDefinition::builder(*name, "tasks/test/PROMPT.md", "DONE")
+                        .verification_command("test -f done.txt")
+                        .build()
+                })
+                .collect(),
+            metadata: crate::task_definition::SuiteMetadata::default(),
+        };
+
+        let results = suite.run_parallel(&manager, &tasks_dir, 3, |task, workspace| {
+            // Finish in reverse order to prove results are re-ordered
+            let delay = match task.name.as_str() {
+                "slow" => 300,
+                "medium" => 150,
+                _ => 0,
+            };
+            std::thread::sleep(Duration::from_millis(delay));
+            fs::write(workspace.path().join("done.txt"), "").unwrap();
+            task.name.len()
+        });
+
+        let names: Vec<&str> = results.iter().map(|r| r.task_name.as_str()).collect();
+        assert_eq!(names, vec!["slow", "medium", "fast"]);
+        assert!(results.iter().all(TaskRunResult::passed));
+        assert_eq!(results[0].output, Some(4));
+
+        // Every task got its own workspace
+        let paths: std::collections::HashSet<_> =
+            results.iter().map(|r| r.workspace_path.clone()).collect();
+        assert_eq!(paths.len(), 3);
+    }
+
+    #[test]
+    fn test_run_parallel_limits_concurrency() {
+        let temp_dir = TempDir::new().unwrap();
+        let tasks_dir = make_suite_dir(&temp_dir);
+        let manager = WorkspaceManager::new(temp_dir.path().join("ws"), CleanupPolicy::Always);
+
+        let suite = TaskSuite {
+            tasks: (0..4)
+                .map(|i| make_test_task(&format!("task-{i}")))
+                .collect(),
+            metadata: crate::task_definition::SuiteMetadata::default(),
+        };
+
+        let in_flight = AtomicUsize::new(0);
+        let max_in_flight = AtomicUsize::new(0);
+        let results = suite.run_parallel(&manager, &tasks_dir, 2, |_, _| {
+            let now = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            max_in_flight.fetch_max(now, Ordering::SeqCst);
+            std::thread::sleep(Duration::from_millis(100));
+            in_flight.fetch_sub(1, Ordering::SeqCst);
+        });
+
+        assert_eq!(results.len(), 4);
+        assert_eq!(max_in_flight.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_run_parallel_applies_cleanup_per_task() {
+        let temp_dir = TempDir::new().unwrap();
+        let tasks_dir = make_suite_dir(&temp_dir);
+        let manager = WorkspaceManager::new(temp_dir.path().join("ws"), CleanupPolicy::OnSuccess);
+
+        let suite = TaskSuite {
+            tasks: vec![
+                make_test_task("passes"),
+                TaskDefinition::builder("fails", "tasks/test/PROMPT.md", "DONE")
+                    .verification_command("exit 1")
+                    .build(),
+            ],
+            metadata: crate::task_definition::SuiteMetadata::default(),
+        };
+
+        let results = suite.run_parallel(&manager, &tasks_dir, 2, |_, _| ());
+
+        assert!(results[0].passed());
+        assert!(results[0].cleaned_up);
+        assert!(!results[0].workspace_path.as_ref().unwrap().exists());
+
+        assert!(!results[1].passed());
+        assert!(!results[1].cleaned_up);
+        assert!(results[1].workspace_path.as_ref().unwrap().exists());
+    }
+
+    #[test]
+    fn test_run_parallel_reports_setup_errors() {
+        let temp_dir = TempDir::new().unwrap();
+        let manager = WorkspaceManager::new(temp_dir.path().join("ws"), CleanupPolicy::Never);
+
+        // No prompt file in the tasks dir
+        let suite = TaskSuite {
+            tasks: vec![make_test_task("missing-prompt")],
+            metadata: crate::task_definition::SuiteMetadata::default(),
+        };
+
+        let results = suite.run_parallel(&manager, temp_dir.path(), 4, |_, _| ());
+
+        assert!(results[0].output.is_none());
+        assert!(matches!(
+            results[0].verification,
+            Err(WorkspaceError::MissingFile(_))
+        ));
+    }
 }
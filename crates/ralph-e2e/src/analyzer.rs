@@ -427,17 +427,25 @@ impl MetaRalphAnalyzer {
         prompt.push_str("Analyze these test results and provide structured feedback.\n\n");
 
         // Summary
-        let total = results.len();
+        let skipped = results.iter().filter(|r| r.is_skipped()).count();
+        let total = results.len() - skipped;
         let passed = results.iter().filter(|r| r.passed).count();
         let failed = total - passed;
 
         prompt.push_str("## Test Run Summary\n");
         prompt.push_str(&format!("- Total: {} tests\n", total));
         prompt.push_str(&format!("- Passed: {}\n", passed));
-        prompt.push_str(&format!("- Failed: {}\n\n", failed));
+        prompt.push_str(&format!("- Failed: {}\n", failed));
+        if skipped > 0 {
+            prompt.push_str(&format!(
+                "- Skipped: {} (not run, nothing to analyze)\n",
+                skipped
+            ));
+        }
+        prompt.push('\n');
 
         // Failed tests section
-        let failed_results: Vec<_> = results.iter().filter(|r| !r.passed).collect();
+        let failed_results: Vec<_> = results.iter().filter(|r| r.is_failed()).collect();
         if !failed_results.is_empty() {
             prompt.push_str("## Failed Tests\n\n");
             for result in &failed_results {
@@ -654,6 +662,7 @@ hats:
             max_iterations: self.config.max_iterations,
             timeout: self.config.timeout,
             extra_args: vec![],
+            seed_events: vec![],
         };
 
         // Run Ralph
@@ -700,6 +709,7 @@ mod tests {
                 },
             ],
             duration: Duration::from_secs_f64(45.2),
+            skip_reason: None,
         }
     }
 
@@ -725,6 +735,7 @@ mod tests {
                 },
             ],
             duration: Duration::from_secs_f64(12.3),
+            skip_reason: None,
         }
     }
 
@@ -18,13 +18,33 @@
 //!     }
 //! }
 //! ```
+//!
+//! # Caching
+//!
+//! Probing every backend spawns several processes, so a checker built with
+//! [`AuthChecker::with_cache`] reuses results younger than the TTL and
+//! persists them to disk. That lets `ralph-e2e --list` followed by a test run
+//! probe once, and the run prints the same status the list did. The status is
+//! informational only: scenarios still run against every selected backend.
+//!
+//! Only successful checks are cached. A backend that is missing or logged out
+//! is probed again on the next check, so installing or logging in takes
+//! effect right away instead of after the TTL.
 
 use crate::backend::Backend;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use std::process::Stdio;
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime};
 use tokio::process::Command;
 
+/// How long cached backend checks stay valid by default.
+pub const DEFAULT_AUTH_CACHE_TTL: Duration = Duration::from_mins(5);
+
 /// Information about a backend's availability and authentication status.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BackendInfo {
     /// The backend this info is about.
     pub backend: Backend,
@@ -34,6 +54,8 @@ pub struct BackendInfo {
     pub is_authenticated: bool,
     /// The CLI version string, if available.
     pub version: Option<String>,
+    /// The model the backend uses when none is requested, if configured.
+    pub model: Option<String>,
     /// Error message if checking failed.
     pub error: Option<String>,
 }
@@ -46,6 +68,7 @@ impl BackendInfo {
             is_available: false,
             is_authenticated: false,
             version: None,
+            model: None,
             error,
         }
     }
@@ -57,6 +80,7 @@ impl BackendInfo {
             is_available: true,
             is_authenticated: false,
             version,
+            model: None,
             error: None,
         }
     }
@@ -68,44 +92,130 @@ impl BackendInfo {
             is_available: true,
             is_authenticated: true,
             version,
+            model: None,
             error: None,
         }
     }
 
+    /// Sets the default model reported by the backend.
+    pub fn with_model(mut self, model: Option<String>) -> Self {
+        self.model = model;
+        self
+    }
+
     /// Returns a human-readable status string.
     pub fn status_string(&self) -> String {
         if !self.is_available {
-            "Not installed".to_string()
-        } else if !self.is_authenticated {
-            match &self.version {
-                Some(v) => format!("{v} - Not authenticated"),
-                None => "Not authenticated".to_string(),
-            }
+            return "Not installed".to_string();
+        }
+
+        let auth = if self.is_authenticated {
+            "Authenticated"
         } else {
-            match &self.version {
-                Some(v) => format!("{v} - Authenticated"),
-                None => "Authenticated".to_string(),
-            }
+            "Not authenticated"
+        };
+        let model = self.model.as_ref().map(|m| format!("model: {m}"));
+        match (&self.version, model) {
+            (Some(v), Some(m)) => format!("{v} ({m}) - {auth}"),
+            (Some(v), None) => format!("{v} - {auth}"),
+            (None, Some(m)) => format!("{m} - {auth}"),
+            (None, None) => auth.to_string(),
         }
     }
 }
 
+/// A backend check result with the time it was taken.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedCheck {
+    checked_at: SystemTime,
+    info: BackendInfo,
+}
+
 /// Checks backend availability and authentication status.
 #[derive(Debug, Default)]
 pub struct AuthChecker {
     /// Optional timeout for auth checks (in seconds). Defaults to 10.
     pub timeout_secs: u64,
+
+    /// How long a cached check stays valid; `None` disables caching.
+    cache_ttl: Option<Duration>,
+
+    /// File the cache is loaded from and persisted to.
+    cache_file: Option<PathBuf>,
+
+    /// Results of previous checks.
+    cache: Mutex<HashMap<Backend, CachedCheck>>,
 }
 
 impl AuthChecker {
     /// Creates a new AuthChecker with default settings.
     pub fn new() -> Self {
-        Self { timeout_secs: 10 }
+        Self::with_timeout(10)
     }
 
     /// Creates a new AuthChecker with a custom timeout.
     pub fn with_timeout(timeout_secs: u64) -> Self {
-        Self { timeout_secs }
+        Self {
+            timeout_secs,
+            ..Self::default()
+        }
+    }
+
+    /// Reuses check results younger than `ttl`, persisting them to `path`.
+    ///
+    /// Entries already in `path` are loaded, so separate invocations (e.g.
+    /// `--list` then a run) share one probe. A missing or unreadable file
+    /// just starts an empty cache.
+    pub fn with_cache(mut self, path: impl Into<PathBuf>, ttl: Duration) -> Self {
+        let path = path.into();
+        let entries: HashMap<Backend, CachedCheck> = std::fs::read_to_string(&path)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default();
+        self.cache = Mutex::new(entries);
+        self.cache_file = Some(path);
+        self.cache_ttl = Some(ttl);
+        self
+    }
+
+    /// Returns a cached result for `backend` if it is still fresh.
+    fn cached(&self, backend: Backend) -> Option<BackendInfo> {
+        let ttl = self.cache_ttl?;
+        let cache = self.cache.lock().ok()?;
+        let entry = cache.get(&backend)?;
+        let age = entry.checked_at.elapsed().ok()?;
+        (age <= ttl).then(|| entry.info.clone())
+    }
+
+    /// Records a fresh result and persists the cache (best effort).
+    ///
+    /// Failed checks are not cached; they also evict any earlier success.
+    pub(crate) fn store(&self, info: &BackendInfo) {
+        if self.cache_ttl.is_none() {
+            return;
+        }
+        let Ok(mut cache) = self.cache.lock() else {
+            return;
+        };
+        if info.is_available && info.is_authenticated {
+            cache.insert(
+                info.backend,
+                CachedCheck {
+                    checked_at: SystemTime::now(),
+                    info: info.clone(),
+                },
+            );
+        } else if cache.remove(&info.backend).is_none() {
+            return;
+        }
+        if let Some(path) = &self.cache_file
+            && let Ok(json) = serde_json::to_string_pretty(&*cache)
+        {
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent).ok();
+            }
+            std::fs::write(path, json).ok();
+        }
     }
 
     /// Checks all backends and returns their status.
@@ -118,21 +228,40 @@ impl AuthChecker {
     }
 
     /// Checks a single backend's availability and authentication.
+    ///
+    /// Returns a cached result when one is fresh (see [`AuthChecker::with_cache`]).
     pub async fn check(&self, backend: Backend) -> BackendInfo {
+        if let Some(info) = self.cached(backend) {
+            return info;
+        }
+        let info = self.probe(backend).await;
+        self.store(&info);
+        info
+    }
+
+    /// Probes a backend without consulting the cache.
+    async fn probe(&self, backend: Backend) -> BackendInfo {
+        // Custom commands are user-supplied; there is nothing generic to probe
+        if backend == Backend::Custom {
+            return BackendInfo::available_not_authenticated(backend, None);
+        }
+
         // First check if the CLI is available
         if !Self::is_available(backend).await {
             return BackendInfo::unavailable(backend, Some("CLI not found on PATH".to_string()));
         }
 
-        // Get the version
+        // Get the version and default model
         let version = Self::get_version(backend).await;
+        let model = Self::get_default_model(backend);
 
         // Check authentication
-        if Self::is_authenticated(backend).await {
+        let info = if Self::is_authenticated(backend).await {
             BackendInfo::authenticated(backend, version)
         } else {
             BackendInfo::available_not_authenticated(backend, version)
-        }
+        };
+        info.with_model(model)
     }
 
     /// Checks if the CLI command is available on PATH.
@@ -169,6 +298,32 @@ impl AuthChecker {
         }
     }
 
+    /// Gets the model the backend uses by default, from its environment or settings.
+    ///
+    /// - Claude: `ANTHROPIC_MODEL`, then `model` in `~/.claude/settings.json`
+    /// - Kiro: `chat.defaultModel` in `~/.kiro/settings/cli.json`
+    /// - OpenCode: `model` in `./opencode.json`, then `~/.config/opencode/opencode.json`
+    ///
+    /// Returns `None` when no default is configured and the CLI picks its own.
+    pub fn get_default_model(backend: Backend) -> Option<String> {
+        let home = std::env::var_os("HOME").map(PathBuf::from);
+        match backend {
+            Backend::Claude => std::env::var("ANTHROPIC_MODEL")
+                .ok()
+                .filter(|m| !m.trim().is_empty())
+                .or_else(|| read_model_setting(&home?.join(".claude/settings.json"), "model")),
+            Backend::Kiro => {
+                read_model_setting(&home?.join(".kiro/settings/cli.json"), "chat.defaultModel")
+            }
+            Backend::OpenCode => {
+                read_model_setting(Path::new("opencode.json"), "model").or_else(|| {
+                    read_model_setting(&home?.join(".config/opencode/opencode.json"), "model")
+                })
+            }
+            Backend::Custom => None,
+        }
+    }
+
     /// Checks if the backend is authenticated by running a minimal command.
     ///
     /// Different backends have different ways to check authentication:
@@ -192,6 +347,7 @@ impl AuthChecker {
             Backend::Claude => Self::check_claude_auth().await,
             Backend::Kiro => Self::check_kiro_auth().await,
             Backend::OpenCode => Self::check_opencode_auth().await,
+            Backend::Custom => false,
         }
     }
 
@@ -237,6 +393,22 @@ impl AuthChecker {
     }
 }
 
+/// Reads a model name from a JSON settings file.
+fn read_model_setting(path: &Path, key: &str) -> Option<String> {
+    let content = std::fs::read_to_string(path).ok()?;
+    model_from_settings(&content, key)
+}
+
+/// Extracts a non-empty string `key` from JSON settings content.
+///
+/// Keys are matched literally, so dotted keys like `chat.defaultModel` work
+/// for backends that store flat settings.
+fn model_from_settings(content: &str, key: &str) -> Option<String> {
+    let settings: serde_json::Value = serde_json::from_str(content).ok()?;
+    let model = settings.get(key)?.as_str()?.trim();
+    (!model.is_empty()).then(|| model.to_string())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -286,6 +458,38 @@ mod tests {
         assert_eq!(info.status_string(), "claude 1.0.5 - Authenticated");
     }
 
+    #[test]
+    fn test_backend_info_status_string_with_model() {
+        let info = BackendInfo::authenticated(Backend::Claude, Some("claude 1.0.5".to_string()))
+            .with_model(Some("sonnet".to_string()));
+        assert_eq!(
+            info.status_string(),
+            "claude 1.0.5 (model: sonnet) - Authenticated"
+        );
+
+        let info = BackendInfo::available_not_authenticated(Backend::Kiro, None)
+            .with_model(Some("auto".to_string()));
+        assert_eq!(info.status_string(), "model: auto - Not authenticated");
+    }
+
+    #[test]
+    fn test_model_from_settings() {
+        assert_eq!(
+            model_from_settings(r#"{"model": "opus", "theme": "dark"}"#, "model"),
+            Some("opus".to_string())
+        );
+        assert_eq!(
+            model_from_settings(
+                r#"{"chat.defaultModel": "claude-sonnet-4"}"#,
+                "chat.defaultModel"
+            ),
+            Some("claude-sonnet-4".to_string())
+        );
+        assert_eq!(model_from_settings(r#"{"model": "  "}"#, "model"), None);
+        assert_eq!(model_from_settings(r#"{"theme": "dark"}"#, "model"), None);
+        assert_eq!(model_from_settings("not json", "model"), None);
+    }
+
     #[test]
     fn test_auth_checker_new() {
         let checker = AuthChecker::new();
@@ -298,6 +502,69 @@ mod tests {
         assert_eq!(checker.timeout_secs, 30);
     }
 
+    #[test]
+    fn test_auth_checker_cache_round_trips_through_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("backend-status.json");
+
+        let checker = AuthChecker::new().with_cache(&path, DEFAULT_AUTH_CACHE_TTL);
+        assert!(checker.cached(Backend::Kiro).is_none());
+        checker.store(
+            &BackendInfo::authenticated(Backend::Kiro, Some("kiro 0.3.2".to_string()))
+                .with_model(Some("auto".to_string())),
+        );
+
+        // A second checker (e.g. the run after `--list`) reuses the result
+        let reloaded = AuthChecker::new().with_cache(&path, DEFAULT_AUTH_CACHE_TTL);
+        let info = reloaded.cached(Backend::Kiro).expect("cached entry");
+        assert!(info.is_authenticated);
+        assert_eq!(
+            info.status_string(),
+            "kiro 0.3.2 (model: auto) - Authenticated"
+        );
+    }
+
+    #[test]
+    fn test_auth_checker_cache_expires_and_can_be_disabled() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("backend-status.json");
+
+        let checker = AuthChecker::new().with_cache(&path, Duration::ZERO);
+        checker.store(&BackendInfo::authenticated(Backend::Claude, None));
+        std::thread::sleep(Duration::from_millis(5));
+        assert!(checker.cached(Backend::Claude).is_none());
+
+        let uncached = AuthChecker::new();
+        uncached.store(&BackendInfo::authenticated(Backend::Claude, None));
+        assert!(uncached.cached(Backend::Claude).is_none());
+    }
+
+    #[test]
+    fn test_auth_checker_cache_skips_failed_checks() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("backend-status.json");
+        let checker = AuthChecker::new().with_cache(&path, DEFAULT_AUTH_CACHE_TTL);
+
+        checker.store(&BackendInfo::unavailable(Backend::Claude, None));
+        checker.store(&BackendInfo::available_not_authenticated(
+            Backend::Kiro,
+            None,
+        ));
+        assert!(checker.cached(Backend::Claude).is_none());
+        assert!(checker.cached(Backend::Kiro).is_none());
+
+        // A later failure evicts an earlier success, on disk too
+        checker.store(&BackendInfo::authenticated(Backend::Kiro, None));
+        assert!(checker.cached(Backend::Kiro).is_some());
+        checker.store(&BackendInfo::available_not_authenticated(
+            Backend::Kiro,
+            None,
+        ));
+        assert!(checker.cached(Backend::Kiro).is_none());
+        let reloaded = AuthChecker::new().with_cache(&path, DEFAULT_AUTH_CACHE_TTL);
+        assert!(reloaded.cached(Backend::Kiro).is_none());
+    }
+
     #[tokio::test]
     async fn test_is_available_for_nonexistent_command() {
         // Create a fake backend for testing
@@ -3,11 +3,13 @@
 //! This module provides functionality to detect which AI backends are available
 //! and whether they are properly authenticated.
 
+use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::time::Duration;
 
 /// Supported AI backends for E2E testing.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum Backend {
     /// Claude CLI backend
     Claude,
@@ -15,6 +17,12 @@ pub enum Backend {
     Kiro,
     /// OpenCode CLI backend
     OpenCode,
+    /// A user-supplied command run through ralph's `custom` backend.
+    ///
+    /// The command itself is configured per run via
+    /// `RunConfig::with_custom_backend`. Scenarios opt in by listing this
+    /// variant in `supported_backends`.
+    Custom,
 }
 
 impl Backend {
@@ -24,10 +32,12 @@ impl Backend {
             Backend::Claude => "claude",
             Backend::Kiro => "kiro-cli",
             Backend::OpenCode => "opencode",
+            // Placeholder: the real command comes from the run's custom backend config
+            Backend::Custom => "custom",
         }
     }
 
-    /// Returns all available backends.
+    /// Returns all built-in backends (excludes [`Backend::Custom`]).
     pub fn all() -> &'static [Backend] {
         &[Backend::Claude, Backend::Kiro, Backend::OpenCode]
     }
@@ -36,7 +46,7 @@ impl Backend {
     pub fn default_timeout(&self) -> Duration {
         match self {
             Backend::Claude => Duration::from_mins(10), // 10 minutes - Claude iterations can take 60-120s each
-            Backend::Kiro | Backend::OpenCode => Duration::from_mins(5), // 5 minutes
+            Backend::Kiro | Backend::OpenCode | Backend::Custom => Duration::from_mins(5), // 5 minutes
         }
     }
 
@@ -44,7 +54,7 @@ impl Backend {
     pub fn default_max_iterations(&self) -> u32 {
         match self {
             Backend::Claude => 5, // Extra buffer for LLM non-determinism
-            Backend::Kiro | Backend::OpenCode => 3,
+            Backend::Kiro | Backend::OpenCode | Backend::Custom => 3,
         }
     }
 
@@ -54,6 +64,7 @@ impl Backend {
             Backend::Claude => "claude",
             Backend::Kiro => "kiro",
             Backend::OpenCode => "opencode",
+            Backend::Custom => "custom",
         }
     }
 }
@@ -64,6 +75,41 @@ impl fmt::Display for Backend {
             Backend::Claude => write!(f, "Claude"),
             Backend::Kiro => write!(f, "Kiro"),
             Backend::OpenCode => write!(f, "OpenCode"),
+            Backend::Custom => write!(f, "Custom"),
         }
     }
 }
+
+/// Command configuration for [`Backend::Custom`] runs.
+///
+/// Written into each scenario's `ralph.yml` as `cli.backend: custom`, so the
+/// command behaves exactly as a user's custom backend would.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CustomBackend {
+    /// Executable to run.
+    pub command: String,
+    /// Arguments inserted before the prompt.
+    pub args: Vec<String>,
+    /// How the prompt is passed: `"arg"` or `"stdin"`.
+    pub prompt_mode: String,
+}
+
+impl CustomBackend {
+    /// Parses a whitespace-separated command line (`"my-proxy --model x"`).
+    ///
+    /// Returns `None` if the command line is empty.
+    pub fn from_command_line(command_line: &str) -> Option<Self> {
+        let mut parts = command_line.split_whitespace().map(str::to_string);
+        Some(Self {
+            command: parts.next()?,
+            args: parts.collect(),
+            prompt_mode: "arg".to_string(),
+        })
+    }
+
+    /// Sets how the prompt is passed to the command.
+    pub fn with_prompt_mode(mut self, prompt_mode: impl Into<String>) -> Self {
+        self.prompt_mode = prompt_mode.into();
+        self
+    }
+}
@@ -21,6 +21,7 @@
 //!         max_iterations: 1,
 //!         timeout: Duration::from_secs(60),
 //!         extra_args: vec![],
+//!         seed_events: vec![],
 //!     };
 //!
 //!     let result = executor.run(&config).await.unwrap();
@@ -50,6 +51,10 @@ pub struct ScenarioConfig {
 
     /// Additional CLI arguments.
     pub extra_args: Vec<String>,
+
+    /// Events as `(topic, payload)` pairs written to the workspace's events
+    /// file before `ralph run` is invoked (e.g. to exercise `--continue`).
+    pub seed_events: Vec<(String, String)>,
 }
 
 impl ScenarioConfig {
@@ -61,6 +66,7 @@ impl ScenarioConfig {
             max_iterations: 1,
             timeout: Duration::from_mins(5), // 5 minutes - Claude iterations can take 60-120s
             extra_args: vec![],
+            seed_events: vec![],
         }
     }
 }
@@ -104,6 +110,10 @@ pub struct ExecutionResult {
 
     /// Whether the execution timed out.
     pub timed_out: bool,
+
+    /// Commands the mock CLI refused because they matched no `--allow` prefix.
+    #[serde(default)]
+    pub denied_commands: Vec<String>,
 }
 
 /// A recorded event from Ralph execution.
@@ -135,6 +145,18 @@ pub enum ExecutorError {
     #[error("ralph binary not found")]
     RalphNotFound,
 
+    /// A binary override env var points at a missing or non-executable file.
+    #[error("{var}={} is not usable: {reason}", path.display())]
+    InvalidBinaryOverride {
+        var: &'static str,
+        path: PathBuf,
+        reason: &'static str,
+    },
+
+    /// Failed to write the scenario's seed events.
+    #[error("failed to seed events: {0}")]
+    SeedEventsError(std::io::Error),
+
     /// Execution timed out.
     #[error("execution timed out after {0:?}")]
     Timeout(Duration),
@@ -156,15 +178,27 @@ pub fn find_workspace_root() -> Option<PathBuf> {
     }
 }
 
+/// Env var that pins the ralph binary used by e2e runs.
+pub const RALPH_BINARY_ENV: &str = "RALPH_BINARY";
+
 /// Resolves the path to the ralph binary.
 ///
 /// Resolution order:
-/// 1. `target/release/ralph` (prefer optimized builds)
-/// 2. `target/debug/ralph` (development builds)
-/// 3. Falls back to "ralph" (PATH lookup)
+/// 1. `$RALPH_BINARY` (must point at an existing executable file)
+/// 2. `target/release/ralph` (prefer optimized builds)
+/// 3. `target/debug/ralph` (development builds)
+/// 4. Falls back to "ralph" (PATH lookup)
 ///
 /// This ensures e2e tests run against the locally built code, not a system-installed version.
-pub fn resolve_ralph_binary() -> PathBuf {
+///
+/// # Errors
+/// Returns [`ExecutorError::InvalidBinaryOverride`] if `$RALPH_BINARY` is set
+/// to a path that doesn't exist or isn't executable.
+pub fn resolve_ralph_binary() -> Result<PathBuf, ExecutorError> {
+    if let Some(path) = std::env::var_os(RALPH_BINARY_ENV) {
+        return validate_binary_override(RALPH_BINARY_ENV, PathBuf::from(path));
+    }
+
     // Try workspace root from cwd first, then CARGO_MANIFEST_DIR (covers
     // cases where cwd has been changed to a temp/artifacts directory).
     let roots = [
@@ -179,18 +213,39 @@ pub fn resolve_ralph_binary() -> PathBuf {
         // Check for release binary first (faster)
         let release_binary = root.join("target/release/ralph");
         if release_binary.exists() {
-            return release_binary;
+            return Ok(release_binary);
         }
 
         // Fall back to debug binary
         let debug_binary = root.join("target/debug/ralph");
         if debug_binary.exists() {
-            return debug_binary;
+            return Ok(debug_binary);
         }
     }
 
     // Fall back to PATH lookup
-    PathBuf::from("ralph")
+    Ok(PathBuf::from("ralph"))
+}
+
+/// Checks that a binary named by an override env var exists and is executable.
+fn validate_binary_override(var: &'static str, path: PathBuf) -> Result<PathBuf, ExecutorError> {
+    let invalid = |reason| ExecutorError::InvalidBinaryOverride {
+        var,
+        path: path.clone(),
+        reason,
+    };
+    let metadata = std::fs::metadata(&path).map_err(|_| invalid("file does not exist"))?;
+    if !metadata.is_file() {
+        return Err(invalid("not a file"));
+    }
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        if metadata.permissions().mode() & 0o111 == 0 {
+            return Err(invalid("file is not executable"));
+        }
+    }
+    Ok(path)
 }
 
 /// Executes Ralph with test configurations.
@@ -260,6 +315,12 @@ impl RalphExecutor {
             return Err(ExecutorError::ConfigNotFound(config_path));
         }
 
+        if !config.seed_events.is_empty() {
+            self.seed_events(&config.seed_events)
+                .await
+                .map_err(ExecutorError::SeedEventsError)?;
+        }
+
         let start = Instant::now();
 
         // Build the command
@@ -338,6 +399,7 @@ impl RalphExecutor {
                     iterations,
                     termination_reason,
                     timed_out: false,
+                    denied_commands: self.read_denied_commands().await,
                 })
             }
             Ok(Err(e)) => Err(ExecutorError::SpawnError(e)),
@@ -355,6 +417,7 @@ impl RalphExecutor {
                     iterations: 0,
                     termination_reason: Some("TIMEOUT".to_string()),
                     timed_out: true,
+                    denied_commands: self.read_denied_commands().await,
                 })
             }
         }
@@ -366,27 +429,70 @@ impl RalphExecutor {
         tokio::fs::read_to_string(scratchpad_path).await.ok()
     }
 
-    /// Reads events from .ralph/events.jsonl file.
-    ///
-    /// Ralph writes events to JSONL format since commit dfb8f8de.
-    /// Each line is a JSON object with "topic" and "payload" fields.
-    async fn read_events_from_jsonl(&self) -> Vec<EventRecord> {
-        // Find the current events file (uses marker file for timestamped paths)
+    /// Reads commands the mock CLI denied, one per line, if any were recorded.
+    async fn read_denied_commands(&self) -> Vec<String> {
+        let denied_path = self.workspace.join(crate::mock_cli::DENIED_COMMANDS_FILE);
+        tokio::fs::read_to_string(denied_path)
+            .await
+            .map(|content| content.lines().map(str::to_string).collect())
+            .unwrap_or_default()
+    }
+
+    /// Resolves the current events file, following the `current-events` marker
+    /// when it points at an existing file and falling back to `.ralph/events.jsonl`.
+    async fn current_events_path(&self) -> PathBuf {
         let events_marker = self.workspace.join(".ralph").join("current-events");
         let fallback_path = self.workspace.join(".ralph/events.jsonl");
 
-        let events_path = match tokio::fs::read_to_string(&events_marker).await {
+        match tokio::fs::read_to_string(&events_marker).await {
             Ok(path) => {
                 let marker_path = self.workspace.join(path.trim());
                 // Fall back to events.jsonl if marker-pointed file doesn't exist
                 if tokio::fs::metadata(&marker_path).await.is_ok() {
                     marker_path
                 } else {
-                    fallback_path.clone()
+                    fallback_path
                 }
             }
-            Err(_) => fallback_path.clone(), // marker file missing
-        };
+            Err(_) => fallback_path, // marker file missing
+        }
+    }
+
+    /// Appends seed events to the current events file, one JSON object per line.
+    async fn seed_events(&self, seed_events: &[(String, String)]) -> std::io::Result<()> {
+        use tokio::io::AsyncWriteExt;
+
+        let events_path = self.current_events_path().await;
+        if let Some(parent) = events_path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+
+        let mut content = String::new();
+        for (topic, payload) in seed_events {
+            let record = serde_json::json!({
+                "topic": topic,
+                "payload": payload,
+                "ts": chrono::Utc::now().to_rfc3339(),
+            });
+            content.push_str(&record.to_string());
+            content.push('\n');
+        }
+
+        let mut file = tokio::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&events_path)
+            .await?;
+        file.write_all(content.as_bytes()).await?;
+        file.flush().await
+    }
+
+    /// Reads events from .ralph/events.jsonl file.
+    ///
+    /// Ralph writes events to JSONL format since commit dfb8f8de.
+    /// Each line is a JSON object with "topic" and "payload" fields.
+    async fn read_events_from_jsonl(&self) -> Vec<EventRecord> {
+        let events_path = self.current_events_path().await;
 
         let mut events = Vec::new();
         if let Ok(content) = tokio::fs::read_to_string(&events_path).await {
@@ -500,7 +606,10 @@ mod tests {
 
     #[test]
     fn test_resolve_ralph_binary_finds_local_or_path() {
-        let binary = super::resolve_ralph_binary();
+        if std::env::var_os(super::RALPH_BINARY_ENV).is_some() {
+            return;
+        }
+        let binary = super::resolve_ralph_binary().unwrap();
         // Should return something - either a local build or "ralph" for PATH
         let binary_str = binary.to_string_lossy();
         assert!(
@@ -512,6 +621,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_binary_override_rejects_missing_path() {
+        let dir = tempfile::tempdir().unwrap();
+        let err = super::validate_binary_override("RALPH_BINARY", dir.path().join("nope"))
+            .unwrap_err()
+            .to_string();
+        assert!(err.starts_with("RALPH_BINARY="), "{err}");
+        assert!(err.contains("does not exist"), "{err}");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_binary_override_requires_executable_file() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let binary = dir.path().join("ralph");
+        fs::write(&binary, "#!/bin/sh\n").unwrap();
+        fs::set_permissions(&binary, fs::Permissions::from_mode(0o644)).unwrap();
+
+        let err = super::validate_binary_override("RALPH_BINARY", binary.clone()).unwrap_err();
+        assert!(err.to_string().contains("not executable"), "{err}");
+
+        fs::set_permissions(&binary, fs::Permissions::from_mode(0o755)).unwrap();
+        let resolved = super::validate_binary_override("RALPH_BINARY", binary.clone()).unwrap();
+        assert_eq!(resolved, binary);
+    }
+
     #[test]
     fn test_executor_new() {
         let workspace = PathBuf::from("/tmp/test-workspace");
@@ -537,6 +674,7 @@ mod tests {
         assert_eq!(config.max_iterations, 1);
         assert_eq!(config.timeout, Duration::from_mins(5));
         assert!(config.extra_args.is_empty());
+        assert!(config.seed_events.is_empty());
     }
 
     #[test]
@@ -614,6 +752,29 @@ mod tests {
         cleanup_workspace(&workspace);
     }
 
+    #[tokio::test]
+    async fn test_seed_events_round_trip_through_events_file() {
+        let workspace = test_workspace("seed-events");
+        fs::create_dir_all(&workspace).unwrap();
+
+        let executor = RalphExecutor::new(workspace.clone());
+        executor
+            .seed_events(&[
+                ("task.start".to_string(), "Original task".to_string()),
+                ("build.done".to_string(), "tests: pass".to_string()),
+            ])
+            .await
+            .unwrap();
+
+        let events = executor.read_events_from_jsonl().await;
+        cleanup_workspace(&workspace);
+
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].topic, "task.start");
+        assert_eq!(events[1].topic, "build.done");
+        assert_eq!(events[1].payload, "tests: pass");
+    }
+
     #[tokio::test]
     async fn test_execution_result_serialization() {
         let result = ExecutionResult {
@@ -629,6 +790,7 @@ mod tests {
             iterations: 2,
             termination_reason: Some("LOOP_COMPLETE".to_string()),
             timed_out: false,
+            denied_commands: vec![],
         };
 
         // Serialize to JSON
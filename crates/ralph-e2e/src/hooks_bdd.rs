@@ -446,7 +446,7 @@ impl HooksBddIntegrationHarness {
         args: &[&str],
         timeout: Duration,
     ) -> Result<HooksBddRunArtifact, String> {
-        let ralph_binary = resolve_ralph_binary();
+        let ralph_binary = resolve_ralph_binary().map_err(|e| e.to_string())?;
         self.run_bounded_command(
             artifact_name,
             workspace_dir,
@@ -35,8 +35,8 @@ pub use crate::analyzer::{
     MetaRalphAnalyzer, Optimization, PassedAnalysis, PassedTestAnalysis, Pattern, PotentialFix,
     QualityScore, Recommendation, Severity, TestMetrics, Warning, WarningCategory,
 };
-pub use crate::auth::{AuthChecker, BackendInfo};
-pub use crate::backend::Backend;
+pub use crate::auth::{AuthChecker, BackendInfo, DEFAULT_AUTH_CACHE_TTL};
+pub use crate::backend::{Backend, CustomBackend};
 pub use crate::executor::{
     EventRecord, ExecutionResult, ExecutorError, PromptSource, RalphExecutor, ScenarioConfig,
     find_workspace_root, resolve_ralph_binary,
@@ -46,14 +46,19 @@ pub use crate::hooks_bdd::{
     discover_hooks_bdd_scenarios, run_hooks_bdd_suite,
 };
 pub use crate::mock::{
-    CassetteError, CassetteResolver, DEFAULT_CASSETTE_DIR, MockConfig, build_mock_cli_args,
+    CassetteError, CassetteMatcher, CassetteResolver, DEFAULT_CASSETTE_DIR, MockConfig,
+    build_mock_cli_args,
+};
+pub use crate::mock_cli::{
+    DENIED_COMMANDS_FILE, MockCliError, MockCliReport, PROMPT_EVENT, run as run_mock_cli,
+    run_matching as run_matching_mock_cli, run_or_record as run_or_record_mock_cli,
 };
-pub use crate::mock_cli::{MockCliError, run as run_mock_cli};
 pub use crate::models::{Assertion, ReportFormat, TestResult};
 pub use crate::reporter::{
-    AnalyzedResultData, BackendSummary, JsonReporter, MarkdownReporter, QualityBreakdown,
-    ReportSummary, ReportWriter, ReporterError, TerminalReporter, TestReport, TierSummary,
-    Verbosity, create_incremental_progress_callback, create_progress_callback,
+    AnalyzedResultData, BackendSummary, HISTORY_FILE_NAME, HistoryEntry, JsonReporter,
+    MarkdownReporter, QualityBreakdown, REPORT_SCHEMA_VERSION, ReportSummary, ReportWriter,
+    ReporterError, TerminalReporter, TestReport, TierSummary, Verbosity,
+    create_incremental_progress_callback, create_progress_callback, glyph, set_no_emoji,
 };
 pub use crate::runner::{
     ProgressCallback, ProgressEvent, RunConfig, RunResults, RunnerError, TestRunner,
@@ -72,6 +77,7 @@ pub use crate::scenarios::{
     CompletionScenario,
     // Tier 1: Connectivity (backend-agnostic)
     ConnectivityScenario,
+    ContinueSeededEventsScenario,
     EventsScenario,
     // Tier 5: Hat Collections (backend-agnostic)
     HatBackendOverrideScenario,
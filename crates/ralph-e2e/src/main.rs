@@ -32,10 +32,14 @@ use ralph_e2e::{
     BackendUnavailableScenario,
     // Tier 3: Events
     BackpressureScenario,
+    CassetteMatcher,
     // Tier 2: Orchestration Loop
     CompletionScenario,
     // Tier 1: Connectivity
     ConnectivityScenario,
+    ContinueSeededEventsScenario,
+    CustomBackend,
+    DEFAULT_AUTH_CACHE_TTL,
     EventsScenario,
     // Tier 5: Hat Collections
     HatBackendOverrideScenario,
@@ -71,9 +75,13 @@ use ralph_e2e::{
     WorkspaceManager,
     create_incremental_progress_callback,
     discover_hooks_bdd_scenarios,
+    glyph,
     resolve_ralph_binary,
     run_hooks_bdd_suite,
+    run_matching_mock_cli,
     run_mock_cli,
+    run_or_record_mock_cli,
+    set_no_emoji,
 };
 
 /// Backend selection for E2E tests.
@@ -88,6 +96,8 @@ pub enum Backend {
     Kiro,
     /// Test OpenCode backend only
     Opencode,
+    /// Test a custom command (set with --backend-cmd)
+    Custom,
 }
 
 impl std::fmt::Display for Backend {
@@ -97,6 +107,7 @@ impl std::fmt::Display for Backend {
             Backend::Claude => write!(f, "claude"),
             Backend::Kiro => write!(f, "kiro"),
             Backend::Opencode => write!(f, "opencode"),
+            Backend::Custom => write!(f, "custom"),
         }
     }
 }
@@ -109,6 +120,7 @@ impl Backend {
             Backend::Claude => Some(LibBackend::Claude),
             Backend::Kiro => Some(LibBackend::Kiro),
             Backend::Opencode => Some(LibBackend::OpenCode),
+            Backend::Custom => Some(LibBackend::Custom),
         }
     }
 }
@@ -133,9 +145,17 @@ pub struct Cli {
 pub enum Command {
     /// Mock CLI adapter for replaying cassettes (used as custom backend).
     MockCli {
-        /// Path to the cassette file to replay
+        /// Path to the cassette file to replay; repeat to give --match-prompt candidates
+        #[arg(long, required = true)]
+        cassette: Vec<std::path::PathBuf>,
+
+        /// Replay the cassette whose recorded prompt matches the prompt on stdin
         #[arg(long)]
-        cassette: std::path::PathBuf,
+        match_prompt: bool,
+
+        /// Regex stripped from both prompts before matching (repeatable)
+        #[arg(long, value_name = "REGEX", requires = "match_prompt")]
+        strip: Vec<String>,
 
         /// Replay speed multiplier (0.0 = instant, 1.0 = real-time, 10.0 = 10x faster)
         #[arg(long, default_value = "0.0")]
@@ -144,6 +164,14 @@ pub enum Command {
         /// Comma-separated list of allowed command prefixes
         #[arg(long)]
         allow: Option<String>,
+
+        /// If the cassette doesn't exist, forward to the real backend and record it
+        #[arg(long, requires = "backend")]
+        record_missing: bool,
+
+        /// Real backend to forward to with --record-missing
+        #[arg(long, value_enum)]
+        backend: Option<Backend>,
     },
 }
 
@@ -154,6 +182,15 @@ pub struct TestOpts {
     #[arg(value_enum, default_value_t = Backend::All)]
     pub backend: Backend,
 
+    /// Command for the custom backend, e.g. "my-proxy --model fast".
+    /// Runs scenarios that support custom backends through ralph's `custom` backend.
+    #[arg(long, value_name = "CMD", required_if_eq("backend", "custom"))]
+    pub backend_cmd: Option<String>,
+
+    /// How the custom backend receives the prompt
+    #[arg(long, value_name = "MODE", value_parser = ["arg", "stdin"], default_value = "arg", requires = "backend_cmd")]
+    pub backend_prompt_mode: String,
+
     /// Show detailed output during tests
     #[arg(short, long)]
     pub verbose: bool,
@@ -162,6 +199,10 @@ pub struct TestOpts {
     #[arg(short, long)]
     pub quiet: bool,
 
+    /// Replace emoji with ASCII in terminal output (also `RALPH_NO_EMOJI=1`)
+    #[arg(long)]
+    pub no_emoji: bool,
+
     /// List available test scenarios without running them
     #[arg(long)]
     pub list: bool,
@@ -170,6 +211,10 @@ pub struct TestOpts {
     #[arg(long)]
     pub filter: Option<String>,
 
+    /// Run only tests with this tag (repeatable; matches any tag, ANDed with --filter)
+    #[arg(long = "tag", value_name = "TAG")]
+    pub tags: Vec<String>,
+
     /// Run the hooks BDD acceptance suite from `features/hooks/*.feature`
     #[arg(long)]
     pub hooks_bdd: bool,
@@ -178,6 +223,14 @@ pub struct TestOpts {
     #[arg(long, value_enum, default_value_t = ReportFormat::Markdown)]
     pub report: ReportFormat,
 
+    /// Stop after the first failed scenario (a report is still written)
+    #[arg(long)]
+    pub bail: bool,
+
+    /// Randomize scenario order; pass a seed to reproduce a previous order
+    #[arg(long, value_name = "SEED")]
+    pub shuffle: Option<Option<u64>>,
+
     /// Keep test workspaces after tests complete (for debugging)
     #[arg(long)]
     pub keep_workspace: bool,
@@ -190,9 +243,21 @@ pub struct TestOpts {
     #[arg(long)]
     pub mock: bool,
 
+    /// In mock mode, record missing cassettes from the real backend
+    #[arg(long, requires = "mock")]
+    pub record_missing: bool,
+
     /// Replay speed for mock mode (0.0 = instant, 10.0 = 10x faster)
     #[arg(long, default_value = "0.0")]
     pub mock_speed: f32,
+
+    /// In mock mode, replay the cassette whose recorded prompt matches
+    #[arg(long, requires = "mock")]
+    pub match_prompt: bool,
+
+    /// Regex stripped from prompts before matching, e.g. timestamps (repeatable)
+    #[arg(long, value_name = "REGEX", requires = "match_prompt")]
+    pub cassette_strip: Vec<String>,
 }
 
 /// Report output format.
@@ -230,6 +295,7 @@ fn get_all_scenarios() -> Vec<Box<dyn TestScenario>> {
         // Tier 3: Events (backend-agnostic)
         Box::new(EventsScenario::new()),
         Box::new(BackpressureScenario::new()),
+        Box::new(ContinueSeededEventsScenario::new()),
         // Tier 4: Capabilities (backend-agnostic)
         Box::new(ToolUseScenario::new()),
         Box::new(StreamingScenario::new()),
@@ -259,17 +325,38 @@ fn get_all_scenarios() -> Vec<Box<dyn TestScenario>> {
 
 fn main() {
     let cli = Cli::parse();
+    set_no_emoji(
+        cli.test_opts.no_emoji
+            || std::env::var("RALPH_NO_EMOJI").is_ok_and(|value| !value.is_empty() && value != "0"),
+    );
 
     // Handle subcommands
     if let Some(command) = cli.command {
         match command {
             Command::MockCli {
                 cassette,
+                match_prompt,
+                strip,
                 speed,
                 allow,
+                record_missing,
+                backend,
             } => {
-                // Run the mock CLI
-                if let Err(e) = run_mock_cli(&cassette, speed, allow.as_deref()) {
+                // Run the mock CLI, recording the cassette first if requested
+                let record_backend = backend.and_then(Backend::to_lib_backend);
+                let result = match record_backend {
+                    Some(backend) if record_missing && !cassette[0].exists() => {
+                        run_or_record_mock_cli(&cassette[0], speed, allow.as_deref(), backend)
+                            .map_err(|e| e.to_string())
+                    }
+                    _ if match_prompt => cassette_matcher(&strip).and_then(|matcher| {
+                        run_matching_mock_cli(&cassette, &matcher, speed, allow.as_deref())
+                            .map_err(|e| e.to_string())
+                    }),
+                    _ => run_mock_cli(&cassette[0], speed, allow.as_deref())
+                        .map_err(|e| e.to_string()),
+                };
+                if let Err(e) = result {
                     eprintln!("{} {}", "Error:".red().bold(), e);
                     std::process::exit(1);
                 }
@@ -281,7 +368,7 @@ fn main() {
     // Print header for test runs
     println!(
         "\n{} {}",
-        "🧪 E2E Test Harness".bold(),
+        format!("{} E2E Test Harness", glyph("🧪", "[e2e]")).bold(),
         format!("v{}", env!("CARGO_PKG_VERSION")).dimmed()
     );
     println!("{}", "━".repeat(40).dimmed());
@@ -311,12 +398,40 @@ fn main() {
     // Run the tests
     let rt = tokio::runtime::Runtime::new().expect("Failed to create Tokio runtime");
 
+    // One checker serves both --list and runs; its on-disk cache lets a run
+    // right after --list reuse the probe results the list displayed.
+    let checker = AuthChecker::new().with_cache(
+        e2e_workspace_path().join("backend-status.json"),
+        DEFAULT_AUTH_CACHE_TTL,
+    );
+
     if cli.test_opts.list {
-        rt.block_on(list_scenarios(&cli.test_opts, verbosity));
+        rt.block_on(list_scenarios(&cli.test_opts, verbosity, &checker));
         return;
     }
 
-    rt.block_on(run_tests(&cli.test_opts, verbosity));
+    rt.block_on(run_tests(&cli.test_opts, verbosity, &checker));
+}
+
+/// Returns the absolute `.e2e-tests` directory under the current directory.
+///
+/// The PTY executor calls std::env::current_dir() which requires the workspace to exist.
+/// Using absolute paths ensures the workspace is resolvable regardless of working directory changes.
+/// Builds the prompt matcher for `--strip`/`--cassette-strip` patterns.
+fn cassette_matcher(patterns: &[String]) -> Result<CassetteMatcher, String> {
+    patterns
+        .iter()
+        .try_fold(CassetteMatcher::new(), |matcher, pattern| {
+            matcher
+                .strip(pattern)
+                .map_err(|e| format!("invalid strip pattern '{pattern}': {e}"))
+        })
+}
+
+fn e2e_workspace_path() -> std::path::PathBuf {
+    std::env::current_dir()
+        .expect("Failed to get current directory")
+        .join(".e2e-tests")
 }
 
 fn list_hooks_bdd_scenarios(opts: &TestOpts, verbosity: Verbosity) {
@@ -391,9 +506,9 @@ fn run_hooks_bdd_placeholder_suite(opts: &TestOpts, verbosity: Verbosity) {
 
     for result in &results.results {
         let status = if result.passed {
-            "✅ PASS".green()
+            format!("{} PASS", glyph("✅", "[ok]")).green()
         } else {
-            "❌ FAIL".red()
+            format!("{} FAIL", glyph("❌", "[x]")).red()
         };
 
         println!(
@@ -425,20 +540,21 @@ fn run_hooks_bdd_placeholder_suite(opts: &TestOpts, verbosity: Verbosity) {
     }
 }
 
-async fn list_scenarios(opts: &TestOpts, verbosity: Verbosity) {
+async fn list_scenarios(opts: &TestOpts, verbosity: Verbosity, checker: &AuthChecker) {
     // Check backend availability (skip in mock mode)
     if !opts.mock && verbosity != Verbosity::Quiet {
         println!("\n{}", "Checking backends...".dimmed());
-        let checker = AuthChecker::new();
         let backends = checker.check_all().await;
 
         for info in backends {
             let status = match info.status_string().as_str() {
-                s if s.contains("Authenticated") => format!("✅ {} - {}", info.backend, s).green(),
+                s if s.contains("Authenticated") => {
+                    format!("{} {} - {}", glyph("✅", "[ok]"), info.backend, s).green()
+                }
                 s if s.contains("Not authenticated") => {
-                    format!("⚠️  {} - {}", info.backend, s).yellow()
+                    format!("{}  {} - {}", glyph("⚠️", "[!]"), info.backend, s).yellow()
                 }
-                s => format!("❌ {} - {}", info.backend, s).red(),
+                s => format!("{} {} - {}", glyph("❌", "[x]"), info.backend, s).red(),
             };
             println!("  {}", status);
         }
@@ -459,6 +575,16 @@ async fn list_scenarios(opts: &TestOpts, verbosity: Verbosity) {
             continue;
         }
 
+        // Filter by tag if specified
+        if !opts.tags.is_empty()
+            && !scenario
+                .tags()
+                .iter()
+                .any(|tag| opts.tags.iter().any(|t| t.eq_ignore_ascii_case(tag)))
+        {
+            continue;
+        }
+
         // Print tier header if changed
         if scenario.tier() != current_tier {
             current_tier = scenario.tier().to_string();
@@ -466,9 +592,10 @@ async fn list_scenarios(opts: &TestOpts, verbosity: Verbosity) {
         }
 
         println!(
-            "    {}  {}",
+            "    {}  {} {}",
             scenario.id().cyan(),
-            scenario.description().dimmed()
+            scenario.description().dimmed(),
+            format!("[{}]", scenario.tags().join(", ")).dimmed()
         );
     }
 
@@ -487,70 +614,80 @@ async fn list_scenarios(opts: &TestOpts, verbosity: Verbosity) {
     );
 }
 
-async fn run_tests(opts: &TestOpts, verbosity: Verbosity) {
-    // Check backend availability first (skip in mock mode)
-    if !opts.mock && verbosity != Verbosity::Quiet {
-        println!();
-        let checker = AuthChecker::new();
-
-        if let Some(backend) = opts.backend.to_lib_backend() {
-            let info = checker.check(backend).await;
-            let status = info.status_string();
-            let status_fmt = if status.contains("Authenticated") {
-                format!("{}: {} ✅", info.backend, status).green()
-            } else if status.contains("Not authenticated") {
-                format!("{}: {} ⚠️", info.backend, status).yellow()
-            } else {
-                format!("{}: {} ❌", info.backend, status).red()
-            };
-            println!("{}", status_fmt);
-        } else {
-            println!("{}", "Checking all backends...".dimmed());
-            for info in checker.check_all().await {
-                let status = match info.status_string().as_str() {
-                    s if s.contains("Authenticated") => {
-                        format!("  ✅ {} - {}", info.backend, s).green()
-                    }
-                    s if s.contains("Not authenticated") => {
-                        format!("  ⚠️  {} - {}", info.backend, s).yellow()
-                    }
-                    s => format!("  ❌ {} - {}", info.backend, s).red(),
-                };
-                println!("{}", status);
-            }
-        }
-    }
-
+async fn run_tests(opts: &TestOpts, verbosity: Verbosity, checker: &AuthChecker) {
     // Set up workspace manager with absolute path
-    // The PTY executor calls std::env::current_dir() which requires the workspace to exist.
-    // Using absolute paths ensures the workspace is resolvable regardless of working directory changes.
-    let workspace_path = std::env::current_dir()
-        .expect("Failed to get current directory")
-        .join(".e2e-tests");
+    let workspace_path = e2e_workspace_path();
     let workspace_mgr = WorkspaceManager::new(workspace_path.clone());
 
     // Get scenarios
     let scenarios = get_all_scenarios();
 
     // Build run configuration
-    let mut config = RunConfig::new().keep_workspaces(opts.keep_workspace);
+    let mut config = RunConfig::new()
+        .keep_workspaces(opts.keep_workspace)
+        .bail(opts.bail);
 
     if let Some(filter) = &opts.filter {
         config = config.with_filter(filter);
     }
 
+    for tag in &opts.tags {
+        config = config.with_tag(tag);
+    }
+
+    if let Some(seed) = opts.shuffle {
+        let seed = seed.unwrap_or_else(|| {
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_nanos() as u64)
+                .unwrap_or_default()
+        });
+        // Always print the seed so a failing order can be replayed
+        println!(
+            "{}",
+            format!("Shuffling scenario order with seed {seed} (reproduce with --shuffle {seed})")
+                .yellow()
+        );
+        config = config.shuffle(seed);
+    }
+
     if let Some(backend) = opts.backend.to_lib_backend() {
         config = config.with_backend(backend);
     }
 
+    if let Some(command_line) = &opts.backend_cmd {
+        let Some(custom) = CustomBackend::from_command_line(command_line) else {
+            eprintln!("{} --backend-cmd must not be empty", "Error:".red().bold());
+            std::process::exit(1);
+        };
+        config = config.with_custom_backend(custom.with_prompt_mode(&opts.backend_prompt_mode));
+    }
+
     // Configure mock mode if enabled
     if opts.mock {
-        let mock_config = MockConfig::default().with_speed(opts.mock_speed);
+        let mut mock_config = MockConfig::default()
+            .with_speed(opts.mock_speed)
+            .with_record_missing(opts.record_missing);
+        if opts.match_prompt {
+            match cassette_matcher(&opts.cassette_strip) {
+                Ok(matcher) => mock_config = mock_config.with_matcher(matcher),
+                Err(e) => {
+                    eprintln!("{} {}", "Error:".red().bold(), e);
+                    std::process::exit(1);
+                }
+            }
+        }
         config = config.with_mock(mock_config);
     }
 
     // Resolve the ralph binary to use (local build preferred over PATH)
-    let ralph_binary = resolve_ralph_binary();
+    let ralph_binary = match resolve_ralph_binary() {
+        Ok(binary) => binary,
+        Err(e) => {
+            eprintln!("{} {}", "Error:".red().bold(), e);
+            std::process::exit(1);
+        }
+    };
     if verbosity != Verbosity::Quiet {
         println!(
             "{}",
@@ -566,6 +703,19 @@ async fn run_tests(opts: &TestOpts, verbosity: Verbosity) {
             workspace_path.clone(),
         ));
 
+    // Check backend availability first (skip in mock mode); results stream
+    // through the progress callback like scenario updates
+    if !opts.mock && verbosity != Verbosity::Quiet {
+        println!("\n{}", "Checking backends...".dimmed());
+        let mut backends = match opts.backend.to_lib_backend() {
+            Some(backend) => vec![backend],
+            None => LibBackend::all().to_vec(),
+        };
+        // Custom commands have no generic availability probe
+        backends.retain(|b| *b != LibBackend::Custom);
+        runner.check_backends(checker, &backends).await;
+    }
+
     // Notify about live report
     if verbosity != Verbosity::Quiet {
         println!(
@@ -602,6 +752,13 @@ async fn run_tests(opts: &TestOpts, verbosity: Verbosity) {
             eprintln!("{} Failed to write report: {}", "Warning:".yellow(), e);
         }
     }
+    if let Err(e) = report_writer.append_history(&results, None) {
+        eprintln!(
+            "{} Failed to append run history: {}",
+            "Warning:".yellow(),
+            e
+        );
+    }
 
     // Print summary
     let reporter = TerminalReporter::with_verbosity(verbosity);
@@ -40,6 +40,7 @@
 //! ```
 
 use crate::Backend;
+use regex::Regex;
 use std::path::{Path, PathBuf};
 use thiserror::Error;
 
@@ -67,6 +68,128 @@ pub enum CassetteError {
     /// Cassette file contains invalid JSONL.
     #[error("cassette parse error in {path}: {message}")]
     ParseError { path: PathBuf, message: String },
+
+    /// No candidate cassette recorded the prompt, even after normalization.
+    ///
+    /// `closest` is the candidate whose recorded prompt differs on the fewest
+    /// lines, and `diff` shows the first line where the two prompts differ.
+    #[error(
+        "no cassette recorded this prompt (tried {tried:?}){}",
+        closest.as_ref().map(|p| format!("; closest: {}\n{}", p.display(), diff)).unwrap_or_default()
+    )]
+    NoMatch {
+        tried: Vec<PathBuf>,
+        closest: Option<PathBuf>,
+        diff: String,
+    },
+
+    /// The cassette passed to mock-cli does not exist.
+    #[error("cassette file does not exist: {0}")]
+    MissingFile(PathBuf),
+
+    /// The command allow-list for mock-cli is malformed.
+    #[error("invalid mock-cli allow list {allow:?}: {reason}")]
+    InvalidAllowList { allow: String, reason: String },
+}
+
+/// Matches the prompt mock-cli receives against the prompts recorded in cassettes.
+///
+/// Each pattern is removed from both prompts before comparing them, so
+/// volatile parts (timestamps, run IDs, absolute workspace paths) don't cause
+/// misses between recording and replay.
+#[derive(Debug, Clone, Default)]
+pub struct CassetteMatcher {
+    /// Patterns whose matches are stripped before comparison.
+    strip: Vec<Regex>,
+}
+
+impl CassetteMatcher {
+    /// Creates a matcher with no normalization (exact prompt matching).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a pattern to strip before matching.
+    pub fn strip(mut self, pattern: &str) -> Result<Self, regex::Error> {
+        self.strip.push(Regex::new(pattern)?);
+        Ok(self)
+    }
+
+    /// Returns the strip patterns, in the order they were added.
+    pub fn patterns(&self) -> impl Iterator<Item = &str> {
+        self.strip.iter().map(Regex::as_str)
+    }
+
+    /// Returns `prompt` with every strip pattern removed.
+    pub fn normalize(&self, prompt: &str) -> String {
+        self.strip.iter().fold(prompt.to_string(), |acc, re| {
+            re.replace_all(&acc, "").into_owned()
+        })
+    }
+
+    /// Picks the first candidate whose recorded prompt matches `prompt`.
+    ///
+    /// Candidates are `(cassette, recorded prompt)` pairs in priority order.
+    /// A cassette without a recorded prompt matches any prompt, so cassettes
+    /// recorded before prompts were stored keep replaying.
+    pub fn select(
+        &self,
+        prompt: &str,
+        candidates: &[(PathBuf, Option<String>)],
+    ) -> Result<PathBuf, CassetteError> {
+        let wanted = self.normalize(prompt);
+        let recorded: Vec<(&PathBuf, Option<String>)> = candidates
+            .iter()
+            .map(|(path, recorded)| (path, recorded.as_deref().map(|r| self.normalize(r))))
+            .collect();
+
+        if let Some((path, _)) = recorded
+            .iter()
+            .find(|(_, recorded)| recorded.as_ref().is_none_or(|r| *r == wanted))
+        {
+            return Ok((*path).clone());
+        }
+
+        let closest = recorded
+            .iter()
+            .filter_map(|(path, recorded)| Some((*path, recorded.as_deref()?)))
+            .min_by_key(|(_, recorded)| mismatched_lines(recorded, &wanted));
+
+        Err(CassetteError::NoMatch {
+            tried: candidates.iter().map(|(path, _)| path.clone()).collect(),
+            closest: closest.map(|(path, _)| path.clone()),
+            diff: closest
+                .map(|(_, recorded)| prompt_diff(recorded, &wanted))
+                .unwrap_or_default(),
+        })
+    }
+}
+
+/// Counts the lines that differ between two prompts, position by position.
+fn mismatched_lines(a: &str, b: &str) -> usize {
+    let (a, b): (Vec<&str>, Vec<&str>) = (a.lines().collect(), b.lines().collect());
+    let differing = a.iter().zip(&b).filter(|(x, y)| x != y).count();
+    differing + a.len().abs_diff(b.len())
+}
+
+/// Renders the first differing line of two normalized prompts.
+fn prompt_diff(recorded: &str, received: &str) -> String {
+    let mut recorded_lines = recorded.lines();
+    let mut received_lines = received.lines();
+    let mut line = 1;
+    loop {
+        match (recorded_lines.next(), received_lines.next()) {
+            (Some(a), Some(b)) if a == b => line += 1,
+            (None, None) => return String::new(),
+            (a, b) => {
+                return format!(
+                    "line {line}:\n- {}\n+ {}",
+                    a.unwrap_or("<end of recorded prompt>"),
+                    b.unwrap_or("<end of prompt>")
+                );
+            }
+        }
+    }
 }
 
 /// Configuration for mock mode execution.
@@ -81,6 +204,14 @@ pub struct MockConfig {
     /// Commands allowed to be executed during mock replay.
     /// Format: comma-separated command prefixes (e.g., "ralph task add,ralph tools memory add").
     pub allow_commands: Option<String>,
+
+    /// When set, mock-cli replays the candidate cassette whose recorded
+    /// prompt matches the prompt it receives.
+    pub matcher: Option<CassetteMatcher>,
+
+    /// When a scenario has no cassette, forward to the real backend and record
+    /// its output as a new cassette instead of failing.
+    pub record_missing: bool,
 }
 
 impl Default for MockConfig {
@@ -89,6 +220,8 @@ impl Default for MockConfig {
             cassette_dir: PathBuf::from(DEFAULT_CASSETTE_DIR),
             speed: 0.0, // Instant by default for CI
             allow_commands: Some("ralph task add,ralph task close,ralph tools memory add".into()),
+            matcher: None,
+            record_missing: false,
         }
     }
 }
@@ -114,6 +247,18 @@ impl MockConfig {
         self
     }
 
+    /// Selects cassettes by recorded prompt, normalized with `matcher`.
+    pub fn with_matcher(mut self, matcher: CassetteMatcher) -> Self {
+        self.matcher = Some(matcher);
+        self
+    }
+
+    /// Sets whether missing cassettes are recorded from the real backend.
+    pub fn with_record_missing(mut self, record_missing: bool) -> Self {
+        self.record_missing = record_missing;
+        self
+    }
+
     /// Disables command execution during replay.
     pub fn without_commands(mut self) -> Self {
         self.allow_commands = None;
@@ -217,6 +362,16 @@ impl CassetteResolver {
         ]
     }
 
+    /// Returns the candidates that exist on disk, in resolution order.
+    ///
+    /// With prompt matching, mock-cli chooses among all of them.
+    pub fn existing_candidates(&self, scenario: &str, backend: Backend) -> Vec<PathBuf> {
+        self.candidates(scenario, backend)
+            .into_iter()
+            .filter(|path| path.exists())
+            .collect()
+    }
+
     /// Returns the cassette directory.
     pub fn cassette_dir(&self) -> &Path {
         &self.cassette_dir
@@ -226,12 +381,41 @@ impl CassetteResolver {
 /// Builds the command-line arguments for invoking the mock CLI.
 ///
 /// This is used when configuring `ralph.yml` to use `mock-cli` as a custom backend.
-pub fn build_mock_cli_args(cassette_path: &Path, config: &MockConfig) -> Vec<String> {
-    let mut args = vec![
-        "mock-cli".to_string(),
-        "--cassette".to_string(),
-        cassette_path.to_string_lossy().to_string(),
-    ];
+/// With `record_missing` enabled, `backend` is the real backend to forward to
+/// when the cassette does not exist yet.
+///
+/// Every cassette is passed as a `--cassette` candidate; with a matcher,
+/// mock-cli replays the one whose recorded prompt matches, otherwise the first.
+///
+/// The configuration is validated up front so a bad cassette path or allow-list
+/// fails here with a descriptive error instead of inside the spawned backend.
+pub fn build_mock_cli_args(
+    cassettes: &[PathBuf],
+    config: &MockConfig,
+    backend: Backend,
+) -> Result<Vec<String>, CassetteError> {
+    if !config.record_missing
+        && let Some(missing) = cassettes.iter().find(|path| !path.is_file())
+    {
+        return Err(CassetteError::MissingFile(missing.clone()));
+    }
+    if let Some(allow) = &config.allow_commands {
+        validate_allow_list(allow)?;
+    }
+
+    let mut args = vec!["mock-cli".to_string()];
+    for cassette in cassettes {
+        args.push("--cassette".to_string());
+        args.push(cassette.to_string_lossy().to_string());
+    }
+
+    if let Some(matcher) = &config.matcher {
+        args.push("--match-prompt".to_string());
+        for pattern in matcher.patterns() {
+            args.push("--strip".to_string());
+            args.push(pattern.to_string());
+        }
+    }
 
     if config.speed > 0.0 {
         args.push("--speed".to_string());
@@ -243,7 +427,41 @@ pub fn build_mock_cli_args(cassette_path: &Path, config: &MockConfig) -> Vec<Str
         args.push(allow.clone());
     }
 
-    args
+    if config.record_missing {
+        args.push("--record-missing".to_string());
+        args.push("--backend".to_string());
+        args.push(backend.as_config_str().to_string());
+    }
+
+    Ok(args)
+}
+
+/// Checks that an allow-list is a comma-separated list of command prefixes.
+fn validate_allow_list(allow: &str) -> Result<(), CassetteError> {
+    let invalid = |reason: String| CassetteError::InvalidAllowList {
+        allow: allow.to_string(),
+        reason,
+    };
+
+    if allow.trim().is_empty() {
+        return Err(invalid(
+            "empty; use MockConfig::without_commands() to disable command execution".to_string(),
+        ));
+    }
+
+    for (index, prefix) in allow.split(',').enumerate() {
+        let prefix = prefix.trim();
+        if prefix.is_empty() {
+            return Err(invalid(format!("entry {} is empty", index + 1)));
+        }
+        if prefix.chars().any(char::is_control) {
+            return Err(invalid(format!(
+                "entry {prefix:?} contains control characters"
+            )));
+        }
+    }
+
+    Ok(())
 }
 
 #[cfg(test)]
@@ -276,6 +494,83 @@ mod tests {
         assert!(path.ends_with("connect-claude.jsonl"));
     }
 
+    #[test]
+    fn test_matcher_selects_by_normalized_prompt() {
+        let matcher = CassetteMatcher::new()
+            .strip(r"\d{4}-\d{2}-\d{2}T[\d:]+Z")
+            .unwrap()
+            .strip(r"/tmp/\S+")
+            .unwrap();
+        let candidates = vec![
+            (
+                PathBuf::from("plan.jsonl"),
+                Some("Plan the work\nStarted 2025-01-24T10:15:00Z".to_string()),
+            ),
+            (
+                PathBuf::from("build.jsonl"),
+                Some("Build it in /tmp/run-1\nStarted 2025-01-24T10:15:00Z".to_string()),
+            ),
+        ];
+
+        let chosen = matcher
+            .select(
+                "Build it in /tmp/run-2\nStarted 2026-03-01T09:00:00Z",
+                &candidates,
+            )
+            .unwrap();
+        assert_eq!(chosen, PathBuf::from("build.jsonl"));
+
+        // Without normalization the volatile parts cause a miss
+        let exact = CassetteMatcher::new().select(
+            "Build it in /tmp/run-2\nStarted 2026-03-01T09:00:00Z",
+            &candidates,
+        );
+        assert!(matches!(exact, Err(CassetteError::NoMatch { .. })));
+    }
+
+    #[test]
+    fn test_matcher_accepts_cassette_without_recorded_prompt() {
+        let candidates = vec![
+            (
+                PathBuf::from("other.jsonl"),
+                Some("something else".to_string()),
+            ),
+            (PathBuf::from("legacy.jsonl"), None),
+        ];
+        let chosen = CassetteMatcher::new()
+            .select("any prompt", &candidates)
+            .unwrap();
+        assert_eq!(chosen, PathBuf::from("legacy.jsonl"));
+    }
+
+    #[test]
+    fn test_matcher_no_match_reports_closest_with_diff() {
+        let candidates = vec![
+            (PathBuf::from("far.jsonl"), Some("a\nb\nc".to_string())),
+            (
+                PathBuf::from("near.jsonl"),
+                Some("Plan\nStep one\nDone".to_string()),
+            ),
+        ];
+        let err = CassetteMatcher::new()
+            .select("Plan\nStep two\nDone", &candidates)
+            .unwrap_err();
+
+        match &err {
+            CassetteError::NoMatch {
+                tried,
+                closest,
+                diff,
+            } => {
+                assert_eq!(tried.len(), 2);
+                assert_eq!(closest.as_deref(), Some(Path::new("near.jsonl")));
+                assert_eq!(diff, "line 2:\n- Step one\n+ Step two");
+            }
+            other => panic!("expected NoMatch, got {other:?}"),
+        }
+        assert!(err.to_string().contains("closest: near.jsonl"));
+    }
+
     #[test]
     fn test_resolver_falls_back_to_generic() {
         let temp = TempDir::new().unwrap();
@@ -348,14 +643,17 @@ mod tests {
 
     #[test]
     fn test_build_mock_cli_args() {
+        let temp = TempDir::new().unwrap();
+        create_test_cassette(temp.path(), "cassette.jsonl");
         let config = MockConfig::default().with_speed(10.0);
-        let cassette = PathBuf::from("/path/to/cassette.jsonl");
+        let cassette = temp.path().join("cassette.jsonl");
 
-        let args = build_mock_cli_args(&cassette, &config);
+        let args =
+            build_mock_cli_args(std::slice::from_ref(&cassette), &config, Backend::Claude).unwrap();
 
         assert!(args.contains(&"mock-cli".to_string()));
         assert!(args.contains(&"--cassette".to_string()));
-        assert!(args.contains(&"/path/to/cassette.jsonl".to_string()));
+        assert!(args.contains(&cassette.to_string_lossy().to_string()));
         assert!(args.contains(&"--speed".to_string()));
         assert!(args.contains(&"10".to_string()));
         assert!(args.contains(&"--allow".to_string()));
@@ -363,10 +661,13 @@ mod tests {
 
     #[test]
     fn test_build_mock_cli_args_instant() {
+        let temp = TempDir::new().unwrap();
+        create_test_cassette(temp.path(), "cassette.jsonl");
         let config = MockConfig::default(); // speed = 0.0
-        let cassette = PathBuf::from("/path/to/cassette.jsonl");
+        let cassette = temp.path().join("cassette.jsonl");
 
-        let args = build_mock_cli_args(&cassette, &config);
+        let args =
+            build_mock_cli_args(std::slice::from_ref(&cassette), &config, Backend::Claude).unwrap();
 
         // Should not include --speed when speed is 0 (instant)
         assert!(!args.contains(&"--speed".to_string()));
@@ -374,14 +675,90 @@ mod tests {
 
     #[test]
     fn test_build_mock_cli_args_no_commands() {
+        let temp = TempDir::new().unwrap();
+        create_test_cassette(temp.path(), "cassette.jsonl");
         let config = MockConfig::default().without_commands();
-        let cassette = PathBuf::from("/path/to/cassette.jsonl");
+        let cassette = temp.path().join("cassette.jsonl");
 
-        let args = build_mock_cli_args(&cassette, &config);
+        let args =
+            build_mock_cli_args(std::slice::from_ref(&cassette), &config, Backend::Claude).unwrap();
 
         assert!(!args.contains(&"--allow".to_string()));
     }
 
+    #[test]
+    fn test_build_mock_cli_args_record_missing() {
+        let temp = TempDir::new().unwrap();
+        create_test_cassette(temp.path(), "cassette.jsonl");
+        let cassette = temp.path().join("cassette.jsonl");
+
+        let args = build_mock_cli_args(
+            std::slice::from_ref(&cassette),
+            &MockConfig::default(),
+            Backend::Kiro,
+        )
+        .unwrap();
+        assert!(!args.contains(&"--record-missing".to_string()));
+
+        // Recording doesn't need the cassette to exist yet
+        let config = MockConfig::default().with_record_missing(true);
+        let missing = temp.path().join("new-kiro.jsonl");
+        let args =
+            build_mock_cli_args(std::slice::from_ref(&missing), &config, Backend::Kiro).unwrap();
+        let tail = &args[args.len() - 3..];
+        assert_eq!(tail, ["--record-missing", "--backend", "kiro"]);
+    }
+
+    #[test]
+    fn test_build_mock_cli_args_missing_cassette() {
+        let temp = TempDir::new().unwrap();
+        let cassette = temp.path().join("nope.jsonl");
+
+        let err = build_mock_cli_args(
+            std::slice::from_ref(&cassette),
+            &MockConfig::default(),
+            Backend::Claude,
+        )
+        .unwrap_err();
+        assert!(matches!(&err, CassetteError::MissingFile(path) if path == &cassette));
+        assert!(err.to_string().contains("nope.jsonl"));
+    }
+
+    #[test]
+    fn test_build_mock_cli_args_passes_candidates_and_matcher() {
+        let temp = TempDir::new().unwrap();
+        create_test_cassette(temp.path(), "a.jsonl");
+        create_test_cassette(temp.path(), "b.jsonl");
+        let cassettes = [temp.path().join("a.jsonl"), temp.path().join("b.jsonl")];
+        let config = MockConfig::default()
+            .without_commands()
+            .with_matcher(CassetteMatcher::new().strip(r"run-\d+").unwrap());
+
+        let args = build_mock_cli_args(&cassettes, &config, Backend::Claude).unwrap();
+
+        assert_eq!(args.iter().filter(|a| *a == "--cassette").count(), 2);
+        let tail = &args[args.len() - 3..];
+        assert_eq!(tail, ["--match-prompt", "--strip", r"run-\d+"]);
+    }
+
+    #[test]
+    fn test_build_mock_cli_args_rejects_malformed_allow_list() {
+        let temp = TempDir::new().unwrap();
+        create_test_cassette(temp.path(), "cassette.jsonl");
+        let cassette = temp.path().join("cassette.jsonl");
+
+        let config = MockConfig::default().with_allow_commands("  ");
+        let err = build_mock_cli_args(std::slice::from_ref(&cassette), &config, Backend::Claude)
+            .unwrap_err();
+        assert!(matches!(err, CassetteError::InvalidAllowList { .. }));
+        assert!(err.to_string().contains("without_commands"));
+
+        let config = MockConfig::default().with_allow_commands("ralph task add,,ralph task close");
+        let err = build_mock_cli_args(std::slice::from_ref(&cassette), &config, Backend::Claude)
+            .unwrap_err();
+        assert!(err.to_string().contains("entry 2 is empty"));
+    }
+
     /// Test that MockConfig::resolve_cassette_dir returns an absolute path
     /// even when initialized with a relative path.
     ///
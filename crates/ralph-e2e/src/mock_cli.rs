@@ -14,15 +14,40 @@
 //!
 //! # With command execution whitelist
 //! ralph-e2e mock-cli --cassette cassettes/e2e/task-add.jsonl --allow "ralph task add"
+//!
+//! # Record the cassette from the real backend if it doesn't exist yet
+//! ralph-e2e mock-cli --cassette cassettes/e2e/new-claude.jsonl --record-missing --backend claude
+//!
+//! # Replay whichever cassette recorded the prompt on stdin, ignoring timestamps
+//! ralph-e2e mock-cli --cassette a.jsonl --cassette b.jsonl --match-prompt --strip '\d{4}-\d{2}-\d{2}T\S+'
 //! ```
 
-use ralph_core::{PlayerConfig, SessionPlayer, TimestampedRecord};
+use crate::Backend;
+use crate::mock::{CassetteError, CassetteMatcher};
+use ralph_core::{PlayerConfig, Record, SessionPlayer, SessionRecorder, TimestampedRecord};
+use ralph_proto::TerminalWrite;
 use std::fs::File;
-use std::io::{self, BufReader, Write};
-use std::path::Path;
-use std::process::Command;
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
 use thiserror::Error;
 
+/// Where denied commands are appended, relative to the working directory, so
+/// the E2E executor can surface them on [`crate::ExecutionResult`].
+pub const DENIED_COMMANDS_FILE: &str = ".ralph/mock-cli-denied.txt";
+
+/// Cassette record holding the prompt the backend received when recording.
+pub const PROMPT_EVENT: &str = "_meta.prompt";
+
+/// Commands extracted from a replayed cassette, split by the allow list.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MockCliReport {
+    /// Commands that matched an allowed prefix and were executed.
+    pub executed: Vec<String>,
+    /// Commands that matched no allowed prefix and were skipped.
+    pub denied: Vec<String>,
+}
+
 /// Errors that can occur during mock CLI execution.
 #[derive(Debug, Error)]
 pub enum MockCliError {
@@ -44,6 +69,14 @@ pub enum MockCliError {
     /// Command execution failed.
     #[error("command execution failed: {0}")]
     CommandError(String),
+
+    /// Forwarding to the real backend or writing the new cassette failed.
+    #[error("recording failed: {0}")]
+    RecordError(String),
+
+    /// No candidate cassette recorded the received prompt.
+    #[error(transparent)]
+    NoMatch(#[from] CassetteError),
 }
 
 /// Runs the mock CLI with the given configuration.
@@ -52,8 +85,13 @@ pub enum MockCliError {
 /// 1. Loads the cassette file
 /// 2. Extracts commands from bus.publish events (tool calls)
 /// 3. Replays terminal write events to stdout
-/// 4. Optionally executes whitelisted commands
-pub fn run(cassette: &Path, speed: f32, allow: Option<&str>) -> Result<(), MockCliError> {
+/// 4. Optionally executes whitelisted commands, recording denied ones in
+///    [`DENIED_COMMANDS_FILE`]
+pub fn run(
+    cassette: &Path,
+    speed: f32,
+    allow: Option<&str>,
+) -> Result<MockCliReport, MockCliError> {
     // Open and parse the cassette
     let file = File::open(cassette).map_err(|e| MockCliError::CassetteOpen {
         path: cassette.display().to_string(),
@@ -96,11 +134,184 @@ pub fn run(cassette: &Path, speed: f32, allow: Option<&str>) -> Result<(), MockC
         .map_err(|e| MockCliError::ReplayError(e.to_string()))?;
 
     // Execute whitelisted commands if configured
-    if let Some(whitelist) = allow {
-        execute_whitelisted_commands(&commands, whitelist)?;
+    let report = match allow {
+        Some(whitelist) => execute_whitelisted_commands(&commands, whitelist)?,
+        None => MockCliReport::default(),
+    };
+
+    if !report.denied.is_empty() {
+        record_denied_commands(Path::new(DENIED_COMMANDS_FILE), &report.denied);
     }
 
-    Ok(())
+    Ok(report)
+}
+
+/// Replays the cassette, or records it from the real backend if it doesn't exist.
+///
+/// Used for `--record-missing`: the prompt is read from stdin and forwarded to
+/// `backend`, whose output is echoed to stdout and saved as a new cassette, so
+/// running the suite once online fills in any missing cassettes.
+pub fn run_or_record(
+    cassette: &Path,
+    speed: f32,
+    allow: Option<&str>,
+    backend: Backend,
+) -> Result<MockCliReport, MockCliError> {
+    if cassette.exists() {
+        return run(cassette, speed, allow);
+    }
+
+    eprintln!(
+        "[mock-cli] No cassette at {}, recording from {}",
+        cassette.display(),
+        backend
+    );
+
+    let mut prompt = String::new();
+    io::stdin()
+        .read_to_string(&mut prompt)
+        .map_err(|e| MockCliError::RecordError(format!("failed to read prompt: {}", e)))?;
+
+    let output = forward_to_backend(backend, &prompt)?;
+
+    let mut handle = io::stdout().lock();
+    handle
+        .write_all(&output)
+        .and_then(|()| handle.flush())
+        .map_err(|e| MockCliError::RecordError(e.to_string()))?;
+
+    write_cassette(cassette, &prompt, &output)?;
+    Ok(MockCliReport::default())
+}
+
+/// Replays the candidate cassette that recorded the prompt read from stdin.
+///
+/// Prompts are compared after `matcher` strips volatile parts, so a cassette
+/// recorded in one run still replays when timestamps or paths in the prompt
+/// change. Candidates are tried in order; see [`CassetteMatcher::select`].
+pub fn run_matching(
+    cassettes: &[PathBuf],
+    matcher: &CassetteMatcher,
+    speed: f32,
+    allow: Option<&str>,
+) -> Result<MockCliReport, MockCliError> {
+    let mut prompt = String::new();
+    io::stdin()
+        .read_to_string(&mut prompt)
+        .map_err(|e| MockCliError::ReplayError(format!("failed to read prompt: {}", e)))?;
+
+    let cassette = select_cassette(cassettes, matcher, &prompt)?;
+    run(&cassette, speed, allow)
+}
+
+/// Pairs each candidate with its recorded prompt and picks the matching one.
+fn select_cassette(
+    cassettes: &[PathBuf],
+    matcher: &CassetteMatcher,
+    prompt: &str,
+) -> Result<PathBuf, MockCliError> {
+    let candidates = cassettes
+        .iter()
+        .map(|path| Ok((path.clone(), recorded_prompt(path)?)))
+        .collect::<Result<Vec<_>, MockCliError>>()?;
+    Ok(matcher.select(prompt, &candidates)?)
+}
+
+/// Returns the prompt stored in a cassette's [`PROMPT_EVENT`] record, if any.
+fn recorded_prompt(cassette: &Path) -> Result<Option<String>, MockCliError> {
+    let file = File::open(cassette).map_err(|e| MockCliError::CassetteOpen {
+        path: cassette.display().to_string(),
+        source: e,
+    })?;
+    let player = SessionPlayer::from_reader(BufReader::new(file))
+        .map_err(|e| MockCliError::CassetteParse(e.to_string()))?;
+
+    Ok(player
+        .filter_by_event(PROMPT_EVENT)
+        .first()
+        .and_then(|r| r.record.data.get("prompt")?.as_str().map(str::to_string)))
+}
+
+/// Returns the headless (non-interactive, plain text) arguments for a backend.
+///
+/// The prompt is piped on stdin for Claude and passed as the last argument
+/// for the others.
+fn passthrough_args(backend: Backend, prompt: &str) -> Vec<String> {
+    match backend {
+        Backend::Claude => vec!["--dangerously-skip-permissions".into(), "--print".into()],
+        Backend::Kiro => vec![
+            "chat".into(),
+            "--no-interactive".into(),
+            "--trust-all-tools".into(),
+            prompt.into(),
+        ],
+        Backend::OpenCode => vec!["run".into(), prompt.into()],
+        Backend::Custom => vec![prompt.into()],
+    }
+}
+
+/// Runs the real backend with the prompt and returns its stdout.
+fn forward_to_backend(backend: Backend, prompt: &str) -> Result<Vec<u8>, MockCliError> {
+    let mut child = Command::new(backend.command())
+        .args(passthrough_args(backend, prompt))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::inherit())
+        .spawn()
+        .map_err(|e| {
+            MockCliError::RecordError(format!("failed to start '{}': {}", backend.command(), e))
+        })?;
+
+    if let Some(mut stdin) = child.stdin.take()
+        && backend == Backend::Claude
+    {
+        stdin
+            .write_all(prompt.as_bytes())
+            .map_err(|e| MockCliError::RecordError(e.to_string()))?;
+    }
+
+    let output = child
+        .wait_with_output()
+        .map_err(|e| MockCliError::RecordError(e.to_string()))?;
+
+    if !output.status.success() {
+        // Don't save a cassette of a failed call; it would replay the failure forever
+        return Err(MockCliError::RecordError(format!(
+            "'{}' exited with status {}",
+            backend.command(),
+            output.status.code().unwrap_or(-1)
+        )));
+    }
+
+    Ok(output.stdout)
+}
+
+/// Writes the prompt and captured backend output as a cassette.
+///
+/// The prompt is kept so `--match-prompt` can pick this cassette on replay.
+fn write_cassette(cassette: &Path, prompt: &str, output: &[u8]) -> Result<(), MockCliError> {
+    let file = File::create(cassette).map_err(|e| {
+        MockCliError::RecordError(format!("failed to create {}: {}", cassette.display(), e))
+    })?;
+    let recorder = SessionRecorder::new(BufWriter::new(file));
+    recorder.record_meta(Record::new(
+        PROMPT_EVENT,
+        serde_json::json!({ "prompt": prompt }),
+    ));
+
+    let mut offset_ms = 0;
+    for line in output.split_inclusive(|b| *b == b'\n') {
+        // Same flat layout as the checked-in cassettes: the write itself is the data
+        recorder.record_meta(Record::new(
+            "ux.terminal.write",
+            TerminalWrite::new(line, true, offset_ms),
+        ));
+        offset_ms += 100;
+    }
+
+    recorder
+        .flush()
+        .map_err(|e| MockCliError::RecordError(e.to_string()))
 }
 
 /// Extracts command strings from bus.publish events.
@@ -180,25 +391,65 @@ fn is_command_allowed(command: &str, whitelist: &[String]) -> bool {
 }
 
 /// Executes whitelisted commands extracted from bus events.
-fn execute_whitelisted_commands(commands: &[String], whitelist: &str) -> Result<(), MockCliError> {
+///
+/// Commands matching no allowed prefix are skipped with a warning and
+/// returned in [`MockCliReport::denied`].
+fn execute_whitelisted_commands(
+    commands: &[String],
+    whitelist: &str,
+) -> Result<MockCliReport, MockCliError> {
+    let mut report = MockCliReport::default();
     let allowed_prefixes = parse_whitelist(whitelist);
     if allowed_prefixes.is_empty() {
-        return Ok(());
+        return Ok(report);
     }
 
     for command in commands {
         // Check if command is whitelisted
         if !is_command_allowed(command, &allowed_prefixes) {
-            eprintln!("[mock-cli] Skipping non-whitelisted command: {}", command);
+            eprintln!(
+                "[mock-cli] Warning: denied command not in allow list: {}",
+                command
+            );
+            report.denied.push(command.clone());
             continue;
         }
 
         // Execute the command
         eprintln!("[mock-cli] Executing: {}", command);
         execute_command(command)?;
+        report.executed.push(command.clone());
     }
 
-    Ok(())
+    Ok(report)
+}
+
+/// Appends denied commands to `path`, one per line.
+///
+/// Failures only warn: the replay itself already succeeded.
+fn record_denied_commands(path: &Path, denied: &[String]) {
+    let result = path
+        .parent()
+        .map_or(Ok(()), std::fs::create_dir_all)
+        .and_then(|()| {
+            std::fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(path)
+        })
+        .and_then(|mut file| {
+            denied
+                .iter()
+                .try_for_each(|command| writeln!(file, "{}", command))
+        });
+
+    if let Err(e) = result {
+        eprintln!(
+            "[mock-cli] Warning: failed to record denied commands in {}: {}",
+            path.display(),
+            e
+        );
+    }
 }
 
 /// Executes a command safely without shell interpretation.
@@ -305,6 +556,54 @@ mod tests {
         path
     }
 
+    #[test]
+    fn test_write_cassette_replays_recorded_output() {
+        let temp = TempDir::new().unwrap();
+        let cassette = temp.path().join("recorded-claude.jsonl");
+
+        write_cassette(&cassette, "ping", b"I'm testing connectivity.\nPONG\n").unwrap();
+
+        let player = SessionPlayer::from_reader(BufReader::new(File::open(&cassette).unwrap()))
+            .unwrap()
+            .with_config(PlayerConfig::terminal().with_speed(1000.0));
+        assert_eq!(
+            player.collect_terminal_output().unwrap(),
+            "I'm testing connectivity.\nPONG\n"
+        );
+        assert_eq!(recorded_prompt(&cassette).unwrap().as_deref(), Some("ping"));
+    }
+
+    #[test]
+    fn test_select_cassette_matches_recorded_prompt() {
+        let temp = TempDir::new().unwrap();
+        let plan = temp.path().join("plan.jsonl");
+        let build = temp.path().join("build.jsonl");
+        write_cassette(&plan, "Plan at 2025-01-24T10:15:00Z", b"planned\n").unwrap();
+        write_cassette(&build, "Build at 2025-01-24T10:15:00Z", b"built\n").unwrap();
+
+        let matcher = CassetteMatcher::new()
+            .strip(r"\d{4}-\d{2}-\d{2}T\S+")
+            .unwrap();
+        let cassettes = [plan, build.clone()];
+
+        let chosen =
+            select_cassette(&cassettes, &matcher, "Build at 2026-03-01T09:00:00Z").unwrap();
+        assert_eq!(chosen, build);
+
+        let err = select_cassette(&cassettes, &matcher, "Review at 2026-03-01T09:00:00Z");
+        assert!(matches!(err, Err(MockCliError::NoMatch(_))));
+    }
+
+    #[test]
+    fn test_passthrough_args() {
+        assert_eq!(
+            passthrough_args(Backend::Claude, "hi"),
+            vec!["--dangerously-skip-permissions", "--print"]
+        );
+        assert_eq!(passthrough_args(Backend::OpenCode, "hi"), vec!["run", "hi"]);
+        assert_eq!(passthrough_args(Backend::Kiro, "hi").last().unwrap(), "hi");
+    }
+
     #[test]
     fn test_parse_whitelist() {
         let prefixes = parse_whitelist("ralph task add, ralph tools memory add");
@@ -335,6 +634,34 @@ mod tests {
         assert!(!is_command_allowed("rm -rf /", &whitelist));
     }
 
+    #[test]
+    fn test_execute_whitelisted_commands_reports_denied() {
+        let commands = vec![
+            "true allowed".to_string(),
+            "rm -rf /tmp/not-allowed".to_string(),
+            "ralph task close 'id'".to_string(),
+        ];
+
+        let report = execute_whitelisted_commands(&commands, "true, ralph task add").unwrap();
+        assert_eq!(report.executed, vec!["true allowed"]);
+        assert_eq!(
+            report.denied,
+            vec!["rm -rf /tmp/not-allowed", "ralph task close 'id'"]
+        );
+    }
+
+    #[test]
+    fn test_record_denied_commands_appends_lines() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join(DENIED_COMMANDS_FILE);
+
+        record_denied_commands(&path, &["rm -rf /".to_string()]);
+        record_denied_commands(&path, &["curl example.com".to_string()]);
+
+        let content = fs::read_to_string(&path).unwrap();
+        assert_eq!(content, "rm -rf /\ncurl example.com\n");
+    }
+
     #[test]
     fn test_extract_command_from_event_bash_tool() {
         let data = serde_json::json!({
@@ -36,6 +36,43 @@ pub struct TestResult {
     /// How long the test took
     #[serde(with = "duration_serde")]
     pub duration: Duration,
+    /// Why the scenario was skipped instead of run (`None` if it ran)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub skip_reason: Option<String>,
+}
+
+impl TestResult {
+    /// Creates a result for a scenario that was skipped rather than run.
+    ///
+    /// Skipped results are neither passed nor failed.
+    pub fn skipped(
+        scenario_id: impl Into<String>,
+        scenario_description: impl Into<String>,
+        backend: impl Into<String>,
+        tier: impl Into<String>,
+        reason: impl Into<String>,
+    ) -> Self {
+        Self {
+            scenario_id: scenario_id.into(),
+            scenario_description: scenario_description.into(),
+            backend: backend.into(),
+            tier: tier.into(),
+            passed: false,
+            assertions: Vec::new(),
+            duration: Duration::ZERO,
+            skip_reason: Some(reason.into()),
+        }
+    }
+
+    /// Returns true if the scenario was skipped.
+    pub fn is_skipped(&self) -> bool {
+        self.skip_reason.is_some()
+    }
+
+    /// Returns true if the scenario ran and did not pass.
+    pub fn is_failed(&self) -> bool {
+        !self.passed && !self.is_skipped()
+    }
 }
 
 /// A single assertion within a test.
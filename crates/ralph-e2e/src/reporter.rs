@@ -29,6 +29,7 @@
 use crate::analyzer::{
     AnalyzedResult, Diagnosis, FailureType, PassedAnalysis, QualityScore, Recommendation, Severity,
 };
+use crate::auth::BackendInfo;
 use crate::models::TestResult;
 use crate::runner::{ProgressEvent, RunResults};
 use chrono::{DateTime, Utc};
@@ -36,9 +37,12 @@ use colored::Colorize;
 use ralph_core::truncate_with_ellipsis;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::io::{self, Write};
+use std::io::{self, IsTerminal, Write};
 use std::path::{Path, PathBuf};
-use std::time::Duration;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
 use thiserror::Error;
 
 /// Verbosity level for terminal output.
@@ -53,6 +57,79 @@ pub enum Verbosity {
     Verbose,
 }
 
+static NO_EMOJI: AtomicBool = AtomicBool::new(false);
+
+/// Disables emoji in terminal output (`--no-emoji` / `RALPH_NO_EMOJI`).
+pub fn set_no_emoji(no_emoji: bool) {
+    NO_EMOJI.store(no_emoji, Ordering::Relaxed);
+}
+
+/// Returns `emoji`, or its ASCII stand-in when emoji output is disabled.
+pub fn glyph<'a>(emoji: &'a str, ascii: &'a str) -> &'a str {
+    if NO_EMOJI.load(Ordering::Relaxed) {
+        ascii
+    } else {
+        emoji
+    }
+}
+
+/// Spinner animation frames.
+const SPINNER_FRAMES: [char; 10] = ['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
+
+/// How often the spinner redraws.
+const SPINNER_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Elapsed-time spinner for the in-flight scenario, drawn on its own thread.
+#[derive(Debug)]
+struct Spinner {
+    stop: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl Spinner {
+    /// Starts redrawing the spinner line for `scenario_id` until stopped.
+    fn start(scenario_id: String) -> Self {
+        let stop = Arc::new(AtomicBool::new(false));
+        let thread_stop = Arc::clone(&stop);
+        let handle = std::thread::spawn(move || {
+            let started = Instant::now();
+            let mut frame = 0;
+            while !thread_stop.load(Ordering::Relaxed) {
+                print!("\r{}", spinner_line(frame, &scenario_id, started.elapsed()));
+                io::stdout().flush().ok();
+                frame += 1;
+                std::thread::sleep(SPINNER_INTERVAL);
+            }
+        });
+        Self {
+            stop,
+            handle: Some(handle),
+        }
+    }
+}
+
+impl Drop for Spinner {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(handle) = self.handle.take() {
+            handle.join().ok();
+        }
+        // Clear the spinner line so the result prints cleanly
+        print!("\r\x1b[2K");
+        io::stdout().flush().ok();
+    }
+}
+
+/// Renders one spinner frame for an in-flight scenario.
+fn spinner_line(frame: usize, scenario_id: &str, elapsed: Duration) -> String {
+    format!(
+        "  {} {} {}",
+        SPINNER_FRAMES[frame % SPINNER_FRAMES.len()],
+        scenario_id,
+        format!("({}s)", elapsed.as_secs()).dimmed()
+    )
+}
+
 /// Terminal reporter for E2E test results.
 #[derive(Debug)]
 pub struct TerminalReporter {
@@ -61,6 +138,12 @@ pub struct TerminalReporter {
 
     /// Track current tier for grouping output.
     current_tier: Option<String>,
+
+    /// Whether to animate in-flight scenarios (TTY and not quiet).
+    spinner_enabled: bool,
+
+    /// Spinner for the scenario currently running, if any.
+    spinner: Option<Spinner>,
 }
 
 impl Default for TerminalReporter {
@@ -72,23 +155,33 @@ impl Default for TerminalReporter {
 impl TerminalReporter {
     /// Creates a new terminal reporter with normal verbosity.
     pub fn new() -> Self {
-        Self {
-            verbosity: Verbosity::Normal,
-            current_tier: None,
-        }
+        Self::with_verbosity(Verbosity::Normal)
     }
 
     /// Creates a reporter with the specified verbosity.
+    ///
+    /// The in-flight spinner is only shown when stdout is a terminal and
+    /// verbosity is not quiet.
     pub fn with_verbosity(verbosity: Verbosity) -> Self {
         Self {
             verbosity,
             current_tier: None,
+            spinner_enabled: verbosity != Verbosity::Quiet && io::stdout().is_terminal(),
+            spinner: None,
         }
     }
 
     /// Handles a progress event, printing appropriate output.
     pub fn handle_progress(&mut self, event: ProgressEvent) {
+        // Any new event ends the in-flight spinner before output is printed
+        self.spinner = None;
+
         match event {
+            ProgressEvent::BackendChecked { status, .. } => {
+                if self.verbosity != Verbosity::Quiet {
+                    self.print_backend_checked(&status);
+                }
+            }
             ProgressEvent::RunStarted { total_scenarios } => {
                 if self.verbosity != Verbosity::Quiet {
                     self.print_run_started(total_scenarios);
@@ -98,6 +191,9 @@ impl TerminalReporter {
                 if self.verbosity == Verbosity::Verbose {
                     self.print_scenario_started(&scenario_id, &tier);
                 }
+                if self.spinner_enabled {
+                    self.spinner = Some(Spinner::start(scenario_id));
+                }
             }
             ProgressEvent::ScenarioCompleted {
                 scenario_id,
@@ -117,6 +213,14 @@ impl TerminalReporter {
                     self.print_scenario_skipped(&scenario_id, &reason);
                 }
             }
+            ProgressEvent::Bailed {
+                scenario_id,
+                remaining,
+            } => {
+                if self.verbosity != Verbosity::Quiet {
+                    self.print_bailed(&scenario_id, remaining);
+                }
+            }
             ProgressEvent::RunCompleted { results } => {
                 // Summary is printed separately via print_summary
                 if self.verbosity == Verbosity::Quiet {
@@ -126,6 +230,20 @@ impl TerminalReporter {
         }
     }
 
+    /// Prints one backend's availability/auth status.
+    fn print_backend_checked(&self, info: &BackendInfo) {
+        let status = match info.status_string().as_str() {
+            s if s.contains("Authenticated") => {
+                format!("  {} {} - {}", glyph("✅", "[ok]"), info.backend, s).green()
+            }
+            s if s.contains("Not authenticated") => {
+                format!("  {}  {} - {}", glyph("⚠️", "[!]"), info.backend, s).yellow()
+            }
+            s => format!("  {} {} - {}", glyph("❌", "[x]"), info.backend, s).red(),
+        };
+        println!("{}", status);
+    }
+
     /// Prints the run started header.
     fn print_run_started(&self, total: usize) {
         println!(
@@ -148,16 +266,19 @@ impl TerminalReporter {
             println!("{}", tier.bold().underline());
         }
 
-        print!("  {} ", scenario_id);
-        io::stdout().flush().ok();
+        // The spinner line shows the scenario ID while it runs
+        if !self.spinner_enabled {
+            print!("  {} ", scenario_id);
+            io::stdout().flush().ok();
+        }
     }
 
     /// Prints scenario completed result.
     fn print_scenario_completed(&self, scenario_id: &str, passed: bool, duration: Duration) {
         let status = if passed {
-            "✅".to_string()
+            glyph("✅", "[ok]")
         } else {
-            "❌".to_string()
+            glyph("❌", "[x]")
         };
 
         let duration_str = format!("({:.1}s)", duration.as_secs_f64()).dimmed();
@@ -169,12 +290,26 @@ impl TerminalReporter {
     fn print_scenario_skipped(&self, scenario_id: &str, reason: &str) {
         println!(
             "  {} {} {}",
-            "⏭️".dimmed(),
+            glyph("⏭️", "[skip]").dimmed(),
             scenario_id.dimmed(),
             format!("({})", reason).dimmed()
         );
     }
 
+    /// Prints the early-stop notice for `--bail`.
+    fn print_bailed(&self, scenario_id: &str, remaining: usize) {
+        println!(
+            "\n  {} {}",
+            glyph("⛔", "[stop]").red(),
+            format!(
+                "Bailed after {} failed ({} not run)",
+                scenario_id, remaining
+            )
+            .red()
+            .bold()
+        );
+    }
+
     /// Prints a quiet summary (just pass/fail counts).
     fn print_quiet_summary(&self, results: &RunResults) {
         let passed = results.passed_count();
@@ -182,9 +317,15 @@ impl TerminalReporter {
         let total = results.total_count();
 
         if failed == 0 {
-            println!("{}", format!("✓ {}/{} passed", passed, total).green());
+            println!(
+                "{}",
+                format!("{} {}/{} passed", glyph("✓", "[ok]"), passed, total).green()
+            );
         } else {
-            println!("{}", format!("✗ {}/{} failed", failed, total).red());
+            println!(
+                "{}",
+                format!("{} {}/{} failed", glyph("✗", "[x]"), failed, total).red()
+            );
         }
     }
 
@@ -194,16 +335,16 @@ impl TerminalReporter {
 
         let passed = results.passed_count();
         let failed = results.failed_count();
-        let skipped = results.skipped_count;
+        let skipped = results.skipped_count();
         let total = results.total_count();
 
         // Determine verdict emoji and color
         let (emoji, verdict, color) = if failed == 0 {
-            ("🟢", "PASSED", colored::Color::Green)
+            (glyph("🟢", "[ok]"), "PASSED", colored::Color::Green)
         } else if passed > 0 {
-            ("🟡", "MIXED", colored::Color::Yellow)
+            (glyph("🟡", "[~]"), "MIXED", colored::Color::Yellow)
         } else {
-            ("🔴", "FAILED", colored::Color::Red)
+            (glyph("🔴", "[x]"), "FAILED", colored::Color::Red)
         };
 
         // Build summary line
@@ -250,14 +391,18 @@ impl TerminalReporter {
 
     /// Prints details of a single failed test.
     fn print_failed_test(&self, result: &TestResult) {
-        println!("  {} {}", "❌".red(), result.scenario_id.red().bold());
+        println!(
+            "  {} {}",
+            glyph("❌", "[x]").red(),
+            result.scenario_id.red().bold()
+        );
         println!("     {}", result.scenario_description.dimmed());
         println!();
 
         // Print failed assertions
         for assertion in &result.assertions {
             if !assertion.passed {
-                println!("     {} {}", "✗".red(), assertion.name);
+                println!("     {} {}", glyph("✗", "[x]").red(), assertion.name);
                 println!("       Expected: {}", assertion.expected.green());
                 println!("       Actual:   {}", assertion.actual.red());
                 println!();
@@ -272,9 +417,11 @@ impl TerminalReporter {
 
             for result in tier_results {
                 let status = if result.passed {
-                    "✅".to_string()
+                    glyph("✅", "[ok]")
+                } else if result.is_skipped() {
+                    glyph("⏭️", "[skip]")
                 } else {
-                    "❌".to_string()
+                    glyph("❌", "[x]")
                 };
                 let duration = format!("({:.1}s)", result.duration.as_secs_f64()).dimmed();
 
@@ -284,9 +431,9 @@ impl TerminalReporter {
                 if self.verbosity == Verbosity::Verbose {
                     for assertion in &result.assertions {
                         let check = if assertion.passed {
-                            "└─ ✓"
+                            format!("└─ {}", glyph("✓", "[ok]"))
                         } else {
-                            "└─ ✗"
+                            format!("└─ {}", glyph("✗", "[x]"))
                         };
                         let check_colored = if assertion.passed {
                             check.green()
@@ -321,6 +468,7 @@ struct IncrementalState {
     output_path: PathBuf,
     total_scenarios: usize,
     start_time: std::time::Instant,
+    bailed: Option<(String, usize)>,
 }
 
 impl IncrementalState {
@@ -341,6 +489,12 @@ impl IncrementalState {
             "**Passed:** {} | **Failed:** {}\n\n",
             passed, failed
         ));
+        if let Some((scenario_id, remaining)) = &self.bailed {
+            content.push_str(&format!(
+                "**Stopped early (--bail):** `{}` failed; {} scenarios not run\n\n",
+                scenario_id, remaining
+            ));
+        }
 
         // Failed tests first (most important)
         let failures: Vec<_> = self.results.iter().filter(|r| !r.passed).collect();
@@ -407,6 +561,7 @@ pub fn create_incremental_progress_callback(
         output_path,
         total_scenarios: 0,
         start_time: std::time::Instant::now(),
+        bailed: None,
     }));
 
     Box::new(move |event| {
@@ -424,6 +579,13 @@ pub fn create_incremental_progress_callback(
                     s.results.push(result.clone());
                     s.write_live_report();
                 }
+                crate::runner::ProgressEvent::Bailed {
+                    scenario_id,
+                    remaining,
+                } => {
+                    s.bailed = Some((scenario_id.clone(), *remaining));
+                    s.write_live_report();
+                }
                 _ => {}
             }
         }
@@ -451,6 +613,7 @@ mod tests {
                         actual: "Received 100 bytes".to_string(),
                     }],
                     duration: Duration::from_secs(5),
+                    skip_reason: None,
                 },
                 TestResult {
                     scenario_id: "test-fail".to_string(),
@@ -465,10 +628,10 @@ mod tests {
                         actual: "Exit code 1".to_string(),
                     }],
                     duration: Duration::from_secs(3),
+                    skip_reason: None,
                 },
             ],
             duration: Duration::from_secs(8),
-            skipped_count: 0,
         }
     }
 
@@ -478,6 +641,16 @@ mod tests {
         assert_eq!(reporter.verbosity, Verbosity::Normal);
     }
 
+    #[test]
+    fn test_glyph_falls_back_to_ascii_when_emoji_disabled() {
+        let previous = NO_EMOJI.load(Ordering::Relaxed);
+        set_no_emoji(true);
+        let ascii = glyph("✅", "[ok]");
+        set_no_emoji(previous);
+        assert_eq!(ascii, "[ok]");
+        assert_eq!(glyph("✅", "[ok]"), if previous { "[ok]" } else { "✅" });
+    }
+
     #[test]
     fn test_reporter_with_verbosity() {
         let reporter = TerminalReporter::with_verbosity(Verbosity::Quiet);
@@ -487,6 +660,44 @@ mod tests {
         assert_eq!(reporter.verbosity, Verbosity::Verbose);
     }
 
+    #[test]
+    fn test_quiet_reporter_never_spins() {
+        let mut reporter = TerminalReporter::with_verbosity(Verbosity::Quiet);
+        assert!(!reporter.spinner_enabled);
+
+        reporter.handle_progress(ProgressEvent::ScenarioStarted {
+            scenario_id: "test-1".to_string(),
+            tier: "Tier 1".to_string(),
+        });
+        assert!(reporter.spinner.is_none());
+    }
+
+    #[test]
+    fn test_spinner_cleared_when_result_arrives() {
+        let mut reporter = TerminalReporter::with_verbosity(Verbosity::Normal);
+        reporter.spinner_enabled = true;
+
+        reporter.handle_progress(ProgressEvent::ScenarioStarted {
+            scenario_id: "test-1".to_string(),
+            tier: "Tier 1".to_string(),
+        });
+        assert!(reporter.spinner.is_some());
+
+        reporter.handle_progress(ProgressEvent::ScenarioSkipped {
+            scenario_id: "test-1".to_string(),
+            reason: "no auth".to_string(),
+        });
+        assert!(reporter.spinner.is_none());
+    }
+
+    #[test]
+    fn test_spinner_line_shows_elapsed_seconds() {
+        let line = spinner_line(11, "claude-connect", Duration::from_millis(61_500));
+        assert!(line.contains('⠙'));
+        assert!(line.contains("claude-connect"));
+        assert!(line.contains("(61s)"));
+    }
+
     #[test]
     fn test_reporter_default() {
         let reporter = TerminalReporter::default();
@@ -520,6 +731,7 @@ mod tests {
                 passed: true,
                 assertions: vec![],
                 duration: Duration::from_secs(5),
+                skip_reason: None,
             },
         });
     }
@@ -555,9 +767,9 @@ mod tests {
                 passed: true,
                 assertions: vec![],
                 duration: Duration::from_secs(1),
+                skip_reason: None,
             }],
             duration: Duration::from_secs(1),
-            skipped_count: 0,
         };
 
         let reporter = TerminalReporter::new();
@@ -583,9 +795,9 @@ mod tests {
                 passed: true,
                 assertions: vec![],
                 duration: Duration::from_secs(1),
+                skip_reason: None,
             }],
             duration: Duration::from_secs(1),
-            skipped_count: 0,
         };
 
         let reporter = TerminalReporter::new();
@@ -620,6 +832,24 @@ mod tests {
         // Just verify it doesn't panic
         callback(ProgressEvent::RunStarted { total_scenarios: 1 });
     }
+
+    #[test]
+    fn test_incremental_callback_notes_bail() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let callback =
+            create_incremental_progress_callback(Verbosity::Quiet, temp_dir.path().to_path_buf());
+
+        callback(ProgressEvent::RunStarted { total_scenarios: 3 });
+        callback(ProgressEvent::Bailed {
+            scenario_id: "test-fail".to_string(),
+            remaining: 2,
+        });
+
+        let content = std::fs::read_to_string(temp_dir.path().join("report-live.md")).unwrap();
+        assert!(
+            content.contains("Stopped early (--bail):** `test-fail` failed; 2 scenarios not run")
+        );
+    }
 }
 
 // ============================================================================
@@ -638,9 +868,25 @@ pub enum ReporterError {
     SerializationError(#[from] serde_json::Error),
 }
 
+/// Version of the `report.json` shape written by [`JsonReporter`].
+///
+/// Bump this whenever a field in the stable subset (see [`TestReport`]) is
+/// renamed, removed, or changes meaning.
+pub const REPORT_SCHEMA_VERSION: u32 = 1;
+
 /// Full test report structure for serialization.
+///
+/// The stable subset consumers may rely on within a schema version is:
+/// `schema_version`, `timestamp`, `ralph_version`, `passed`,
+/// `summary.{total,passed,failed,skipped}`, and
+/// `results[].{scenario_id,backend,tier,passed}`. Other fields may change
+/// between releases without a version bump.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TestReport {
+    /// Report shape version; reports written before versioning load as `0`.
+    #[serde(default)]
+    pub schema_version: u32,
+
     /// Timestamp of the report generation.
     pub timestamp: DateTime<Utc>,
 
@@ -667,6 +913,97 @@ pub struct TestReport {
     pub recommendations: Vec<Recommendation>,
 }
 
+impl TestReport {
+    /// Loads a previously written `report.json`, e.g. as a trend baseline.
+    ///
+    /// A report from a different schema version prints a warning instead of
+    /// being trusted silently; it still loads when its shape is compatible.
+    pub fn load_baseline(path: &Path) -> Result<Self, ReporterError> {
+        let content = std::fs::read_to_string(path)?;
+        let (report, warning) = Self::parse_baseline(&content)?;
+        if let Some(warning) = warning {
+            eprintln!("{} {}: {}", "Warning:".yellow(), path.display(), warning);
+        }
+        Ok(report)
+    }
+
+    /// Parses report JSON, returning a warning when its schema version differs.
+    fn parse_baseline(content: &str) -> Result<(Self, Option<String>), ReporterError> {
+        let value: serde_json::Value = serde_json::from_str(content)?;
+        let version = value
+            .get("schema_version")
+            .and_then(serde_json::Value::as_u64)
+            .unwrap_or(0);
+        let warning = (version != u64::from(REPORT_SCHEMA_VERSION)).then(|| {
+            format!(
+                "baseline report uses schema version {version}, expected {REPORT_SCHEMA_VERSION}; \
+                 comparisons may be inaccurate"
+            )
+        });
+        Ok((serde_json::from_value(value)?, warning))
+    }
+}
+
+/// File name of the run history appended by [`ReportWriter::append_history`].
+pub const HISTORY_FILE_NAME: &str = "history.jsonl";
+
+/// One line of `history.jsonl`: a single run, summarized for trend charts.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    /// When the run finished.
+    pub timestamp: DateTime<Utc>,
+
+    /// Ralph version used.
+    pub ralph_version: String,
+
+    /// Number of passed tests.
+    pub passed: usize,
+
+    /// Number of failed tests.
+    pub failed: usize,
+
+    /// Number of skipped tests.
+    #[serde(default)]
+    pub skipped: usize,
+
+    /// Number of tests that ran (skipped excluded).
+    pub total: usize,
+
+    /// Fraction of tests that passed (`0.0`..=`1.0`).
+    pub pass_rate: f64,
+
+    /// Passed tests by analyzer quality score; unanalyzed passes count as good.
+    #[serde(default)]
+    pub quality_breakdown: QualityBreakdown,
+
+    /// Total duration of the run.
+    #[serde(with = "duration_serde")]
+    pub duration: Duration,
+}
+
+impl HistoryEntry {
+    /// Summarizes a run as a history entry stamped with the current time.
+    pub fn from_results(results: &RunResults, analyzed: Option<&[AnalyzedResult]>) -> Self {
+        let total = results.total_count();
+        let passed = results.passed_count();
+        Self {
+            timestamp: Utc::now(),
+            ralph_version: crate::VERSION.to_string(),
+            passed,
+            failed: results.failed_count(),
+            skipped: results.skipped_count(),
+            total,
+            pass_rate: if total == 0 {
+                0.0
+            } else {
+                passed as f64 / total as f64
+            },
+            quality_breakdown: QualityBreakdown::from_results(results, analyzed),
+            duration: results.duration,
+        }
+    }
+}
+
 /// Summary statistics for the report.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ReportSummary {
@@ -693,7 +1030,7 @@ pub struct ReportSummary {
 }
 
 /// Quality score breakdown.
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct QualityBreakdown {
     pub optimal: usize,
     pub good: usize,
@@ -701,12 +1038,42 @@ pub struct QualityBreakdown {
     pub suboptimal: usize,
 }
 
+impl QualityBreakdown {
+    /// Counts passed tests by quality score; passes without analysis count as good.
+    pub fn from_results(results: &RunResults, analyzed: Option<&[AnalyzedResult]>) -> Self {
+        let mut quality_breakdown = Self::default();
+
+        if let Some(analyzed) = analyzed {
+            for result in analyzed {
+                if result.result.passed {
+                    if let Some(ref analysis) = result.analysis {
+                        match analysis.quality_score {
+                            QualityScore::Optimal => quality_breakdown.optimal += 1,
+                            QualityScore::Good => quality_breakdown.good += 1,
+                            QualityScore::Acceptable => quality_breakdown.acceptable += 1,
+                            QualityScore::Suboptimal => quality_breakdown.suboptimal += 1,
+                        }
+                    } else {
+                        quality_breakdown.good += 1;
+                    }
+                }
+            }
+        } else {
+            quality_breakdown.good = results.passed_count();
+        }
+
+        quality_breakdown
+    }
+}
+
 /// Summary for a single tier.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct TierSummary {
     pub total: usize,
     pub passed: usize,
     pub failed: usize,
+    #[serde(default)]
+    pub skipped: usize,
 }
 
 /// Summary for a single backend.
@@ -715,6 +1082,39 @@ pub struct BackendSummary {
     pub total: usize,
     pub passed: usize,
     pub failed: usize,
+    #[serde(default)]
+    pub skipped: usize,
+    /// Combined duration of the tests that ran on this backend.
+    #[serde(default, with = "duration_serde")]
+    pub duration: Duration,
+    /// Fraction of tests that passed (`0.0`..=`1.0`); `0.0` when none ran.
+    #[serde(default)]
+    pub pass_rate: f64,
+}
+
+/// Summarizes results per backend, ignoring skipped tests for timing and pass rate.
+fn backend_summaries(results: &RunResults) -> HashMap<String, BackendSummary> {
+    let mut by_backend: HashMap<String, BackendSummary> = HashMap::new();
+    for result in &results.results {
+        let entry = by_backend.entry(result.backend.clone()).or_default();
+        if result.is_skipped() {
+            entry.skipped += 1;
+            continue;
+        }
+        entry.total += 1;
+        entry.duration += result.duration;
+        if result.passed {
+            entry.passed += 1;
+        } else {
+            entry.failed += 1;
+        }
+    }
+    for summary in by_backend.values_mut() {
+        if summary.total > 0 {
+            summary.pass_rate = summary.passed as f64 / summary.total as f64;
+        }
+    }
+    by_backend
 }
 
 /// Analyzed result data for serialization.
@@ -749,6 +1149,10 @@ pub struct AnalyzedResultData {
     /// Analysis for passed tests.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub analysis: Option<PassedAnalysis>,
+
+    /// Why the test was skipped, if it was.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub skip_reason: Option<String>,
 }
 
 impl From<&TestResult> for AnalyzedResultData {
@@ -763,6 +1167,7 @@ impl From<&TestResult> for AnalyzedResultData {
             assertions: result.assertions.clone(),
             diagnosis: None,
             analysis: None,
+            skip_reason: result.skip_reason.clone(),
         }
     }
 }
@@ -779,6 +1184,7 @@ impl From<&AnalyzedResult> for AnalyzedResultData {
             assertions: result.result.assertions.clone(),
             diagnosis: result.diagnosis.clone(),
             analysis: result.analysis.clone(),
+            skip_reason: result.result.skip_reason.clone(),
         }
     }
 }
@@ -833,6 +1239,9 @@ impl MarkdownReporter {
         // Failed tests section
         self.write_failed_tests(&mut report, results, analyzed);
 
+        // Skipped tests, with the reason each was not run
+        self.write_skipped_tests(&mut report, results);
+
         // Passed tests section
         self.write_passed_tests(&mut report, results, analyzed);
 
@@ -893,8 +1302,8 @@ impl MarkdownReporter {
         report.push_str("|--------|-------|\n");
         report.push_str(&format!("| ✅ Passed | {} |\n", results.passed_count()));
         report.push_str(&format!("| ❌ Failed | {} |\n", results.failed_count()));
-        if results.skipped_count > 0 {
-            report.push_str(&format!("| ⏭️ Skipped | {} |\n", results.skipped_count));
+        if results.skipped_count() > 0 {
+            report.push_str(&format!("| ⏭️ Skipped | {} |\n", results.skipped_count()));
         }
         report.push('\n');
 
@@ -902,18 +1311,46 @@ impl MarkdownReporter {
         let tiers = results.by_tier();
         let failed_tiers: Vec<_> = tiers
             .iter()
-            .filter(|(_, tests)| tests.iter().any(|t| !t.passed))
+            .filter(|(_, tests)| tests.iter().any(|t| t.is_failed()))
             .collect();
 
         if !failed_tiers.is_empty() {
             report.push_str("### Failures by Tier\n");
             for (tier, tests) in failed_tiers {
-                let failed = tests.iter().filter(|t| !t.passed).count();
+                let failed = tests.iter().filter(|t| t.is_failed()).count();
                 report.push_str(&format!("- {}: {} failures\n", tier, failed));
             }
             report.push('\n');
         }
 
+        // Backend comparison when the run covered several backends
+        let mut backends: Vec<_> = backend_summaries(results)
+            .into_iter()
+            .filter(|(_, summary)| summary.total > 0)
+            .collect();
+        if backends.len() > 1 {
+            backends.sort_by(|a, b| a.0.cmp(&b.0));
+            report.push_str("### Backend Comparison\n");
+            report.push_str(
+                "| Backend | ✅ Passed | ❌ Failed | ⏭️ Skipped | Pass Rate | Duration |\n",
+            );
+            report.push_str(
+                "|---------|----------:|----------:|-----------:|----------:|---------:|\n",
+            );
+            for (backend, summary) in backends {
+                report.push_str(&format!(
+                    "| {} | {} | {} | {} | {:.0}% | {:.1}s |\n",
+                    backend,
+                    summary.passed,
+                    summary.failed,
+                    summary.skipped,
+                    summary.pass_rate * 100.0,
+                    summary.duration.as_secs_f64()
+                ));
+            }
+            report.push('\n');
+        }
+
         // Quality breakdown if we have analysis
         if let Some(analyzed) = analyzed {
             let quality_counts = self.count_quality_scores(analyzed);
@@ -981,6 +1418,26 @@ impl MarkdownReporter {
         }
     }
 
+    fn write_skipped_tests(&self, report: &mut String, results: &RunResults) {
+        let skipped: Vec<_> = results.results.iter().filter(|r| r.is_skipped()).collect();
+        if skipped.is_empty() {
+            return;
+        }
+
+        report.push_str("## ⏭️ Skipped Tests\n\n");
+        report.push_str("| Test | Backend | Reason |\n");
+        report.push_str("|------|---------|--------|\n");
+        for result in skipped {
+            report.push_str(&format!(
+                "| {} | {} | {} |\n",
+                result.scenario_id,
+                result.backend,
+                result.skip_reason.as_deref().unwrap_or_default()
+            ));
+        }
+        report.push_str("\n---\n\n");
+    }
+
     fn write_failed_test(
         &self,
         report: &mut String,
@@ -1288,7 +1745,7 @@ impl MarkdownReporter {
         let mut recommendations: Vec<Recommendation> = Vec::new();
 
         // Critical: from failed tests
-        for result in analyzed.iter().filter(|r| !r.result.passed) {
+        for result in analyzed.iter().filter(|r| r.result.is_failed()) {
             if let Some(ref diagnosis) = result.diagnosis
                 && !diagnosis.potential_fixes.is_empty()
             {
@@ -1458,6 +1915,7 @@ impl JsonReporter {
         let recommendations = self.collect_recommendations(analyzed);
 
         TestReport {
+            schema_version: REPORT_SCHEMA_VERSION,
             timestamp: Utc::now(),
             ralph_version: crate::VERSION.to_string(),
             duration: results.duration,
@@ -1474,58 +1932,29 @@ impl JsonReporter {
         results: &RunResults,
         analyzed: Option<&[AnalyzedResult]>,
     ) -> ReportSummary {
-        let mut quality_breakdown = QualityBreakdown::default();
-
-        if let Some(analyzed) = analyzed {
-            for result in analyzed {
-                if result.result.passed {
-                    if let Some(ref analysis) = result.analysis {
-                        match analysis.quality_score {
-                            QualityScore::Optimal => quality_breakdown.optimal += 1,
-                            QualityScore::Good => quality_breakdown.good += 1,
-                            QualityScore::Acceptable => quality_breakdown.acceptable += 1,
-                            QualityScore::Suboptimal => quality_breakdown.suboptimal += 1,
-                        }
-                    } else {
-                        quality_breakdown.good += 1;
-                    }
-                }
-            }
-        } else {
-            quality_breakdown.good = results.passed_count();
-        }
+        let quality_breakdown = QualityBreakdown::from_results(results, analyzed);
 
         // By tier
         let mut by_tier: HashMap<String, TierSummary> = HashMap::new();
         for (tier, tests) in results.by_tier() {
+            let skipped = tests.iter().filter(|t| t.is_skipped()).count();
             let tier_summary = TierSummary {
-                total: tests.len(),
+                total: tests.len() - skipped,
                 passed: tests.iter().filter(|t| t.passed).count(),
-                failed: tests.iter().filter(|t| !t.passed).count(),
+                failed: tests.iter().filter(|t| t.is_failed()).count(),
+                skipped,
             };
             by_tier.insert(tier.to_string(), tier_summary);
         }
 
-        // By backend
-        let mut by_backend: HashMap<String, BackendSummary> = HashMap::new();
-        for result in &results.results {
-            let entry = by_backend.entry(result.backend.clone()).or_default();
-            entry.total += 1;
-            if result.passed {
-                entry.passed += 1;
-            } else {
-                entry.failed += 1;
-            }
-        }
-
         ReportSummary {
             total: results.total_count(),
             passed: results.passed_count(),
             failed: results.failed_count(),
-            skipped: results.skipped_count,
+            skipped: results.skipped_count(),
             quality_breakdown,
             by_tier,
-            by_backend,
+            by_backend: backend_summaries(results),
         }
     }
 
@@ -1537,7 +1966,7 @@ impl JsonReporter {
         let mut recommendations = Vec::new();
 
         // From failed tests
-        for result in analyzed.iter().filter(|r| !r.result.passed) {
+        for result in analyzed.iter().filter(|r| r.result.is_failed()) {
             if let Some(ref diagnosis) = result.diagnosis
                 && !diagnosis.potential_fixes.is_empty()
             {
@@ -1658,6 +2087,30 @@ impl ReportWriter {
 
         Ok(path)
     }
+
+    /// Appends a one-line summary of this run to `history.jsonl`.
+    ///
+    /// Unlike the report files this never overwrites, so the history
+    /// accumulates across runs for trend tracking.
+    pub fn append_history(
+        &self,
+        results: &RunResults,
+        analyzed: Option<&[AnalyzedResult]>,
+    ) -> Result<PathBuf, ReporterError> {
+        std::fs::create_dir_all(&self.output_dir)?;
+
+        let mut line = serde_json::to_string(&HistoryEntry::from_results(results, analyzed))?;
+        line.push('\n');
+
+        let path = self.output_dir.join(HISTORY_FILE_NAME);
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)?;
+        file.write_all(line.as_bytes())?;
+
+        Ok(path)
+    }
 }
 
 // ============================================================================
@@ -1688,6 +2141,7 @@ mod reporter_tests {
                 actual: "Received 100 bytes".to_string(),
             }],
             duration: Duration::from_secs(12),
+            skip_reason: None,
         }
     }
 
@@ -1713,6 +2167,7 @@ mod reporter_tests {
                 },
             ],
             duration: Duration::from_secs(45),
+            skip_reason: None,
         }
     }
 
@@ -1720,15 +2175,23 @@ mod reporter_tests {
         RunResults {
             results: vec![mock_passed_result()],
             duration: Duration::from_secs(12),
-            skipped_count: 0,
         }
     }
 
     fn mock_run_results_mixed() -> RunResults {
         RunResults {
-            results: vec![mock_passed_result(), mock_failed_result()],
+            results: vec![
+                mock_passed_result(),
+                mock_failed_result(),
+                TestResult::skipped(
+                    "claude-connect-kiro",
+                    "Basic connectivity",
+                    "Kiro",
+                    "Tier 1: Connectivity",
+                    "Not supported on Kiro",
+                ),
+            ],
             duration: Duration::from_secs(57),
-            skipped_count: 1,
         }
     }
 
@@ -1780,6 +2243,7 @@ mod reporter_tests {
                     actual: "Found memories".to_string(),
                 }],
                 duration: Duration::from_secs(38),
+                skip_reason: None,
             },
             diagnosis: None,
             analysis: Some(PassedAnalysis {
@@ -1834,6 +2298,8 @@ mod reporter_tests {
         assert!(report.contains("✅ Passed | 1"));
         assert!(report.contains("❌ Failed | 1"));
         assert!(report.contains("⏭️ Skipped | 1"));
+        assert!(report.contains("## ⏭️ Skipped Tests"));
+        assert!(report.contains("| claude-connect-kiro | Kiro | Not supported on Kiro |"));
     }
 
     #[test]
@@ -1882,7 +2348,6 @@ mod reporter_tests {
         let results = RunResults {
             results: vec![mock_passed_result()],
             duration: Duration::from_secs(38),
-            skipped_count: 0,
         };
         let analyzed = vec![mock_analyzed_with_warnings()];
         let report = reporter.generate(&results, Some(&analyzed));
@@ -1966,6 +2431,17 @@ mod reporter_tests {
         assert_eq!(parsed.summary.passed, 1);
         assert_eq!(parsed.summary.failed, 1);
         assert_eq!(parsed.summary.skipped, 1);
+        assert_eq!(parsed.summary.by_backend["Kiro"].skipped, 1);
+        assert_eq!(parsed.summary.by_backend["Kiro"].total, 0);
+        let skipped = parsed
+            .results
+            .iter()
+            .find(|r| r.scenario_id == "claude-connect-kiro")
+            .unwrap();
+        assert_eq!(
+            skipped.skip_reason.as_deref(),
+            Some("Not supported on Kiro")
+        );
     }
 
     #[test]
@@ -2028,6 +2504,8 @@ mod reporter_tests {
         let tier1 = parsed.summary.by_tier.get("Tier 1: Connectivity");
         assert!(tier1.is_some());
         assert_eq!(tier1.unwrap().passed, 1);
+        assert_eq!(tier1.unwrap().total, 1);
+        assert_eq!(tier1.unwrap().skipped, 1);
     }
 
     #[test]
@@ -2042,6 +2520,27 @@ mod reporter_tests {
         let claude = parsed.summary.by_backend.get("Claude");
         assert!(claude.is_some());
         assert_eq!(claude.unwrap().total, 2);
+        assert!((claude.unwrap().pass_rate - 0.5).abs() < f64::EPSILON);
+        assert_eq!(claude.unwrap().duration, Duration::from_secs(57));
+    }
+
+    #[test]
+    fn test_markdown_backend_comparison_only_for_multiple_backends() {
+        let reporter = MarkdownReporter::new();
+
+        // Kiro only has a skipped result, so just one backend actually ran
+        let report = reporter.generate(&mock_run_results_mixed(), None);
+        assert!(!report.contains("### Backend Comparison"));
+
+        let mut results = mock_run_results_mixed();
+        let mut kiro = mock_passed_result();
+        kiro.scenario_id = "claude-connect-kiro-ran".to_string();
+        kiro.backend = "Kiro".to_string();
+        results.results.push(kiro);
+        let report = reporter.generate(&results, None);
+        assert!(report.contains("### Backend Comparison"));
+        assert!(report.contains("| Claude | 1 | 1 | 0 | 50% | 57.0s |"));
+        assert!(report.contains("| Kiro | 1 | 0 | 1 | 100% | 12.0s |"));
     }
 
     #[test]
@@ -2148,11 +2647,66 @@ mod reporter_tests {
         std::fs::remove_dir_all(temp_dir.parent().unwrap()).ok();
     }
 
+    #[test]
+    fn test_report_writer_append_history_accumulates_lines() {
+        let temp_dir =
+            std::env::temp_dir().join(format!("ralph-e2e-test-history-{}", std::process::id()));
+        std::fs::remove_dir_all(&temp_dir).ok();
+
+        let writer = ReportWriter::new(temp_dir.clone());
+        let first = writer
+            .append_history(&mock_run_results_all_pass(), None)
+            .unwrap();
+        let second = writer
+            .append_history(&mock_run_results_mixed(), None)
+            .unwrap();
+        assert_eq!(first, second);
+        assert_eq!(first, temp_dir.join(HISTORY_FILE_NAME));
+
+        let content = std::fs::read_to_string(&first).unwrap();
+        let entries: Vec<HistoryEntry> = content
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].passed, 1);
+        assert_eq!(entries[0].total, 1);
+        assert!((entries[0].pass_rate - 1.0).abs() < f64::EPSILON);
+        assert_eq!(entries[1].failed, 1);
+        assert_eq!(entries[1].skipped, 1);
+        assert!((entries[1].pass_rate - 0.5).abs() < f64::EPSILON);
+        assert_eq!(entries[1].quality_breakdown.good, 1);
+
+        std::fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_history_entry_records_analyzed_quality() {
+        let results = mock_run_results_all_pass();
+        let analyzed = vec![mock_analyzed_passed()];
+
+        let entry = HistoryEntry::from_results(&results, Some(&analyzed));
+        assert_eq!(
+            entry.quality_breakdown,
+            QualityBreakdown {
+                optimal: 1,
+                ..QualityBreakdown::default()
+            }
+        );
+
+        // Older history lines without the field still parse
+        let mut value = serde_json::to_value(&entry).unwrap();
+        value.as_object_mut().unwrap().remove("quality_breakdown");
+        let legacy: HistoryEntry = serde_json::from_value(value).unwrap();
+        assert_eq!(legacy.quality_breakdown, QualityBreakdown::default());
+    }
+
     // ==================== Data Structure Tests ====================
 
     #[test]
     fn test_test_report_serialization() {
         let report = TestReport {
+            schema_version: REPORT_SCHEMA_VERSION,
             timestamp: Utc::now(),
             ralph_version: "2.1.3".to_string(),
             duration: Duration::from_secs(100),
@@ -2169,6 +2723,43 @@ mod reporter_tests {
         assert!(parsed.passed);
     }
 
+    fn schema_results() -> RunResults {
+        RunResults {
+            results: vec![mock_passed_result(), mock_failed_result()],
+            duration: Duration::from_secs(10),
+        }
+    }
+
+    #[test]
+    fn test_json_report_includes_schema_version() {
+        let reporter = JsonReporter::new();
+        let json = reporter.generate(&schema_results(), None).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["schema_version"], REPORT_SCHEMA_VERSION);
+
+        let (parsed, warning) = TestReport::parse_baseline(&json).unwrap();
+        assert_eq!(parsed.schema_version, REPORT_SCHEMA_VERSION);
+        assert!(warning.is_none());
+    }
+
+    #[test]
+    fn test_baseline_with_other_schema_version_warns() {
+        let json = JsonReporter::new()
+            .generate(&schema_results(), None)
+            .unwrap();
+        let mut value: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        // Reports written before versioning have no schema_version at all
+        value.as_object_mut().unwrap().remove("schema_version");
+        let (parsed, warning) = TestReport::parse_baseline(&value.to_string()).unwrap();
+        assert_eq!(parsed.schema_version, 0);
+        assert!(warning.unwrap().contains("schema version 0"));
+
+        value["schema_version"] = serde_json::json!(REPORT_SCHEMA_VERSION + 1);
+        let (_, warning) = TestReport::parse_baseline(&value.to_string()).unwrap();
+        assert!(warning.is_some());
+    }
+
     #[test]
     fn test_analyzed_result_data_from_test_result() {
         let result = mock_passed_result();
@@ -23,7 +23,8 @@
 //! }
 //! ```
 
-use crate::Backend;
+use crate::auth::{AuthChecker, BackendInfo};
+use crate::backend::{Backend, CustomBackend};
 use crate::executor::RalphExecutor;
 use crate::mock::{CassetteResolver, MockConfig, build_mock_cli_args};
 use crate::models::TestResult;
@@ -47,6 +48,10 @@ pub enum RunnerError {
     /// No scenarios matched the filter.
     #[error("no scenarios matched filter: {0}")]
     NoMatchingScenarios(String),
+
+    /// A scenario requested via [`TestRunner::run_one`] could not be set up.
+    #[error("scenario '{scenario_id}' skipped: {reason}")]
+    ScenarioSkipped { scenario_id: String, reason: String },
 }
 
 /// Configuration for a test run.
@@ -55,6 +60,11 @@ pub struct RunConfig {
     /// Filter scenarios by pattern (matches scenario ID or description).
     pub filter: Option<String>,
 
+    /// Only run scenarios carrying at least one of these tags.
+    ///
+    /// Combined with `filter` using AND semantics.
+    pub tags: Vec<String>,
+
     /// Only run scenarios for this backend.
     pub backend: Option<Backend>,
 
@@ -64,8 +74,19 @@ pub struct RunConfig {
     /// Skip scenarios that require unavailable backends.
     pub skip_unavailable: bool,
 
+    /// Stop the run after the first failed scenario.
+    pub bail: bool,
+
+    /// Seed for randomizing scenario order; `None` keeps the registered order.
+    pub shuffle_seed: Option<u64>,
+
     /// Mock mode configuration (if enabled).
     pub mock_config: Option<MockConfig>,
+
+    /// Command used for [`Backend::Custom`] runs.
+    ///
+    /// Without it, custom-capable scenarios only run for built-in backends.
+    pub custom_backend: Option<CustomBackend>,
 }
 
 impl RunConfig {
@@ -80,6 +101,12 @@ impl RunConfig {
         self
     }
 
+    /// Adds a tag filter; scenarios matching any added tag are selected.
+    pub fn with_tag(mut self, tag: impl Into<String>) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+
     /// Sets the backend filter.
     pub fn with_backend(mut self, backend: Backend) -> Self {
         self.backend = Some(backend);
@@ -92,11 +119,32 @@ impl RunConfig {
         self
     }
 
+    /// Sets whether to stop after the first failed scenario.
+    pub fn bail(mut self, bail: bool) -> Self {
+        self.bail = bail;
+        self
+    }
+
+    /// Randomizes scenario execution order, deterministically from `seed`.
+    ///
+    /// The same seed always yields the same order, so a failing order can be
+    /// reproduced by re-running with the seed that produced it.
+    pub fn shuffle(mut self, seed: u64) -> Self {
+        self.shuffle_seed = Some(seed);
+        self
+    }
+
     /// Enables mock mode with the given configuration.
     pub fn with_mock(mut self, config: MockConfig) -> Self {
         self.mock_config = Some(config);
         self
     }
+
+    /// Sets the command used for scenarios run against [`Backend::Custom`].
+    pub fn with_custom_backend(mut self, custom: CustomBackend) -> Self {
+        self.custom_backend = Some(custom);
+        self
+    }
 }
 
 /// Aggregated results from a test run.
@@ -107,9 +155,6 @@ pub struct RunResults {
 
     /// Total duration of the run.
     pub duration: Duration,
-
-    /// Number of scenarios that were skipped.
-    pub skipped_count: usize,
 }
 
 impl RunResults {
@@ -120,17 +165,22 @@ impl RunResults {
 
     /// Returns the number of failed tests.
     pub fn failed_count(&self) -> usize {
-        self.results.iter().filter(|r| !r.passed).count()
+        self.results.iter().filter(|r| r.is_failed()).count()
+    }
+
+    /// Returns the number of skipped scenarios.
+    pub fn skipped_count(&self) -> usize {
+        self.results.iter().filter(|r| r.is_skipped()).count()
     }
 
-    /// Returns the total number of tests run.
+    /// Returns the total number of tests run (skipped scenarios excluded).
     pub fn total_count(&self) -> usize {
-        self.results.len()
+        self.results.len() - self.skipped_count()
     }
 
-    /// Returns true if all tests passed.
+    /// Returns true if no test failed. Skipped scenarios are ignored.
     pub fn all_passed(&self) -> bool {
-        self.results.iter().all(|r| r.passed)
+        !self.results.iter().any(TestResult::is_failed)
     }
 
     /// Returns results grouped by tier.
@@ -149,16 +199,84 @@ impl RunResults {
 
     /// Returns only failed results.
     pub fn failures(&self) -> Vec<&TestResult> {
-        self.results.iter().filter(|r| !r.passed).collect()
+        self.results.iter().filter(|r| r.is_failed()).collect()
+    }
+
+    /// Renders a compact Markdown summary suitable for a PR comment.
+    ///
+    /// Unlike the `ReportWriter` output this is a single string with a
+    /// pass/fail table per tier and a totals line. At most
+    /// [`MARKDOWN_SUMMARY_MAX_FAILURES`] failing scenarios are listed so the
+    /// result stays within a few KB.
+    pub fn to_markdown_summary(&self) -> String {
+        let mut out = String::new();
+
+        let (emoji, verdict) = if self.all_passed() {
+            ("🟢", "PASSED")
+        } else if self.passed_count() > 0 {
+            ("🟡", "MIXED")
+        } else {
+            ("🔴", "FAILED")
+        };
+        out.push_str(&format!("### {} E2E {}\n\n", emoji, verdict));
+
+        out.push_str("| Tier | ✅ Passed | ❌ Failed |\n");
+        out.push_str("|------|----------:|----------:|\n");
+        for (tier, tests) in self.by_tier() {
+            out.push_str(&format!(
+                "| {} | {} | {} |\n",
+                tier.replace('|', "\\|"),
+                tests.iter().filter(|t| t.passed).count(),
+                tests.iter().filter(|t| t.is_failed()).count()
+            ));
+        }
+
+        out.push_str(&format!(
+            "\n**Total:** {} passed, {} failed",
+            self.passed_count(),
+            self.failed_count()
+        ));
+        let skipped = self.skipped_count();
+        if skipped > 0 {
+            out.push_str(&format!(", {} skipped", skipped));
+        }
+        out.push_str(&format!(" in {:.1}s\n", self.duration.as_secs_f64()));
+
+        let failures = self.failures();
+        if !failures.is_empty() {
+            out.push_str("\n**Failed:**");
+            for result in failures.iter().take(MARKDOWN_SUMMARY_MAX_FAILURES) {
+                out.push_str(&format!(" `{}`", result.scenario_id));
+            }
+            if failures.len() > MARKDOWN_SUMMARY_MAX_FAILURES {
+                out.push_str(&format!(
+                    " and {} more",
+                    failures.len() - MARKDOWN_SUMMARY_MAX_FAILURES
+                ));
+            }
+            out.push('\n');
+        }
+
+        out
     }
 }
 
+/// Maximum number of failing scenario IDs listed by
+/// [`RunResults::to_markdown_summary`].
+pub const MARKDOWN_SUMMARY_MAX_FAILURES: usize = 10;
+
 /// Progress callback for test execution updates.
 pub type ProgressCallback = Box<dyn Fn(ProgressEvent) + Send + Sync>;
 
 /// Events emitted during test execution.
 #[derive(Debug, Clone)]
 pub enum ProgressEvent {
+    /// A backend's availability/auth probe finished.
+    BackendChecked {
+        backend: Backend,
+        status: BackendInfo,
+    },
+
     /// A test run has started.
     RunStarted { total_scenarios: usize },
 
@@ -177,10 +295,26 @@ pub enum ProgressEvent {
     /// A scenario was skipped.
     ScenarioSkipped { scenario_id: String, reason: String },
 
+    /// The run stopped early because a scenario failed with `bail` enabled.
+    Bailed {
+        /// The scenario whose failure stopped the run.
+        scenario_id: String,
+        /// Number of scenario runs that were not executed.
+        remaining: usize,
+    },
+
     /// The test run has completed.
     RunCompleted { results: RunResults },
 }
 
+/// How a single scenario run ended.
+enum ScenarioOutcome {
+    /// The scenario executed and produced a result (passing or failing).
+    Completed(TestResult),
+    /// Setup failed, so the scenario never executed.
+    Skipped(String),
+}
+
 /// Orchestrates E2E test scenario execution.
 pub struct TestRunner {
     /// Manages isolated test workspaces.
@@ -236,38 +370,67 @@ impl TestRunner {
             .collect()
     }
 
+    /// Checks each backend's availability and auth, emitting
+    /// [`ProgressEvent::BackendChecked`] as each probe finishes.
+    pub async fn check_backends(
+        &self,
+        checker: &AuthChecker,
+        backends: &[Backend],
+    ) -> Vec<BackendInfo> {
+        let mut results = Vec::with_capacity(backends.len());
+        for &backend in backends {
+            let status = checker.check(backend).await;
+            self.emit_progress(ProgressEvent::BackendChecked {
+                backend,
+                status: status.clone(),
+            });
+            results.push(status);
+        }
+        results
+    }
+
     /// Runs all scenarios matching the configuration.
     ///
     /// When a specific backend is set in `config`, each scenario runs once for that backend.
     /// When no backend is set (running "all"), each scenario runs once per supported backend.
     pub async fn run(&self, config: &RunConfig) -> Result<RunResults, RunnerError> {
         let start = Instant::now();
-        let matching = self.matching_scenarios(config);
+        // Select by filter/tags only; backend support is checked per run so
+        // unsupported scenarios are recorded as skipped rather than dropped
+        let selection = RunConfig {
+            backend: None,
+            ..config.clone()
+        };
+        let mut matching = self.matching_scenarios(&selection);
+        if let Some(seed) = config.shuffle_seed {
+            shuffle_with_seed(&mut matching, seed);
+        }
 
-        if matching.is_empty() && config.filter.is_some() {
-            return Err(RunnerError::NoMatchingScenarios(
-                config.filter.clone().unwrap(),
-            ));
+        if matching.is_empty() && (config.filter.is_some() || !config.tags.is_empty()) {
+            let mut criteria: Vec<String> = config.filter.iter().cloned().collect();
+            if !config.tags.is_empty() {
+                criteria.push(format!("tag {}", config.tags.join("|")));
+            }
+            return Err(RunnerError::NoMatchingScenarios(criteria.join(" and ")));
         }
 
         // Calculate total scenarios: if no backend specified, multiply by supported backends
         let total_scenarios: usize = if config.backend.is_some() {
             matching.len()
         } else {
-            matching.iter().map(|s| s.supported_backends().len()).sum()
+            matching
+                .iter()
+                .map(|s| Self::backends_for(*s, config).len())
+                .sum()
         };
 
         self.emit_progress(ProgressEvent::RunStarted { total_scenarios });
 
         let mut results = Vec::new();
-        let mut skipped_count = 0;
 
-        for scenario in matching {
+        'scenarios: for scenario in matching {
             // Determine which backends to run for this scenario
-            let backends_to_run: Vec<Backend> = match &config.backend {
-                Some(b) => vec![*b],
-                None => scenario.supported_backends(),
-            };
+            let backends_to_run = Self::backends_for(scenario, config);
 
             for backend in backends_to_run {
                 let scenario_id = if config.backend.is_some() {
@@ -277,120 +440,38 @@ impl TestRunner {
                     // All backends mode: append backend name
                     format!("{}-{}", scenario.id(), backend.as_config_str())
                 };
-                let tier = scenario.tier().to_string();
-
-                self.emit_progress(ProgressEvent::ScenarioStarted {
-                    scenario_id: scenario_id.clone(),
-                    tier: tier.clone(),
-                });
 
-                // Create workspace for this scenario
-                let workspace_path = self
-                    .workspace_mgr
-                    .create_workspace(&scenario_id)
-                    .map_err(|e| RunnerError::WorkspaceError(e.to_string()))?;
-
-                // Setup the scenario with the target backend
-                let setup_result = scenario.setup(&workspace_path, backend);
-                let scenario_config = match setup_result {
-                    Ok(cfg) => cfg,
-                    Err(e) => {
-                        self.emit_progress(ProgressEvent::ScenarioSkipped {
-                            scenario_id: scenario_id.clone(),
-                            reason: format!("Setup failed: {}", e),
-                        });
-                        skipped_count += 1;
-
-                        if !config.keep_workspaces {
-                            self.workspace_mgr.cleanup(&scenario_id).ok();
-                        }
-                        continue;
-                    }
-                };
-
-                // Configure mock mode if enabled
-                if let Some(ref mock_config) = config.mock_config
-                    && let Err(e) = self.configure_mock_mode(
-                        &workspace_path,
-                        scenario.id(),
-                        backend,
-                        mock_config,
-                    )
-                {
+                let outcome = if let Some(reason) = backend_skip_reason(scenario, backend) {
                     self.emit_progress(ProgressEvent::ScenarioSkipped {
                         scenario_id: scenario_id.clone(),
-                        reason: format!("Mock setup failed: {}", e),
+                        reason: reason.clone(),
                     });
-                    skipped_count += 1;
-
-                    if !config.keep_workspaces {
-                        self.workspace_mgr.cleanup(&scenario_id).ok();
-                    }
-                    continue;
-                }
-
-                // Execute the scenario
-                let executor = match &self.ralph_binary {
-                    Some(binary) => {
-                        RalphExecutor::with_binary(workspace_path.clone(), binary.clone())
-                    }
-                    None => RalphExecutor::new(workspace_path.clone()),
+                    ScenarioOutcome::Skipped(reason)
+                } else {
+                    self.run_scenario(scenario, backend, scenario_id.clone(), config)
+                        .await?
                 };
-                let scenario_start = Instant::now();
-
-                let result = scenario.run(&executor, &scenario_config).await;
-                let scenario_duration = scenario_start.elapsed();
-
-                match result {
-                    Ok(mut test_result) => {
-                        // Update scenario_id to include backend suffix when running all
-                        if config.backend.is_none() {
-                            test_result.scenario_id = scenario_id.clone();
-                        }
-                        test_result.backend = backend.to_string();
-                        let passed = test_result.passed;
-
-                        self.emit_progress(ProgressEvent::ScenarioCompleted {
-                            scenario_id: scenario_id.clone(),
-                            passed,
-                            duration: scenario_duration,
-                            result: test_result.clone(),
-                        });
-
-                        results.push(test_result);
-                    }
-                    Err(e) => {
-                        // Create a failed result for the scenario
-                        let failed_result = TestResult {
-                            scenario_id: scenario_id.clone(),
-                            scenario_description: scenario.description().to_string(),
-                            backend: backend.to_string(),
-                            tier: tier.clone(),
-                            passed: false,
-                            assertions: vec![crate::models::Assertion {
-                                name: "Execution".to_string(),
-                                passed: false,
-                                expected: "Scenario executes successfully".to_string(),
-                                actual: format!("Error: {}", e),
-                            }],
-                            duration: scenario_duration,
-                        };
-
-                        self.emit_progress(ProgressEvent::ScenarioCompleted {
-                            scenario_id: scenario_id.clone(),
-                            passed: false,
-                            duration: scenario_duration,
-                            result: failed_result.clone(),
-                        });
-
-                        results.push(failed_result);
+
+                match outcome {
+                    ScenarioOutcome::Completed(result) => results.push(result),
+                    ScenarioOutcome::Skipped(reason) => {
+                        results.push(TestResult::skipped(
+                            scenario_id,
+                            scenario.description(),
+                            backend.to_string(),
+                            scenario.tier(),
+                            reason,
+                        ));
+                        continue;
                     }
                 }
 
-                // Cleanup unless keeping workspaces
-                if !config.keep_workspaces {
-                    scenario.cleanup(&workspace_path).ok();
-                    self.workspace_mgr.cleanup(&scenario_id).ok();
+                if config.bail && results.last().is_some_and(TestResult::is_failed) {
+                    self.emit_progress(ProgressEvent::Bailed {
+                        scenario_id,
+                        remaining: total_scenarios.saturating_sub(results.len()),
+                    });
+                    break 'scenarios;
                 }
             }
         }
@@ -398,7 +479,6 @@ impl TestRunner {
         let run_results = RunResults {
             results,
             duration: start.elapsed(),
-            skipped_count,
         };
 
         self.emit_progress(ProgressEvent::RunCompleted {
@@ -413,11 +493,204 @@ impl TestRunner {
         self.run(&RunConfig::default()).await
     }
 
+    /// Runs a single scenario by exact ID against one backend.
+    ///
+    /// Bypasses filter matching and returns the scenario's full result
+    /// directly, which is handy when debugging one scenario.
+    pub async fn run_one(
+        &self,
+        scenario_id: &str,
+        backend: Backend,
+    ) -> Result<TestResult, RunnerError> {
+        let scenario = self
+            .scenarios
+            .iter()
+            .find(|s| s.id() == scenario_id)
+            .ok_or_else(|| RunnerError::NoMatchingScenarios(scenario_id.to_string()))?;
+        if let Some(reason) = backend_skip_reason(scenario.as_ref(), backend) {
+            return Err(RunnerError::ScenarioSkipped {
+                scenario_id: scenario_id.to_string(),
+                reason,
+            });
+        }
+
+        let config = RunConfig::default().with_backend(backend);
+        match self
+            .run_scenario(scenario.as_ref(), backend, scenario_id.to_string(), &config)
+            .await?
+        {
+            ScenarioOutcome::Completed(result) => Ok(result),
+            ScenarioOutcome::Skipped(reason) => Err(RunnerError::ScenarioSkipped {
+                scenario_id: scenario_id.to_string(),
+                reason,
+            }),
+        }
+    }
+
+    /// Sets up, executes, and cleans up one scenario for one backend.
+    async fn run_scenario(
+        &self,
+        scenario: &dyn TestScenario,
+        backend: Backend,
+        scenario_id: String,
+        config: &RunConfig,
+    ) -> Result<ScenarioOutcome, RunnerError> {
+        let tier = scenario.tier().to_string();
+
+        self.emit_progress(ProgressEvent::ScenarioStarted {
+            scenario_id: scenario_id.clone(),
+            tier: tier.clone(),
+        });
+
+        // Create a uniquely named workspace so parallel or overlapping runs
+        // of the same scenario never share a directory
+        let workspace_path = self
+            .workspace_mgr
+            .create_unique_workspace(&scenario_id)
+            .map_err(|e| RunnerError::WorkspaceError(e.to_string()))?;
+        let workspace_name = workspace_path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+
+        // Setup the scenario with the target backend
+        let setup_result = scenario.setup(&workspace_path, backend);
+        let scenario_config = match setup_result {
+            Ok(cfg) => cfg,
+            Err(e) => {
+                let reason = format!("Setup failed: {}", e);
+                self.emit_progress(ProgressEvent::ScenarioSkipped {
+                    scenario_id: scenario_id.clone(),
+                    reason: reason.clone(),
+                });
+                if !config.keep_workspaces {
+                    self.workspace_mgr.cleanup(&workspace_name).ok();
+                }
+                return Ok(ScenarioOutcome::Skipped(reason));
+            }
+        };
+
+        // Point ralph.yml at the user's command for custom-backend runs
+        if backend == Backend::Custom
+            && let Err(reason) = Self::configure_custom_backend(&workspace_path, config)
+        {
+            self.emit_progress(ProgressEvent::ScenarioSkipped {
+                scenario_id: scenario_id.clone(),
+                reason: reason.clone(),
+            });
+            if !config.keep_workspaces {
+                self.workspace_mgr.cleanup(&workspace_name).ok();
+            }
+            return Ok(ScenarioOutcome::Skipped(reason));
+        }
+
+        // Configure mock mode if enabled
+        if let Some(ref mock_config) = config.mock_config
+            && let Err(e) =
+                self.configure_mock_mode(&workspace_path, scenario.id(), backend, mock_config)
+        {
+            let reason = format!("Mock setup failed: {}", e);
+            self.emit_progress(ProgressEvent::ScenarioSkipped {
+                scenario_id: scenario_id.clone(),
+                reason: reason.clone(),
+            });
+            if !config.keep_workspaces {
+                self.workspace_mgr.cleanup(&workspace_name).ok();
+            }
+            return Ok(ScenarioOutcome::Skipped(reason));
+        }
+
+        // Execute the scenario
+        let executor = match &self.ralph_binary {
+            Some(binary) => RalphExecutor::with_binary(workspace_path.clone(), binary.clone()),
+            None => RalphExecutor::new(workspace_path.clone()),
+        };
+        let scenario_start = Instant::now();
+
+        let result = scenario.run(&executor, &scenario_config).await;
+        let scenario_duration = scenario_start.elapsed();
+
+        let test_result = match result {
+            Ok(mut test_result) => {
+                // Update scenario_id to include backend suffix when running all
+                if config.backend.is_none() {
+                    test_result.scenario_id = scenario_id.clone();
+                }
+                test_result.backend = backend.to_string();
+                let passed = test_result.passed;
+
+                self.emit_progress(ProgressEvent::ScenarioCompleted {
+                    scenario_id: scenario_id.clone(),
+                    passed,
+                    duration: scenario_duration,
+                    result: test_result.clone(),
+                });
+
+                test_result
+            }
+            Err(e) => {
+                // Create a failed result for the scenario
+                let failed_result = TestResult {
+                    scenario_id: scenario_id.clone(),
+                    scenario_description: scenario.description().to_string(),
+                    backend: backend.to_string(),
+                    tier: tier.clone(),
+                    passed: false,
+                    assertions: vec![crate::models::Assertion {
+                        name: "Execution".to_string(),
+                        passed: false,
+                        expected: "Scenario executes successfully".to_string(),
+                        actual: format!("Error: {}", e),
+                    }],
+                    duration: scenario_duration,
+                    skip_reason: None,
+                };
+
+                self.emit_progress(ProgressEvent::ScenarioCompleted {
+                    scenario_id: scenario_id.clone(),
+                    passed: false,
+                    duration: scenario_duration,
+                    result: failed_result.clone(),
+                });
+
+                failed_result
+            }
+        };
+
+        // Cleanup unless keeping workspaces
+        if !config.keep_workspaces {
+            scenario.cleanup(&workspace_path).ok();
+            self.workspace_mgr.cleanup(&workspace_name).ok();
+        }
+        Ok(ScenarioOutcome::Completed(test_result))
+    }
+
+    /// Returns the backends a matching scenario runs against.
+    ///
+    /// "All backends" mode only includes [`Backend::Custom`] when a custom
+    /// command is configured.
+    fn backends_for(scenario: &dyn TestScenario, config: &RunConfig) -> Vec<Backend> {
+        match config.backend {
+            Some(backend) => vec![backend],
+            None => {
+                // Explicitly unsupported backends are included so their skips are recorded
+                let mut backends = scenario.supported_backends();
+                for backend in scenario.unsupported_backends() {
+                    if !backends.contains(backend) {
+                        backends.push(*backend);
+                    }
+                }
+                backends.retain(|b| *b != Backend::Custom || config.custom_backend.is_some());
+                backends
+            }
+        }
+    }
+
     /// Checks if a scenario matches the run configuration.
     fn matches_config(&self, scenario: &dyn TestScenario, config: &RunConfig) -> bool {
         // Check backend filter: scenario must support the requested backend
-        if let Some(backend) = &config.backend
-            && !scenario.supported_backends().contains(backend)
+        if let Some(backend) = config.backend
+            && backend_skip_reason(scenario, backend).is_some()
         {
             return false;
         }
@@ -437,6 +710,16 @@ impl TestRunner {
             }
         }
 
+        // Check tag filter: scenario must carry at least one requested tag
+        if !config.tags.is_empty()
+            && !scenario
+                .tags()
+                .iter()
+                .any(|tag| config.tags.iter().any(|t| t.eq_ignore_ascii_case(tag)))
+        {
+            return false;
+        }
+
         true
     }
 
@@ -447,6 +730,58 @@ impl TestRunner {
         }
     }
 
+    /// Rewrites the scenario's `cli` config to run the configured custom command.
+    ///
+    /// Other `cli` settings the scenario wrote are kept.
+    fn configure_custom_backend(workspace_path: &Path, config: &RunConfig) -> Result<(), String> {
+        let custom = config
+            .custom_backend
+            .as_ref()
+            .ok_or_else(|| "Custom backend selected but no command configured".to_string())?;
+
+        let ralph_yml_path = workspace_path.join("ralph.yml");
+        let existing = std::fs::read_to_string(&ralph_yml_path).unwrap_or_default();
+        let mut yaml: serde_yaml::Value = serde_yaml::from_str(&existing)
+            .unwrap_or(serde_yaml::Value::Mapping(serde_yaml::Mapping::new()));
+        let serde_yaml::Value::Mapping(map) = &mut yaml else {
+            return Err("ralph.yml is not a mapping".to_string());
+        };
+
+        let cli = map
+            .entry(serde_yaml::Value::String("cli".to_string()))
+            .or_insert_with(|| serde_yaml::Value::Mapping(serde_yaml::Mapping::new()));
+        if !cli.is_mapping() {
+            *cli = serde_yaml::Value::Mapping(serde_yaml::Mapping::new());
+        }
+        if let serde_yaml::Value::Mapping(cli) = cli {
+            for (key, value) in [
+                ("backend", serde_yaml::Value::from("custom")),
+                ("command", serde_yaml::Value::from(custom.command.as_str())),
+                (
+                    "prompt_mode",
+                    serde_yaml::Value::from(custom.prompt_mode.as_str()),
+                ),
+                (
+                    "args",
+                    serde_yaml::Value::Sequence(
+                        custom
+                            .args
+                            .iter()
+                            .map(|a| serde_yaml::Value::from(a.as_str()))
+                            .collect(),
+                    ),
+                ),
+            ] {
+                cli.insert(serde_yaml::Value::String(key.to_string()), value);
+            }
+        }
+
+        let content = serde_yaml::to_string(&yaml)
+            .map_err(|e| format!("YAML serialization failed: {}", e))?;
+        std::fs::write(&ralph_yml_path, content)
+            .map_err(|e| format!("Failed to write ralph.yml: {}", e))
+    }
+
     /// Configures mock mode for a scenario by overwriting ralph.yml with custom backend.
     fn configure_mock_mode(
         &self,
@@ -461,10 +796,34 @@ impl TestRunner {
         let cassette_dir = mock_config.resolve_cassette_dir();
         let resolver = CassetteResolver::new(&cassette_dir);
 
-        // Resolve cassette path for this scenario
-        let cassette_path = resolver.resolve(scenario_id, backend).map_err(|e| {
-            RunnerError::WorkspaceError(format!("Cassette resolution failed: {}", e))
-        })?;
+        // Resolve cassette path for this scenario. When recording missing
+        // cassettes, fall back to the backend-specific path mock-cli will create.
+        let cassette_path = match resolver.resolve(scenario_id, backend) {
+            Ok(path) => path,
+            Err(_) if mock_config.record_missing => {
+                fs::create_dir_all(&cassette_dir).map_err(|e| {
+                    RunnerError::WorkspaceError(format!(
+                        "Failed to create cassette directory: {}",
+                        e
+                    ))
+                })?;
+                resolver.candidates(scenario_id, backend).remove(0)
+            }
+            Err(e) => {
+                return Err(RunnerError::WorkspaceError(format!(
+                    "Cassette resolution failed: {}",
+                    e
+                )));
+            }
+        };
+
+        // With prompt matching, mock-cli picks among every candidate on disk
+        let existing = resolver.existing_candidates(scenario_id, backend);
+        let cassettes = if mock_config.matcher.is_some() && !existing.is_empty() {
+            existing
+        } else {
+            vec![cassette_path]
+        };
 
         // Get the ralph-e2e binary path (same as the currently running binary)
         let mock_cli_binary = std::env::current_exe().map_err(|e| {
@@ -472,7 +831,9 @@ impl TestRunner {
         })?;
 
         // Build the mock-cli args
-        let mock_args = build_mock_cli_args(&cassette_path, mock_config);
+        let mock_args = build_mock_cli_args(&cassettes, mock_config, backend).map_err(|e| {
+            RunnerError::WorkspaceError(format!("Invalid mock configuration: {}", e))
+        })?;
 
         // Read the existing ralph.yml to preserve non-backend config
         let ralph_yml_path = workspace_path.join("ralph.yml");
@@ -528,6 +889,40 @@ impl TestRunner {
     }
 }
 
+/// Returns why `scenario` can't run on `backend`, if it can't.
+///
+/// A scenario runs only if `backend` is in its positive list and not in its
+/// explicit opt-out list.
+fn backend_skip_reason(scenario: &dyn TestScenario, backend: Backend) -> Option<String> {
+    if scenario.unsupported_backends().contains(&backend) {
+        Some(format!("Known incompatible with {backend}"))
+    } else if !scenario.supported_backends().contains(&backend) {
+        Some(format!("Not supported on {backend}"))
+    } else {
+        None
+    }
+}
+
+/// Shuffles `items` in place with a Fisher-Yates pass driven by SplitMix64.
+///
+/// Implemented locally so the order for a given seed never changes with a
+/// dependency upgrade.
+fn shuffle_with_seed<T>(items: &mut [T], seed: u64) {
+    let mut state = seed;
+    let mut next = || {
+        state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    };
+
+    for i in (1..items.len()).rev() {
+        let j = (next() % (i as u64 + 1)) as usize;
+        items.swap(i, j);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -545,6 +940,8 @@ mod tests {
         description: String,
         tier: String,
         supported_backends: Vec<Backend>,
+        unsupported_backends: Vec<Backend>,
+        tags: Vec<&'static str>,
         should_pass: bool,
     }
 
@@ -555,10 +952,17 @@ mod tests {
                 description: format!("Mock scenario {}", id),
                 tier: "Tier 0: Mock".to_string(),
                 supported_backends: vec![Backend::Claude, Backend::Kiro, Backend::OpenCode],
+                unsupported_backends: Vec::new(),
+                tags: Vec::new(),
                 should_pass: pass,
             }
         }
 
+        fn with_tags(mut self, tags: &[&'static str]) -> Self {
+            self.tags = tags.to_vec();
+            self
+        }
+
         #[allow(dead_code)]
         fn with_tier(mut self, tier: &str) -> Self {
             self.tier = tier.to_string();
@@ -569,6 +973,11 @@ mod tests {
             self.supported_backends = vec![backend];
             self
         }
+
+        fn without_backend(mut self, backend: Backend) -> Self {
+            self.unsupported_backends.push(backend);
+            self
+        }
     }
 
     #[async_trait]
@@ -585,10 +994,18 @@ mod tests {
             &self.tier
         }
 
+        fn tags(&self) -> &[&str] {
+            &self.tags
+        }
+
         fn supported_backends(&self) -> Vec<Backend> {
             self.supported_backends.clone()
         }
 
+        fn unsupported_backends(&self) -> &[Backend] {
+            &self.unsupported_backends
+        }
+
         fn setup(
             &self,
             workspace: &Path,
@@ -629,6 +1046,7 @@ mod tests {
                     actual: if self.should_pass { "pass" } else { "fail" }.to_string(),
                 }],
                 duration: Duration::from_millis(100),
+                skip_reason: None,
             })
         }
     }
@@ -653,6 +1071,7 @@ mod tests {
     fn test_run_config_defaults() {
         let config = RunConfig::new();
         assert!(config.filter.is_none());
+        assert!(config.tags.is_empty());
         assert!(config.backend.is_none());
         assert!(!config.keep_workspaces);
     }
@@ -681,6 +1100,7 @@ mod tests {
                     passed: true,
                     assertions: vec![],
                     duration: Duration::from_secs(1),
+                    skip_reason: None,
                 },
                 TestResult {
                     scenario_id: "test-2".to_string(),
@@ -690,6 +1110,7 @@ mod tests {
                     passed: false,
                     assertions: vec![],
                     duration: Duration::from_secs(2),
+                    skip_reason: None,
                 },
                 TestResult {
                     scenario_id: "test-3".to_string(),
@@ -699,10 +1120,10 @@ mod tests {
                     passed: true,
                     assertions: vec![],
                     duration: Duration::from_secs(1),
+                    skip_reason: None,
                 },
             ],
             duration: Duration::from_secs(4),
-            skipped_count: 0,
         };
 
         assert_eq!(results.passed_count(), 2);
@@ -722,9 +1143,9 @@ mod tests {
                 passed: true,
                 assertions: vec![],
                 duration: Duration::from_secs(1),
+                skip_reason: None,
             }],
             duration: Duration::from_secs(1),
-            skipped_count: 0,
         };
 
         assert!(results.all_passed());
@@ -742,6 +1163,7 @@ mod tests {
                     passed: true,
                     assertions: vec![],
                     duration: Duration::from_secs(1),
+                    skip_reason: None,
                 },
                 TestResult {
                     scenario_id: "test-2".to_string(),
@@ -751,6 +1173,7 @@ mod tests {
                     passed: true,
                     assertions: vec![],
                     duration: Duration::from_secs(1),
+                    skip_reason: None,
                 },
                 TestResult {
                     scenario_id: "test-3".to_string(),
@@ -760,10 +1183,10 @@ mod tests {
                     passed: true,
                     assertions: vec![],
                     duration: Duration::from_secs(1),
+                    skip_reason: None,
                 },
             ],
             duration: Duration::from_secs(3),
-            skipped_count: 0,
         };
 
         let by_tier = results.by_tier();
@@ -785,6 +1208,7 @@ mod tests {
                     passed: true,
                     assertions: vec![],
                     duration: Duration::from_secs(1),
+                    skip_reason: None,
                 },
                 TestResult {
                     scenario_id: "fail".to_string(),
@@ -794,10 +1218,10 @@ mod tests {
                     passed: false,
                     assertions: vec![],
                     duration: Duration::from_secs(1),
+                    skip_reason: None,
                 },
             ],
             duration: Duration::from_secs(2),
-            skipped_count: 0,
         };
 
         let failures = results.failures();
@@ -805,6 +1229,76 @@ mod tests {
         assert_eq!(failures[0].scenario_id, "fail");
     }
 
+    #[test]
+    fn test_run_results_skipped_is_neither_passed_nor_failed() {
+        let mut results = RunResults {
+            results: vec![TestResult::skipped(
+                "connect-kiro",
+                "connect",
+                "Kiro",
+                "Tier 1: Connectivity",
+                "Not supported on Kiro",
+            )],
+            duration: Duration::from_secs(1),
+        };
+        assert_eq!(results.passed_count(), 0);
+        assert_eq!(results.failed_count(), 0);
+        assert_eq!(results.skipped_count(), 1);
+        assert_eq!(results.total_count(), 0);
+        assert!(results.all_passed());
+        assert!(results.failures().is_empty());
+
+        results.results[0].skip_reason = None;
+        assert!(!results.all_passed());
+    }
+
+    #[test]
+    fn test_run_results_markdown_summary() {
+        let result = |id: &str, tier: &str, passed: bool| TestResult {
+            scenario_id: id.to_string(),
+            scenario_description: id.to_string(),
+            backend: "Claude".to_string(),
+            tier: tier.to_string(),
+            passed,
+            assertions: vec![],
+            duration: Duration::from_secs(1),
+            skip_reason: None,
+        };
+        let mut results = RunResults {
+            results: vec![
+                result("connect", "Tier 1: Connectivity", true),
+                result("events", "Tier 2: Orchestration", true),
+                result("hats", "Tier 2: Orchestration", false),
+                TestResult::skipped(
+                    "connect-kiro",
+                    "connect",
+                    "Kiro",
+                    "Tier 1: Connectivity",
+                    "Not supported on Kiro",
+                ),
+            ],
+            duration: Duration::from_millis(2500),
+        };
+
+        let summary = results.to_markdown_summary();
+        assert!(summary.starts_with("### 🟡 E2E MIXED"));
+        assert!(summary.contains("| Tier 1: Connectivity | 1 | 0 |"));
+        assert!(summary.contains("| Tier 2: Orchestration | 1 | 1 |"));
+        assert!(summary.contains("**Total:** 2 passed, 1 failed, 1 skipped in 2.5s"));
+        assert!(summary.contains("**Failed:** `hats`"));
+
+        // The failure list is capped so large runs stay comment-sized
+        for i in 0..50 {
+            results
+                .results
+                .push(result(&format!("broken-{i}"), "Tier 3", false));
+        }
+        let summary = results.to_markdown_summary();
+        assert!(summary.contains("and 41 more"));
+        assert!(!summary.contains("`broken-20`"));
+        assert!(summary.len() < 4096);
+    }
+
     #[test]
     fn test_runner_scenario_count() {
         let workspace = test_workspace_base("scenario-count");
@@ -868,6 +1362,40 @@ mod tests {
         cleanup_workspace(&workspace);
     }
 
+    #[test]
+    fn test_runner_matching_scenarios_with_tags() {
+        let workspace = test_workspace_base("matching-tags");
+        let workspace_mgr = WorkspaceManager::new(workspace.clone());
+        let scenarios: Vec<Box<dyn TestScenario>> = vec![
+            Box::new(MockScenario::new("memory-add", true).with_tags(&["memory"])),
+            Box::new(MockScenario::new("memory-corrupt", true).with_tags(&["memory", "chaos"])),
+            Box::new(MockScenario::new("events", true).with_tags(&["events"])),
+            Box::new(MockScenario::new("untagged", true)),
+        ];
+
+        let runner = TestRunner::new(workspace_mgr, scenarios);
+        let ids = |config: &RunConfig| -> Vec<String> {
+            runner
+                .matching_scenarios(config)
+                .iter()
+                .map(|s| s.id().to_string())
+                .collect()
+        };
+
+        // Repeated tags match any of them
+        let config = RunConfig::new().with_tag("chaos").with_tag("EVENTS");
+        assert_eq!(ids(&config), vec!["memory-corrupt", "events"]);
+
+        // Tags combine with --filter using AND
+        let config = RunConfig::new().with_tag("memory").with_filter("add");
+        assert_eq!(ids(&config), vec!["memory-add"]);
+
+        let config = RunConfig::new().with_tag("events").with_filter("memory");
+        assert!(ids(&config).is_empty());
+
+        cleanup_workspace(&workspace);
+    }
+
     #[test]
     fn test_runner_matching_scenarios_with_backend() {
         let workspace = test_workspace_base("matching-backend");
@@ -1000,6 +1528,104 @@ mod tests {
         cleanup_workspace(&workspace);
     }
 
+    #[tokio::test]
+    async fn test_runner_bail_stops_after_first_failure() {
+        let workspace = test_workspace_base("run-bail");
+        let workspace_mgr = WorkspaceManager::new(workspace.clone());
+        let scenarios: Vec<Box<dyn TestScenario>> = vec![
+            Box::new(MockScenario::new("pass-1", true).with_backend(Backend::Claude)),
+            Box::new(MockScenario::new("fail-1", false).with_backend(Backend::Claude)),
+            Box::new(MockScenario::new("pass-2", true).with_backend(Backend::Claude)),
+            Box::new(MockScenario::new("fail-2", false).with_backend(Backend::Claude)),
+        ];
+
+        let bailed = Arc::new(std::sync::Mutex::new(None));
+        let bailed_clone = bailed.clone();
+        let runner =
+            TestRunner::new(workspace_mgr, scenarios).on_progress(Box::new(move |event| {
+                if let ProgressEvent::Bailed {
+                    scenario_id,
+                    remaining,
+                } = event
+                {
+                    *bailed_clone.lock().unwrap() = Some((scenario_id, remaining));
+                }
+            }));
+        let results = runner.run(&RunConfig::new().bail(true)).await.unwrap();
+
+        // Results cover only what ran, up to and including the failure
+        let ids: Vec<_> = results
+            .results
+            .iter()
+            .map(|r| r.scenario_id.as_str())
+            .collect();
+        assert_eq!(ids, vec!["pass-1-claude", "fail-1-claude"]);
+        assert_eq!(
+            *bailed.lock().unwrap(),
+            Some(("fail-1-claude".to_string(), 2))
+        );
+
+        cleanup_workspace(&workspace);
+    }
+
+    #[test]
+    fn test_shuffle_with_seed_is_deterministic() {
+        let original: Vec<u32> = (0..20).collect();
+
+        let mut a = original.clone();
+        let mut b = original.clone();
+        shuffle_with_seed(&mut a, 42);
+        shuffle_with_seed(&mut b, 42);
+        assert_eq!(a, b, "same seed must give the same order");
+        assert_ne!(a, original, "order should actually change");
+
+        let mut sorted = a.clone();
+        sorted.sort_unstable();
+        assert_eq!(sorted, original, "shuffle must be a permutation");
+
+        let mut c = original.clone();
+        shuffle_with_seed(&mut c, 7);
+        assert_ne!(a, c, "different seeds should give different orders");
+    }
+
+    #[tokio::test]
+    async fn test_runner_shuffle_changes_execution_order() {
+        let workspace = test_workspace_base("run-shuffle");
+        let ids: Vec<String> = (0..8).map(|i| format!("s{i}")).collect();
+        let run_order = |seed: Option<u64>| {
+            let workspace_mgr = WorkspaceManager::new(workspace.clone());
+            let scenarios: Vec<Box<dyn TestScenario>> = ids
+                .iter()
+                .map(|id| {
+                    Box::new(MockScenario::new(id, true).with_backend(Backend::Claude))
+                        as Box<dyn TestScenario>
+                })
+                .collect();
+            let mut config = RunConfig::new().with_backend(Backend::Claude);
+            if let Some(seed) = seed {
+                config = config.shuffle(seed);
+            }
+            async move {
+                let runner = TestRunner::new(workspace_mgr, scenarios);
+                let results = runner.run(&config).await.unwrap();
+                results
+                    .results
+                    .into_iter()
+                    .map(|r| r.scenario_id)
+                    .collect::<Vec<_>>()
+            }
+        };
+
+        // Default keeps the registered order
+        assert_eq!(run_order(None).await, ids);
+
+        let shuffled = run_order(Some(1234)).await;
+        assert_ne!(shuffled, ids);
+        assert_eq!(run_order(Some(1234)).await, shuffled);
+
+        cleanup_workspace(&workspace);
+    }
+
     #[tokio::test]
     async fn test_runner_run_with_filter() {
         let workspace = test_workspace_base("run-filter");
@@ -1036,6 +1662,198 @@ mod tests {
         cleanup_workspace(&workspace);
     }
 
+    #[tokio::test]
+    async fn test_runner_check_backends_emits_progress() {
+        use std::sync::{Arc, Mutex};
+
+        let workspace = test_workspace_base("check-backends");
+        let dir = tempfile::tempdir().unwrap();
+        let cache = dir.path().join("backend-status.json");
+        let checker = AuthChecker::new().with_cache(&cache, Duration::from_mins(5));
+        // Pre-populate the cache so the test doesn't probe real CLIs
+        checker.store(&BackendInfo::authenticated(Backend::Kiro, None));
+
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let events_clone = events.clone();
+        let runner = TestRunner::new(WorkspaceManager::new(workspace.clone()), vec![]).on_progress(
+            Box::new(move |event| {
+                if let ProgressEvent::BackendChecked { backend, status } = event {
+                    events_clone
+                        .lock()
+                        .unwrap()
+                        .push((backend, status.is_available));
+                }
+            }),
+        );
+
+        let infos = runner.check_backends(&checker, &[Backend::Kiro]).await;
+
+        assert_eq!(infos.len(), 1);
+        assert_eq!(*events.lock().unwrap(), vec![(Backend::Kiro, true)]);
+
+        cleanup_workspace(&workspace);
+    }
+
+    #[test]
+    fn test_configure_custom_backend_rewrites_cli_section() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("ralph.yml"),
+            "cli:\n  backend: custom\n  idle_timeout_secs: 5\nevent_loop:\n  max_iterations: 1\n",
+        )
+        .unwrap();
+
+        let config = RunConfig::new().with_custom_backend(
+            CustomBackend::from_command_line("my-proxy --model fast")
+                .unwrap()
+                .with_prompt_mode("stdin"),
+        );
+        TestRunner::configure_custom_backend(dir.path(), &config).unwrap();
+
+        let yaml: serde_yaml::Value =
+            serde_yaml::from_str(&std::fs::read_to_string(dir.path().join("ralph.yml")).unwrap())
+                .unwrap();
+        assert_eq!(yaml["cli"]["backend"], "custom");
+        assert_eq!(yaml["cli"]["command"], "my-proxy");
+        assert_eq!(yaml["cli"]["prompt_mode"], "stdin");
+        assert_eq!(yaml["cli"]["args"][1], "fast");
+        assert_eq!(yaml["cli"]["idle_timeout_secs"], 5);
+        assert_eq!(yaml["event_loop"]["max_iterations"], 1);
+
+        assert!(TestRunner::configure_custom_backend(dir.path(), &RunConfig::new()).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_runner_custom_backend_is_opt_in() {
+        let workspace = test_workspace_base("run-custom");
+        let workspace_mgr = WorkspaceManager::new(workspace.clone());
+        let scenarios: Vec<Box<dyn TestScenario>> = vec![
+            Box::new(MockScenario::new("builtin-only", true).with_backend(Backend::Claude)),
+            Box::new(MockScenario::new("custom-ok", true).with_backend(Backend::Custom)),
+        ];
+        let runner = TestRunner::new(workspace_mgr, scenarios);
+
+        // "All" mode without a custom command skips the custom backend entirely
+        let results = runner.run(&RunConfig::new()).await.unwrap();
+        assert_eq!(results.total_count(), 1);
+        assert_eq!(results.results[0].scenario_id, "builtin-only-claude");
+
+        // Selecting the custom backend only runs scenarios that opted in
+        let config = RunConfig::new()
+            .with_backend(Backend::Custom)
+            .with_custom_backend(CustomBackend::from_command_line("my-proxy").unwrap());
+        let results = runner.run(&config).await.unwrap();
+        assert_eq!(results.total_count(), 1);
+        let ran: Vec<_> = results.results.iter().filter(|r| !r.is_skipped()).collect();
+        assert_eq!(ran[0].scenario_id, "custom-ok");
+        assert_eq!(ran[0].backend, "Custom");
+
+        cleanup_workspace(&workspace);
+    }
+
+    #[tokio::test]
+    async fn test_runner_records_unsupported_backends_as_skipped() {
+        let workspace = test_workspace_base("run-unsupported");
+        let workspace_mgr = WorkspaceManager::new(workspace.clone());
+        let scenarios: Vec<Box<dyn TestScenario>> = vec![
+            Box::new(MockScenario::new("everywhere", true)),
+            Box::new(MockScenario::new("no-kiro", true).without_backend(Backend::Kiro)),
+            Box::new(MockScenario::new("claude-only", true).with_backend(Backend::Claude)),
+        ];
+        let runner = TestRunner::new(workspace_mgr, scenarios);
+
+        // A single backend run records every filtered-out scenario as skipped
+        let results = runner
+            .run(&RunConfig::new().with_backend(Backend::Kiro))
+            .await
+            .unwrap();
+        assert_eq!(results.total_count(), 1);
+        assert_eq!(results.skipped_count(), 2);
+        assert!(results.all_passed());
+        let skipped: Vec<_> = results.results.iter().filter(|r| r.is_skipped()).collect();
+        assert_eq!(skipped[0].scenario_id, "no-kiro");
+        assert!(
+            skipped[0]
+                .skip_reason
+                .as_deref()
+                .unwrap()
+                .contains("incompatible")
+        );
+        assert_eq!(skipped[1].scenario_id, "claude-only");
+        assert_eq!(skipped[1].backend, "Kiro");
+
+        // "All" mode runs the rest of the default list and skips the opt-out
+        let results = runner.run(&RunConfig::new()).await.unwrap();
+        let ids: Vec<&str> = results
+            .results
+            .iter()
+            .filter(|r| !r.is_skipped())
+            .map(|r| r.scenario_id.as_str())
+            .collect();
+        assert!(ids.contains(&"no-kiro-claude"));
+        assert!(ids.contains(&"no-kiro-opencode"));
+        assert!(!ids.contains(&"no-kiro-kiro"));
+        assert_eq!(results.skipped_count(), 1);
+
+        cleanup_workspace(&workspace);
+    }
+
+    #[test]
+    fn test_backend_skip_reason() {
+        let scenario = MockScenario::new("no-kiro", true).without_backend(Backend::Kiro);
+        assert_eq!(backend_skip_reason(&scenario, Backend::Claude), None);
+        assert!(
+            backend_skip_reason(&scenario, Backend::Kiro)
+                .unwrap()
+                .contains("incompatible")
+        );
+        assert!(
+            backend_skip_reason(&scenario, Backend::Custom)
+                .unwrap()
+                .contains("Not supported")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_runner_run_one_returns_single_result() {
+        let workspace = test_workspace_base("run-one");
+        let workspace_mgr = WorkspaceManager::new(workspace.clone());
+        let scenarios: Vec<Box<dyn TestScenario>> = vec![
+            Box::new(MockScenario::new("mock-1", true)),
+            Box::new(MockScenario::new("mock-10", false)),
+        ];
+
+        let runner = TestRunner::new(workspace_mgr, scenarios);
+        let result = runner.run_one("mock-10", Backend::Kiro).await.unwrap();
+
+        assert_eq!(result.scenario_id, "mock-10");
+        assert_eq!(result.backend, Backend::Kiro.to_string());
+        assert!(!result.passed);
+
+        cleanup_workspace(&workspace);
+    }
+
+    #[tokio::test]
+    async fn test_runner_run_one_rejects_unknown_or_unsupported() {
+        let workspace = test_workspace_base("run-one-errors");
+        let workspace_mgr = WorkspaceManager::new(workspace.clone());
+        let scenarios: Vec<Box<dyn TestScenario>> = vec![Box::new(
+            MockScenario::new("mock-1", true).with_backend(Backend::Claude),
+        )];
+
+        let runner = TestRunner::new(workspace_mgr, scenarios);
+        assert!(matches!(
+            runner.run_one("mock", Backend::Claude).await,
+            Err(RunnerError::NoMatchingScenarios(_))
+        ));
+        assert!(matches!(
+            runner.run_one("mock-1", Backend::Kiro).await,
+            Err(RunnerError::ScenarioSkipped { .. })
+        ));
+
+        cleanup_workspace(&workspace);
+    }
+
     #[tokio::test]
     async fn test_runner_progress_callback() {
         let workspace = test_workspace_base("run-progress");
@@ -1071,9 +1889,12 @@ mod tests {
         let config = RunConfig::new().keep_workspaces(true);
         runner.run(&config).await.unwrap();
 
-        // Workspace should still exist (with backend suffix)
-        let scenario_workspace = workspace.join("mock-1-claude");
-        assert!(scenario_workspace.exists());
+        // Workspace should still exist (with backend and uniqueness suffix)
+        let workspaces = WorkspaceManager::new(workspace.clone())
+            .list_workspaces()
+            .unwrap();
+        assert_eq!(workspaces.len(), 1);
+        assert!(workspaces[0].starts_with("mock-1-claude-"));
 
         cleanup_workspace(&workspace);
     }
@@ -1090,9 +1911,11 @@ mod tests {
         let config = RunConfig::default(); // keep_workspaces = false
         runner.run(&config).await.unwrap();
 
-        // Workspace should be cleaned up (with backend suffix)
-        let scenario_workspace = workspace.join("mock-1-claude");
-        assert!(!scenario_workspace.exists());
+        // Workspace should be cleaned up
+        let workspaces = WorkspaceManager::new(workspace.clone())
+            .list_workspaces()
+            .unwrap();
+        assert!(workspaces.is_empty(), "{workspaces:?}");
 
         cleanup_workspace(&workspace);
     }
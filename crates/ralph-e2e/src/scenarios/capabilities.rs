@@ -67,6 +67,10 @@ impl TestScenario for ToolUseScenario {
         &self.tier
     }
 
+    fn tags(&self) -> &[&str] {
+        &["capabilities", "tools"]
+    }
+
     fn setup(&self, workspace: &Path, backend: Backend) -> Result<ScenarioConfig, ScenarioError> {
         // Create the .agent directory
         let agent_dir = workspace.join(".agent");
@@ -115,6 +119,7 @@ You MUST use a tool to read the file. Do not guess the contents.",
             max_iterations: 1,
             timeout: backend.default_timeout(),
             extra_args: vec![],
+            seed_events: vec![],
         })
     }
 
@@ -150,6 +155,7 @@ You MUST use a tool to read the file. Do not guess the contents.",
             passed: all_passed,
             assertions,
             duration,
+            skip_reason: None,
         })
     }
 }
@@ -258,6 +264,10 @@ impl TestScenario for StreamingScenario {
         &self.tier
     }
 
+    fn tags(&self) -> &[&str] {
+        &["capabilities", "streaming"]
+    }
+
     fn setup(&self, workspace: &Path, backend: Backend) -> Result<ScenarioConfig, ScenarioError> {
         // Create the .agent directory
         let agent_dir = workspace.join(".agent");
@@ -297,6 +307,7 @@ Keep your response short."#;
             max_iterations: 1,
             timeout: backend.default_timeout(),
             extra_args: vec![],
+            seed_events: vec![],
         })
     }
 
@@ -332,6 +343,7 @@ Keep your response short."#;
             passed: all_passed,
             assertions,
             duration,
+            skip_reason: None,
         })
     }
 }
@@ -446,6 +458,7 @@ mod tests {
             iterations: 1,
             termination_reason: Some("LOOP_COMPLETE".to_string()),
             timed_out: false,
+            denied_commands: vec![],
         }
     }
 
@@ -460,6 +473,7 @@ mod tests {
             iterations: 1,
             termination_reason: Some("LOOP_COMPLETE".to_string()),
             timed_out: false,
+            denied_commands: vec![],
         }
     }
 
@@ -72,7 +72,19 @@ impl TestScenario for ConnectivityScenario {
         &self.tier
     }
 
-    // Uses default supported_backends() which returns all backends
+    fn tags(&self) -> &[&str] {
+        &["connectivity"]
+    }
+
+    // Backend-agnostic, so it also validates custom backend commands
+    fn supported_backends(&self) -> Vec<Backend> {
+        vec![
+            Backend::Claude,
+            Backend::Kiro,
+            Backend::OpenCode,
+            Backend::Custom,
+        ]
+    }
 
     fn setup(&self, workspace: &Path, backend: Backend) -> Result<ScenarioConfig, ScenarioError> {
         // Create the .agent directory
@@ -108,6 +120,7 @@ event_loop:
             max_iterations: 1,
             timeout: backend.default_timeout(),
             extra_args: vec![],
+            seed_events: vec![],
         })
     }
 
@@ -143,6 +156,7 @@ event_loop:
             passed: all_passed,
             assertions,
             duration,
+            skip_reason: None,
         })
     }
 }
@@ -238,6 +252,7 @@ mod tests {
             iterations: 1,
             termination_reason: Some("PONG".to_string()),
             timed_out: false,
+            denied_commands: vec![],
         }
     }
 
@@ -261,6 +276,7 @@ mod tests {
         assert!(supported.contains(&Backend::Claude));
         assert!(supported.contains(&Backend::Kiro));
         assert!(supported.contains(&Backend::OpenCode));
+        assert!(supported.contains(&Backend::Custom));
     }
 
     #[test]
@@ -71,6 +71,10 @@ impl TestScenario for TimeoutScenario {
         &self.tier
     }
 
+    fn tags(&self) -> &[&str] {
+        &["errors", "chaos"]
+    }
+
     fn supported_backends(&self) -> Vec<Backend> {
         vec![Backend::Claude, Backend::Kiro, Backend::OpenCode]
     }
@@ -116,6 +120,7 @@ Start the essay now.";
             // Very short timeout to trigger timeout condition
             timeout: Duration::from_secs(5),
             extra_args: vec![],
+            seed_events: vec![],
         })
     }
 
@@ -151,6 +156,7 @@ Start the essay now.";
             passed: all_passed,
             assertions,
             duration,
+            skip_reason: None,
         })
     }
 }
@@ -263,6 +269,10 @@ impl TestScenario for MaxIterationsScenario {
         &self.tier
     }
 
+    fn tags(&self) -> &[&str] {
+        &["errors", "orchestration"]
+    }
+
     fn supported_backends(&self) -> Vec<Backend> {
         vec![Backend::Claude, Backend::Kiro, Backend::OpenCode]
     }
@@ -308,6 +318,7 @@ Complete exactly one step per iteration. Stop after each step."#;
             max_iterations: 2,
             timeout: backend.default_timeout(),
             extra_args: vec![],
+            seed_events: vec![],
         })
     }
 
@@ -343,6 +354,7 @@ Complete exactly one step per iteration. Stop after each step."#;
             passed: all_passed,
             assertions,
             duration,
+            skip_reason: None,
         })
     }
 }
@@ -442,6 +454,10 @@ impl TestScenario for AuthFailureScenario {
         &self.tier
     }
 
+    fn tags(&self) -> &[&str] {
+        &["errors", "chaos"]
+    }
+
     fn supported_backends(&self) -> Vec<Backend> {
         vec![Backend::Claude, Backend::Kiro, Backend::OpenCode]
     }
@@ -478,6 +494,7 @@ event_loop:
             timeout: Duration::from_secs(30),
             // Pass invalid API key to trigger auth failure
             extra_args: vec!["--api-key".to_string(), "invalid-key-12345".to_string()],
+            seed_events: vec![],
         })
     }
 
@@ -512,6 +529,7 @@ event_loop:
             passed: all_passed,
             assertions,
             duration,
+            skip_reason: None,
         })
     }
 }
@@ -635,6 +653,10 @@ impl TestScenario for BackendUnavailableScenario {
         &self.tier
     }
 
+    fn tags(&self) -> &[&str] {
+        &["errors", "chaos"]
+    }
+
     fn supported_backends(&self) -> Vec<Backend> {
         vec![Backend::Claude, Backend::Kiro, Backend::OpenCode]
     }
@@ -671,6 +693,7 @@ event_loop:
             max_iterations: 1,
             timeout: Duration::from_secs(30),
             extra_args: vec![],
+            seed_events: vec![],
         })
     }
 
@@ -705,6 +728,7 @@ event_loop:
             passed: all_passed,
             assertions,
             duration,
+            skip_reason: None,
         })
     }
 }
@@ -838,6 +862,7 @@ mod tests {
             iterations: 1,
             termination_reason: None,
             timed_out: true,
+            denied_commands: vec![],
         }
     }
 
@@ -852,6 +877,7 @@ mod tests {
             iterations: 2,
             termination_reason: Some("MAX_ITERATIONS".to_string()),
             timed_out: false,
+            denied_commands: vec![],
         }
     }
 
@@ -866,6 +892,7 @@ mod tests {
             iterations: 0,
             termination_reason: None,
             timed_out: false,
+            denied_commands: vec![],
         }
     }
 
@@ -881,6 +908,7 @@ mod tests {
             iterations: 0,
             termination_reason: None,
             timed_out: false,
+            denied_commands: vec![],
         }
     }
 
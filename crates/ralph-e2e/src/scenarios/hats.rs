@@ -89,6 +89,10 @@ impl TestScenario for HatSingleScenario {
         &self.tier
     }
 
+    fn tags(&self) -> &[&str] {
+        &["hats"]
+    }
+
     // Uses default supported_backends() which returns all backends
 
     fn setup(&self, workspace: &Path, backend: Backend) -> Result<ScenarioConfig, ScenarioError> {
@@ -157,6 +161,7 @@ After the builder completes, output LOOP_COMPLETE."#;
             max_iterations: 2,
             timeout: backend.default_timeout(),
             extra_args: vec![],
+            seed_events: vec![],
         })
     }
 
@@ -193,6 +198,7 @@ After the builder completes, output LOOP_COMPLETE."#;
             passed: all_passed,
             assertions,
             duration,
+            skip_reason: None,
         })
     }
 }
@@ -298,6 +304,10 @@ impl TestScenario for HatMultiWorkflowScenario {
         &self.tier
     }
 
+    fn tags(&self) -> &[&str] {
+        &["hats", "orchestration"]
+    }
+
     // Uses default supported_backends() which returns all backends
 
     fn setup(&self, workspace: &Path, backend: Backend) -> Result<ScenarioConfig, ScenarioError> {
@@ -380,6 +390,7 @@ IMPORTANT: You MUST output the XML event tags exactly as shown above in your res
             max_iterations: 6, // Extra buffer for multi-hat workflow
             timeout: backend.default_timeout(),
             extra_args: vec![],
+            seed_events: vec![],
         })
     }
 
@@ -415,6 +426,7 @@ IMPORTANT: You MUST output the XML event tags exactly as shown above in your res
             passed: all_passed,
             assertions,
             duration,
+            skip_reason: None,
         })
     }
 }
@@ -518,6 +530,10 @@ impl TestScenario for HatInstructionsScenario {
         &self.tier
     }
 
+    fn tags(&self) -> &[&str] {
+        &["hats"]
+    }
+
     // Uses default supported_backends() which returns all backends
 
     fn setup(&self, workspace: &Path, backend: Backend) -> Result<ScenarioConfig, ScenarioError> {
@@ -585,6 +601,7 @@ After the review is complete, output LOOP_COMPLETE."#;
             max_iterations: 2,
             timeout: backend.default_timeout(),
             extra_args: vec![],
+            seed_events: vec![],
         })
     }
 
@@ -620,6 +637,7 @@ After the review is complete, output LOOP_COMPLETE."#;
             passed: all_passed,
             assertions,
             duration,
+            skip_reason: None,
         })
     }
 }
@@ -739,6 +757,10 @@ impl TestScenario for HatEventRoutingScenario {
         &self.tier
     }
 
+    fn tags(&self) -> &[&str] {
+        &["hats", "events"]
+    }
+
     // Uses default supported_backends() which returns all backends
 
     fn setup(&self, workspace: &Path, backend: Backend) -> Result<ScenarioConfig, ScenarioError> {
@@ -815,6 +837,7 @@ After tests complete, output LOOP_COMPLETE."#;
             max_iterations: 3,
             timeout: backend.default_timeout(),
             extra_args: vec![],
+            seed_events: vec![],
         })
     }
 
@@ -850,6 +873,7 @@ After tests complete, output LOOP_COMPLETE."#;
             passed: all_passed,
             assertions,
             duration,
+            skip_reason: None,
         })
     }
 }
@@ -951,6 +975,10 @@ impl TestScenario for HatBackendOverrideScenario {
         &self.tier
     }
 
+    fn tags(&self) -> &[&str] {
+        &["hats", "backends"]
+    }
+
     // Uses default supported_backends() which returns all backends
 
     fn setup(&self, workspace: &Path, backend: Backend) -> Result<ScenarioConfig, ScenarioError> {
@@ -1014,6 +1042,7 @@ After completion, output LOOP_COMPLETE."#;
             max_iterations: 2,
             timeout: backend.default_timeout(),
             extra_args: vec![],
+            seed_events: vec![],
         })
     }
 
@@ -1049,6 +1078,7 @@ After completion, output LOOP_COMPLETE."#;
             passed: all_passed,
             assertions,
             duration,
+            skip_reason: None,
         })
     }
 }
@@ -1167,6 +1197,7 @@ mod tests {
             iterations: 2,
             termination_reason: Some("LOOP_COMPLETE".to_string()),
             timed_out: false,
+            denied_commands: vec![],
         }
     }
 
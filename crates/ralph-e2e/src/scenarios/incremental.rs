@@ -96,6 +96,10 @@ impl TestScenario for IncrementalFeatureScenario {
         &self.tier
     }
 
+    fn tags(&self) -> &[&str] {
+        &["incremental", "orchestration"]
+    }
+
     fn supported_backends(&self) -> Vec<Backend> {
         vec![Backend::Claude, Backend::Kiro, Backend::OpenCode]
     }
@@ -179,6 +183,7 @@ Begin with Phase 1."#;
             max_iterations: 10,
             timeout: backend.default_timeout() * 3, // Longer timeout for multi-phase
             extra_args: vec![],
+            seed_events: vec![],
         })
     }
 
@@ -248,6 +253,7 @@ Begin with Phase 1."#;
             passed: all_passed,
             assertions,
             duration,
+            skip_reason: None,
         })
     }
 }
@@ -453,6 +459,10 @@ impl TestScenario for ChainedLoopScenario {
         &self.tier
     }
 
+    fn tags(&self) -> &[&str] {
+        &["incremental", "orchestration"]
+    }
+
     fn supported_backends(&self) -> Vec<Backend> {
         vec![Backend::Claude, Backend::Kiro, Backend::OpenCode]
     }
@@ -499,6 +509,7 @@ memories:
             max_iterations: 3,
             timeout: backend.default_timeout(),
             extra_args: vec![],
+            seed_events: vec![],
         })
     }
 
@@ -527,6 +538,7 @@ IMPORTANT: Use Bash for ralph commands and Write/Edit for files."#;
             max_iterations: 3,
             timeout: std::time::Duration::from_mins(2),
             extra_args: vec![],
+            seed_events: vec![],
         };
 
         let _phase1_result = executor
@@ -553,6 +565,7 @@ IMPORTANT: Use Bash for ralph commands and Write/Edit for files."#;
             max_iterations: 3,
             timeout: std::time::Duration::from_mins(2),
             extra_args: vec![],
+            seed_events: vec![],
         };
 
         let _phase2_result = executor
@@ -580,6 +593,7 @@ IMPORTANT: Use Bash for ralph commands and Write/Edit for files."#;
             max_iterations: 3,
             timeout: std::time::Duration::from_mins(2),
             extra_args: vec![],
+            seed_events: vec![],
         };
 
         let phase3_result = executor
@@ -617,6 +631,7 @@ IMPORTANT: Use Bash for ralph commands and Write/Edit for files."#;
             passed: all_passed,
             assertions,
             duration,
+            skip_reason: None,
         })
     }
 }
@@ -87,6 +87,10 @@ impl TestScenario for MemoryAddScenario {
         &self.tier
     }
 
+    fn tags(&self) -> &[&str] {
+        &["memory"]
+    }
+
     fn supported_backends(&self) -> Vec<Backend> {
         vec![Backend::Claude, Backend::Kiro, Backend::OpenCode]
     }
@@ -143,6 +147,7 @@ IMPORTANT: You MUST actually execute the command using the Bash tool, not just d
             max_iterations: 1,
             timeout: backend.default_timeout(),
             extra_args: vec![],
+            seed_events: vec![],
         })
     }
 
@@ -188,6 +193,7 @@ IMPORTANT: You MUST actually execute the command using the Bash tool, not just d
             passed: all_passed,
             assertions,
             duration,
+            skip_reason: None,
         })
     }
 }
@@ -308,6 +314,10 @@ impl TestScenario for MemorySearchScenario {
         &self.tier
     }
 
+    fn tags(&self) -> &[&str] {
+        &["memory"]
+    }
+
     fn supported_backends(&self) -> Vec<Backend> {
         vec![Backend::Claude, Backend::Kiro, Backend::OpenCode]
     }
@@ -383,6 +393,7 @@ Output LOOP_COMPLETE when done."#;
             max_iterations: 1,
             timeout: backend.default_timeout(),
             extra_args: vec![],
+            seed_events: vec![],
         })
     }
 
@@ -418,6 +429,7 @@ Output LOOP_COMPLETE when done."#;
             passed: all_passed,
             assertions,
             duration,
+            skip_reason: None,
         })
     }
 }
@@ -528,6 +540,10 @@ impl TestScenario for MemoryInjectionScenario {
         &self.tier
     }
 
+    fn tags(&self) -> &[&str] {
+        &["memory"]
+    }
+
     fn supported_backends(&self) -> Vec<Backend> {
         vec![Backend::Claude, Backend::Kiro, Backend::OpenCode]
     }
@@ -603,6 +619,7 @@ Then output LOOP_COMPLETE."#;
             max_iterations: 1,
             timeout: backend.default_timeout(),
             extra_args: vec![],
+            seed_events: vec![],
         })
     }
 
@@ -638,6 +655,7 @@ Then output LOOP_COMPLETE."#;
             passed: all_passed,
             assertions,
             duration,
+            skip_reason: None,
         })
     }
 }
@@ -752,6 +770,10 @@ impl TestScenario for MemoryPersistenceScenario {
         &self.tier
     }
 
+    fn tags(&self) -> &[&str] {
+        &["memory"]
+    }
+
     fn supported_backends(&self) -> Vec<Backend> {
         vec![Backend::Claude, Backend::Kiro, Backend::OpenCode]
     }
@@ -805,6 +827,7 @@ IMPORTANT: You MUST actually execute the command using the Bash tool."#;
             max_iterations: 2,
             timeout: backend.default_timeout(),
             extra_args: vec![],
+            seed_events: vec![],
         })
     }
 
@@ -850,6 +873,7 @@ IMPORTANT: You MUST actually execute the command using the Bash tool."#;
             passed: all_passed,
             assertions,
             duration,
+            skip_reason: None,
         })
     }
 }
@@ -976,6 +1000,10 @@ impl TestScenario for MemoryCorruptedFileScenario {
         &self.tier
     }
 
+    fn tags(&self) -> &[&str] {
+        &["memory", "chaos"]
+    }
+
     fn supported_backends(&self) -> Vec<Backend> {
         vec![Backend::Claude, Backend::Kiro, Backend::OpenCode]
     }
@@ -1061,6 +1089,7 @@ IMPORTANT: Use the Bash tool to execute the command."#;
             max_iterations: 1,
             timeout: backend.default_timeout(),
             extra_args: vec![],
+            seed_events: vec![],
         })
     }
 
@@ -1100,6 +1129,7 @@ IMPORTANT: Use the Bash tool to execute the command."#;
             passed: all_passed,
             assertions,
             duration,
+            skip_reason: None,
         })
     }
 }
@@ -1196,6 +1226,10 @@ impl TestScenario for MemoryMissingFileScenario {
         &self.tier
     }
 
+    fn tags(&self) -> &[&str] {
+        &["memory", "chaos"]
+    }
+
     fn supported_backends(&self) -> Vec<Backend> {
         vec![Backend::Claude, Backend::Kiro, Backend::OpenCode]
     }
@@ -1245,6 +1279,7 @@ IMPORTANT: Use the Bash tool to execute the command."#;
             max_iterations: 1,
             timeout: backend.default_timeout(),
             extra_args: vec![],
+            seed_events: vec![],
         })
     }
 
@@ -1290,6 +1325,7 @@ IMPORTANT: Use the Bash tool to execute the command."#;
             passed: all_passed,
             assertions,
             duration,
+            skip_reason: None,
         })
     }
 }
@@ -1394,6 +1430,10 @@ impl TestScenario for MemoryRapidWriteScenario {
         &self.tier
     }
 
+    fn tags(&self) -> &[&str] {
+        &["memory", "chaos"]
+    }
+
     fn supported_backends(&self) -> Vec<Backend> {
         vec![Backend::Claude, Backend::Kiro, Backend::OpenCode]
     }
@@ -1443,6 +1483,7 @@ IMPORTANT: Use the Bash tool to execute each command."#;
             max_iterations: 1,
             timeout: backend.default_timeout(),
             extra_args: vec![],
+            seed_events: vec![],
         })
     }
 
@@ -1482,6 +1523,7 @@ IMPORTANT: Use the Bash tool to execute each command."#;
             passed: all_passed,
             assertions,
             duration,
+            skip_reason: None,
         })
     }
 }
@@ -1583,6 +1625,10 @@ impl TestScenario for MemoryLargeContentScenario {
         &self.tier
     }
 
+    fn tags(&self) -> &[&str] {
+        &["memory", "chaos"]
+    }
+
     fn supported_backends(&self) -> Vec<Backend> {
         vec![Backend::Claude, Backend::Kiro, Backend::OpenCode]
     }
@@ -1631,6 +1677,7 @@ IMPORTANT: Use the Bash tool to execute the command."#;
             max_iterations: 1,
             timeout: backend.default_timeout(),
             extra_args: vec![],
+            seed_events: vec![],
         })
     }
 
@@ -1670,6 +1717,7 @@ IMPORTANT: Use the Bash tool to execute the command."#;
             passed: all_passed,
             assertions,
             duration,
+            skip_reason: None,
         })
     }
 }
@@ -1752,6 +1800,7 @@ mod tests {
             iterations: 1,
             termination_reason: Some("LOOP_COMPLETE".to_string()),
             timed_out: false,
+            denied_commands: vec![],
         }
     }
 
@@ -56,7 +56,7 @@ pub use connectivity::ConnectivityScenario;
 pub use errors::{
     AuthFailureScenario, BackendUnavailableScenario, MaxIterationsScenario, TimeoutScenario,
 };
-pub use events::{BackpressureScenario, EventsScenario};
+pub use events::{BackpressureScenario, ContinueSeededEventsScenario, EventsScenario};
 pub use hats::{
     HatBackendOverrideScenario, HatEventRoutingScenario, HatInstructionsScenario,
     HatMultiWorkflowScenario, HatSingleScenario,
@@ -114,6 +114,13 @@ pub trait TestScenario: Send + Sync {
     /// The tier this scenario belongs to (e.g., "Tier 1: Connectivity").
     fn tier(&self) -> &str;
 
+    /// Capability tags for selecting scenarios with `--tag` (e.g., "memory", "chaos").
+    ///
+    /// Default implementation returns no tags.
+    fn tags(&self) -> &[&str] {
+        &[]
+    }
+
     /// Returns the list of backends this scenario supports.
     ///
     /// Default implementation returns all backends. Override this to restrict
@@ -122,6 +129,15 @@ pub trait TestScenario: Send + Sync {
         vec![Backend::Claude, Backend::Kiro, Backend::OpenCode]
     }
 
+    /// Backends this scenario is known not to work with.
+    ///
+    /// Lets a scenario keep the default `supported_backends()` while opting
+    /// out of one backend. The runner records these as skipped, with a
+    /// reason, instead of running them. Default implementation returns none.
+    fn unsupported_backends(&self) -> &[Backend] {
+        &[]
+    }
+
     /// Sets up the scenario by creating necessary files in the workspace.
     ///
     /// The `backend` parameter specifies which backend to configure for.
@@ -288,6 +304,23 @@ impl Assertions {
             .with_passed(found)
     }
 
+    /// Asserts that the mock CLI denied a command starting with `prefix`.
+    pub fn command_denied(result: &ExecutionResult, prefix: &str) -> Assertion {
+        let denied = result
+            .denied_commands
+            .iter()
+            .any(|command| command.starts_with(prefix));
+        AssertionBuilder::new(format!("Command '{}' denied", truncate(prefix, 30)))
+            .expected(format!("Denied command starting with '{}'", prefix))
+            .actual(if denied {
+                "Command was denied".to_string()
+            } else {
+                format!("Not denied. Denied commands: {:?}", result.denied_commands)
+            })
+            .build()
+            .with_passed(denied)
+    }
+
     /// Asserts that the execution completed within the expected iteration count.
     pub fn iterations_within(result: &ExecutionResult, max: u32) -> Assertion {
         let within = result.iterations <= max;
@@ -400,6 +433,7 @@ mod tests {
             iterations: 1,
             termination_reason: Some("LOOP_COMPLETE".to_string()),
             timed_out: false,
+            denied_commands: vec![],
         }
     }
 
@@ -510,6 +544,16 @@ mod tests {
         assert!(!assertion.passed);
     }
 
+    #[test]
+    fn test_command_denied() {
+        let mut result = mock_execution_result();
+        assert!(!Assertions::command_denied(&result, "rm -rf").passed);
+
+        result.denied_commands = vec!["rm -rf /tmp/workspace".to_string()];
+        assert!(Assertions::command_denied(&result, "rm -rf").passed);
+        assert!(!Assertions::command_denied(&result, "curl").passed);
+    }
+
     #[test]
     fn test_iterations_within_passed() {
         let result = mock_execution_result();
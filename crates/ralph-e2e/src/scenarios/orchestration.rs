@@ -74,6 +74,10 @@ impl TestScenario for SingleIterScenario {
         &self.tier
     }
 
+    fn tags(&self) -> &[&str] {
+        &["orchestration"]
+    }
+
     fn supported_backends(&self) -> Vec<Backend> {
         vec![Backend::Claude, Backend::Kiro, Backend::OpenCode]
     }
@@ -115,6 +119,7 @@ Output LOOP_COMPLETE when the work is complete.";
             max_iterations: 1,
             timeout: backend.default_timeout(),
             extra_args: vec![],
+            seed_events: vec![],
         })
     }
 
@@ -154,6 +159,7 @@ Output LOOP_COMPLETE when the work is complete.";
             passed: all_passed,
             assertions,
             duration,
+            skip_reason: None,
         })
     }
 }
@@ -234,6 +240,10 @@ impl TestScenario for MultiIterScenario {
         &self.tier
     }
 
+    fn tags(&self) -> &[&str] {
+        &["orchestration"]
+    }
+
     fn supported_backends(&self) -> Vec<Backend> {
         vec![Backend::Claude, Backend::Kiro, Backend::OpenCode]
     }
@@ -286,6 +296,7 @@ After all events are emitted, output LOOP_COMPLETE on its own line."#;
             max_iterations: 3,
             timeout: backend.default_timeout(),
             extra_args: vec![],
+            seed_events: vec![],
         })
     }
 
@@ -324,6 +335,7 @@ After all events are emitted, output LOOP_COMPLETE on its own line."#;
             passed: all_passed,
             assertions,
             duration,
+            skip_reason: None,
         })
     }
 }
@@ -414,6 +426,10 @@ impl TestScenario for CompletionScenario {
         &self.tier
     }
 
+    fn tags(&self) -> &[&str] {
+        &["orchestration"]
+    }
+
     fn supported_backends(&self) -> Vec<Backend> {
         vec![Backend::Claude, Backend::Kiro, Backend::OpenCode]
     }
@@ -463,6 +479,7 @@ Keep outputting LOOP_COMPLETE until the orchestration ends.";
             max_iterations: 5,
             timeout: backend.default_timeout(),
             extra_args: vec![],
+            seed_events: vec![],
         })
     }
 
@@ -501,6 +518,7 @@ Keep outputting LOOP_COMPLETE until the orchestration ends.";
             passed: all_passed,
             assertions,
             duration,
+            skip_reason: None,
         })
     }
 }
@@ -582,6 +600,7 @@ mod tests {
             iterations: 1,
             termination_reason: Some("LOOP_COMPLETE".to_string()),
             timed_out: false,
+            denied_commands: vec![],
         }
     }
 
@@ -88,6 +88,10 @@ impl TestScenario for TaskAddScenario {
         &self.tier
     }
 
+    fn tags(&self) -> &[&str] {
+        &["tasks"]
+    }
+
     // Uses default supported_backends() which returns all backends
 
     fn setup(&self, workspace: &Path, backend: Backend) -> Result<ScenarioConfig, ScenarioError> {
@@ -139,6 +143,7 @@ IMPORTANT: You MUST actually execute the command using the Bash tool, not just d
             max_iterations: 1,
             timeout: backend.default_timeout(),
             extra_args: vec![],
+            seed_events: vec![],
         })
     }
 
@@ -184,6 +189,7 @@ IMPORTANT: You MUST actually execute the command using the Bash tool, not just d
             passed: all_passed,
             assertions,
             duration,
+            skip_reason: None,
         })
     }
 }
@@ -294,6 +300,10 @@ impl TestScenario for TaskCloseScenario {
         &self.tier
     }
 
+    fn tags(&self) -> &[&str] {
+        &["tasks"]
+    }
+
     // Uses default supported_backends() which returns all backends
 
     fn setup(&self, workspace: &Path, backend: Backend) -> Result<ScenarioConfig, ScenarioError> {
@@ -358,6 +368,7 @@ IMPORTANT: Execute each command using the Bash tool."#;
             max_iterations: 1,
             timeout: backend.default_timeout(),
             extra_args: vec![],
+            seed_events: vec![],
         })
     }
 
@@ -402,6 +413,7 @@ IMPORTANT: Execute each command using the Bash tool."#;
             passed: all_passed,
             assertions,
             duration,
+            skip_reason: None,
         })
     }
 }
@@ -511,6 +523,10 @@ impl TestScenario for TaskCompletionScenario {
         &self.tier
     }
 
+    fn tags(&self) -> &[&str] {
+        &["tasks"]
+    }
+
     // Uses default supported_backends() which returns all backends
 
     fn setup(&self, workspace: &Path, backend: Backend) -> Result<ScenarioConfig, ScenarioError> {
@@ -549,6 +565,7 @@ memories:
             max_iterations: 5,
             timeout: backend.default_timeout(),
             extra_args: vec![],
+            seed_events: vec![],
         })
     }
 
@@ -584,6 +601,7 @@ memories:
             passed: all_passed,
             assertions,
             duration,
+            skip_reason: None,
         })
     }
 }
@@ -678,6 +696,10 @@ impl TestScenario for TaskReadyScenario {
         &self.tier
     }
 
+    fn tags(&self) -> &[&str] {
+        &["tasks"]
+    }
+
     // Uses default supported_backends() which returns all backends
 
     fn setup(&self, workspace: &Path, backend: Backend) -> Result<ScenarioConfig, ScenarioError> {
@@ -736,6 +758,7 @@ Execute each command using the Bash tool."#;
             max_iterations: 1,
             timeout: backend.default_timeout(),
             extra_args: vec![],
+            seed_events: vec![],
         })
     }
 
@@ -779,6 +802,7 @@ Execute each command using the Bash tool."#;
             passed: all_passed,
             assertions,
             duration,
+            skip_reason: None,
         })
     }
 }
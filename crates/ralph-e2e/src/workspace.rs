@@ -7,6 +7,10 @@
 use std::fs;
 use std::io;
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Process-wide counter for unique workspace suffixes.
+static WORKSPACE_COUNTER: AtomicU64 = AtomicU64::new(0);
 
 /// Manages isolated test workspaces for E2E tests.
 ///
@@ -67,6 +71,37 @@ impl WorkspaceManager {
         Ok(workspace_path)
     }
 
+    /// Creates a workspace whose name no other caller can be handed.
+    ///
+    /// The directory is named `{scenario_id}-{pid}-{n}`, where `n` comes from
+    /// a process-wide counter, and is created with an exclusive `create_dir`
+    /// that fails if the name already exists (the next `n` is then tried). So
+    /// concurrent scenarios in one process and overlapping harness
+    /// invocations never share a workspace, even for the same scenario ID.
+    ///
+    /// Returns the path to the created workspace directory; its file name is
+    /// what [`WorkspaceManager::cleanup`] expects.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory cannot be created.
+    pub fn create_unique_workspace(&self, scenario_id: &str) -> io::Result<PathBuf> {
+        fs::create_dir_all(&self.base_path)?;
+        let pid = std::process::id();
+        loop {
+            let n = WORKSPACE_COUNTER.fetch_add(1, Ordering::Relaxed);
+            let workspace_path = self.base_path.join(format!("{scenario_id}-{pid}-{n}"));
+            match fs::create_dir(&workspace_path) {
+                Ok(()) => {
+                    fs::create_dir_all(workspace_path.join(".agent"))?;
+                    return Ok(workspace_path);
+                }
+                Err(e) if e.kind() == io::ErrorKind::AlreadyExists => {}
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
     /// Cleans up a specific workspace.
     ///
     /// Removes the entire workspace directory for the given scenario.
@@ -254,6 +289,59 @@ mod tests {
         ws.cleanup_all().unwrap();
     }
 
+    #[test]
+    fn test_unique_workspaces_are_collision_free_under_concurrency() {
+        let base = test_base_path("unique-stress");
+        let ws = WorkspaceManager::new(&base);
+
+        let handles: Vec<_> = (0..16)
+            .map(|_| {
+                let ws = ws.clone();
+                std::thread::spawn(move || {
+                    (0..25)
+                        .map(|_| ws.create_unique_workspace("same-scenario").unwrap())
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+        let paths: Vec<PathBuf> = handles
+            .into_iter()
+            .flat_map(|h| h.join().unwrap())
+            .collect();
+
+        let unique: std::collections::HashSet<_> = paths.iter().collect();
+        assert_eq!(unique.len(), 16 * 25);
+        assert!(paths.iter().all(|p| p.join(".agent").is_dir()));
+        assert_eq!(ws.list_workspaces().unwrap().len(), 16 * 25);
+
+        // Names are usable with cleanup()
+        let name = paths[0].file_name().unwrap().to_str().unwrap();
+        assert!(name.starts_with("same-scenario-"));
+        ws.cleanup(name).unwrap();
+        assert!(!paths[0].exists());
+
+        ws.cleanup_all().unwrap();
+    }
+
+    #[test]
+    fn test_unique_workspace_skips_existing_names() {
+        let base = test_base_path("unique-existing");
+        let ws = WorkspaceManager::new(&base);
+
+        // Simulate an overlapping invocation that already claimed the next name
+        let next = WORKSPACE_COUNTER.load(Ordering::Relaxed);
+        for n in next..next + 3 {
+            let taken = base.join(format!("taken-{}-{n}", std::process::id()));
+            fs::create_dir_all(taken).unwrap();
+        }
+
+        let path = ws.create_unique_workspace("taken").unwrap();
+        assert!(path.join(".agent").is_dir());
+        assert_eq!(ws.list_workspaces().unwrap().len(), 4);
+
+        ws.cleanup_all().unwrap();
+    }
+
     #[test]
     fn test_base_path_accessor() {
         let base = PathBuf::from("/tmp/test-base");
@@ -73,6 +73,8 @@ impl EventBus {
 
     /// Publishes an event to all subscribed hats.
     ///
+    /// Hats matching via a specific pattern (`build.*`, `build.done`) receive the
+    /// event; global `*` subscribers only receive it when no such hat exists.
     /// Returns the list of hat IDs that received the event.
     /// If an observer is set, it receives the event before routing.
     #[allow(clippy::needless_pass_by_value)] // Event is cloned to multiple recipients
@@ -142,6 +144,19 @@ impl EventBus {
         self.pending.remove(hat_id).unwrap_or_default()
     }
 
+    /// Returns events taken with [`EventBus::take_pending`] to the front of a
+    /// hat's queue, ahead of anything published since.
+    ///
+    /// Observers are not notified; the events were already published once.
+    pub fn requeue(&mut self, hat_id: &HatId, mut events: Vec<Event>) {
+        if events.is_empty() {
+            return;
+        }
+        let queue = self.pending.entry(hat_id.clone()).or_default();
+        events.append(queue);
+        *queue = events;
+    }
+
     /// Takes all pending human interaction events.
     pub fn take_human_pending(&mut self) -> Vec<Event> {
         std::mem::take(&mut self.human_pending)
@@ -152,6 +167,20 @@ impl EventBus {
         self.pending.get(hat_id)
     }
 
+    /// Lists pending event topics per hat without consuming them.
+    ///
+    /// Hats with empty queues are omitted; order follows hat ID.
+    pub fn pending_summary(&self) -> Vec<(HatId, Vec<String>)> {
+        self.pending
+            .iter()
+            .filter(|(_, events)| !events.is_empty())
+            .map(|(id, events)| {
+                let topics = events.iter().map(|e| e.topic.to_string()).collect();
+                (id.clone(), topics)
+            })
+            .collect()
+    }
+
     /// Returns a reference to pending human interaction events without consuming them.
     pub fn peek_human_pending(&self) -> &[Event] {
         &self.human_pending
@@ -205,6 +234,20 @@ mod tests {
         assert_eq!(recipients[0].as_str(), "impl");
     }
 
+    #[test]
+    fn test_specific_subscription_beats_global_wildcard() {
+        let mut bus = EventBus::new();
+        bus.register(Hat::new("ralph", "Ralph").subscribe("*"));
+        bus.register(Hat::new("builder", "Builder").subscribe("build.*"));
+
+        let recipients = bus.publish(Event::new("build.done", "done"));
+        assert_eq!(recipients, vec![HatId::new("builder")]);
+
+        // Orphaned topics fall back to the wildcard hat
+        let recipients = bus.publish(Event::new("review.done", "done"));
+        assert_eq!(recipients, vec![HatId::new("ralph")]);
+    }
+
     #[test]
     fn test_no_match() {
         let mut bus = EventBus::new();
@@ -252,6 +295,25 @@ mod tests {
         assert!(bus.take_pending(&hat_id).is_empty());
     }
 
+    #[test]
+    fn test_requeue_restores_events_ahead_of_new_ones() {
+        let mut bus = EventBus::new();
+        bus.register(Hat::new("impl", "Implementer").subscribe("*"));
+        let hat_id = HatId::new("impl");
+
+        bus.publish(Event::new("task.start", "Start"));
+        let taken = bus.take_pending(&hat_id);
+        bus.publish(Event::new("task.continue", "Continue"));
+        bus.requeue(&hat_id, taken);
+
+        let topics: Vec<String> = bus
+            .take_pending(&hat_id)
+            .iter()
+            .map(|e| e.topic.as_str().to_string())
+            .collect();
+        assert_eq!(topics, vec!["task.start", "task.continue"]);
+    }
+
     #[test]
     fn test_human_events_use_separate_queue() {
         let mut bus = EventBus::new();
@@ -368,6 +430,33 @@ mod tests {
         assert_eq!(*count.lock().unwrap(), 1); // Still 1, observers cleared
     }
 
+    #[test]
+    fn test_pending_summary_lists_topics_per_hat() {
+        let mut bus = EventBus::new();
+        bus.register(Hat::new("builder", "Builder").subscribe("build.*"));
+        bus.register(Hat::new("reviewer", "Reviewer").subscribe("review.*"));
+        bus.register(Hat::new("idle", "Idle").subscribe("idle.*"));
+
+        bus.publish(Event::new("review.request", "Review"));
+        bus.publish(Event::new("build.task", "Build"));
+        bus.publish(Event::new("build.retry", "Retry"));
+
+        let summary = bus.pending_summary();
+        assert_eq!(
+            summary,
+            vec![
+                (
+                    HatId::new("builder"),
+                    vec!["build.task".to_string(), "build.retry".to_string()]
+                ),
+                (HatId::new("reviewer"), vec!["review.request".to_string()]),
+            ]
+        );
+
+        // Summarizing leaves the queues intact
+        assert_eq!(bus.take_pending(&HatId::new("builder")).len(), 2);
+    }
+
     #[test]
     fn test_peek_pending_does_not_consume() {
         let mut bus = EventBus::new();
@@ -30,6 +30,37 @@ impl Topic {
         self.0 == "*"
     }
 
+    /// Returns how specific this pattern is, for picking between overlapping matches.
+    ///
+    /// Counts the literal (non-`*`) segments, so for `build.done`:
+    /// `build.done` (2) beats `build.*` (1), which beats `*` (0).
+    pub fn specificity(&self) -> usize {
+        self.0.split('.').filter(|segment| *segment != "*").count()
+    }
+
+    /// Returns the first segment of the topic (`spec` for `spec.ready`).
+    pub fn prefix(&self) -> &str {
+        self.0.split('.').next().unwrap_or_default()
+    }
+
+    /// Maps the topic's prefix to a stable bucket in `0..buckets`.
+    ///
+    /// Uses FNV-1a rather than `std`'s hasher so the result is identical across
+    /// runs and Rust releases; display code uses it to give each custom topic
+    /// family a consistent color.
+    pub fn prefix_bucket(&self, buckets: usize) -> usize {
+        if buckets == 0 {
+            return 0;
+        }
+        let hash = self
+            .prefix()
+            .bytes()
+            .fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+                (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+            });
+        (hash % buckets as u64) as usize
+    }
+
     /// Checks if this topic pattern matches a given topic.
     ///
     /// Pattern rules:
@@ -132,6 +163,14 @@ mod tests {
         assert!(!pattern.matches(&Topic::new("impl.started")));
     }
 
+    #[test]
+    fn test_specificity_orders_exact_over_glob_over_wildcard() {
+        assert_eq!(Topic::new("build.done").specificity(), 2);
+        assert_eq!(Topic::new("build.*").specificity(), 1);
+        assert_eq!(Topic::new("*.done").specificity(), 1);
+        assert_eq!(Topic::new("*").specificity(), 0);
+    }
+
     #[test]
     fn test_global_wildcard() {
         let pattern = Topic::new("*");
@@ -144,4 +183,17 @@ mod tests {
         let pattern = Topic::new("impl.*");
         assert!(!pattern.matches(&Topic::new("impl.sub.done")));
     }
+
+    #[test]
+    fn test_prefix_bucket_is_stable_per_prefix() {
+        let spec = Topic::new("spec.ready");
+        assert_eq!(spec.prefix(), "spec");
+        assert_eq!(
+            spec.prefix_bucket(8),
+            Topic::new("spec.approved").prefix_bucket(8)
+        );
+        assert_eq!(Topic::new("deploy").prefix(), "deploy");
+        assert!(Topic::new("deploy.start").prefix_bucket(8) < 8);
+        assert_eq!(spec.prefix_bucket(0), 0);
+    }
 }
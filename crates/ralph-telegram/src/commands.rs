@@ -634,7 +634,7 @@ fn cmd_restart(workspace_root: &Path) -> String {
 ///
 /// Writes a signal file (`.ralph/stop-requested`) that the event loop
 /// checks at each iteration boundary. When detected, the loop terminates
-/// gracefully with `TerminationReason::Stopped`.
+/// gracefully with `TerminationReason::UserStop`.
 fn cmd_stop(workspace_root: &Path) -> String {
     let stop_path = workspace_root.join(".ralph/stop-requested");
 
@@ -8,7 +8,7 @@ use crate::input::{Action, map_key};
 use crate::rpc_writer::RpcWriter;
 use crate::state::TuiState;
 use crate::update_check;
-use crate::widgets::{content::ContentPane, footer, header, help};
+use crate::widgets::{content::ContentPane, events, footer, header, help};
 use anyhow::Result;
 use crossterm::{
     cursor::Show,
@@ -105,10 +105,19 @@ pub fn dispatch_action(action: Action, state: &mut TuiState, viewport_height: us
             // "Search: " prompt immediately; characters are captured by
             // `handle_search_input` while `search_mode` is active.
             state.search_state.search_mode = true;
+            state.search_state.topic_mode = false;
             state.search_state.query = Some(String::new());
             state.search_state.matches.clear();
             state.search_state.current_match = 0;
         }
+        Action::StartTopicSearch => {
+            // Same input flow as text search, but the query matches event
+            // topics and `n`/`N` jump between iterations instead of lines.
+            state.clear_search();
+            state.search_state.search_mode = true;
+            state.search_state.topic_mode = true;
+            state.search_state.query = Some(String::new());
+        }
         Action::SearchNext => {
             state.next_match();
         }
@@ -133,6 +142,9 @@ pub fn dispatch_action(action: Action, state: &mut TuiState, viewport_height: us
         Action::ExportAllIterations => {
             state.export_all_iterations_to_disk();
         }
+        Action::ToggleEventPanel => {
+            state.show_event_panel = !state.show_event_panel;
+        }
         Action::None => {}
     }
     false
@@ -179,12 +191,12 @@ fn handle_search_input(state: &mut TuiState, code: crossterm::event::KeyCode) ->
             // `search` preserves `search_mode`, so the user stays in input mode.
             let mut query = state.search_state.query.clone().unwrap_or_default();
             query.pop();
-            state.search(&query);
+            run_search(state, &query);
         }
         KeyCode::Char(c) => {
             let mut query = state.search_state.query.clone().unwrap_or_default();
             query.push(c);
-            state.search(&query);
+            run_search(state, &query);
         }
         _ => {}
     }
@@ -192,6 +204,15 @@ fn handle_search_input(state: &mut TuiState, code: crossterm::event::KeyCode) ->
     true
 }
 
+/// Re-runs the active search (content or topic) with an edited query.
+fn run_search(state: &mut TuiState, query: &str) {
+    if state.search_state.topic_mode {
+        state.search_topics(query);
+    } else {
+        state.search(query);
+    }
+}
+
 fn set_mouse_capture(enabled: bool) -> Result<()> {
     if enabled {
         execute!(io::stdout(), EnableMouseCapture)?;
@@ -458,11 +479,21 @@ impl<W: AsyncWrite + Unpin + Send + 'static> App<W> {
                         ])
                         .split(frame_area);
 
-                    let content_area = chunks[1];
-                    viewport_height = content_area.height as usize;
+                    viewport_height = chunks[1].height as usize;
 
                     let mut state = self.state.lock().unwrap();
 
+                    // Split off the event stream panel on the right when enabled
+                    let (content_area, event_panel_area) = if state.show_event_panel {
+                        let columns = Layout::default()
+                            .direction(Direction::Horizontal)
+                            .constraints([Constraint::Min(0), Constraint::Percentage(35)])
+                            .split(chunks[1]);
+                        (columns[0], Some(columns[1]))
+                    } else {
+                        (chunks[1], None)
+                    };
+
                     // Clear expired flash messages (e.g., guidance send confirmation)
                     state.clear_expired_guidance_flash();
                     state.clear_expired_export_flash();
@@ -502,6 +533,11 @@ impl<W: AsyncWrite + Unpin + Send + 'static> App<W> {
                             f.render_widget(content_widget, content_area);
                         }
 
+                        // Render event stream panel if enabled
+                        if let Some(area) = event_panel_area {
+                            f.render_widget(events::render(&state), area);
+                        }
+
                         // Render footer
                         f.render_widget(footer::render(&state), chunks[2]);
 
@@ -727,7 +763,10 @@ mod tests {
 
         dispatch_action(Action::StartSearch, &mut state, 10);
 
-        assert!(state.search_state.search_mode, "'/' must enter input mode");
+        assert!(
+            state.search_state.search_mode,
+            "text search must enter input mode"
+        );
         assert_eq!(
             state.search_state.query.as_deref(),
             Some(""),
@@ -735,6 +774,41 @@ mod tests {
         );
     }
 
+    #[test]
+    fn topic_search_jumps_between_iterations_that_published_topic() {
+        let mut state = TuiState::new();
+        for topic in ["build.task", "build.blocked", "build.task", "build.blocked"] {
+            state.start_new_iteration();
+            state.update(&ralph_proto::Event::new(topic, ""));
+        }
+        state.current_view = 0;
+        state.following_latest = false;
+
+        dispatch_action(Action::StartTopicSearch, &mut state, 10);
+        assert!(state.search_state.topic_mode);
+        for c in "blocked".chars() {
+            assert!(handle_search_input(&mut state, KeyCode::Char(c)));
+        }
+        handle_search_input(&mut state, KeyCode::Enter);
+
+        assert_eq!(state.search_state.topic_matches, vec![1, 3]);
+        assert_eq!(
+            state.current_view, 1,
+            "should jump to first matching iteration"
+        );
+
+        dispatch_action(Action::SearchNext, &mut state, 10);
+        assert_eq!(state.current_view, 3);
+        assert!(
+            state.following_latest,
+            "landing on latest resumes following"
+        );
+
+        dispatch_action(Action::SearchPrev, &mut state, 10);
+        assert_eq!(state.current_view, 1);
+        assert!(!state.following_latest);
+    }
+
     #[test]
     fn typed_chars_build_query_and_do_not_dispatch_actions() {
         let mut state = seed_search_state();
@@ -876,6 +950,18 @@ mod tests {
         assert!(!state.mouse_capture_enabled);
     }
 
+    #[test]
+    fn dispatch_action_toggle_event_panel_flips_state() {
+        let mut state = TuiState::new();
+        assert!(!state.show_event_panel);
+
+        dispatch_action(Action::ToggleEventPanel, &mut state, 10);
+        assert!(state.show_event_panel);
+
+        dispatch_action(Action::ToggleEventPanel, &mut state, 10);
+        assert!(!state.show_event_panel);
+    }
+
     #[test]
     fn dispatch_action_export_current_writes_current_iteration_file() {
         let temp_dir = tempfile::tempdir().unwrap();
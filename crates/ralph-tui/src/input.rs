@@ -26,8 +26,10 @@ pub enum Action {
     ScrollTop,
     /// Jump to bottom of content
     ScrollBottom,
-    /// Enter search mode
+    /// Enter search mode (match text in the current iteration)
     StartSearch,
+    /// Enter topic search mode (jump to iterations that published a topic)
+    StartTopicSearch,
     /// Jump to next search match
     SearchNext,
     /// Jump to previous search match
@@ -48,6 +50,8 @@ pub enum Action {
     ExportCurrentIteration,
     /// Export every iteration buffer to disk
     ExportAllIterations,
+    /// Toggle the event stream side panel
+    ToggleEventPanel,
     /// Key not mapped to any action
     None,
 }
@@ -62,12 +66,14 @@ pub enum Action {
 /// - `↑`/`k`: Scroll up
 /// - `g`: Scroll to top
 /// - `G`: Scroll to bottom
-/// - `/`: Start search
+/// - `/`: Start topic search
+/// - `f`: Start text search
 /// - `n`: Next search match
 /// - `N`: Previous search match
 /// - `m`: Toggle mouse mode
 /// - `e`: Export current iteration
 /// - `E`: Export all iterations
+/// - `p`: Toggle event stream panel
 /// - `?`: Show help
 /// - `Esc`: Dismiss help/cancel search
 pub fn map_key(key: KeyEvent) -> Action {
@@ -86,7 +92,8 @@ pub fn map_key(key: KeyEvent) -> Action {
         KeyCode::Char('G') => Action::ScrollBottom,
 
         // Search
-        KeyCode::Char('/') => Action::StartSearch,
+        KeyCode::Char('/') => Action::StartTopicSearch,
+        KeyCode::Char('f') => Action::StartSearch,
         KeyCode::Char('n') => Action::SearchNext,
         KeyCode::Char('N') => Action::SearchPrev,
 
@@ -104,6 +111,9 @@ pub fn map_key(key: KeyEvent) -> Action {
         KeyCode::Char('e') => Action::ExportCurrentIteration,
         KeyCode::Char('E') => Action::ExportAllIterations,
 
+        // Event panel
+        KeyCode::Char('p') => Action::ToggleEventPanel,
+
         // Help
         KeyCode::Char('?') => Action::ShowHelp,
         KeyCode::Esc => Action::DismissHelp,
@@ -167,11 +177,11 @@ mod tests {
         assert_eq!(map_key(key), Action::ScrollBottom);
     }
 
-    // AC8: / Start Search
+    // AC8: / Start Search (event topics)
     #[test]
-    fn slash_returns_start_search() {
+    fn slash_returns_start_topic_search() {
         let key = KeyEvent::new(KeyCode::Char('/'), KeyModifiers::NONE);
-        assert_eq!(map_key(key), Action::StartSearch);
+        assert_eq!(map_key(key), Action::StartTopicSearch);
     }
 
     // AC9: n Search Next
@@ -188,6 +198,12 @@ mod tests {
         assert_eq!(map_key(key), Action::SearchPrev);
     }
 
+    #[test]
+    fn f_returns_start_search() {
+        let key = KeyEvent::new(KeyCode::Char('f'), KeyModifiers::NONE);
+        assert_eq!(map_key(key), Action::StartSearch);
+    }
+
     // AC11: ? Show Help
     #[test]
     fn question_mark_returns_show_help() {
@@ -251,6 +267,12 @@ mod tests {
         assert_eq!(map_key(key), Action::ExportAllIterations);
     }
 
+    #[test]
+    fn p_returns_toggle_event_panel() {
+        let key = KeyEvent::new(KeyCode::Char('p'), KeyModifiers::NONE);
+        assert_eq!(map_key(key), Action::ToggleEventPanel);
+    }
+
     // AC20: Unknown Key Returns None
     #[test]
     fn unknown_key_returns_none() {
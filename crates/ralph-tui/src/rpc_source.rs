@@ -435,8 +435,15 @@ fn apply_rpc_event(event: &RpcEvent, state: &Arc<Mutex<TuiState>>, acc: &mut Tex
             s.last_event_at = Some(Instant::now());
         }
 
-        RpcEvent::OrchestrationEvent { topic, .. } => {
-            // Generic orchestration events - just update liveness
+        RpcEvent::OrchestrationEvent {
+            topic,
+            source,
+            target,
+            ..
+        } => {
+            // Feed topic search and the event stream panel
+            s.record_published_event(topic, source.as_deref(), target.as_deref());
+
             s.last_event = Some(topic.clone());
             s.last_event_at = Some(Instant::now());
         }
@@ -1158,4 +1165,89 @@ mod tests {
             "should NOT set subprocess_error when events were received"
         );
     }
+
+    fn orchestration_event(topic: &str, source: Option<&str>) -> RpcEvent {
+        RpcEvent::OrchestrationEvent {
+            topic: topic.to_string(),
+            payload: String::new(),
+            source: source.map(str::to_string),
+            target: None,
+        }
+    }
+
+    #[test]
+    fn test_topic_search_finds_iterations_from_orchestration_events() {
+        let state = make_state();
+        let mut acc = make_acc();
+
+        for (iteration, topic) in [
+            (1, "build.task"),
+            (2, "build.blocked"),
+            (3, "build.blocked"),
+        ] {
+            apply_rpc_event(
+                &RpcEvent::IterationStart {
+                    iteration,
+                    max_iterations: None,
+                    hat: "builder".to_string(),
+                    hat_display: "🔨Builder".to_string(),
+                    backend: "claude".to_string(),
+                    started_at: 0,
+                },
+                &state,
+                &mut acc,
+            );
+            apply_rpc_event(
+                &orchestration_event(topic, Some("builder")),
+                &state,
+                &mut acc,
+            );
+        }
+
+        let mut s = state.lock().unwrap();
+        s.current_view = 0;
+        s.following_latest = false;
+        s.search_topics("blocked");
+
+        assert_eq!(s.search_state.topic_matches, vec![1, 2]);
+        assert_eq!(s.current_view, 1, "should jump to first matching iteration");
+        s.next_match();
+        assert_eq!(s.current_view, 2);
+    }
+
+    #[test]
+    fn test_orchestration_event_fills_event_panel() {
+        let state = make_state();
+        let mut acc = make_acc();
+
+        apply_rpc_event(
+            &RpcEvent::OrchestrationEvent {
+                topic: "build.task".to_string(),
+                payload: "Implement".to_string(),
+                source: Some("planner".to_string()),
+                target: Some("builder".to_string()),
+            },
+            &state,
+            &mut acc,
+        );
+        apply_rpc_event(
+            &orchestration_event("build.done", Some("builder")),
+            &state,
+            &mut acc,
+        );
+
+        let s = state.lock().unwrap();
+        let entries: Vec<_> = s
+            .event_log
+            .iter()
+            .map(|e| (e.topic.as_str(), e.source.as_deref(), e.hat.as_deref()))
+            .collect();
+        assert_eq!(
+            entries,
+            vec![
+                ("build.task", Some("planner"), Some("builder")),
+                ("build.done", Some("builder"), None),
+            ]
+        );
+    }
 }
@@ -2,7 +2,7 @@
 
 use crate::export::{ExportScope, IterationExport};
 use ralph_proto::{Event, HatId};
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::path::{Path, PathBuf};
 use std::time::{Duration, Instant};
 
@@ -62,6 +62,26 @@ impl TaskCounts {
     }
 }
 
+// ============================================================================
+// EventLogEntry - One row of the live event stream panel
+// ============================================================================
+
+/// Maximum number of events retained for the event stream panel.
+pub const EVENT_LOG_CAPACITY: usize = 500;
+
+/// A published event as shown in the event stream panel.
+#[derive(Debug, Clone)]
+pub struct EventLogEntry {
+    /// Event topic (e.g., "build.done").
+    pub topic: String,
+    /// Hat that published the event, if any.
+    pub source: Option<String>,
+    /// Hat the event triggered, if known.
+    pub hat: Option<String>,
+    /// When the event was observed.
+    pub at: Instant,
+}
+
 // ============================================================================
 // SearchState - Search functionality for TUI content
 // ============================================================================
@@ -78,6 +98,10 @@ pub struct SearchState {
     pub current_match: usize,
     /// Whether search input mode is active (user is typing query).
     pub search_mode: bool,
+    /// Whether the query matches event topics instead of content text.
+    pub topic_mode: bool,
+    /// Iteration indices whose recorded event topics match the query (topic mode).
+    pub topic_matches: Vec<usize>,
 }
 
 impl SearchState {
@@ -92,6 +116,17 @@ impl SearchState {
         self.matches.clear();
         self.current_match = 0;
         self.search_mode = false;
+        self.topic_mode = false;
+        self.topic_matches.clear();
+    }
+
+    /// Returns the number of matches for the active search mode.
+    pub fn match_count(&self) -> usize {
+        if self.topic_mode {
+            self.topic_matches.len()
+        } else {
+            self.matches.len()
+        }
     }
 }
 
@@ -214,6 +249,11 @@ pub struct TuiState {
     pub last_event_at: Option<Instant>,
     /// Whether to show help overlay.
     pub show_help: bool,
+    /// Whether the event stream side panel is visible.
+    pub show_event_panel: bool,
+    /// Recent events (topic → triggered hat), oldest first, capped at
+    /// [`EVENT_LOG_CAPACITY`].
+    pub event_log: VecDeque<EventLogEntry>,
     /// Whether mouse capture is enabled for wheel scrolling.
     /// When false, the terminal keeps native drag-to-select behavior.
     pub mouse_capture_enabled: bool,
@@ -341,6 +381,8 @@ impl TuiState {
             last_event: None,
             last_event_at: None,
             show_help: false,
+            show_event_panel: false,
+            event_log: VecDeque::new(),
             mouse_capture_enabled: false,
             in_scroll_mode: false,
             search_query: String::new(),
@@ -462,6 +504,8 @@ impl TuiState {
                 let saved_guidance_next_queue = Arc::clone(&self.guidance_next_queue);
                 let saved_events_path = self.events_path.clone();
                 let saved_urgent_steer_path = self.urgent_steer_path.clone();
+                let saved_show_event_panel = self.show_event_panel;
+                let saved_event_log = std::mem::take(&mut self.event_log);
                 *self = Self::new();
                 self.hat_map = saved_hat_map;
                 self.loop_started = saved_loop_started; // Keep original timer
@@ -477,6 +521,8 @@ impl TuiState {
                 self.guidance_next_queue = saved_guidance_next_queue;
                 self.events_path = saved_events_path;
                 self.urgent_steer_path = saved_urgent_steer_path;
+                self.show_event_panel = saved_show_event_panel;
+                self.event_log = saved_event_log;
                 if let Some((hat_id, hat_display)) = custom_hat {
                     self.pending_hat = Some((hat_id, hat_display));
                 } else {
@@ -528,6 +574,52 @@ impl TuiState {
                 // Unknown topic - don't change pending_hat
             }
         }
+
+        self.record_published_event(
+            topic,
+            event.source.as_ref().map(HatId::as_str),
+            event.target.as_ref().map(HatId::as_str),
+        );
+    }
+
+    /// Records a published event for topic search and the event stream panel.
+    ///
+    /// The topic is attributed to the latest iteration. The triggered hat is
+    /// the event's explicit target, otherwise the hat subscribed to the topic
+    /// in the hat map. The panel evicts its oldest entry when full.
+    pub fn record_published_event(
+        &mut self,
+        topic: &str,
+        source: Option<&str>,
+        target: Option<&str>,
+    ) {
+        if let Some(buffer) = self.iterations.last_mut() {
+            buffer.topics.push(topic.to_string());
+        }
+
+        let hat = match target {
+            Some(target) => Some(self.hat_display_for_id(target)),
+            None => self.hat_map.get(topic).map(|(_, display)| display.clone()),
+        };
+        let source = source.map(|source| self.hat_display_for_id(source));
+
+        if self.event_log.len() >= EVENT_LOG_CAPACITY {
+            self.event_log.pop_front();
+        }
+        self.event_log.push_back(EventLogEntry {
+            topic: topic.to_string(),
+            source,
+            hat,
+            at: Instant::now(),
+        });
+    }
+
+    /// Display name for a hat id from the hat map, or the id itself.
+    fn hat_display_for_id(&self, hat_id: &str) -> String {
+        self.hat_map
+            .values()
+            .find(|(id, _)| id.as_str() == hat_id)
+            .map_or_else(|| hat_id.to_string(), |(_, display)| display.clone())
     }
 
     /// Returns formatted hat display (emoji + name).
@@ -777,6 +869,7 @@ impl TuiState {
         self.search_state.query = Some(query.to_string());
         self.search_state.matches.clear();
         self.search_state.current_match = 0;
+        self.search_state.topic_mode = false;
 
         // Check if we have an iteration to search
         if self.iterations.get(self.current_view).is_none() {
@@ -817,25 +910,57 @@ impl TuiState {
         }
     }
 
+    /// Searches recorded event topics across all iterations.
+    /// Populates topic matches with the indices of iterations that published a
+    /// matching topic and jumps to the first one. Search is case-insensitive.
+    pub fn search_topics(&mut self, query: &str) {
+        self.search_state.query = Some(query.to_string());
+        self.search_state.matches.clear();
+        self.search_state.current_match = 0;
+        self.search_state.topic_mode = true;
+
+        let query_lower = query.to_lowercase();
+        self.search_state.topic_matches = if query_lower.is_empty() {
+            Vec::new()
+        } else {
+            self.iterations
+                .iter()
+                .enumerate()
+                .filter(|(_, buffer)| {
+                    buffer
+                        .topics
+                        .iter()
+                        .any(|topic| topic.to_lowercase().contains(&query_lower))
+                })
+                .map(|(idx, _)| idx)
+                .collect()
+        };
+
+        if !self.search_state.topic_matches.is_empty() {
+            self.jump_to_current_match();
+        }
+    }
+
     /// Navigates to the next match, cycling back to the first if at the end.
     pub fn next_match(&mut self) {
-        if self.search_state.matches.is_empty() {
+        let count = self.search_state.match_count();
+        if count == 0 {
             return;
         }
 
-        self.search_state.current_match =
-            (self.search_state.current_match + 1) % self.search_state.matches.len();
+        self.search_state.current_match = (self.search_state.current_match + 1) % count;
         self.jump_to_current_match();
     }
 
     /// Navigates to the previous match, cycling to the last if at the beginning.
     pub fn prev_match(&mut self) {
-        if self.search_state.matches.is_empty() {
+        let count = self.search_state.match_count();
+        if count == 0 {
             return;
         }
 
         if self.search_state.current_match == 0 {
-            self.search_state.current_match = self.search_state.matches.len() - 1;
+            self.search_state.current_match = count - 1;
         } else {
             self.search_state.current_match -= 1;
         }
@@ -849,6 +974,21 @@ impl TuiState {
 
     /// Jumps to the current match by adjusting scroll_offset to show the match line.
     fn jump_to_current_match(&mut self) {
+        if self.search_state.topic_mode {
+            if let Some(&idx) = self
+                .search_state
+                .topic_matches
+                .get(self.search_state.current_match)
+            {
+                self.current_view = idx;
+                self.following_latest = idx + 1 == self.iterations.len();
+                if self.following_latest {
+                    self.new_iteration_alert = None;
+                }
+            }
+            return;
+        }
+
         if self.search_state.matches.is_empty() {
             return;
         }
@@ -1231,6 +1371,8 @@ pub struct IterationBuffer {
     pub elapsed: Option<Duration>,
     /// Wave data associated with this iteration (stored on wave completion).
     pub wave_info: Option<WaveInfo>,
+    /// Event topics observed while this iteration was the latest one.
+    pub topics: Vec<String>,
 }
 
 impl IterationBuffer {
@@ -1246,6 +1388,7 @@ impl IterationBuffer {
             started_at: None,
             elapsed: None,
             wave_info: None,
+            topics: Vec::new(),
         }
     }
 
@@ -1848,6 +1991,56 @@ mod tests {
         );
     }
 
+    #[test]
+    fn event_log_records_topic_and_triggered_hat() {
+        use std::collections::HashMap;
+
+        let mut hat_map = HashMap::new();
+        hat_map.insert(
+            "review.security".to_string(),
+            (
+                HatId::new("security_reviewer"),
+                "🔒 Security Reviewer".to_string(),
+            ),
+        );
+        let mut state = TuiState::with_hat_map(hat_map);
+        state.show_event_panel = true;
+
+        state.update(&Event::new("review.security", "Review PR #123"));
+        state.update(&Event::new("review.done", "LGTM").with_source("security_reviewer"));
+        state.update(&Event::new("unknown.topic", "Some payload"));
+        state.update(&Event::new("task.start", "Restart").with_target("builder"));
+
+        let entries: Vec<_> = state
+            .event_log
+            .iter()
+            .map(|e| (e.topic.as_str(), e.source.as_deref(), e.hat.as_deref()))
+            .collect();
+        assert_eq!(
+            entries,
+            vec![
+                ("review.security", None, Some("🔒 Security Reviewer")),
+                ("review.done", Some("🔒 Security Reviewer"), None),
+                ("unknown.topic", None, None),
+                ("task.start", None, Some("builder")),
+            ]
+        );
+        assert!(
+            state.show_event_panel,
+            "task.start should not hide the event panel"
+        );
+    }
+
+    #[test]
+    fn event_log_is_capped() {
+        let mut state = TuiState::new();
+        for i in 0..EVENT_LOG_CAPACITY + 5 {
+            state.update(&Event::new(format!("topic.{i}"), ""));
+        }
+        assert_eq!(state.event_log.len(), EVENT_LOG_CAPACITY);
+        assert_eq!(state.event_log.front().unwrap().topic, "topic.5");
+    }
+
     #[test]
     fn task_start_preserves_iterations_across_reset() {
         // Regression test: task.start used to do *self = Self::new() which wiped
@@ -0,0 +1,153 @@
+//! Event stream side panel widget.
+//!
+//! Lists published events (publishing hat: topic → triggered hat) as they
+//! happen, newest at the bottom. Toggled with `p`.
+
+use crate::state::TuiState;
+use ralph_proto::Topic;
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Widget},
+};
+
+/// Side panel showing the tail of the event stream.
+pub struct EventPanel<'a> {
+    state: &'a TuiState,
+}
+
+impl<'a> EventPanel<'a> {
+    pub fn new(state: &'a TuiState) -> Self {
+        Self { state }
+    }
+}
+
+impl Widget for EventPanel<'_> {
+    fn render(self, area: Rect, buf: &mut ratatui::buffer::Buffer) {
+        let block = Block::default().borders(Borders::LEFT).title(" Events ");
+        let inner_area = block.inner(area);
+        block.render(area, buf);
+
+        // Show only the most recent events that fit, keeping the newest at the bottom
+        let visible = inner_area.height as usize;
+        let skip = self.state.event_log.len().saturating_sub(visible);
+        let lines: Vec<Line> = self
+            .state
+            .event_log
+            .iter()
+            .skip(skip)
+            .map(|entry| {
+                let mut spans = Vec::new();
+                if let Some(source) = &entry.source {
+                    spans.push(Span::styled(
+                        format!("{source}: "),
+                        Style::default().fg(Color::DarkGray),
+                    ));
+                }
+                spans.push(Span::styled(
+                    entry.topic.clone(),
+                    Style::default().fg(topic_color(&entry.topic)),
+                ));
+                if let Some(hat) = &entry.hat {
+                    spans.push(Span::styled(" → ", Style::default().fg(Color::DarkGray)));
+                    spans.push(Span::raw(hat.clone()));
+                }
+                Line::from(spans)
+            })
+            .collect();
+
+        Paragraph::new(lines).render(inner_area, buf);
+    }
+}
+
+/// Colors cycled across topic families, picked by hashing the topic prefix so
+/// `spec.*` and `deploy.*` events stay distinguishable and stable across runs.
+const TOPIC_PALETTE: [Color; 8] = [
+    Color::Cyan,
+    Color::Magenta,
+    Color::Yellow,
+    Color::Blue,
+    Color::LightCyan,
+    Color::LightMagenta,
+    Color::LightYellow,
+    Color::LightBlue,
+];
+
+fn topic_color(topic: &str) -> Color {
+    TOPIC_PALETTE[Topic::new(topic).prefix_bucket(TOPIC_PALETTE.len())]
+}
+
+/// Convenience function for rendering the event panel.
+pub fn render(state: &TuiState) -> EventPanel<'_> {
+    EventPanel::new(state)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ralph_proto::Event;
+    use ratatui::Terminal;
+    use ratatui::backend::TestBackend;
+
+    fn render_to_string(state: &TuiState, height: u16) -> String {
+        let backend = TestBackend::new(40, height);
+        let mut terminal = Terminal::new(backend).unwrap();
+
+        terminal
+            .draw(|f| {
+                f.render_widget(render(state), f.area());
+            })
+            .unwrap();
+
+        terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect::<String>()
+    }
+
+    #[test]
+    fn shows_topic_and_triggered_hat() {
+        let mut state = TuiState::new();
+        state.update(
+            &Event::new("build.task", "Implement feature")
+                .with_source("planner")
+                .with_target("builder"),
+        );
+
+        let text = render_to_string(&state, 3);
+        assert!(text.contains("Events"), "should show panel title: {text}");
+        assert!(text.contains("planner: "), "should show source hat: {text}");
+        assert!(text.contains("build.task"), "should show topic: {text}");
+        assert!(
+            text.contains("builder"),
+            "should show triggered hat: {text}"
+        );
+    }
+
+    #[test]
+    fn shows_most_recent_events_when_full() {
+        let mut state = TuiState::new();
+        for topic in ["first.topic", "second.topic", "third.topic"] {
+            state.update(&Event::new(topic, ""));
+        }
+
+        // Title row + two visible rows
+        let text = render_to_string(&state, 3);
+        assert!(
+            !text.contains("first.topic"),
+            "oldest should scroll off: {text}"
+        );
+        assert!(text.contains("second.topic"));
+        assert!(text.contains("third.topic"));
+    }
+
+    #[test]
+    fn topic_color_is_stable_per_prefix() {
+        assert_eq!(topic_color("spec.ready"), topic_color("spec.approved"));
+        assert_eq!(topic_color("deploy.start"), topic_color("deploy.done"));
+    }
+}
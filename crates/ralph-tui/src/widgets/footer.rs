@@ -94,20 +94,25 @@ impl Widget for Footer<'_> {
             let match_info = if query.is_empty() {
                 // Still typing the query; no count to show yet.
                 String::new()
-            } else if self.state.search_state.matches.is_empty() {
+            } else if self.state.search_state.match_count() == 0 {
                 "no matches".to_string()
             } else {
                 format!(
                     "{}/{}",
                     self.state.search_state.current_match + 1,
-                    self.state.search_state.matches.len()
+                    self.state.search_state.match_count()
                 )
             };
+            let label = if self.state.search_state.topic_mode {
+                "Topic"
+            } else {
+                "Search"
+            };
 
             let line = Line::from(vec![
                 Span::raw(" "),
                 Span::styled(
-                    format!("Search: {} ", query),
+                    format!("{}: {} ", label, query),
                     Style::default().fg(Color::Yellow),
                 ),
                 Span::styled(match_info, Style::default().fg(Color::Cyan)),
@@ -53,11 +53,19 @@ pub fn render(f: &mut Frame, area: Rect) {
             Span::styled("  m", Style::default().fg(Color::Cyan)),
             Span::raw("      Toggle mouse mode (select/scroll)"),
         ]),
+        Line::from(vec![
+            Span::styled("  p", Style::default().fg(Color::Cyan)),
+            Span::raw("      Toggle event stream panel"),
+        ]),
         Line::from(""),
         Line::from(Span::styled("Search:", Style::default().fg(Color::Yellow))),
         Line::from(vec![
             Span::styled("  /", Style::default().fg(Color::Cyan)),
-            Span::raw("      Start search"),
+            Span::raw("      Jump to iteration by event topic"),
+        ]),
+        Line::from(vec![
+            Span::styled("  f", Style::default().fg(Color::Cyan)),
+            Span::raw("      Search text in this iteration"),
         ]),
         Line::from(vec![
             Span::styled("  n/N", Style::default().fg(Color::Cyan)),
@@ -1,4 +1,5 @@
 pub mod content;
+pub mod events;
 pub mod footer;
 pub mod header;
 pub mod help;
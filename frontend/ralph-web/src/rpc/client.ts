@@ -29,6 +29,7 @@ const MUTATING_METHODS = new Set<string>([
   "collection.delete",
   "collection.import",
   "collection.run",
+  "collection.restart",
 ]);
 
 let requestCounter = 0;